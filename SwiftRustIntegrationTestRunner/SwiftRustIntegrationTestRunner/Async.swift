@@ -0,0 +1,16 @@
+//
+//  Async.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_async_reflect_u32(arg: UInt32) async -> UInt32 {
+    await Task.yield()
+    return arg
+}
+
+func swift_async_reflect_string(arg: RustString) async -> String {
+    await Task.yield()
+    return arg.toString()
+}
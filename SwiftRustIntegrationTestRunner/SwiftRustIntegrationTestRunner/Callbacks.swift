@@ -34,6 +34,24 @@ func swift_takes_two_fnonce_callbacks(
     return arg2(3)
 }
 
+func swift_calls_fn_callback_three_times(arg: @escaping (UInt32) -> UInt32) -> UInt32 {
+    arg(1) + arg(2) + arg(3)
+}
+
+var storedFnCallback: (() -> ())?
+
+func swift_stores_fn_callback(arg: @escaping () -> ()) {
+    storedFnCallback = arg
+}
+
+func swift_calls_stored_fn_callback() {
+    storedFnCallback!()
+}
+
+func swift_drops_stored_fn_callback() {
+    storedFnCallback = nil
+}
+
 func swift_takes_fnonce_callback_with_two_params(
     arg: (UInt8, UInt16) -> UInt16
 ) -> UInt16 {
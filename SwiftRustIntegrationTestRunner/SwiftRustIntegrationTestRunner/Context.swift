@@ -0,0 +1,28 @@
+//
+//  Context.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// A request ID that `swift_bridge::context` propagates between Swift and Rust.
+class RequestIdContextPropagator: ContextPropagator {
+    private static let key = "swift-bridge.integration-tests.request-id"
+
+    static var current: String? {
+        get { Thread.current.threadDictionary[key] as? String }
+        set { Thread.current.threadDictionary[key] = newValue }
+    }
+
+    func capture() -> String? {
+        Self.current
+    }
+
+    func restore(_ value: String?) {
+        Self.current = value
+    }
+}
+
+func context_test_swift_request_id() -> RustString {
+    (RequestIdContextPropagator.current ?? "").intoRustString()
+}
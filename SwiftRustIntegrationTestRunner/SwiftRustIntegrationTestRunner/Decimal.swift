@@ -0,0 +1,10 @@
+//
+//  Decimal.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_decimal_multiply(lhs: Decimal, rhs: Decimal) -> Decimal {
+    lhs * rhs
+}
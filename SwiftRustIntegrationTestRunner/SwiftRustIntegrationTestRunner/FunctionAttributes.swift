@@ -10,3 +10,31 @@ import Foundation
 func testCallSwiftFromRustByNameAttribute() -> RustString {
     return "StringFromSwift".intoRustString()
 }
+
+/// Calls back into a different `NonReentrantCounter` than the one that notified it.
+public class NonReentrantListener {
+    let other: NonReentrantCounter
+    var counts: [UInt32] = []
+
+    init(other: NonReentrantCounter) {
+        self.other = other
+    }
+
+    func on_increment(count: UInt32) {
+        counts.append(count)
+        _ = other.increment()
+    }
+}
+
+func swift_subtract_with_argument_labels(_ lhs: Int32, minus rhs: Int32) -> Int32 {
+    lhs - rhs
+}
+
+/// The calls that made it through the `swift_bridge(throttle_ms)` attribute.
+var throttledProgressCalls: [(UInt32, String)] = []
+var onThrottledProgress: ((UInt32) -> ())?
+
+func swift_throttled_progress(completed: UInt32, label: RustString) {
+    throttledProgressCalls.append((completed, label.toString()))
+    onThrottledProgress?(completed)
+}
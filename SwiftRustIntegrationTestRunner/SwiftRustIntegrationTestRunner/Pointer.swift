@@ -21,3 +21,7 @@ func swift_echo_const_u8(ptr: UnsafePointer<UInt8>) -> UnsafePointer<UInt8> {
 func swift_echo_mut_u8(ptr: UnsafeMutablePointer<UInt8>) -> UnsafeMutablePointer<UInt8> {
     ptr
 }
+
+func swift_read_u8(ptr: UnsafePointer<UInt8>) -> UInt8 {
+    ptr.pointee
+}
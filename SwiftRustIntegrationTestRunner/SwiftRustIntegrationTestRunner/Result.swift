@@ -24,7 +24,6 @@ public class ResultTestOpaqueSwiftType {
 }
 
 extension ResultTestOpaqueRustType: @unchecked Sendable {}
-extension ResultTestOpaqueRustType: Error {}
 
 extension AsyncResultOpaqueRustType1: @unchecked Sendable {}
 extension AsyncResultOpaqueRustType1: Error {}
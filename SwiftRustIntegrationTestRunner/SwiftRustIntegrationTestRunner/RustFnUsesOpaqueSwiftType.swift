@@ -19,3 +19,14 @@ public class SomeSwiftType {
         self.text = text.toString()
     }
 }
+
+/// We expose this to the `opaque_type_attributes/already_declared.rs` test.
+public class AlreadyDeclaredSwiftTypeTest {
+    func a_ref_method() -> Bool {
+        true
+    }
+}
+
+func already_declared_swift_type_test_new() -> AlreadyDeclaredSwiftTypeTest {
+    AlreadyDeclaredSwiftTypeTest()
+}
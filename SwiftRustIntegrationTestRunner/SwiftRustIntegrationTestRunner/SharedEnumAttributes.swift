@@ -14,3 +14,10 @@ func extern_swift_enum_rename(arg: EnumRename) -> EnumRename {
 func swift_reflect_already_declared_enum(arg: AlreadyDeclaredEnumTest) -> AlreadyDeclaredEnumTest {
     arg
 }
+
+/// A handwritten enum that is mirrored by the bridged `TrafficLight` enum.
+enum HandwrittenTrafficLight {
+    case Red
+    case Yellow
+    case Green
+}
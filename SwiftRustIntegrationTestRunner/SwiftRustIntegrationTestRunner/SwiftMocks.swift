@@ -0,0 +1,22 @@
+//
+//  SwiftMocks.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+class SwiftMockedGreeter {
+    private let name: String
+
+    init(name: RustStr) {
+        self.name = name.toString()
+    }
+
+    func greet(times: UInt8) -> String {
+        "hello \(name) x\(times)"
+    }
+}
+
+func swift_mocked_add(a: UInt8, b: UInt8) -> UInt8 {
+    a + b
+}
@@ -0,0 +1,44 @@
+//
+//  ArcTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::sync::Arc<T>` where `T` is an opaque Rust type.
+class ArcTests: XCTestCase {
+    /// Verify that Swift can call `&self` methods through an `Arc`.
+    func testCallMethodThroughArc() throws {
+        let counter = rust_arc_new_counter()
+
+        XCTAssertEqual(counter.increment(), 1)
+        XCTAssertEqual(counter.increment(), 2)
+    }
+
+    /// Verify that cloning an `Arc` in Swift bumps the strong count and that releasing a clone
+    /// decrements it.
+    func testCloneBumpsStrongCount() throws {
+        let counter = rust_arc_new_counter()
+        XCTAssertEqual(rust_arc_swift_strong_count(counter), 1)
+
+        var clone: ArcCounterArc? = counter.clone()
+        XCTAssertEqual(rust_arc_swift_strong_count(counter), 2)
+        XCTAssertEqual(clone!.increment(), 1)
+        XCTAssertEqual(counter.increment(), 2)
+
+        clone = nil
+        XCTAssertEqual(rust_arc_swift_strong_count(counter), 1)
+    }
+
+    /// Verify that Rust keeps the value alive after Swift releases its reference.
+    func testRustAndSwiftShareOwnership() throws {
+        var counter: ArcCounterArc? = rust_arc_new_counter()
+        XCTAssertEqual(counter!.increment(), 1)
+
+        rust_arc_keep_counter(counter!)
+        counter = nil
+
+        XCTAssertEqual(rust_arc_increment_kept_counter(), 2)
+    }
+}
@@ -18,4 +18,16 @@ final class ArgumentAttributesTest: XCTestCase {
         XCTAssertEqual(test_argument_label(someArg: 10, 100), 110)
     }
 
+    /// Verify that a method argument can use a Swift keyword as its label.
+    func testMethodArgumentLabel() throws {
+        let directory = ArgumentLabelDirectory()
+
+        XCTAssertEqual(directory.lookup(for: 5), 50)
+    }
+
+    /// Verify that Rust calls an extern "Swift" function with its argument labels.
+    func testExternSwiftArgumentLabels() throws {
+        XCTAssertEqual(rust_calls_swift_with_argument_labels(), 7)
+    }
+
 }
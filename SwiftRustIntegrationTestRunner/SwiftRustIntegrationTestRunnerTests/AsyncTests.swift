@@ -31,6 +31,24 @@ class AsyncTests: XCTestCase {
         let string = await rust_async_reflect_string("hello world")
         XCTAssertEqual(string.toString(), "hello world")
     }
+
+    /// Verify that an async Rust function can await an async Swift function.
+    func testRustAsyncFnCallsSwiftAsyncFn() async throws {
+        let num = await rust_async_calls_swift_async_reflect_u32(456)
+        XCTAssertEqual(num, 456)
+
+        let string = await rust_async_calls_swift_async_reflect_string("hello world")
+        XCTAssertEqual(string.toString(), "hello world")
+    }
+
+    /// Verify that we can pass and return an Option<String> to an async Rust function
+    func testSwiftCallsRustAsyncFnReflectOptionString() async throws {
+        let string = await rust_async_reflect_option_string("hello world")
+        XCTAssertEqual(string!.toString(), "hello world")
+
+        let none: String? = nil
+        XCTAssertNil(await rust_async_reflect_option_string(none))
+    }
     
     /// Verify that we can call async Rust methods
     func testSwiftCallsRustAsyncMethodReflectU16() async throws {
@@ -0,0 +1,47 @@
+//
+//  AtomicCellTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for sharing a `swift_bridge::atomic::AtomicCell<T>` between Swift and Rust.
+class AtomicCellTests: XCTestCase {
+    /// Verify that Swift sees the value that Rust created the cell with.
+    func testLoadRustCreatedCell() throws {
+        let cell = rust_atomic_cell_new(5)
+
+        XCTAssertEqual(cell.load(), 5)
+    }
+
+    /// Verify that Swift and Rust share the same value.
+    func testSwiftAndRustShareValue() throws {
+        let counter = rust_atomic_cell_new(0)
+
+        rust_atomic_cell_increment(counter)
+        XCTAssertEqual(counter.load(), 1)
+
+        counter.store(10)
+        rust_atomic_cell_increment(counter)
+        XCTAssertEqual(counter.load(), 11)
+    }
+
+    /// Verify that Rust sees values that Swift stores in a cell that Swift created.
+    func testSwiftCreatedCell() throws {
+        let cell = RustAtomicCell(1.5)
+        XCTAssertEqual(rust_atomic_cell_load_f64(cell), 1.5)
+
+        cell.store(-2.25)
+        XCTAssertEqual(rust_atomic_cell_load_f64(cell), -2.25)
+    }
+
+    /// Verify that Swift sees a store that Rust made from another thread.
+    func testStoreFromRustThread() throws {
+        let flag = RustAtomicCell(false)
+
+        rust_atomic_cell_set_from_other_thread(flag)
+
+        XCTAssertTrue(flag.load())
+    }
+}
@@ -0,0 +1,32 @@
+//
+//  BreadcrumbsTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the breadcrumbs that `#[swift_bridge::bridge(breadcrumbs)]` modules record.
+class BreadcrumbsTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that calling functions and methods in a breadcrumbs module records breadcrumbs.
+    func testRecordsBreadcrumbs() throws {
+        breadcrumbs_test_function()
+        let value = BreadcrumbsTestType()
+        value.some_method()
+
+        let breadcrumbs = rustBreadcrumbs().suffix(3)
+
+        XCTAssertEqual(breadcrumbs.count, 3)
+        XCTAssert(breadcrumbs[breadcrumbs.startIndex].hasPrefix("breadcrumbs_test_function on thread "))
+        XCTAssert(breadcrumbs[breadcrumbs.startIndex + 1].hasPrefix("BreadcrumbsTestType::new on thread "))
+        XCTAssert(breadcrumbs[breadcrumbs.startIndex + 2].hasPrefix("BreadcrumbsTestType::some_method on thread "))
+    }
+}
@@ -0,0 +1,70 @@
+//
+//  ByteStreamTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for consuming a `swift_bridge::byte_stream::ByteStream` as an `AsyncSequence`.
+class ByteStreamTests: XCTestCase {
+    /// Verify that we receive every chunk in order, even though Rust has to wait for us to
+    /// consume each chunk before it can send the next one.
+    func testReceivesChunksInOrder() async throws {
+        var chunks: [Data] = []
+        for await chunk in byte_stream_test_chunks(5, 1024) {
+            chunks.append(chunk)
+        }
+
+        XCTAssertEqual(chunks.count, 5)
+        for (n, chunk) in chunks.enumerated() {
+            XCTAssertEqual(chunk, Data(repeating: UInt8(n), count: 1024))
+        }
+    }
+
+    /// Verify that a stream whose sender was dropped without sending anything ends immediately.
+    func testEmptyStream() async throws {
+        var chunkCount = 0
+        for await _ in byte_stream_test_empty() {
+            chunkCount += 1
+        }
+
+        XCTAssertEqual(chunkCount, 0)
+    }
+
+    /// Verify that we can stop consuming a stream early.
+    func testStopConsumingEarly() async throws {
+        var iterator = byte_stream_test_chunks(100, 8).makeAsyncIterator()
+
+        let first = await iterator.next()
+
+        XCTAssertEqual(first, Data(repeating: 0, count: 8))
+    }
+
+    /// Verify that every task gets a chunk when multiple tasks iterate the same stream at once.
+    func testConcurrentIterators() async throws {
+        let stream = byte_stream_test_chunks(2, 8)
+
+        async let firstChunk = stream.nextChunk()
+        async let secondChunk = stream.nextChunk()
+        let chunks = await [firstChunk, secondChunk]
+
+        XCTAssertEqual(Set(chunks.map { $0!.first! }), [0, 1])
+    }
+
+    /// Verify that cancelling a task that is waiting for a chunk ends its iteration.
+    func testCancelWhileWaiting() async throws {
+        let task = Task { () -> Int in
+            var chunkCount = 0
+            for await _ in byte_stream_test_never_ends() {
+                chunkCount += 1
+            }
+            return chunkCount
+        }
+
+        task.cancel()
+
+        let chunkCount = await task.value
+        XCTAssertEqual(chunkCount, 0)
+    }
+}
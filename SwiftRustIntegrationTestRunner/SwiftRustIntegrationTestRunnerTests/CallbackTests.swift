@@ -14,4 +14,41 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+
+    /// Run our tests where Rust passes a `Box<dyn Fn>` to Swift, which can call it any number of
+    /// times and frees it when the Swift closure is released.
+    func testRustPassesFnClosuresToSwift() throws {
+        test_rust_passes_fn_closures_to_swift()
+    }
+
+    /// Verify that Rust can call a Swift closure that was passed as a `Box<dyn Fn(u32)>`.
+    func testRustCallsSwiftClosure() throws {
+        var nums: [UInt32] = []
+
+        rust_calls_swift_closure_for_each(3, { num in nums.append(num) })
+
+        XCTAssertEqual(nums, [0, 1, 2])
+    }
+
+    /// Verify that a Swift closure is released when Rust drops its `Box<dyn Fn(u32) -> u32>`.
+    func testSwiftClosureReleasedWhenRustDropsIt() throws {
+        class Multiplier {
+            let factor: UInt32 = 3
+        }
+
+        weak var weakMultiplier: Multiplier?
+        var holder: SwiftClosureHolder?
+
+        do {
+            let multiplier = Multiplier()
+            weakMultiplier = multiplier
+            holder = rust_holds_swift_closure({ num in num * multiplier.factor })
+        }
+
+        XCTAssertEqual(holder!.call(5), 15)
+        XCTAssertNotNil(weakMultiplier)
+
+        holder = nil
+        XCTAssertNil(weakMultiplier)
+    }
 }
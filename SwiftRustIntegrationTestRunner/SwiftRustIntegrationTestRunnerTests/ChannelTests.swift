@@ -0,0 +1,51 @@
+//
+//  ChannelTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::sync::mpsc::Sender<T>` and `Receiver<T>`.
+class ChannelTests: XCTestCase {
+    /// Verify that we can receive values that Rust sends from another thread, and that iterating
+    /// a receiver stops once every sender has been dropped.
+    func testReceiveFromRust() throws {
+        let receiver = rust_channel_of_numbers(5)
+
+        XCTAssertEqual(Array(receiver), [0, 1, 2, 3, 4])
+        XCTAssertNil(receiver.recv())
+    }
+
+    /// Verify that Swift can send values to a Rust receiver and pass receivers back into Rust.
+    func testSendToRust() throws {
+        let pair = rust_channel_make_pair()
+        let sender = pair.sender()
+        let doubled = rust_channel_doubler(pair.receiver())
+
+        XCTAssertTrue(sender.send(1))
+        XCTAssertTrue(sender.clone().send(2))
+        XCTAssertEqual(doubled.recv(), 2)
+        XCTAssertEqual(doubled.recv(), 4)
+    }
+
+    /// Verify that sending fails once the receiver has been dropped.
+    func testSendAfterReceiverDropped() throws {
+        let pair = rust_channel_make_pair()
+        let sender = pair.sender()
+        _ = pair.receiver()
+
+        XCTAssertFalse(sender.send(1))
+    }
+
+    /// Verify that tryRecv returns nil instead of blocking when no value is waiting.
+    func testTryRecv() throws {
+        let pair = rust_channel_make_pair()
+        let sender = pair.sender()
+        let receiver = pair.receiver()
+
+        XCTAssertNil(receiver.tryRecv())
+        sender.send(10)
+        XCTAssertEqual(receiver.tryRecv(), 10)
+    }
+}
@@ -25,4 +25,23 @@ class ConditionalCompilationTests: XCTestCase {
     func testConditionalCompilation() throws {
         XCTAssertEqual(conditionally_exposed_fn(), 123)
     }
+
+    /// Verify that Swift can check which of the Rust crate's cargo features are enabled.
+    /// The build script exposes the "this_is_enabled" and "this_is_not_enabled" features.
+    func testBridgeFeatures() throws {
+        XCTAssertTrue(BridgeFeatures.this_is_enabled)
+        XCTAssertFalse(BridgeFeatures.this_is_not_enabled)
+    }
+
+    /// Verify that Swift can check which build of the Rust crate it is running.
+    /// The build script calls `expose_bridge_info`.
+    func testBridgeInfo() throws {
+        let info = bridgeInfo()
+        XCTAssertEqual(info.crateName, "swift-integration-tests")
+        XCTAssertEqual(info.crateVersion, "0.1.0")
+        XCTAssertTrue(info.features.contains("this_is_enabled"))
+        XCTAssertFalse(info.features.contains("this_is_not_enabled"))
+        XCTAssertEqual(info.abiVersion, 1)
+        XCTAssertTrue(info.description.hasPrefix("swift-integration-tests 0.1.0"))
+    }
 }
@@ -0,0 +1,45 @@
+//
+//  ContextTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for propagating context between Swift and Rust with `swift_bridge::context`.
+class ContextTests: XCTestCase {
+    private static let registerPropagators: Void = {
+        registerContextPropagator(name: "request_id", RequestIdContextPropagator())
+        register_context_test_propagator()
+    }()
+
+    override func setUpWithError() throws {
+        _ = Self.registerPropagators
+        RequestIdContextPropagator.current = nil
+    }
+
+    override func tearDownWithError() throws {
+        RequestIdContextPropagator.current = nil
+    }
+
+    /// Verify that Swift's context is restored in Rust when Swift calls a Rust function.
+    func testSwiftContextIsPropagatedToRust() throws {
+        RequestIdContextPropagator.current = "swift-request"
+
+        XCTAssertEqual(context_test_rust_request_id().toString(), "swift-request")
+    }
+
+    /// Verify that Rust's context is restored in Swift when Rust calls a Swift function, and that
+    /// Swift's own context is put back afterwards.
+    func testRustContextIsPropagatedToSwift() throws {
+        RequestIdContextPropagator.current = "swift-request"
+
+        XCTAssertEqual(context_test_call_swift_with_request_id("rust-request").toString(), "rust-request")
+        XCTAssertEqual(RequestIdContextPropagator.current, "swift-request")
+    }
+
+    /// Verify that Rust does not see any context when Swift does not have any.
+    func testNoContext() throws {
+        XCTAssertEqual(context_test_rust_request_id().toString(), "")
+    }
+}
@@ -0,0 +1,28 @@
+//
+//  CrossModuleTypeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridge modules that use opaque types that other bridge modules declared.
+class CrossModuleTypeTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that we can pass a type that one bridge module declared to a function that another
+    /// bridge module declared.
+    func testPassTypeDeclaredInAnotherModule() throws {
+        let scene = CrossModuleScene()
+        scene.add_shape()
+        scene.add_shape()
+
+        XCTAssertEqual(cross_module_render(scene), 2)
+    }
+}
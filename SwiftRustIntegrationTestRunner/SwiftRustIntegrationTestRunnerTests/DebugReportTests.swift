@@ -0,0 +1,53 @@
+//
+//  DebugReportTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for reporting the state of the Rust runtime with `rustBridgeReport()`.
+class DebugReportTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that the report counts the values of hardened types that Swift holds handles to.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/hardened.rs
+    func testReportCountsLiveHandles() throws {
+        let typeName = "swift_integration_tests::opaque_type_attributes::hardened::RustHardenedType"
+        let before = rustBridgeReport().liveHandles[typeName] ?? 0
+
+        let val = RustHardenedType(5)
+        XCTAssertEqual(rustBridgeReport().liveHandles[typeName], before + 1)
+
+        XCTAssertEqual(val.into_secret(), 5)
+        XCTAssertEqual(rustBridgeReport().liveHandles[typeName] ?? 0, before)
+    }
+
+    /// Verify that the report counts the callbacks that Rust registered.
+    ///
+    /// See crates/swift-integration-tests/src/memory_pressure.rs
+    func testReportCountsRegisteredCallbacks() throws {
+        let before = rustBridgeReport().purgeCallbacks
+
+        register_memory_pressure_test_cache()
+
+        XCTAssertEqual(rustBridgeReport().purgeCallbacks, before + 1)
+    }
+
+    /// Verify that the report counts the values that are waiting for a scope to end.
+    func testReportCountsDeferredFrees() throws {
+        withRustScope {
+            _ = ScopeTestValue()
+            XCTAssertEqual(rustBridgeReport().deferredFrees, 1)
+        }
+
+        XCTAssertEqual(rustBridgeReport().deferredFrees, 0)
+    }
+}
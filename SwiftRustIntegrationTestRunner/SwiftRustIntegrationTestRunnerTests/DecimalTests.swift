@@ -0,0 +1,49 @@
+//
+//  DecimalTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing Foundation's `Decimal` to and from Rust as a `rust_decimal::Decimal`.
+class DecimalTests: XCTestCase {
+    /// Verify that decimals keep their exact value when passed to Rust and back.
+    func testDecimalRoundTrip() throws {
+        for string in ["0", "1", "-1", "19.99", "-0.000001", "79228162514264337593543950335", "0.0000000000000000000000000001"] {
+            let value = Decimal(string: string)!
+
+            XCTAssertEqual(rust_decimal_test_identity(value), value)
+            XCTAssertEqual(rust_decimal_test_to_string(value).toString(), string)
+        }
+    }
+
+    /// Verify that Rust does decimal arithmetic on Swift's decimals without floating point error.
+    func testDecimalArithmetic() throws {
+        let sum = rust_decimal_test_add(Decimal(string: "0.1")!, Decimal(string: "0.2")!)
+
+        XCTAssertEqual(sum, Decimal(string: "0.3")!)
+    }
+
+    /// Verify that a Foundation decimal with a positive exponent is scaled into Rust's mantissa.
+    func testDecimalWithPositiveExponent() throws {
+        let value = Decimal(sign: .plus, exponent: 3, significand: 12)
+
+        XCTAssertEqual(rust_decimal_test_to_string(value).toString(), "12000")
+    }
+
+    /// Verify that shared structs can have decimal fields.
+    func testDecimalStructFields() throws {
+        let invoice = rust_decimal_test_invoice(Decimal(string: "250.50")!)
+
+        XCTAssertEqual(invoice.subtotal, Decimal(string: "250.50")!)
+        XCTAssertEqual(invoice.tax, Decimal(string: "20.04")!)
+    }
+
+    /// Verify that Rust can pass decimals to Swift functions and get decimals back.
+    func testRustCallsSwiftWithDecimals() throws {
+        let product = rust_calls_swift_decimal_multiply(Decimal(string: "1.5")!, Decimal(string: "-2.25")!)
+
+        XCTAssertEqual(product, Decimal(string: "-3.375")!)
+    }
+}
@@ -24,4 +24,171 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that the `swift_bridge(progress)` attribute lets Rust report progress to a Swift closure.
+    func testProgressAttribute() throws {
+        var reported: [Double] = []
+        let count = count_to_with_progress(4, progress: { fractionCompleted in
+            reported.append(fractionCompleted)
+        })
+
+        XCTAssertEqual(count, 4)
+        XCTAssertEqual(reported, [0.25, 0.5, 0.75, 1.0])
+    }
+
+    /// Verify that the progress closure for a `swift_bridge(progress)` function is optional.
+    func testProgressAttributeWithoutClosure() throws {
+        XCTAssertEqual(count_to_with_progress(4), 4)
+        XCTAssertFalse(progress_is_observed())
+        XCTAssertTrue(progress_is_observed(progress: { _ in }))
+    }
+
+    /// Verify that a `swift_bridge(cancellable)` function runs to completion when it is not cancelled.
+    func testCancellableAttributeNotCancelled() throws {
+        XCTAssertEqual(count_until_cancelled(100), 100)
+        XCTAssertEqual(count_until_cancelled(100, cancellationToken: CancellationToken()), 100)
+    }
+
+    /// Verify that a `swift_bridge(cancellable)` function sees a token that was cancelled in Swift.
+    func testCancellableAttributeCancelledInSwift() throws {
+        let token = CancellationToken()
+        token.cancel()
+
+        XCTAssertTrue(token.isCancelled)
+        XCTAssertEqual(count_until_cancelled(100, cancellationToken: token), 0)
+    }
+
+    /// Verify that Swift sees a cancellation that happened in Rust.
+    func testCancellableAttributeCancelledInRust() throws {
+        let token = CancellationToken()
+
+        XCTAssertEqual(cancel_after(5, 100, cancellationToken: token), 5)
+        XCTAssertTrue(token.isCancelled)
+    }
+
+    /// Verify that the `swift_bridge(timeout)` variant returns the value of a call that finishes in time.
+    func testTimeoutAttributeFinishesInTime() async throws {
+        let value = try await timeout_add_one(1, timeout: 5)
+        XCTAssertEqual(value, 2)
+    }
+
+    /// Verify that the `swift_bridge(timeout)` variant throws when the call takes too long, and that
+    /// it cancels the Rust function.
+    func testTimeoutAttributeTimesOut() async throws {
+        do {
+            _ = try await timeout_loop_until_cancelled(timeout: 0.05)
+            XCTFail("Expected the call to time out")
+        } catch let error as RustTimeoutError {
+            XCTAssertEqual(error.seconds, 0.05)
+        }
+
+        // The Rust function keeps running on its own thread until it notices the cancellation.
+        var attempts = 0
+        while !timeout_loop_saw_cancellation() && attempts < 100 {
+            try await Task.sleep(nanoseconds: 10_000_000)
+            attempts += 1
+        }
+        XCTAssertTrue(timeout_loop_saw_cancellation())
+    }
+
+    /// Verify that a `swift_bridge(swift_data)` function returns a `Vec<u8>` as `Data`.
+    func testSwiftDataAttributeReturnsData() throws {
+        XCTAssertEqual(swift_data_make_bytes(4), Data([0, 1, 2, 3]))
+        XCTAssertEqual(swift_data_make_bytes(0), Data())
+    }
+
+    /// Verify that a `swift_bridge(swift_data)` function accepts `Data` as a `Vec<u8>` or `&[u8]`.
+    func testSwiftDataAttributeTakesData() throws {
+        let data = Data([1, 2, 3, 250])
+
+        XCTAssertEqual(swift_data_sum_vec(data), 256)
+        XCTAssertEqual(swift_data_sum_slice(data), 256)
+        XCTAssertEqual(swift_data_sum_slice(Data()), 0)
+        XCTAssertEqual(swift_data_reverse(data), Data([250, 3, 2, 1]))
+    }
+
+    /// Verify that a `swift_bridge(non_reentrant)` method can be called again once it returned, and
+    /// that a callback can call `non_reentrant` methods on other objects.
+    func testNonReentrantAttribute() throws {
+        let counter = NonReentrantCounter()
+        let other = NonReentrantCounter()
+        let listener = NonReentrantListener(other: other)
+
+        XCTAssertEqual(counter.increment_and_notify(listener), 1)
+        XCTAssertEqual(listener.counts, [1])
+        XCTAssertEqual(counter.increment(), 2)
+        XCTAssertEqual(other.increment(), 2)
+    }
+
+    /// Verify that `swift_bridge(init, labeled_args)` initializers that take the same argument
+    /// types are told apart by their labels.
+    func testLabeledArgsInitializers() throws {
+        XCTAssertEqual(LabeledGreeter(name: "Ada").greeting().toString(), "Hello, Ada!")
+        XCTAssertEqual(
+            LabeledGreeter(title: "Countess", name: "Ada").greeting().toString(),
+            "Hello, Countess Ada!"
+        )
+        XCTAssertEqual(LabeledGreeter(nickname: "Ada").greeting().toString(), "Hello, \"Ada\"!")
+    }
+
+    /// Verify that `swift_bridge(get)` and `swift_bridge(set)` methods become Swift properties.
+    func testPropertyAttributes() throws {
+        let speaker = PropertySpeaker()
+        XCTAssertEqual(speaker.volume, 50)
+        XCTAssertFalse(speaker.is_muted)
+
+        speaker.volume = 0
+        XCTAssertEqual(speaker.volume, 0)
+        XCTAssertTrue(speaker.is_muted)
+
+        speaker.volume += 150
+        XCTAssertEqual(speaker.volume, 100)
+
+        speaker.displayName = "Kitchen".intoRustString()
+        XCTAssertEqual(speaker.displayName.toString(), "Kitchen")
+    }
+
+    /// Verify that Rust constants and statics become Swift properties.
+    func testConstants() throws {
+        XCTAssertEqual(ConstantServer.MAX_USERS, 500)
+        XCTAssertEqual(ConstantServer.defaultPort, 8080)
+        XCTAssertEqual(SERVER_NAME.toString(), "swift-bridge")
+    }
+
+    /// Verify that `swift_bridge(since = "...")` functions are listed in `RustApi` and are
+    /// available when the Rust library that has them is loaded.
+    func testSinceAttribute() throws {
+        XCTAssertEqual(RustApi.versioned_double.name, "versioned_double")
+        XCTAssertEqual(RustApi.versioned_double.since, "1.2.3")
+        XCTAssertTrue(RustApi.versioned_double.isAvailable)
+        XCTAssertEqual(versioned_double(21), 42)
+
+        XCTAssertEqual(RustApi.VersionedCounter.increment.name, "VersionedCounter.increment")
+        XCTAssertEqual(RustApi.VersionedCounter.increment.since, "1.1")
+        XCTAssertTrue(RustApi.VersionedCounter.increment.isAvailable)
+        XCTAssertEqual(VersionedCounter().increment(), 1)
+    }
+
+    /// Verify that a `swift_bridge(throttle_ms)` function is called on the main thread far fewer
+    /// times than Rust calls it, and that the latest call always makes it through.
+    func testThrottleAttribute() throws {
+        throttledProgressCalls = []
+        let finished = expectation(description: "The last progress update arrives")
+        onThrottledProgress = { completed in
+            XCTAssertTrue(Thread.isMainThread)
+            if completed == 10_000 {
+                finished.fulfill()
+            }
+        }
+
+        throttle_report_progress(10_000)
+
+        wait(for: [finished], timeout: 5)
+        onThrottledProgress = nil
+
+        XCTAssertEqual(throttledProgressCalls.first?.0, 1)
+        XCTAssertEqual(throttledProgressCalls.last?.0, 10_000)
+        XCTAssertEqual(throttledProgressCalls.last?.1, "10000 of 10000")
+        XCTAssertLessThan(throttledProgressCalls.count, 100)
+    }
 }
@@ -23,6 +23,18 @@ class GenericTests: XCTestCase {
         let _: SomeGenericType<UInt32> = reflect_generic_u32(val)
     }
     
+    /// Verify that each monomorphization of a generic opaque Rust type has its own methods.
+    func testGenericOpaqueRustTypeMethods() {
+        let val = SomeGenericType<UInt32>(10)
+        XCTAssertEqual(val.field(), 10)
+
+        val.set_field(20)
+        XCTAssertEqual(val.field(), 20)
+
+        let other: SomeGenericType<Int16> = new_some_generic_type_i16()
+        XCTAssertEqual(other.field(), -5)
+    }
+    
     func testReflectGenericOpaqueCopyRustType() {
         let val = new_some_generic_copy_type_u32()
         let _: SomeGenericCopyType<UInt32> = reflect_generic_copy_u32(val)
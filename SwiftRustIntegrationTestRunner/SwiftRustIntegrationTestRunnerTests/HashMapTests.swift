@@ -0,0 +1,54 @@
+//
+//  HashMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::collections::HashMap<K, V>` as `RustMap<K, V>`.
+class HashMapTests: XCTestCase {
+    /// Verify that we can look up values in a map returned from Rust.
+    func testReturnMapFromRust() throws {
+        let counts = rust_hash_map_word_counts("a b a c a")
+
+        XCTAssertEqual(counts.count, 3)
+        XCTAssertEqual(counts["a"], 3)
+        XCTAssertEqual(counts["b"], 1)
+        XCTAssertNil(counts["d"])
+        XCTAssertEqual(counts.keys.sorted(), ["a", "b", "c"])
+    }
+
+    /// Verify that we can iterate over the entries of a map.
+    func testIterateMap() throws {
+        var entries: [String: UInt32] = [:]
+        for (key, value) in rust_hash_map_word_counts("x y y") {
+            entries[key] = value
+        }
+
+        XCTAssertEqual(entries, ["x": 1, "y": 2])
+    }
+
+    /// Verify that we can build a map in Swift and pass it to Rust.
+    func testPassMapToRust() throws {
+        let map = RustMap<UInt8, Double>()
+        map.insert(1, 1.5)
+        map.insert(2, 2.5)
+        map.insert(2, 3.0)
+
+        XCTAssertEqual(map.count, 2)
+        XCTAssertEqual(rust_hash_map_sum_values(map), 4.5)
+    }
+
+    /// Verify that we can pass a map with string keys to Rust and get a map with string values back.
+    func testPassAndReturnMap() throws {
+        let map = RustMap<String, Int64>()
+        map.insert("one", 1)
+        map.insert("two", 2)
+
+        let inverted = rust_hash_map_invert(map)
+
+        XCTAssertEqual(inverted[1], "one")
+        XCTAssertEqual(inverted[2], "two")
+    }
+}
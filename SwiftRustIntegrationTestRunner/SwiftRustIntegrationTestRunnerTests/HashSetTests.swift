@@ -0,0 +1,53 @@
+//
+//  HashSetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::collections::HashSet<T>` as `RustSet<T>`.
+class HashSetTests: XCTestCase {
+    /// Verify that we can query a set returned from Rust.
+    func testReturnSetFromRust() throws {
+        let words = rust_hash_set_unique_words("a b a c")
+
+        XCTAssertEqual(words.count, 3)
+        XCTAssertTrue(words.contains("a"))
+        XCTAssertFalse(words.contains("d"))
+        XCTAssertEqual(words.sorted(), ["a", "b", "c"])
+    }
+
+    /// Verify that inserting and removing report whether the set changed.
+    func testInsertAndRemove() throws {
+        let set = RustSet<String>()
+
+        XCTAssertTrue(set.insert("hello"))
+        XCTAssertFalse(set.insert("hello"))
+        XCTAssertTrue(set.remove("hello"))
+        XCTAssertFalse(set.remove("hello"))
+        XCTAssertEqual(set.count, 0)
+    }
+
+    /// Verify that we can build a set in Swift and pass it to Rust.
+    func testPassSetToRust() throws {
+        let set = RustSet<UInt16>()
+        set.insert(10)
+        set.insert(20)
+        set.insert(10)
+
+        XCTAssertEqual(rust_hash_set_sum(set), 30)
+    }
+
+    /// Verify that we can pass a set to Rust and get a different kind of set back.
+    func testPassAndReturnSet() throws {
+        let set = RustSet<String>()
+        set.insert("a")
+        set.insert("bb")
+        set.insert("cc")
+
+        let lengths = rust_hash_set_lengths(set)
+
+        XCTAssertEqual(Array(lengths).sorted(), [1, 2])
+    }
+}
@@ -0,0 +1,55 @@
+//
+//  ImageBufferTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import CoreVideo
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing pixel memory to Rust through `swift_bridge::image::ImageBuffer`.
+class ImageBufferTests: XCTestCase {
+    /// Verify that Rust writes to the pixels of each row and leaves the padding at the end of
+    /// each row alone.
+    func testImageBufferFromUnsafeMemory() throws {
+        // Two rows of one RGBA pixel, each padded to 8 bytes.
+        var pixels: [UInt8] = [
+            1, 2, 3, 4, 0, 0, 0, 0,
+            5, 6, 7, 8, 0, 0, 0, 0,
+        ]
+
+        let description = pixels.withUnsafeMutableBytes { bytes in
+            ImageBuffer.withUnsafeMemory(bytes.baseAddress!, width: 1, height: 2, bytesPerRow: 8, pixelFormat: .rgba8) { image in
+                XCTAssertEqual(image.pixelFormat, .rgba8)
+                image_test_invert(image)
+                return image_test_describe(image).toString()
+            }
+        }
+
+        XCTAssertEqual(description, "1x2 Rgba8 8")
+        XCTAssertEqual(pixels, [
+            254, 253, 252, 251, 0, 0, 0, 0,
+            250, 249, 248, 247, 0, 0, 0, 0,
+        ])
+    }
+
+    /// Verify that we can pass a `CVPixelBuffer` to Rust.
+    func testImageBufferFromPixelBuffer() throws {
+        var pixelBuffer: CVPixelBuffer?
+        CVPixelBufferCreate(kCFAllocatorDefault, 3, 2, kCVPixelFormatType_32BGRA, nil, &pixelBuffer)
+        let pixelBuffer = pixelBuffer!
+
+        let description = try ImageBuffer.withPixelBuffer(pixelBuffer) { image in
+            image_test_describe(image).toString()
+        }
+        XCTAssertEqual(description, "3x2 Bgra8 \(CVPixelBufferGetBytesPerRow(pixelBuffer))")
+    }
+
+    /// Verify that planar pixel buffers are rejected.
+    func testImageBufferRejectsPlanarPixelBuffer() throws {
+        var pixelBuffer: CVPixelBuffer?
+        CVPixelBufferCreate(kCFAllocatorDefault, 4, 4, kCVPixelFormatType_420YpCbCr8BiPlanarFullRange, nil, &pixelBuffer)
+
+        XCTAssertThrowsError(try ImageBuffer.withPixelBuffer(pixelBuffer!) { _ in })
+    }
+}
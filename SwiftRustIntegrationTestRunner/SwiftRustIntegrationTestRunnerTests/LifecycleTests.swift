@@ -0,0 +1,32 @@
+//
+//  LifecycleTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for forwarding app lifecycle events to handlers registered with `swift_bridge::lifecycle`.
+class LifecycleTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that `notifyRustLifecycleEvent(_:)` calls the lifecycle handlers that Rust registered.
+    func testNotifyRustLifecycleEvent() throws {
+        register_lifecycle_test_handler()
+
+        notifyRustLifecycleEvent(.didEnterBackground)
+        XCTAssertEqual(lifecycle_test_last_event()!.toString(), "DidEnterBackground")
+
+        notifyRustLifecycleEvent(.willEnterForeground)
+        XCTAssertEqual(lifecycle_test_last_event()!.toString(), "WillEnterForeground")
+
+        notifyRustLifecycleEvent(.willTerminate)
+        XCTAssertEqual(lifecycle_test_last_event()!.toString(), "WillTerminate")
+    }
+}
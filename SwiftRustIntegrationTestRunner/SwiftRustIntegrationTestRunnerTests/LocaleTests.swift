@@ -0,0 +1,50 @@
+//
+//  LocaleTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing a `Locale` to Rust through `swift_bridge::locale::SwiftLocale`.
+class LocaleTests: XCTestCase {
+    /// Verify that Rust formats numbers with the separators of the Swift locale.
+    func testFormatDecimalWithSwiftLocale() throws {
+        let german = SwiftLocale(Locale(identifier: "de_DE"))
+
+        XCTAssertEqual(german.decimalSeparator, ",")
+        XCTAssertEqual(german.groupingSeparator, ".")
+        XCTAssertEqual(locale_test_format_decimal(1234.5, german).toString(), "1.234,50")
+    }
+
+    /// Verify that the identifier, calendar and currency code reach Rust.
+    func testDescribeSwiftLocale() throws {
+        let locale = SwiftLocale(Locale(identifier: "ja_JP@calendar=japanese"))
+
+        XCTAssertEqual(
+            locale_test_describe(locale).toString(),
+            "ja_JP@calendar=japanese japanese JPY"
+        )
+    }
+
+    /// Verify that Swift can read a locale that Rust created.
+    func testLocaleCreatedInRust() throws {
+        let locale = locale_test_german()
+
+        XCTAssertEqual(locale.identifier, "de_DE")
+        XCTAssertEqual(locale.decimalSeparator, ",")
+        XCTAssertEqual(locale.groupingSeparator, ".")
+        XCTAssertEqual(locale.calendarIdentifier, "gregorian")
+        XCTAssertEqual(locale.currencyCode, "EUR")
+        XCTAssertEqual(locale.locale.identifier, "de_DE")
+    }
+
+    /// Verify that a locale without a currency code stays without one after a round trip.
+    func testRoundtripCurrentLocale() throws {
+        let current = SwiftLocale.current
+        let roundtripped = locale_test_roundtrip(SwiftLocale.current)
+
+        XCTAssertEqual(roundtripped.identifier, current.identifier)
+        XCTAssertEqual(roundtripped.currencyCode, current.currencyCode)
+    }
+}
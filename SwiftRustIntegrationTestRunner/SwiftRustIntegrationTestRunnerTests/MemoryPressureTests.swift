@@ -0,0 +1,28 @@
+//
+//  MemoryPressureTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for purging Rust caches registered with `swift_bridge::memory_pressure`.
+class MemoryPressureTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that `purgeRustCaches()` calls the purge callbacks that Rust registered.
+    func testPurgeRustCaches() throws {
+        register_memory_pressure_test_cache()
+        let before = memory_pressure_test_cache_purge_count()
+
+        purgeRustCaches()
+
+        XCTAssertEqual(memory_pressure_test_cache_purge_count(), before + 1)
+    }
+}
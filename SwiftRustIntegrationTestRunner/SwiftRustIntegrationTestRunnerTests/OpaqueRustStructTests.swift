@@ -57,6 +57,32 @@ class OpaqueRustStructTests: XCTestCase {
         XCTAssertEqual(ref1.len(), ref2.len())
     }
     
+    /// Verify that we can borrow an opaque Rust type through a generated scoped accessor.
+    func testScopedAccessorToOpaqueRustStruct() throws {
+        let stack_wrapper = StackWrapper()
+        
+        let len = stack_wrapper.withGetStackMut { stack -> UInt in
+            stack.push(5)
+            return stack.len()
+        }
+        
+        XCTAssertEqual(len, 1)
+        XCTAssertEqual(stack_wrapper.get_stack_mut().len(), 1)
+    }
+
+    /// Verify that an `into_*` method consumes the opaque Rust type and returns the converted value.
+    func testIntoMethodConsumesOpaqueRustStruct() throws {
+        let stack = ARustStack()
+        stack.push(5)
+        stack.push(10)
+
+        let vec = stack.into_vec()
+
+        XCTAssertFalse(stack.isOwned)
+        XCTAssertEqual(vec.len(), 2)
+        XCTAssertEqual(vec.get(index: 1), 10)
+    }
+
     /// Verify that we can pass a Copy opaque Rust type between Rust and Swift.
     func testOpaqueRustTypeImplCopy() throws {
         let val = RustCopyType()
@@ -90,6 +116,90 @@ class OpaqueRustStructTests: XCTestCase {
         }
     }
 
+    func testOpaqueRustCopyTypeImplEquatable() throws {
+        XCTAssertEqual(
+            RustCopyEquatableType.new_copy_equatable(5),
+            RustCopyEquatableType.new_copy_equatable(5)
+        )
+        XCTAssertNotEqual(
+            RustCopyEquatableType.new_copy_equatable(5),
+            RustCopyEquatableType.new_copy_equatable(10)
+        )
+    }
+
+    func testOpaqueRustTypeImplComparable() throws {
+        let values = [RustComparableType(3), RustComparableType(1), RustComparableType(2)]
+
+        XCTAssertEqual(values.sorted().map { $0.value() }, [1, 2, 3])
+        XCTAssert(RustComparableType(1) < RustComparableType(2))
+        XCTAssertFalse(RustComparableType(2) < RustComparableType(2))
+    }
+
+    func testOpaqueRustCopyTypeImplComparable() throws {
+        XCTAssertLessThan(
+            RustCopyComparableType.new_copy_comparable(5),
+            RustCopyComparableType.new_copy_comparable(10)
+        )
+        XCTAssertEqual(
+            [10, 5].map { RustCopyComparableType.new_copy_comparable($0) }.min(),
+            RustCopyComparableType.new_copy_comparable(5)
+        )
+    }
+
+    func testOpaqueRustTypeImplLocalizedError() throws {
+        XCTAssertEqual(try rust_parse_port("8080"), 8080)
+
+        do {
+            _ = try rust_parse_port("http")
+            XCTFail("Expected an error")
+        } catch let error as RustLocalizedError {
+            XCTAssertEqual(error.localizedDescription, "\"http\" is not a valid port")
+        }
+    }
+
+    func testOpaqueRustTypeImplClone() throws {
+        let original = RustCloneType(10)
+        let cloned = original.clone()
+
+        cloned.set_value(20)
+
+        XCTAssertEqual(original.value(), 10)
+        XCTAssertEqual(cloned.value(), 20)
+    }
+
+    func testOpaqueRustTypeImplIterator() throws {
+        XCTAssertEqual(Array(rust_even_numbers(7)), [0, 2, 4, 6])
+
+        var words: [String] = []
+        for word in rust_words("hello from rust") {
+            words.append(word.toString())
+        }
+        XCTAssertEqual(words, ["hello", "from", "rust"])
+    }
+
+    func testOpaqueRustTypeImplStream() async throws {
+        var countdown: [UInt32] = []
+        for await number in rust_countdown(3) {
+            countdown.append(number)
+        }
+        XCTAssertEqual(countdown, [3, 2, 1])
+
+        for await message in rust_messages() {
+            XCTAssertEqual(message.toString(), "message 1")
+            break
+        }
+        XCTAssertTrue(rust_messages_were_dropped())
+    }
+
+    func testOpaqueRustCopyTypeImplHashable() throws {
+        var set: Set<RustCopyHashableType> = []
+        set.insert(RustCopyHashableType.new_copy_hashable(10))
+        set.insert(RustCopyHashableType.new_copy_hashable(10))
+        set.insert(RustCopyHashableType.new_copy_hashable(100))
+
+        XCTAssertEqual(set.count, 2)
+    }
+
     func testOpaqueRustTypeImplHashable() throws {
         XCTContext.runActivity(named: "Same hash value"){
             _ in
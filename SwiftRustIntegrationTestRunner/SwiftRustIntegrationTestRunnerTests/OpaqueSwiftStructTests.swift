@@ -22,4 +22,10 @@ class OpaqueSwiftStructTests: XCTestCase {
     func testOpaqueSwiftStruct() throws {
         run_opaque_swift_class_tests()
     }
+
+    /// Verify that a module with `swift_mocks` calls the real Swift functions outside of Rust
+    /// tests.
+    func testSwiftMocksCallSwiftOutsideOfRustTests() throws {
+        XCTAssertEqual(rust_greet_sum(2, 3).toString(), "hello sum x5")
+    }
 }
@@ -42,6 +42,185 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
+    /// Verify that Rust can call the methods that a second bridge module declared on an already
+    /// declared opaque Swift type.
+    func testExternSwiftAlreadyDeclaredOpaqueSwiftType() throws {
+        XCTAssert(rust_calls_already_declared_swift_type_method())
+    }
+
+    /// Verify that a SwiftUI Binding generated by the `Bindings` attribute reads from and writes to the Rust type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/bindings.rs
+    func testExternRustBindings() throws {
+        let val = RustBindingsType()
+        let binding = val.volume_binding
+
+        XCTAssertEqual(binding.wrappedValue, 0.5)
+
+        binding.wrappedValue = 0.8
+        XCTAssertEqual(val.volume(), 0.8)
+    }
+
+    /// Verify that the `on_drop` function runs when Swift frees an extern "Rust" type.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/on_drop.rs
+    func testExternRustOnDrop() throws {
+        let closeCount = rust_on_drop_type_close_count()
+
+        do {
+            let _ = RustOnDropType()
+        }
+
+        XCTAssertEqual(rust_on_drop_type_close_count(), closeCount + 1)
+    }
+
+    /// Verify that an extern "Rust" type with the `manual_release` attribute is only freed when `release()` is called.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/manual_release.rs
+    func testExternRustManualRelease() throws {
+        let dropCount = rust_manual_release_type_drop_count()
+
+        do {
+            let _ = RustManualReleaseType()
+        }
+        XCTAssertEqual(rust_manual_release_type_drop_count(), dropCount)
+
+        let val = RustManualReleaseType()
+        val.release()
+        XCTAssertEqual(rust_manual_release_type_drop_count(), dropCount + 1)
+
+        // Releasing more than once is a no-op.
+        val.release()
+        XCTAssertEqual(rust_manual_release_type_drop_count(), dropCount + 1)
+    }
+
+    /// Verify that an extern "Rust" type with the `hardened` attribute is owned by its handle table
+    /// until Swift frees it or passes it back to Rust.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/hardened.rs
+    func testExternRustHardened() throws {
+        let liveCount = rust_hardened_type_live_count()
+
+        do {
+            let val = RustHardenedType(5)
+            XCTAssertEqual(rust_hardened_type_live_count(), liveCount + 1)
+
+            val.increment()
+            XCTAssertEqual(val.secret(), 6)
+
+            let reflected = rust_hardened_type_reflect(val)
+            XCTAssertEqual(reflected.secret(), 6)
+            XCTAssertEqual(rust_hardened_type_live_count(), liveCount + 1)
+
+            let other = RustHardenedType(2)
+            XCTAssertEqual(rust_hardened_type_sum(reflected, other), 8)
+            XCTAssertEqual(rust_hardened_type_sum(reflected, nil), 6)
+            XCTAssertEqual(rust_hardened_type_live_count(), liveCount + 2)
+
+            XCTAssertEqual(reflected.into_secret(), 6)
+            XCTAssertEqual(rust_hardened_type_live_count(), liveCount + 1)
+
+            let _ = RustHardenedType(1)
+        }
+
+        XCTAssertEqual(rust_hardened_type_live_count(), liveCount)
+    }
+
+    /// Verify that `&mut self` methods on an extern "Rust" type with the `interior_mut` attribute
+    /// can be called through a shared reference.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/interior_mut.rs
+    func testExternRustInteriorMut() throws {
+        let val = RustInteriorMutType(5)
+        let shared: RustInteriorMutTypeRef = val
+
+        shared.increment()
+        XCTAssertEqual(val.count(), 6)
+        XCTAssertEqual(shared.count(), 6)
+
+        XCTAssertEqual(val.into_count(), 6)
+    }
+
+    /// Verify that an extern "Rust" type with `swift_repr = "struct"` has value semantics in Swift.
+    ///
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/swift_repr_struct.rs
+    func testExternRustSwiftReprStruct() throws {
+        let liveCount = rust_value_type_live_count()
+
+        do {
+            let val = RustValueType(5)
+            let copy = val
+
+            // Copies share the same Rust value until Rust needs to take ownership.
+            XCTAssertEqual(rust_value_type_live_count(), liveCount + 1)
+            XCTAssertEqual(val, copy)
+
+            // Rust consumes a clone, so the original is still usable.
+            XCTAssertEqual(copy.into_val(), 5)
+            XCTAssertEqual(val.val(), 5)
+
+            // Borrowed values that Rust returns are cloned into a new value.
+            let echoed = rust_value_type_echo_ref(val)
+            withExtendedLifetime(val) {
+                XCTAssertEqual(echoed.val(), 5)
+                XCTAssertEqual(rust_value_type_live_count(), liveCount + 2)
+            }
+        }
+
+        XCTAssertEqual(rust_value_type_live_count(), liveCount)
+    }
+
+    /// Verify that we can call methods on a `Box<dyn Trait>` that uses
+    /// `#[swift_bridge(trait_object)]`, and that calls are dispatched to each implementation.
+    func testTraitObject() throws {
+        let square = rust_new_square(2)
+        let circle = rust_new_circle(1)
+
+        XCTAssertEqual(square.name().toString(), "square")
+        XCTAssertEqual(circle.name().toString(), "circle")
+        XCTAssertEqual(square.area(), 4)
+        XCTAssertEqual(circle.area(), Double.pi, accuracy: 0.0001)
+
+        square.scale(3)
+        XCTAssertEqual(square.area(), 36)
+
+        let shapes = rust_all_shapes()
+        XCTAssertEqual(shapes.len(), 2)
+        XCTAssertEqual(shapes.get(index: 0)!.name().toString(), "square")
+        XCTAssertEqual(rust_total_area(shapes), 4 + Double.pi, accuracy: 0.0001)
+    }
+
+    /// Verify that we can use an opaque Rust type through the name that it was given by
+    /// `#[swift_bridge(swift_name = "...")]`.
+    func testSwiftName() throws {
+        let users = RustVec<RenamedRustUser>()
+        users.push(value: RenamedRustUser("alice"))
+        users.push(value: RenamedRustUser("bob"))
+
+        let reversed = rust_reverse_users(users)
+
+        XCTAssertEqual(reversed.get(index: 0)!.userName().toString(), "bob")
+        XCTAssertEqual(reversed.get(index: 1)!.userName().toString(), "alice")
+    }
+
+    /// Verify that a Swift class that conforms to the protocol of a `#[swift_bridge(trait_object)]`
+    /// extern "Swift" type can be passed to Rust as a `Box<dyn Trait>`, and that Rust frees it.
+    func testSwiftTraitObject() throws {
+        let downloader = RustDownloader(5)
+
+        var observer: TestDownloadObserver? = TestDownloadObserver(stopAfter: 3)
+        weak var weakObserver = observer
+        downloader.set_observer(observer!)
+
+        XCTAssertEqual(downloader.run(), 3)
+        XCTAssertEqual(observer!.progress, [1, 2, 3])
+        XCTAssertEqual(observer!.summary, "3 of 5 chunks")
+
+        observer = nil
+        XCTAssertNotNil(weakObserver)
+        downloader.clear_observer()
+        XCTAssertNil(weakObserver)
+    }
+
     
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
@@ -51,3 +230,25 @@ class OpaqueTypeAttributeTests: XCTestCase {
     }
 
 }
+
+private class TestDownloadObserver: DownloadObserver {
+    let stopAfter: UInt32
+    var progress: [UInt32] = []
+    var summary: String?
+
+    init(stopAfter: UInt32) {
+        self.stopAfter = stopAfter
+    }
+
+    func on_progress(completed: UInt32, total: UInt32) {
+        progress.append(completed)
+    }
+
+    func should_continue(completed: UInt32) -> Bool {
+        completed < stopAfter
+    }
+
+    func on_finished(summary: RustString) {
+        self.summary = summary.toString()
+    }
+}
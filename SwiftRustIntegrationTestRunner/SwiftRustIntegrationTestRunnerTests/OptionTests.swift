@@ -63,6 +63,22 @@ class OptionTests: XCTestCase {
         XCTAssertNil(rust_reflect_option_string(none))
     }
 
+    /// Verify that Swift can call a Rust function that accepts a &str and returns an
+    /// Option<String>, including when the Option<String> is nested in a Result or a tuple.
+    func testSwiftCallRustFindOptionString() throws {
+        XCTAssertEqual(rust_find_option_string("hello")!.toString(), "world")
+        XCTAssertNil(rust_find_option_string("goodbye"))
+
+        XCTAssertEqual(try rust_find_option_string_result("hello")!.toString(), "world")
+        XCTAssertNil(try rust_find_option_string_result("goodbye"))
+        XCTAssertThrowsError(try rust_find_option_string_result(""))
+
+        let (found, len) = rust_find_option_string_tuple("hello")
+        XCTAssertEqual(found!.toString(), "world")
+        XCTAssertEqual(len, 5)
+        XCTAssertNil(rust_find_option_string_tuple("goodbye").0)
+    }
+
     /// We use an `Option<&'static str>` that we create on the Rust side so that
     ///  we don't run into any lifetime issues.
     func testSwiftCallRustReturnOptionStr() throws {
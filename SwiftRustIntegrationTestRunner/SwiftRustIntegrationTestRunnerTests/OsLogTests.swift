@@ -0,0 +1,44 @@
+//
+//  OsLogTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import OSLog
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for forwarding Rust `log` records to os_log with `swift_bridge::os_log`.
+class OsLogTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that a record logged in Rust shows up in the unified log under the logger's
+    /// subsystem, with the record's target as the category.
+    func testRustLogRecordIsForwardedToOsLog() throws {
+        guard #available(macOS 12.0, iOS 15.0, *) else {
+            throw XCTSkip("OSLogStore requires macOS 12 or iOS 15")
+        }
+
+        init_os_log_test_logger()
+
+        let message = "Hello from Rust \(UUID().uuidString)"
+        os_log_test_log_info(message)
+
+        let store = try OSLogStore(scope: .currentProcessIdentifier)
+        let entries = try store.getEntries(
+            matching: NSPredicate(format: "subsystem == %@", "dev.swift-bridge.integration-tests")
+        )
+        let entry = entries
+            .compactMap { $0 as? OSLogEntryLog }
+            .first { $0.composedMessage == message }
+
+        XCTAssertNotNil(entry)
+        XCTAssertEqual(entry?.category, "os_log_test")
+        XCTAssertEqual(entry?.level, .info)
+    }
+}
@@ -37,4 +37,38 @@ class PointerTests: XCTestCase {
     func testRustCallSwiftUInt8() throws {
         rust_run_u8_pointer_tests()
     }
+
+    /// Verify that we can convert an opaque Rust type to a raw pointer and back in Swift.
+    func testOpaqueRustTypeRawPointerRoundTrip() throws {
+        let pointer = RawPointerTestType(123).intoRawPointer()
+        let val = RawPointerTestType(fromRawPointer: pointer)
+
+        XCTAssertEqual(val.val(), 123)
+    }
+
+    /// Verify that raw pointers to opaque Rust types can be created in one language and consumed in the other.
+    func testOpaqueRustTypeRawPointerAcrossLanguages() throws {
+        XCTAssertEqual(
+            rust_raw_pointer_test_type_from_raw_pointer(RawPointerTestType(456).intoRawPointer()),
+            456
+        )
+
+        let val = RawPointerTestType(fromRawPointer: rust_raw_pointer_test_type_into_raw_pointer(789))
+        XCTAssertEqual(val.val(), 789)
+    }
+
+    /// Verify that `unsafe` Rust functions and methods get an `unsafe` prefix in Swift.
+    func testCallUnsafeRustFunctions() throws {
+        var num: UInt8 = 42
+        XCTAssertEqual(unsafeRustReadU8(&num), 42)
+
+        var newVal: UInt32 = 5
+        let val = RawPointerTestType(1)
+        val.unsafeSetValFrom(&newVal)
+        XCTAssertEqual(val.val(), 5)
+    }
+
+    func testRustCallUnsafeSwiftFunction() throws {
+        rust_run_unsafe_swift_fn_tests()
+    }
 }
@@ -0,0 +1,64 @@
+//
+//  ReaderTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing a `RandomAccessReader` to Rust as a `swift_bridge::reader::SwiftReader`.
+class ReaderTests: XCTestCase {
+    /// Verify that Rust can read all of the data, even when it asks for it in small pieces.
+    func testRustReadsToEnd() throws {
+        let reader = DataReader(Data("hello world".utf8))
+
+        XCTAssertEqual(reader_test_len(SwiftReader(reader)), 11)
+        XCTAssertEqual(SwiftReader(reader).length, 11)
+
+        let bytes = reader_test_read_to_end(SwiftReader(reader))
+        XCTAssertEqual(Data(bytes.as_slice()), Data("hello world".utf8))
+        XCTAssertGreaterThan(reader.readCount, 1)
+    }
+
+    /// Verify that Rust can seek before reading.
+    func testRustSeeksFromEnd() throws {
+        let bytes = reader_test_read_last(SwiftReader(DataReader(Data("hello world".utf8))), 5)
+
+        XCTAssertEqual(Data(bytes.as_slice()), Data("world".utf8))
+    }
+
+    /// Verify that the Swift reader gets released once Rust drops the `SwiftReader`.
+    func testReaderReleasedWhenRustDropsIt() throws {
+        weak var weakReader: DataReader?
+
+        do {
+            let reader = DataReader(Data([1, 2, 3]))
+            weakReader = reader
+
+            let _ = reader_test_read_to_end(SwiftReader(reader))
+        }
+
+        XCTAssertNil(weakReader)
+    }
+}
+
+class DataReader: RandomAccessReader {
+    let data: Data
+    var readCount = 0
+
+    init(_ data: Data) {
+        self.data = data
+    }
+
+    var length: UInt64 {
+        UInt64(data.count)
+    }
+
+    func read(at offset: UInt64, length: Int) -> Data {
+        readCount += 1
+
+        let start = Int(offset)
+        let end = min(start + length, data.count)
+        return data.subdata(in: start..<end)
+    }
+}
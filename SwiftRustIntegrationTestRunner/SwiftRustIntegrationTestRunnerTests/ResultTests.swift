@@ -249,4 +249,32 @@ class ResultTests: XCTestCase {
             XCTAssertEqual(UInt32(i), value.val())
         }
     }
+
+    /// Verify that we can use throwing initializers defined on the Rust side.
+    func testThrowingInitializer() throws {
+        XCTContext.runActivity(named: "Should initialize") {
+            _ in
+            let throwingInitializer = try! ThrowingInitializer(true)
+            XCTAssertEqual(throwingInitializer.val(), 123)
+        }
+
+        XCTContext.runActivity(named: "Should throw an error") {
+            _ in
+            do {
+                let _ = try ThrowingInitializer(false)
+                XCTFail("The initializer should have thrown an error.")
+            } catch let error as ResultTransparentEnum {
+                switch error {
+                case .NamedField(let data):
+                    XCTAssertEqual(data, -123)
+                case .UnnamedFields(_, _):
+                    XCTFail()
+                case .NoFields:
+                    XCTFail()
+                }
+            } catch {
+                XCTFail()
+            }
+        }
+    }
 }
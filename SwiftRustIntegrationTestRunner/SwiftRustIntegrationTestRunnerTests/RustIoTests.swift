@@ -0,0 +1,59 @@
+//
+//  RustIoTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for writing to a `swift_bridge::rust_io::RustWriter` and reading from a
+/// `swift_bridge::rust_io::RustReader`.
+class RustIoTests: XCTestCase {
+    /// Verify that all of the data reaches the Rust writer, even though it only accepts a few bytes
+    /// per write.
+    func testWriteData() throws {
+        let writer = rust_io_test_uppercase_writer()
+
+        try writer.write(Data("hello ".utf8))
+        try writer.write(Data("world".utf8))
+        try writer.flush()
+
+        XCTAssertEqual(rust_io_test_finish_uppercase_writer(writer).toString(), "HELLO WORLD")
+    }
+
+    /// Verify that we can pipe an `InputStream` into a Rust writer.
+    func testWriteContentsOfInputStream() throws {
+        let writer = rust_io_test_uppercase_writer()
+
+        try writer.write(contentsOf: InputStream(data: Data("piped through rust".utf8)), chunkSize: 4)
+
+        XCTAssertEqual(rust_io_test_finish_uppercase_writer(writer).toString(), "PIPED THROUGH RUST")
+    }
+
+    /// Verify that a failed write returns -1 like `OutputStream` does, and that the Rust error is
+    /// available afterwards.
+    func testWriteError() throws {
+        let writer = rust_io_test_failing_writer()
+        XCTAssertNil(writer.streamError)
+
+        let bytes: [UInt8] = [1, 2, 3]
+        XCTAssertEqual(writer.write(bytes, maxLength: bytes.count), -1)
+        XCTAssertEqual(writer.streamError?.message, "disk full")
+
+        XCTAssertThrowsError(try writer.write(Data(bytes))) { error in
+            XCTAssertEqual((error as! RustIoError).message, "disk full")
+        }
+    }
+
+    /// Verify that we can read from a Rust reader a chunk at a time.
+    func testReadChunks() throws {
+        let reader = rust_io_test_reader("hello world")
+        XCTAssertTrue(reader.hasBytesAvailable)
+
+        XCTAssertEqual(try reader.read(maxLength: 5), Data("hello".utf8))
+        XCTAssertEqual(try reader.readToEnd(chunkSize: 2), Data(" world".utf8))
+
+        XCTAssertFalse(reader.hasBytesAvailable)
+        XCTAssertEqual(try reader.read(maxLength: 5), Data())
+    }
+}
@@ -0,0 +1,47 @@
+//
+//  ScopeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for freeing Rust values in bulk with `withRustScope`.
+class ScopeTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    /// Verify that Rust values that are freed inside of a scope are dropped when the scope ends.
+    func testValuesAreDroppedWhenScopeEnds() throws {
+        let before = scope_test_value_drop_count()
+
+        withRustScope {
+            for _ in 0..<3 {
+                _ = ScopeTestValue()
+            }
+
+            XCTAssertEqual(scope_test_value_drop_count(), before)
+        }
+
+        XCTAssertEqual(scope_test_value_drop_count(), before + 3)
+    }
+
+    /// Verify that a value that outlives the scope is freed when Swift is done with it.
+    func testValueThatOutlivesScope() throws {
+        let before = scope_test_value_drop_count()
+
+        var value: ScopeTestValue? = withRustScope {
+            ScopeTestValue()
+        }
+        XCTAssertEqual(scope_test_value_drop_count(), before)
+
+        value = nil
+        XCTAssertNil(value)
+        XCTAssertEqual(scope_test_value_drop_count(), before + 1)
+    }
+}
@@ -36,6 +36,14 @@ class SharedEnumAttributeTests: XCTestCase {
         test_rust_calls_swift_already_declared_enum()
     }
     
+    /// Verify that we can convert between a transparent enum and the handwritten Swift enum that
+    /// it mirrors.
+    func testSharedEnumSwiftMirror() throws {
+        let next = rust_next_traffic_light(TrafficLight(HandwrittenTrafficLight.Red))
+
+        XCTAssertEqual(HandwrittenTrafficLight(next), HandwrittenTrafficLight.Green)
+    }
+
     /// Verify that we can use the generated Debug impl.
     func testSharedEnumDeriveDebug() throws {
         let debugString = String(reflecting: DeriveDebugEnum.Variant)
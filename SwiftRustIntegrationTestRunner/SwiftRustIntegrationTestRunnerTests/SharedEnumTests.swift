@@ -128,4 +128,19 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    /// Verify that we can call methods, associated functions and initializers that are declared
+    /// on a shared enum.
+    func testEnumWithMethods() {
+        let off = EnumWithMethods(0)
+        XCTAssertTrue(off.is_off())
+        XCTAssertEqual(off.label().toString(), "off")
+
+        let level = EnumWithMethods(3)
+        XCTAssertFalse(level.is_off())
+        XCTAssertEqual(level.label().toString(), "level 3")
+
+        XCTAssertEqual(EnumWithMethods.max().label().toString(), "level 255")
+        XCTAssertTrue(level.toggled().is_off())
+        XCTAssertEqual(off.toggled().label().toString(), "level 255")
+    }
 }
@@ -34,5 +34,64 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
-}
 
+    /// Verify that Swift can pass a struct that has the `initialize` attribute to Rust's
+    /// `initialize` function.
+    /// See crates/swift-integration-tests/src/struct_attributes/initialize.rs
+    func testSharedStructInitialize() throws {
+        initialize(config: AppConfig(
+            log_level: "debug".intoRustString(),
+            data_directory: "/tmp/app".intoRustString(),
+            verbose: true
+        ))
+
+        XCTAssertEqual(initialized_log_level()!.toString(), "debug")
+        XCTAssertTrue(initialized_verbose())
+    }
+
+    /// Verify that a struct with the `Identifiable` attribute uses the named field as its `id`.
+    /// See crates/swift-integration-tests/src/struct_attributes/identifiable.rs
+    func testSharedStructIdentifiable() throws {
+        let items = [rust_todo_item(1), rust_todo_item(2)]
+
+        XCTAssertEqual(items.map(\.id), [1, 2])
+    }
+
+    /// Verify that a typed id can be used as a dictionary key and is encoded as the value that it
+    /// wraps.
+    /// See crates/swift-integration-tests/src/struct_attributes/codable.rs
+    func testSharedStructHashableCodable() throws {
+        var names: [CodableUserId: String] = [:]
+        names[rust_user_id(1)] = "admin"
+        names[rust_user_id(2)] = "guest"
+        names[rust_user_id(1)] = "root"
+
+        XCTAssertEqual(names.count, 2)
+        XCTAssertEqual(names[CodableUserId(_0: 1)], "root")
+        XCTAssertTrue(rust_user_id_is_admin(CodableUserId(_0: 1)))
+
+        let json = try JSONEncoder().encode([rust_user_id(5)])
+        XCTAssertEqual(String(data: json, encoding: .utf8), "[5]")
+        XCTAssertEqual(try JSONDecoder().decode([CodableUserId].self, from: json), [rust_user_id(5)])
+    }
+
+    /// Verify that Swift can send a partial update of a struct that has the `patch` attribute.
+    /// See crates/swift-integration-tests/src/struct_attributes/patch.rs
+    func testSharedStructPatch() throws {
+        reset_player_settings()
+
+        var patch = PlayerSettingsPatch()
+        patch.volume = 80
+        update_player_settings(patch)
+
+        var settings = player_settings()
+        XCTAssertEqual(settings.name.toString(), "player")
+        XCTAssertEqual(settings.volume, 80)
+        XCTAssertFalse(settings.muted)
+
+        settings.apply(PlayerSettingsPatch(name: "swift".intoRustString(), volume: nil, muted: true))
+        XCTAssertEqual(settings.name.toString(), "swift")
+        XCTAssertEqual(settings.volume, 80)
+        XCTAssertTrue(settings.muted)
+    }
+}
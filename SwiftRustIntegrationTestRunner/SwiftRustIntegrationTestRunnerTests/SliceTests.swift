@@ -0,0 +1,38 @@
+//
+//  SliceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `&[T]` and `&mut [T]`.
+class SliceTests: XCTestCase {
+    /// Verify that we can pass a Swift array to a Rust function that takes a `&[T]`.
+    func testSwiftArrayAsSlice() throws {
+        let values: [UInt32] = [1, 2, 3]
+
+        let sum = values.withUnsafeBufferPointer { rust_sum_slice($0) }
+
+        XCTAssertEqual(sum, 6)
+    }
+
+    /// Verify that Rust can mutate a Swift array through a `&mut [T]`.
+    func testSwiftArrayAsMutSlice() throws {
+        var values: [UInt32] = [1, 2, 3]
+
+        values.withUnsafeMutableBufferPointer { rust_double_slice_in_place($0) }
+
+        XCTAssertEqual(values, [2, 4, 6])
+    }
+
+    /// Verify that we can mutate a slice that Rust returned as a `&mut [T]`.
+    func testRustReturnsMutSlice() throws {
+        let buffer = SliceTestBuffer()
+
+        let values = buffer.values_mut()
+        values[0] = 10
+
+        XCTAssertEqual(Array(buffer.values()), [10, 2, 3])
+    }
+}
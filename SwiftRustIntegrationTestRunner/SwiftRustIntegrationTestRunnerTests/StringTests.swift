@@ -47,6 +47,53 @@ class StringTests: XCTestCase {
         )
     }
 
+    /// Verify that we can call a Rust function that declares lifetimes.
+    func testRustFnWithLifetimes() throws {
+        XCTAssertEqual(longest_str("hi", "hello").toString(), "hello")
+    }
+
+    /// Verify that we can pass a Substring to a Rust function that takes a `&str`.
+    func testPassSubstringToRustStr() throws {
+        let string = "hello world"
+        let substring = string[string.index(string.startIndex, offsetBy: 6)...]
+
+        XCTAssertEqual(create_string(substring).toString(), "world")
+    }
+
+    /// Verify that we can pass an empty String to a Rust function that takes a `&str`.
+    func testPassEmptyStringToRustStr() throws {
+        XCTAssertEqual(create_string("").len(), 0)
+    }
+
+    /// Verify that we can pass a non-ASCII String to a Rust function that takes a `&str`.
+    func testPassNonAsciiStringToRustStr() throws {
+        XCTAssertEqual(create_string("héllo 👋").toString(), "héllo 👋")
+    }
+
+    /// Verify that we can translate between Rust byte offsets and Swift string indices.
+    func testRustByteOffsetTranslation() throws {
+        let string = "héllo 👋 world"
+
+        let offset = rust_find_str(string, "world")!
+        let index = string.index(rustByteOffset: offset)!
+        XCTAssertEqual(string[index...], "world")
+        XCTAssertEqual(string.rustByteOffset(of: index), offset)
+
+        let range = string.range(rustByteOffsets: offset..<(offset + 3))!
+        XCTAssertEqual(string[range], "wor")
+        XCTAssertEqual(string.rustByteOffsets(of: range), offset..<(offset + 3))
+
+        let emojiIndex = string.firstIndex(of: "👋")!
+        XCTAssertEqual(
+            rust_str_len_up_to(string, string.rustByteOffset(of: emojiIndex)).toString(),
+            "héllo "
+        )
+
+        // The middle of "é" and an offset past the end are not valid indices.
+        XCTAssertNil(string.index(rustByteOffset: 2))
+        XCTAssertNil(string.index(rustByteOffset: UInt(string.utf8.count + 1)))
+    }
+
     func testRustStrEqualityOperator() throws {
         XCTContext.runActivity(named: "Should be equal"){
             _ in
@@ -0,0 +1,31 @@
+//
+//  SwiftNamespaceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `#[swift_bridge::bridge(swift_namespace = "...")]` attribute.
+class SwiftNamespaceTests: XCTestCase {
+    /// Verify that we can create and use an opaque Rust type through its namespace.
+    func testOpaqueRustTypeInNamespace() throws {
+        let mixer: Engine.Audio.Mixer = Engine.Audio.Mixer(.Stereo)
+        mixer.set_volume(7)
+
+        XCTAssertEqual(mixer.volume(), 7)
+        XCTAssertEqual(mixer.channels(), Engine.Audio.MixerChannels.Stereo)
+
+        let mixerRef: Engine.Audio.MixerRef = mixer
+        XCTAssertEqual(rust_namespace_mixer_volume(mixerRef), 7)
+    }
+
+    /// Verify that modules that share a parent namespace can both be used.
+    func testSiblingNamespaces() throws {
+        let renderer = rust_namespace_new_renderer(Engine.Video.FrameSize(width: 1920, height: 1080))
+
+        XCTAssert(type(of: renderer) == Engine.Video.Renderer.self)
+        XCTAssertEqual(renderer.frame_size().width, 1920)
+        XCTAssertEqual(renderer.frame_size().height, 1080)
+    }
+}
@@ -0,0 +1,38 @@
+//
+//  TextMatchesTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for returning match ranges to Swift through `swift_bridge::text::TextMatches`.
+class TextMatchesTests: XCTestCase {
+    /// Verify that Swift converts the Rust byte ranges of each match into ranges of the string,
+    /// even when the string has characters that take up more than one byte.
+    func testTextMatchesRanges() throws {
+        let text = "café ☕️ café 👋 café"
+
+        let matches = text_test_find_words(text, "café")
+
+        XCTAssertEqual(matches.count, 3)
+        XCTAssertEqual(matches.ranges(in: text).map { String(text[$0]) }, ["café", "café", "café"])
+        XCTAssertEqual(matches.matches[0].byteRange, 0..<5)
+    }
+
+    /// Verify that we can get the ranges of named capture groups, and that groups which did not
+    /// take part in a match are left out.
+    func testTextMatchesCaptures() throws {
+        let text = "name=ü empty= flag"
+
+        let matches = text_test_find_key_values(text).matches
+
+        XCTAssertEqual(matches.count, 2)
+        XCTAssertEqual(text[matches[0].range(in: text)!], "name=ü")
+        XCTAssertEqual(text[matches[0].range(of: "key", in: text)!], "name")
+        XCTAssertEqual(text[matches[0].range(of: "value", in: text)!], "ü")
+
+        XCTAssertEqual(text[matches[1].range(of: "key", in: text)!], "empty")
+        XCTAssertNil(matches[1].range(of: "value", in: text))
+    }
+}
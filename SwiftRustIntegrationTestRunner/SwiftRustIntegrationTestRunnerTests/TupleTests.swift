@@ -34,6 +34,13 @@ final class TupleTest: XCTestCase {
             XCTAssertEqual(tuple.1, 123)
             XCTAssertEqual(tuple.2, true)
         }
+        XCTContext.runActivity(named: "Verify that we can pass and return a (UInt64, UInt64, Int64).") {
+            _ in
+            let tuple = rust_reflect_tuple_u64_and_i64((UInt64.max, 123, Int64.min))
+            XCTAssertEqual(tuple.0, UInt64.max)
+            XCTAssertEqual(tuple.1, 123)
+            XCTAssertEqual(tuple.2, Int64.min)
+        }
     }
     
     /// Verify that Rust can call Swift functions that accept and return Tuples.
@@ -99,6 +99,34 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.pop()!, TransparentEnumInsideVecT.VariantB)
     }
     
+    /// Verify that Swift can push chunks of bytes into a Rust parser and get back zero or more
+    /// data-carrying enum events per chunk.
+    func testPushParserReturnsVecOfDataCarryingEnums() throws {
+        let parser = LineParser()
+
+        func lines(_ events: RustVec<LineEvent>) -> [String] {
+            events.map { event in
+                switch event {
+                case .Line(let line):
+                    return line.toString()
+                case .BlankLine:
+                    return ""
+                }
+            }
+        }
+
+        func feed(_ chunk: String) -> [String] {
+            let bytes: [UInt8] = Array(chunk.utf8)
+            return lines(bytes.withUnsafeBufferPointer { parser.feed($0) })
+        }
+
+        XCTAssertEqual(feed("hel"), [])
+        XCTAssertEqual(feed("lo\n\nwor"), ["hello", ""])
+        XCTAssertEqual(feed("ld"), [])
+        XCTAssertEqual(lines(parser.finish()), ["world"])
+        XCTAssertEqual(lines(parser.finish()), [])
+    }
+    
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
     /// functions that generate the pieces of the RustVec support aren't accidentally hard coded to
@@ -15,5 +15,8 @@ fn main() {
         )]),
         out_dir: PathBuf::from("swift-package-rust-library-fixture/MySwiftPackage"),
         package_name: "MySwiftPackage".to_string(),
+        resources: vec![PathBuf::from(
+            "swift-package-rust-library-fixture/resources/greeting.txt",
+        )],
     });
 }
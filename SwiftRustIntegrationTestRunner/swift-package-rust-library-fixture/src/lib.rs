@@ -2,6 +2,7 @@
 mod ffi {
     extern "Rust" {
         fn hello_rust() -> String;
+        fn read_greeting_resource() -> String;
     }
 
     #[swift_bridge(swift_repr = "struct")]
@@ -16,3 +17,8 @@ mod ffi {
 fn hello_rust() -> String {
     String::from("Hello, From Rust!")
 }
+
+fn read_greeting_resource() -> String {
+    let path = swift_bridge::resources::path("greeting.txt").unwrap();
+    std::fs::read_to_string(path).unwrap()
+}
@@ -7,6 +7,10 @@ final class swift_package_test_packageTests: XCTestCase {
         XCTAssertEqual("Hello, From Rust!", hello_rust().toString())
     }
 
+    func testReadResourceFromRust() throws {
+        XCTAssertEqual("Hello, From a Resource!\n", read_greeting_resource().toString())
+    }
+
     func testInstantiateSharedStruct() throws {
         XCTAssertEqual(SomeStruct(field: 1).field, 1);
     }
@@ -0,0 +1,497 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+/// The public Swift API that was generated for a set of bridge modules.
+///
+/// Record the manifest for a release and compare it against the manifest for the next release in
+/// order to find changes that would break code that uses the generated Swift.
+///
+/// The manifest's `Display` implementation writes it in a line based format that
+/// [`SwiftApiManifest::parse`] can read back in.
+#[derive(Debug, Default, PartialEq)]
+pub struct SwiftApiManifest {
+    // Declarations that are not inside of a type are stored under the empty string.
+    // Type name -> Declaration name -> Full signatures
+    containers: BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+}
+
+/// A change to the generated Swift API that can break code that uses it.
+#[derive(Debug, PartialEq)]
+pub enum SwiftApiChange {
+    /// A declaration no longer exists.
+    Removed {
+        /// The type that the declaration belonged to, or `None` for top level declarations.
+        container: Option<String>,
+        /// The declaration's signature.
+        signature: String,
+    },
+    /// A declaration still exists under the same name, but its signature is different.
+    Changed {
+        /// The type that the declaration belongs to, or `None` for top level declarations.
+        container: Option<String>,
+        /// The declaration's signature in the previous manifest.
+        old_signature: String,
+        /// The declaration's signature in the current manifest.
+        new_signature: String,
+    },
+}
+
+impl SwiftApiManifest {
+    /// Collect the public declarations in Swift code that was generated by `swift-bridge`.
+    pub fn from_generated_swift(swift: &str) -> Self {
+        let mut manifest = SwiftApiManifest::default();
+
+        // The type whose members we are currently collecting. `None` if we are inside of a
+        // declaration that is not part of the public API.
+        let mut container: Option<String> = None;
+
+        for line in swift.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if !line.starts_with(' ') {
+                container = None;
+
+                let declaration = strip_body(line);
+
+                if let Some(extended) = declaration.strip_prefix("extension ") {
+                    let type_name = type_name(extended);
+                    if is_private_type(type_name) {
+                        continue;
+                    }
+
+                    if extended.contains(':') {
+                        manifest.insert("", declaration);
+                    }
+                    container = Some(type_name.to_string());
+                } else if declaration.starts_with("public ") {
+                    let type_decl = strip_modifiers(declaration);
+                    let type_decl = ["class ", "struct ", "enum ", "protocol "]
+                        .iter()
+                        .find_map(|kind| type_decl.strip_prefix(kind));
+
+                    match type_decl {
+                        Some(type_decl) if is_private_type(type_name(type_decl)) => {}
+                        Some(type_decl) => {
+                            manifest.insert("", declaration);
+                            container = Some(type_name(type_decl).to_string());
+                        }
+                        None => {
+                            manifest.insert("", declaration);
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            let container = match &container {
+                Some(container) => container,
+                None => continue,
+            };
+
+            // Only look at the members of the type, not at the contents of their bodies.
+            if !line.starts_with("    ") || line.starts_with("     ") {
+                continue;
+            }
+
+            let declaration = strip_body(line.trim());
+            if declaration.starts_with("public ") || declaration.starts_with("case ") {
+                manifest.insert(container, declaration);
+            }
+        }
+
+        manifest
+    }
+
+    /// Parse a manifest that was written using the manifest's `Display` implementation.
+    pub fn parse(manifest: &str) -> Self {
+        let mut parsed = SwiftApiManifest::default();
+
+        let mut container = "".to_string();
+
+        for line in manifest.lines() {
+            if line.trim().is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            if line.starts_with(' ') {
+                parsed.insert(&container, line.trim());
+            } else if is_type_name(line) {
+                container = line.to_string();
+            } else {
+                container = "".to_string();
+                parsed.insert("", line);
+            }
+        }
+
+        parsed
+    }
+
+    /// Get the changes that were made since the `previous` manifest that could break code that
+    /// uses the generated Swift.
+    ///
+    /// Declarations that were added are not breaking changes, so they are not included.
+    pub fn breaking_changes(&self, previous: &SwiftApiManifest) -> Vec<SwiftApiChange> {
+        let mut changes = vec![];
+
+        let empty = BTreeMap::new();
+
+        for (container_name, old_declarations) in &previous.containers {
+            let new_declarations = self.containers.get(container_name).unwrap_or(&empty);
+            let container = if container_name.is_empty() {
+                None
+            } else {
+                Some(container_name.to_string())
+            };
+
+            for (name, old_signatures) in old_declarations {
+                let new_signatures = match new_declarations.get(name) {
+                    Some(new_signatures) => new_signatures,
+                    None => {
+                        for signature in old_signatures {
+                            changes.push(SwiftApiChange::Removed {
+                                container: container.clone(),
+                                signature: signature.to_string(),
+                            });
+                        }
+                        continue;
+                    }
+                };
+
+                let removed = old_signatures.difference(new_signatures);
+                let mut added = new_signatures.difference(old_signatures);
+
+                for old_signature in removed {
+                    match added.next() {
+                        Some(new_signature) => changes.push(SwiftApiChange::Changed {
+                            container: container.clone(),
+                            old_signature: old_signature.to_string(),
+                            new_signature: new_signature.to_string(),
+                        }),
+                        None => changes.push(SwiftApiChange::Removed {
+                            container: container.clone(),
+                            signature: old_signature.to_string(),
+                        }),
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    fn insert(&mut self, container: &str, signature: &str) {
+        let signature = signature.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        self.containers
+            .entry(container.to_string())
+            .or_default()
+            .entry(declaration_name(&signature))
+            .or_default()
+            .insert(signature);
+    }
+}
+
+impl Display for SwiftApiManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "// The public Swift API generated by swift-bridge.")?;
+
+        if let Some(top_level) = self.containers.get("") {
+            writeln!(f)?;
+            for signature in top_level.values().flatten() {
+                writeln!(f, "{}", signature)?;
+            }
+        }
+
+        for (container, declarations) in &self.containers {
+            if container.is_empty() {
+                continue;
+            }
+
+            writeln!(f)?;
+            writeln!(f, "{}", container)?;
+            for signature in declarations.values().flatten() {
+                writeln!(f, "    {}", signature)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SwiftApiChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwiftApiChange::Removed {
+                container,
+                signature,
+            } => {
+                write!(f, "removed: ")?;
+                if let Some(container) = container {
+                    write!(f, "{} ", container)?;
+                }
+                write!(f, "`{}`", signature)
+            }
+            SwiftApiChange::Changed {
+                container,
+                old_signature,
+                new_signature,
+            } => {
+                write!(f, "changed: ")?;
+                if let Some(container) = container {
+                    write!(f, "{} ", container)?;
+                }
+                write!(f, "`{}` is now `{}`", old_signature, new_signature)
+            }
+        }
+    }
+}
+
+/// The name that identifies a declaration, such as `func add(_:to:)` or `var count`.
+///
+/// A declaration that keeps its name but changes its signature is reported as changed instead of
+/// as removed.
+fn declaration_name(signature: &str) -> String {
+    let declaration = strip_modifiers(signature);
+
+    for kind in ["func ", "var ", "let ", "case "] {
+        if let Some(rest) = declaration.strip_prefix(kind) {
+            let name_end = rest.find(['(', '<', ':', ' ']).unwrap_or(rest.len());
+            let name = &rest[..name_end];
+
+            if kind != "func " {
+                return format!("{}{}", kind, name);
+            }
+
+            return format!("{}{}({})", kind, name, argument_labels(rest));
+        }
+    }
+
+    if declaration.starts_with("init") {
+        return format!("init({})", argument_labels(declaration));
+    }
+
+    for kind in ["class ", "struct ", "enum ", "protocol "] {
+        if let Some(rest) = declaration.strip_prefix(kind) {
+            return format!("type {}", type_name(rest));
+        }
+    }
+
+    declaration.to_string()
+}
+
+/// `add(_ value: Int, to other: Int) -> Int` -> `_:to:`
+fn argument_labels(func: &str) -> String {
+    let args_start = match func.find('(') {
+        Some(idx) => idx + 1,
+        None => return "".to_string(),
+    };
+
+    let mut labels = "".to_string();
+
+    let mut depth = 0;
+    let mut arg_start = args_start;
+    for (idx, c) in func[args_start..].char_indices() {
+        let idx = idx + args_start;
+
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            // The `>` in `->` does not close anything.
+            ')' | '>' | ']' if depth > 0 && !(c == '>' && func[..idx].ends_with('-')) => {
+                depth -= 1;
+            }
+            ',' | ')' if depth == 0 => {
+                let arg = func[arg_start..idx].trim();
+                if let Some(label) = arg.split([' ', ':']).next() {
+                    if !label.is_empty() {
+                        labels += label;
+                        labels += ":";
+                    }
+                }
+
+                if c == ')' {
+                    break;
+                }
+                arg_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    labels
+}
+
+fn strip_modifiers(mut declaration: &str) -> &str {
+    loop {
+        let stripped = [
+            "public ",
+            "open ",
+            "final ",
+            "override ",
+            "convenience ",
+            "static ",
+            "mutating ",
+            "@discardableResult ",
+        ]
+        .iter()
+        .find_map(|modifier| declaration.strip_prefix(modifier));
+
+        match stripped {
+            Some(stripped) => declaration = stripped,
+            None => return declaration,
+        }
+    }
+}
+
+// `public func foo() -> Int {` -> `public func foo() -> Int`
+fn strip_body(line: &str) -> &str {
+    line.trim_end().trim_end_matches('{').trim_end()
+}
+
+// `Foo<T>: Bar where T: Baz` -> `Foo`
+fn type_name(declaration: &str) -> &str {
+    let end = declaration
+        .find(['<', ':', ' '])
+        .unwrap_or(declaration.len());
+    &declaration[..end]
+}
+
+fn is_type_name(line: &str) -> bool {
+    !line.is_empty() && line.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// Types such as `__swift_bridge__$Foo` are implementation details of the generated code.
+fn is_private_type(type_name: &str) -> bool {
+    type_name.starts_with("__") || type_name.contains('$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENERATED: &str = r#"
+public func add(_ a: UInt32, to b: UInt32) -> UInt32 {
+    __swift_bridge__$add(a, b)
+}
+class CbWrapper$run {
+    public init(cb: @escaping (Result<(), Never>) -> ()) {
+        self.cb = cb
+    }
+}
+public enum Color {
+    case Red
+    case Custom(UInt8)
+}
+extension __swift_bridge__$Color {
+    func intoSwiftRepr() -> Color {
+        switch self.tag {
+            case __swift_bridge__$Color$Red:
+                return Color.Red
+        }
+    }
+}
+public class Foo: FooRefMut {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+extension FooRef {
+    public func bar<GenericToRustStr: ToRustStr>(_ a: UInt8, _ b: GenericToRustStr) -> UInt32 {
+        return b.toRustStr({ bAsRustStr in
+            __swift_bridge__$Foo$bar(ptr, a, bAsRustStr)
+        })
+    }
+}
+extension FooRef: Equatable {
+    public static func == (lhs: FooRef, rhs: FooRef) -> Bool {
+        __swift_bridge__$Foo$_partial_eq(lhs.ptr, rhs.ptr)
+    }
+}
+"#;
+
+    /// Verify that we collect the public declarations from generated Swift and ignore the
+    /// generated implementation details.
+    #[test]
+    fn collects_public_declarations() {
+        let manifest = SwiftApiManifest::from_generated_swift(GENERATED);
+
+        let expected = r#"// The public Swift API generated by swift-bridge.
+
+extension FooRef: Equatable
+public func add(_ a: UInt32, to b: UInt32) -> UInt32
+public enum Color
+public class Foo: FooRefMut
+
+Color
+    case Custom(UInt8)
+    case Red
+
+Foo
+    public override init(ptr: UnsafeMutableRawPointer)
+
+FooRef
+    public static func == (lhs: FooRef, rhs: FooRef) -> Bool
+    public func bar<GenericToRustStr: ToRustStr>(_ a: UInt8, _ b: GenericToRustStr) -> UInt32
+"#;
+        assert_eq!(manifest.to_string(), expected);
+    }
+
+    /// Verify that we can read back in a manifest that we wrote.
+    #[test]
+    fn parse_written_manifest() {
+        let manifest = SwiftApiManifest::from_generated_swift(GENERATED);
+
+        assert_eq!(SwiftApiManifest::parse(&manifest.to_string()), manifest);
+    }
+
+    /// Verify that we report removed and changed declarations, but not added ones.
+    #[test]
+    fn breaking_changes() {
+        let previous = SwiftApiManifest::from_generated_swift(GENERATED);
+
+        let current = GENERATED
+            .replace("to b: UInt32", "to b: UInt64")
+            .replace("    case Custom(UInt8)\n", "")
+            .replace(
+                "public class Foo: FooRefMut {",
+                "public class Foo: FooRefMut {\n    public func added() {\n    }",
+            );
+        let current = SwiftApiManifest::from_generated_swift(&current);
+
+        assert_eq!(
+            current.breaking_changes(&previous),
+            vec![
+                SwiftApiChange::Changed {
+                    container: None,
+                    old_signature: "public func add(_ a: UInt32, to b: UInt32) -> UInt32"
+                        .to_string(),
+                    new_signature: "public func add(_ a: UInt32, to b: UInt64) -> UInt32"
+                        .to_string(),
+                },
+                SwiftApiChange::Removed {
+                    container: Some("Color".to_string()),
+                    signature: "case Custom(UInt8)".to_string(),
+                },
+            ]
+        );
+    }
+
+    /// Verify that changing a function's argument labels is reported as a removal, since Swift
+    /// callers refer to the function by its labels.
+    #[test]
+    fn argument_labels_identify_functions() {
+        assert_eq!(
+            declaration_name("public func add(_ a: UInt32, to b: (UInt8) -> ()) -> UInt32"),
+            "func add(_:to:)"
+        );
+        assert_eq!(
+            declaration_name(
+                "public convenience init(fromRawPointer ptr: UnsafeMutableRawPointer)"
+            ),
+            "init(fromRawPointer:)"
+        );
+        assert_eq!(declaration_name("public func noArgs()"), "func noArgs()");
+    }
+}
@@ -0,0 +1,66 @@
+/// Whether or not a cargo feature of the crate whose build script is running is enabled.
+pub(crate) fn cargo_feature_is_enabled(feature_name: &str) -> bool {
+    let normalized_feature_name = feature_name.replace("-", "_");
+    let normalized_feature_name = normalized_feature_name.to_uppercase();
+
+    let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
+    std::env::var(env_var_name).is_ok()
+}
+
+/// Generate a `BridgeFeatures` enum with a static `Bool` for every feature, so that Swift can
+/// check which of the Rust crate's optional features were compiled in.
+///
+/// ```swift
+/// if BridgeFeatures.metal_renderer {
+///     // ...
+/// }
+/// ```
+pub(crate) fn generate_bridge_features_swift(
+    features: &[String],
+    is_enabled: impl Fn(&str) -> bool,
+) -> String {
+    if features.is_empty() {
+        return "".to_string();
+    }
+
+    let mut properties = vec![];
+    for feature in features {
+        properties.push(format!(
+            "    public static let {}: Bool = {}",
+            feature.replace("-", "_"),
+            is_enabled(feature)
+        ));
+    }
+
+    format!(
+        "public enum BridgeFeatures {{\n{}\n}}\n",
+        properties.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we generate a `Bool` for every feature, and that dashes in feature names
+    /// become underscores.
+    #[test]
+    fn generates_a_bool_per_feature() {
+        let features = vec!["decimal".to_string(), "metal-renderer".to_string()];
+
+        let swift = generate_bridge_features_swift(&features, |feature| feature == "decimal");
+
+        let expected = r#"public enum BridgeFeatures {
+    public static let decimal: Bool = true
+    public static let metal_renderer: Bool = false
+}
+"#;
+        assert_eq!(swift, expected);
+    }
+
+    /// Verify that we do not generate a `BridgeFeatures` enum if no features are exposed.
+    #[test]
+    fn no_features() {
+        assert_eq!(generate_bridge_features_swift(&[], |_| true), "");
+    }
+}
@@ -0,0 +1,146 @@
+use std::process::Command;
+
+/// The environment variable that a build script can set to the git hash of the Rust code that is
+/// being built. Otherwise we ask `git` for the hash of the crate's repository.
+const GIT_HASH_ENV_VAR: &str = "SWIFT_BRIDGE_GIT_HASH";
+
+/// Information about the crate whose build script is running.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BridgeInfo {
+    pub crate_name: String,
+    pub crate_version: String,
+    pub git_hash: Option<String>,
+    pub features: Vec<String>,
+    pub abi_version: u32,
+}
+
+impl BridgeInfo {
+    /// Read the information from the environment variables that cargo sets for build scripts.
+    pub fn from_build_env() -> Self {
+        let mut features: Vec<String> = std::env::vars()
+            .filter_map(|(key, _)| {
+                key.strip_prefix("CARGO_FEATURE_")
+                    .map(|feature| feature.to_lowercase())
+            })
+            .collect();
+        features.sort();
+
+        BridgeInfo {
+            crate_name: std::env::var("CARGO_PKG_NAME").unwrap_or_default(),
+            crate_version: std::env::var("CARGO_PKG_VERSION").unwrap_or_default(),
+            git_hash: std::env::var(GIT_HASH_ENV_VAR).ok().or_else(git_hash),
+            features,
+            abi_version: swift_bridge_ir::BRIDGE_ABI_VERSION,
+        }
+    }
+}
+
+fn git_hash() -> Option<String> {
+    let mut command = Command::new("git");
+    command.args(["rev-parse", "--short", "HEAD"]);
+    if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        command.current_dir(manifest_dir);
+    }
+
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?;
+    let hash = hash.trim();
+    (!hash.is_empty()).then(|| hash.to_string())
+}
+
+/// Generate a `BridgeInfo` struct and a `bridgeInfo()` function that returns the information about
+/// the Rust crate that the Swift code was generated for.
+///
+/// ```swift
+/// print("Running \(bridgeInfo())")
+/// ```
+pub(crate) fn generate_bridge_info_swift(info: &BridgeInfo) -> String {
+    let git_hash = match &info.git_hash {
+        Some(git_hash) => swift_string_literal(git_hash),
+        None => "nil".to_string(),
+    };
+    let features: Vec<String> = info
+        .features
+        .iter()
+        .map(|feature| swift_string_literal(feature))
+        .collect();
+
+    format!(
+        r#"public struct BridgeInfo: Equatable, CustomStringConvertible {{
+    public let crateName: String
+    public let crateVersion: String
+    public let gitHash: String?
+    public let features: [String]
+    public let abiVersion: UInt32
+
+    public var description: String {{
+        var description = "\(crateName) \(crateVersion)"
+        if let gitHash = gitHash {{
+            description += " (\(gitHash))"
+        }}
+        if !features.isEmpty {{
+            description += " [\(features.joined(separator: ", "))]"
+        }}
+        return description + " ABI \(abiVersion)"
+    }}
+}}
+public func bridgeInfo() -> BridgeInfo {{
+    BridgeInfo(crateName: {crate_name}, crateVersion: {crate_version}, gitHash: {git_hash}, features: [{features}], abiVersion: {abi_version})
+}}
+"#,
+        crate_name = swift_string_literal(&info.crate_name),
+        crate_version = swift_string_literal(&info.crate_version),
+        git_hash = git_hash,
+        features = features.join(", "),
+        abi_version = info.abi_version,
+    )
+}
+
+fn swift_string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we generate a `bridgeInfo()` function that returns the crate's information.
+    #[test]
+    fn generates_bridge_info() {
+        let info = BridgeInfo {
+            crate_name: "my_crate".to_string(),
+            crate_version: "1.2.3".to_string(),
+            git_hash: Some("abc1234".to_string()),
+            features: vec!["decimal".to_string(), "metal_renderer".to_string()],
+            abi_version: 1,
+        };
+
+        let swift = generate_bridge_info_swift(&info);
+
+        assert!(swift.contains(
+            r#"public func bridgeInfo() -> BridgeInfo {
+    BridgeInfo(crateName: "my_crate", crateVersion: "1.2.3", gitHash: "abc1234", features: ["decimal", "metal_renderer"], abiVersion: 1)
+}"#
+        ));
+    }
+
+    /// Verify that the git hash is `nil` when it is not known.
+    #[test]
+    fn no_git_hash() {
+        let info = BridgeInfo {
+            crate_name: "my_crate".to_string(),
+            crate_version: "0.1.0".to_string(),
+            git_hash: None,
+            features: vec![],
+            abi_version: 1,
+        };
+
+        let swift = generate_bridge_info_swift(&info);
+
+        assert!(swift.contains(r#"gitHash: nil, features: [], abiVersion: 1)"#));
+    }
+}
@@ -1,10 +1,38 @@
 use crate::generate_core::boxed_fn_support::{
-    C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN,
+    C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, C_FN_CLOSURE_SUPPORT,
+    SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_FN_CLOSURE_SUPPORT,
+};
+use crate::generate_core::breadcrumbs_support::{C_BREADCRUMBS_SUPPORT, SWIFT_BREADCRUMBS_SUPPORT};
+use crate::generate_core::byte_stream_support::{C_BYTE_STREAM_SUPPORT, SWIFT_BYTE_STREAM_SUPPORT};
+use crate::generate_core::cancellation_support::{C_CANCELLATION_TOKEN, SWIFT_CANCELLATION_TOKEN};
+use crate::generate_core::consume_support::SWIFT_CONSUME_SUPPORT;
+use crate::generate_core::context_support::{C_CONTEXT_SUPPORT, SWIFT_CONTEXT_SUPPORT};
+use crate::generate_core::data_support::{C_DATA_SUPPORT, SWIFT_DATA_SUPPORT};
+use crate::generate_core::debug_support::{C_DEBUG_SUPPORT, SWIFT_DEBUG_SUPPORT};
+use crate::generate_core::decimal_support::{C_DECIMAL_SUPPORT, SWIFT_DECIMAL_SUPPORT};
+use crate::generate_core::hash_map_support::{
+    c_rust_map_headers, swift_rust_map_initializers, SWIFT_RUST_MAP,
+};
+use crate::generate_core::hash_set_support::{
+    c_rust_set_headers, swift_rust_set_initializers, SWIFT_RUST_SET,
+};
+use crate::generate_core::image_support::{C_IMAGE_SUPPORT, SWIFT_IMAGE_SUPPORT};
+use crate::generate_core::lifecycle_support::{C_LIFECYCLE_SUPPORT, SWIFT_LIFECYCLE_SUPPORT};
+use crate::generate_core::locale_support::{C_LOCALE_SUPPORT, SWIFT_LOCALE_SUPPORT};
+use crate::generate_core::memory_pressure_support::{
+    C_MEMORY_PRESSURE_SUPPORT, SWIFT_MEMORY_PRESSURE_SUPPORT,
 };
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
+use crate::generate_core::os_log_support::SWIFT_OS_LOG_SUPPORT;
+use crate::generate_core::reader_support::{C_READER_SUPPORT, SWIFT_READER_SUPPORT};
 use crate::generate_core::result_support::{C_RESULT_SUPPORT, SWIFT_RUST_RESULT};
+use crate::generate_core::rust_io_support::{C_RUST_IO_SUPPORT, SWIFT_RUST_IO_SUPPORT};
+use crate::generate_core::scope_support::{C_SCOPE_SUPPORT, SWIFT_SCOPE_SUPPORT};
+use crate::generate_core::text_support::{C_TEXT_SUPPORT, SWIFT_TEXT_SUPPORT};
+use crate::generate_core::timeout_support::SWIFT_TIMEOUT_SUPPORT;
+use crate::generate_core::versioning_support::SWIFT_VERSIONING_SUPPORT;
 use std::path::Path;
 
 const RUST_STRING_SWIFT: &'static str = include_str!("./generate_core/rust_string.swift");
@@ -12,35 +40,208 @@ const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_CHANNEL_SWIFT: &str = include_str!("./generate_core/rust_channel.swift");
+const RUST_ATOMIC_CELL_SWIFT: &str = include_str!("./generate_core/rust_atomic_cell.swift");
 
 mod boxed_fn_support;
+mod breadcrumbs_support;
+mod byte_stream_support;
+mod cancellation_support;
+mod consume_support;
+mod context_support;
+mod data_support;
+mod debug_support;
+mod decimal_support;
+mod hash_map_support;
+mod hash_set_support;
+mod image_support;
+mod lifecycle_support;
+mod locale_support;
+mod memory_pressure_support;
 mod option_support;
+mod os_log_support;
+mod reader_support;
 mod result_support;
+mod rust_io_support;
+mod scope_support;
+mod text_support;
+mod timeout_support;
+mod versioning_support;
+
+/// `generated_swift` is the Swift code that was generated for the bridge modules. Support code
+/// that only some bridge modules need is only written if the generated code uses it.
+pub(super) fn write_core_swift_and_c(out_dir: &Path, generated_swift: &str) {
+    let features = RuntimeFeatures::enabled();
 
-pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     let core_swift_out = out_dir.join("SwiftBridgeCore.swift");
+    std::fs::write(core_swift_out, core_swift_file(generated_swift, &features)).unwrap();
+
+    let core_c_header_out = out_dir.join("SwiftBridgeCore.h");
+    std::fs::write(
+        core_c_header_out,
+        core_c_header_file(generated_swift, &features),
+    )
+    .unwrap();
+}
+
+/// The optional `swift-bridge` runtime modules that the support code gets written for.
+///
+/// Their support code calls `extern "C"` functions that only exist when the module is compiled
+/// into the Rust library, so `swift-bridge` enables our feature of the same name along with its
+/// own.
+#[derive(Debug, Default, Copy, Clone)]
+struct RuntimeFeatures {
+    breadcrumbs: bool,
+    byte_stream: bool,
+    context: bool,
+    debug: bool,
+    decimal: bool,
+    image: bool,
+    lifecycle: bool,
+    locale: bool,
+    memory_pressure: bool,
+    os_log: bool,
+    reader: bool,
+    rust_io: bool,
+    scope: bool,
+    text: bool,
+}
+
+impl RuntimeFeatures {
+    fn enabled() -> Self {
+        RuntimeFeatures {
+            breadcrumbs: cfg!(feature = "breadcrumbs"),
+            byte_stream: cfg!(feature = "byte_stream"),
+            context: cfg!(feature = "context"),
+            debug: cfg!(feature = "debug"),
+            decimal: cfg!(feature = "decimal"),
+            image: cfg!(feature = "image"),
+            lifecycle: cfg!(feature = "lifecycle"),
+            locale: cfg!(feature = "locale"),
+            memory_pressure: cfg!(feature = "memory_pressure"),
+            os_log: cfg!(feature = "os_log"),
+            reader: cfg!(feature = "reader"),
+            rust_io: cfg!(feature = "rust_io"),
+            scope: cfg!(feature = "scope"),
+            text: cfg!(feature = "text"),
+        }
+    }
+}
+
+/// Whether the generated code converts between Foundation's `Data` and `RustVec<UInt8>`.
+fn uses_data(generated_swift: &str) -> bool {
+    generated_swift.contains("RustVec<UInt8>") || generated_swift.contains(".toRustVec()")
+}
+
+/// The contents of SwiftBridgeCore.swift
+fn core_swift_file(generated_swift: &str, features: &RuntimeFeatures) -> String {
     let mut swift = core_swift();
     swift += "\n";
     swift += &RUST_STRING_SWIFT;
     swift += "\n";
     swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     swift += "\n";
+    swift += SWIFT_FN_CLOSURE_SUPPORT;
+    swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
     swift += &swift_option_primitive_support();
+    swift += "\n";
+    swift += "\n";
+    swift += SWIFT_CANCELLATION_TOKEN;
+    swift += "\n";
+    // `withTaskCancellationHandler(operation:onCancel:)` needs Swift 5.7.
+    if generated_swift.contains("__private__withTimeout(") {
+        swift += SWIFT_TIMEOUT_SUPPORT;
+        swift += "\n";
+    }
+    if generated_swift.contains("RustApiFunction(") {
+        swift += SWIFT_VERSIONING_SUPPORT;
+        swift += "\n";
+    }
+    if generated_swift.contains("__private__consume(") {
+        swift += SWIFT_CONSUME_SUPPORT;
+        swift += "\n";
+    }
+    if uses_data(generated_swift) {
+        swift += SWIFT_DATA_SUPPORT;
+        swift += "\n";
+    }
+
+    for (enabled, support) in [
+        (features.memory_pressure, SWIFT_MEMORY_PRESSURE_SUPPORT),
+        (features.scope, SWIFT_SCOPE_SUPPORT),
+        (features.debug, SWIFT_DEBUG_SUPPORT),
+        (features.lifecycle, SWIFT_LIFECYCLE_SUPPORT),
+        (features.os_log, SWIFT_OS_LOG_SUPPORT),
+        (features.breadcrumbs, SWIFT_BREADCRUMBS_SUPPORT),
+        (features.context, SWIFT_CONTEXT_SUPPORT),
+        (features.byte_stream, SWIFT_BYTE_STREAM_SUPPORT),
+        (features.reader, SWIFT_READER_SUPPORT),
+        (features.rust_io, SWIFT_RUST_IO_SUPPORT),
+        (features.locale, SWIFT_LOCALE_SUPPORT),
+        (features.text, SWIFT_TEXT_SUPPORT),
+        (features.image, SWIFT_IMAGE_SUPPORT),
+        (features.decimal, SWIFT_DECIMAL_SUPPORT),
+    ] {
+        if enabled {
+            swift += support;
+            swift += "\n";
+        }
+    }
 
-    std::fs::write(core_swift_out, swift).unwrap();
+    swift
+}
 
-    let core_c_header_out = out_dir.join("SwiftBridgeCore.h");
+/// The contents of SwiftBridgeCore.h
+///
+/// The declarations are wrapped in `extern "C"` so that the header can also be included from
+/// C++.
+fn core_c_header_file(generated_swift: &str, features: &RuntimeFeatures) -> String {
     let mut c_header = core_c_header().to_string();
     c_header += "\n";
     c_header += &RUST_STRING_C;
     c_header += "\n";
     c_header += &C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     c_header += "\n";
+    c_header += C_FN_CLOSURE_SUPPORT;
+    c_header += "\n";
     c_header += &C_RESULT_SUPPORT;
+    c_header += "\n";
+    c_header += C_CANCELLATION_TOKEN;
+    c_header += "\n";
+    if uses_data(generated_swift) {
+        c_header += C_DATA_SUPPORT;
+        c_header += "\n";
+    }
 
-    std::fs::write(core_c_header_out, c_header).unwrap();
+    for (enabled, support) in [
+        (features.memory_pressure, C_MEMORY_PRESSURE_SUPPORT),
+        (features.scope, C_SCOPE_SUPPORT),
+        (features.debug, C_DEBUG_SUPPORT),
+        (features.lifecycle, C_LIFECYCLE_SUPPORT),
+        (features.breadcrumbs, C_BREADCRUMBS_SUPPORT),
+        (features.context, C_CONTEXT_SUPPORT),
+        (features.byte_stream, C_BYTE_STREAM_SUPPORT),
+        (features.reader, C_READER_SUPPORT),
+        (features.rust_io, C_RUST_IO_SUPPORT),
+        (features.locale, C_LOCALE_SUPPORT),
+        (features.text, C_TEXT_SUPPORT),
+        (features.image, C_IMAGE_SUPPORT),
+        (features.decimal, C_DECIMAL_SUPPORT),
+    ] {
+        if enabled {
+            c_header += support;
+            c_header += "\n";
+        }
+    }
+    c_header += r#"
+#ifdef __cplusplus
+}
+#endif
+"#;
+
+    c_header
 }
 
 fn core_swift() -> String {
@@ -48,6 +249,8 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += RUST_CHANNEL_SWIFT;
+    core_swift += RUST_ATOMIC_CELL_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -68,8 +271,15 @@ fn core_swift() -> String {
         ("Double", "f64"),
     ] {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
+        core_swift += &conform_to_channelable(swift_ty, rust_ty);
+        core_swift += &conform_to_atomic_cellable(swift_ty, rust_ty);
     }
 
+    core_swift += SWIFT_RUST_MAP;
+    core_swift += &swift_rust_map_initializers();
+    core_swift += SWIFT_RUST_SET;
+    core_swift += &swift_rust_set_initializers();
+
     core_swift += &generic_freer();
     core_swift += &generic_copy_type_ffi_repr();
 
@@ -79,6 +289,16 @@ fn core_swift() -> String {
 fn core_c_header() -> String {
     let mut header = r#"#include <stdint.h>
 #include <stdbool.h> 
+#ifdef __cplusplus
+// C++ cannot include <stdatomic.h>, so we use the compiler builtins that it is built on.
+#define __swift_bridge__atomic_load(ty, ptr) __atomic_load_n((ty*)(ptr), __ATOMIC_SEQ_CST)
+#define __swift_bridge__atomic_store(ty, ptr, val) __atomic_store_n((ty*)(ptr), (val), __ATOMIC_SEQ_CST)
+extern "C" {
+#else
+#include <stdatomic.h>
+#define __swift_bridge__atomic_load(ty, ptr) atomic_load((_Atomic ty*)(ptr))
+#define __swift_bridge__atomic_store(ty, ptr, val) atomic_store((_Atomic ty*)(ptr), (val))
+#endif
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
 void* __swift_bridge__null_pointer(void);
@@ -106,7 +326,11 @@ void* __swift_bridge__null_pointer(void);
         ("f64", "double"),
     ] {
         header += &vec_of_primitive_headers(rust_ty, c_ty);
+        header += &channel_of_primitive_headers(rust_ty, c_ty);
+        header += &atomic_cell_of_primitive_headers(rust_ty, c_ty);
     }
+    header += &c_rust_map_headers();
+    header += &c_rust_set_headers();
 
     header
 }
@@ -139,6 +363,72 @@ void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
     )
 }
 
+/// Headers for Sender<T> and Receiver<T> where T is a primitive such as u8, i32, bool
+fn channel_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
+    let mut chars = rust_ty.chars();
+
+    // u8 -> U8, bool -> Bool, etc...
+    let capatilized_first_letter =
+        chars.next().unwrap().to_string().to_uppercase() + chars.as_str();
+
+    // __private__OptionU8 ... etc
+    let option_ty = format!("{}{}", "__private__Option", capatilized_first_letter);
+
+    format!(
+        r#"
+bool __swift_bridge__$Sender_{rust_ty}$send(void* const sender, {c_ty} val);
+void* __swift_bridge__$Sender_{rust_ty}$clone(void* const sender);
+void __swift_bridge__$Sender_{rust_ty}$_free(void* const sender);
+{option_ty} __swift_bridge__$Receiver_{rust_ty}$recv(void* const receiver);
+{option_ty} __swift_bridge__$Receiver_{rust_ty}$try_recv(void* const receiver);
+void __swift_bridge__$Receiver_{rust_ty}$_free(void* const receiver);
+"#,
+        rust_ty = rust_ty,
+        c_ty = c_ty,
+        option_ty = option_ty
+    )
+}
+
+/// Headers for AtomicCell<T> where T is a primitive such as u8, i32, bool
+///
+/// Loads and stores are `static inline` so that Swift accesses the value directly instead of
+/// calling into Rust.
+fn atomic_cell_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
+    let (load, store) = match rust_ty {
+        // Rust stores floats as their bits.
+        "f32" | "f64" => {
+            let bits_ty = if rust_ty == "f32" {
+                "uint32_t"
+            } else {
+                "uint64_t"
+            };
+            (
+                format!(
+                    "union {{ {bits_ty} bits; {c_ty} val; }} u; u.bits = __swift_bridge__atomic_load({bits_ty}, value); return u.val;",
+                ),
+                format!(
+                    "union {{ {bits_ty} bits; {c_ty} val; }} u; u.val = val; __swift_bridge__atomic_store({bits_ty}, value, u.bits);",
+                ),
+            )
+        }
+        _ => (
+            format!("return __swift_bridge__atomic_load({c_ty}, value);"),
+            format!("__swift_bridge__atomic_store({c_ty}, value, val);"),
+        ),
+    };
+
+    format!(
+        r#"
+void* __swift_bridge__$AtomicCell_{rust_ty}$new({c_ty} value);
+void* __swift_bridge__$AtomicCell_{rust_ty}$clone(void* const cell);
+void* __swift_bridge__$AtomicCell_{rust_ty}$value_ptr(void* const cell);
+void __swift_bridge__$AtomicCell_{rust_ty}$_free(void* const cell);
+static inline {c_ty} __swift_bridge__$AtomicCell_{rust_ty}$load(void* const value) {{ {load} }}
+static inline void __swift_bridge__$AtomicCell_{rust_ty}$store(void* const value, {c_ty} val) {{ {store} }}
+"#,
+    )
+}
+
 fn conform_to_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
     format!(
         r#"
@@ -196,6 +486,84 @@ extension {swift_ty}: Vectorizable {{
     )
 }
 
+fn conform_to_channelable(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: Channelable {{
+    public static func senderOfSelfSend(senderPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$Sender_{rust_ty}$send(senderPtr, value)
+    }}
+
+    public static func senderOfSelfClone(senderPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Sender_{rust_ty}$clone(senderPtr)
+    }}
+
+    public static func senderOfSelfFree(senderPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Sender_{rust_ty}$_free(senderPtr)
+    }}
+
+    public static func receiverOfSelfRecv(receiverPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let val = __swift_bridge__$Receiver_{rust_ty}$recv(receiverPtr)
+        if val.is_some {{
+            return val.val
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func receiverOfSelfTryRecv(receiverPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let val = __swift_bridge__$Receiver_{rust_ty}$try_recv(receiverPtr)
+        if val.is_some {{
+            return val.val
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func receiverOfSelfFree(receiverPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Receiver_{rust_ty}$_free(receiverPtr)
+    }}
+}}
+    "#,
+        rust_ty = rust_ty,
+        swift_ty = swift_ty
+    )
+}
+
+fn conform_to_atomic_cellable(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: AtomicCellable {{
+    public static func atomicCellOfSelfNew(value: Self) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$AtomicCell_{rust_ty}$new(value)
+    }}
+
+    public static func atomicCellOfSelfClone(cellPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$AtomicCell_{rust_ty}$clone(cellPtr)
+    }}
+
+    public static func atomicCellOfSelfValuePtr(cellPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$AtomicCell_{rust_ty}$value_ptr(cellPtr)
+    }}
+
+    public static func atomicCellOfSelfFree(cellPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$AtomicCell_{rust_ty}$_free(cellPtr)
+    }}
+
+    public static func atomicCellOfSelfLoad(valuePtr: UnsafeMutableRawPointer) -> Self {{
+        __swift_bridge__$AtomicCell_{rust_ty}$load(valuePtr)
+    }}
+
+    public static func atomicCellOfSelfStore(valuePtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$AtomicCell_{rust_ty}$store(valuePtr, value)
+    }}
+}}
+    "#,
+        rust_ty = rust_ty,
+        swift_ty = swift_ty
+    )
+}
+
 /// Used to free memory for generic Opaque Rust types such as `type SomeType<u32>`
 fn generic_freer() -> &'static str {
     r#"
@@ -212,3 +580,77 @@ fn generic_copy_type_ffi_repr() -> &'static str {
 protocol SwiftBridgeGenericCopyTypeFfiRepr {}
 "#
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we only write the timeout support code if a function uses the `timeout`
+    /// attribute.
+    #[test]
+    fn timeout_support_is_only_written_when_used() {
+        let features = RuntimeFeatures::default();
+        assert!(!core_swift_file("", &features).contains("__private__withTimeout"));
+
+        let generated_swift = "try await __private__withTimeout(timeout, { _ in some_function() })";
+        assert!(core_swift_file(generated_swift, &features).contains("func __private__withTimeout"));
+    }
+
+    /// Verify that we only write the support code for a runtime module if its feature is enabled.
+    #[test]
+    fn runtime_module_support_is_only_written_when_enabled() {
+        let disabled = RuntimeFeatures::default();
+        assert!(!core_swift_file("", &disabled).contains("func notifyRustLifecycleEvent"));
+        assert!(!core_c_header_file("", &disabled).contains("__swift_bridge__$lifecycle$dispatch"));
+
+        let enabled = RuntimeFeatures {
+            lifecycle: true,
+            ..RuntimeFeatures::default()
+        };
+        assert!(core_swift_file("", &enabled).contains("func notifyRustLifecycleEvent"));
+        assert!(core_c_header_file("", &enabled).contains("__swift_bridge__$lifecycle$dispatch"));
+        assert!(!core_swift_file("", &enabled).contains("func purgeRustCaches"));
+    }
+
+    /// Verify that we only write the support code for consuming methods, `Data` conversions and
+    /// versioned functions if the generated code uses it.
+    #[test]
+    fn generated_code_support_is_only_written_when_used() {
+        let features = RuntimeFeatures::default();
+        let core = core_swift_file("", &features);
+        assert!(!core.contains("func __private__consume"));
+        assert!(!core.contains("public func toData"));
+        assert!(!core.contains("public struct RustApiFunction"));
+        assert!(!core_c_header_file("", &features).contains("Vec_u8$from_slice"));
+
+        let generated_swift = r#"
+__swift_bridge__$SomeType$finish({__private__consume("SomeType.finish()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}())
+RustVec<UInt8>(ptr: __swift_bridge__$some_function()).toData()
+static let some_function = RustApiFunction(name: "some_function", since: "1.2.0", symbol: "__swift_bridge__$some_function")
+"#;
+        let core = core_swift_file(generated_swift, &features);
+        assert!(core.contains("func __private__consume"));
+        assert!(core.contains("public func toData"));
+        assert!(core.contains("public struct RustApiFunction"));
+        assert!(core_c_header_file(generated_swift, &features).contains("Vec_u8$from_slice"));
+    }
+
+    /// Verify that SwiftBridgeCore.h can be included from C++.
+    #[test]
+    fn core_c_header_is_cxx_compatible() {
+        let header = core_c_header_file("", &RuntimeFeatures::default());
+
+        let cxx_branch_end = header.find("#else").unwrap();
+        assert!(header[..cxx_branch_end].contains(r#"extern "C" {"#));
+        assert!(!header[..cxx_branch_end].contains("_Atomic"));
+        assert!(header.ends_with(
+            r#"#ifdef __cplusplus
+}
+#endif
+"#
+        ));
+
+        // `_Atomic` is only used by the C definitions of the atomic load and store macros.
+        assert_eq!(header.matches("_Atomic").count(), 2);
+    }
+}
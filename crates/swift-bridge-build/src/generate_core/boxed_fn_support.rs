@@ -31,3 +31,49 @@ pub const C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN: &'static str = r#"
 void __swift_bridge__$call_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 void __swift_bridge__$free_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 "#;
+
+/// Declares support types for passing closures between Swift and Rust as a `Box<dyn Fn(..)>`.
+pub const SWIFT_FN_CLOSURE_SUPPORT: &str = r#"
+public class __private__SwiftClosureBox<T> {
+    let closure: T
+
+    public init(_ closure: T) {
+        self.closure = closure
+    }
+
+    public func toFfiClosure() -> __private__FfiClosure {
+        __private__FfiClosure(
+            context: Unmanaged.passRetained(self).toOpaque(),
+            free: __private__releaseSwiftClosure
+        )
+    }
+
+    public static func fromContext(_ context: UnsafeMutableRawPointer?) -> T {
+        Unmanaged<__private__SwiftClosureBox<T>>.fromOpaque(context!).takeUnretainedValue().closure
+    }
+}
+
+public func __private__releaseSwiftClosure(_ context: UnsafeMutableRawPointer?) {
+    Unmanaged<AnyObject>.fromOpaque(context!).release()
+}
+
+public class __private__RustFnClosure {
+    let closure: __private__FfiClosure
+
+    public init(_ closure: __private__FfiClosure) {
+        self.closure = closure
+    }
+
+    public var context: UnsafeMutableRawPointer? {
+        closure.context
+    }
+
+    deinit {
+        closure.free(closure.context)
+    }
+}
+"#;
+
+pub const C_FN_CLOSURE_SUPPORT: &str = r#"
+typedef struct __private__FfiClosure { void* context; void (*free)(void*); } __private__FfiClosure;
+"#;
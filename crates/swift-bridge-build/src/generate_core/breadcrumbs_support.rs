@@ -0,0 +1,15 @@
+/// Lets Swift read the breadcrumbs that `#[swift_bridge::bridge(breadcrumbs)]` modules record.
+pub const SWIFT_BREADCRUMBS_SUPPORT: &str = r#"
+/// The most recent calls that Swift made into `#[swift_bridge::bridge(breadcrumbs)]` modules,
+/// oldest first, such as `"some_function on thread main"`.
+///
+/// Useful for attaching to crash reports.
+public func rustBreadcrumbs() -> [String] {
+    let breadcrumbs = RustString(ptr: __swift_bridge__$breadcrumbs$snapshot()).toString()
+    return breadcrumbs.split(separator: "\n").map(String.init)
+}
+"#;
+
+pub const C_BREADCRUMBS_SUPPORT: &str = r#"
+void* __swift_bridge__$breadcrumbs$snapshot(void);
+"#;
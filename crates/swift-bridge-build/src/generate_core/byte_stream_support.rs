@@ -0,0 +1,118 @@
+/// Swift's side of `swift_bridge::byte_stream::ByteStream`, which Swift consumes as an
+/// `AsyncSequence` of `Data` chunks.
+///
+/// Each call to `next()` hands Rust a retained wrapper around a continuation. Rust calls back
+/// exactly once, either with a chunk, which we copy into a `Data` before returning, or with the
+/// end of the stream. Rust queues the calls of tasks that iterate the same stream concurrently,
+/// and a cancelled task's call ends as if the stream had ended.
+///
+/// Swift concurrency needs macOS 10.15 / iOS 13, so the `AsyncSequence` conformance is gated with
+/// `@available`. Swift 5.7 swapped the parameters of `withTaskCancellationHandler`, so we pick the
+/// order based on the compiler version to keep supporting older toolchains.
+pub const SWIFT_BYTE_STREAM_SUPPORT: &str = r#"
+public class ByteStream {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$ByteStream$_free(ptr)
+    }
+}
+
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+extension ByteStream: AsyncSequence {
+    public typealias Element = Data
+
+    public struct AsyncIterator: AsyncIteratorProtocol {
+        let stream: ByteStream
+
+        public mutating func next() async -> Data? {
+            await stream.nextChunk()
+        }
+    }
+
+    public func makeAsyncIterator() -> AsyncIterator {
+        AsyncIterator(stream: self)
+    }
+
+    /// Returns nil once the stream has ended, or if the task is cancelled while it waits for a
+    /// chunk.
+    func nextChunk() async -> Data? {
+        let request = __private__ByteStreamNextChunk()
+        let ptr = self.ptr
+        let cancel: @Sendable () -> Void = {
+            request.cancel {
+                __swift_bridge__$ByteStream$cancel_next(ptr, Unmanaged.passUnretained(request).toOpaque())
+            }
+        }
+
+        #if compiler(>=5.7)
+        return await withTaskCancellationHandler(operation: { await requestChunk(request) }, onCancel: cancel)
+        #else
+        return await withTaskCancellationHandler(handler: cancel, operation: { await requestChunk(request) })
+        #endif
+    }
+
+    private func requestChunk(_ request: __private__ByteStreamNextChunk) async -> Data? {
+        await withCheckedContinuation({ (continuation: CheckedContinuation<Data?, Never>) in
+            request.continuation = continuation
+
+            let requested = request.start {
+                __swift_bridge__$ByteStream$next(ptr, Unmanaged.passRetained(request).toOpaque(), { wrapperPtr, start, len, isEnd in
+                    let wrapper = Unmanaged<__private__ByteStreamNextChunk>.fromOpaque(wrapperPtr!).takeRetainedValue()
+                    if isEnd {
+                        wrapper.continuation!.resume(returning: nil)
+                    } else {
+                        wrapper.continuation!.resume(returning: Data(bytes: start!, count: Int(len)))
+                    }
+                })
+            }
+            if !requested {
+                continuation.resume(returning: nil)
+            }
+        })
+    }
+}
+
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+final class __private__ByteStreamNextChunk {
+    var continuation: CheckedContinuation<Data?, Never>?
+
+    // Makes sure that Rust either never sees the request, or sees it before it gets cancelled.
+    private let lock = NSLock()
+    private var isStarted = false
+    private var isCancelled = false
+
+    /// Returns false without calling `sendRequest` if the task was already cancelled.
+    func start(_ sendRequest: () -> Void) -> Bool {
+        lock.lock()
+        defer { lock.unlock() }
+
+        if isCancelled {
+            return false
+        }
+        isStarted = true
+        sendRequest()
+        return true
+    }
+
+    func cancel(_ cancelRequest: () -> Void) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        isCancelled = true
+        if isStarted {
+            cancelRequest()
+        }
+    }
+}
+"#;
+
+pub const C_BYTE_STREAM_SUPPORT: &str = r#"
+void __swift_bridge__$ByteStream$next(void* stream, void* callback_wrapper, void (*callback)(void* callback_wrapper, const uint8_t* start, uintptr_t len, bool is_end));
+void __swift_bridge__$ByteStream$cancel_next(void* stream, void* callback_wrapper);
+void __swift_bridge__$ByteStream$_free(void* stream);
+"#;
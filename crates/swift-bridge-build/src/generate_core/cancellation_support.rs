@@ -0,0 +1,31 @@
+/// Swift's side of `swift_bridge::cancellation::CancellationToken`, which is used by functions
+/// with the `#[swift_bridge(cancellable)]` attribute.
+pub const SWIFT_CANCELLATION_TOKEN: &str = r#"
+public class CancellationToken {
+    var ptr: UnsafeMutableRawPointer
+
+    public init() {
+        self.ptr = __swift_bridge__$CancellationToken$new()
+    }
+
+    deinit {
+        __swift_bridge__$CancellationToken$_free(ptr)
+    }
+
+    /// Ask any Rust functions that were given this token to stop early.
+    public func cancel() {
+        __swift_bridge__$CancellationToken$cancel(ptr)
+    }
+
+    public var isCancelled: Bool {
+        __swift_bridge__$CancellationToken$is_cancelled(ptr)
+    }
+}
+"#;
+
+pub const C_CANCELLATION_TOKEN: &str = r#"
+void* __swift_bridge__$CancellationToken$new(void);
+void __swift_bridge__$CancellationToken$cancel(void* token);
+bool __swift_bridge__$CancellationToken$is_cancelled(void* token);
+void __swift_bridge__$CancellationToken$_free(void* token);
+"#;
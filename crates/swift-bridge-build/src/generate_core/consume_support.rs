@@ -0,0 +1,18 @@
+/// Support for `self` methods, which consume an opaque Rust type.
+///
+/// Giving up ownership happens under a lock, so when two threads consume the same value only one
+/// of them hands it to Rust and the other one traps instead of freeing it a second time.
+pub const SWIFT_CONSUME_SUPPORT: &str = r#"
+private let __private__consumeLock = NSLock()
+
+/// Gives up ownership of a Rust value that a consuming method is about to hand to Rust.
+///
+/// `giveUpOwnership` sets the value's `isOwned` to false and returns what it was before.
+func __private__consume(_ method: StaticString, _ giveUpOwnership: () -> Bool) {
+    __private__consumeLock.lock()
+    let wasOwned = giveUpOwnership()
+    __private__consumeLock.unlock()
+
+    precondition(wasOwned, "\(method) was called on a value that was already consumed")
+}
+"#;
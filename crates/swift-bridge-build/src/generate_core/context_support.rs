@@ -0,0 +1,80 @@
+/// Swift's side of `swift_bridge::context`, which is used by modules with the
+/// `#[swift_bridge::bridge(propagate_context)]` attribute.
+///
+/// The context that the caller captures is stored in a thread-local stack of frames on the Rust
+/// side, where the callee reads it from.
+pub const SWIFT_CONTEXT_SUPPORT: &str = r#"
+/// Captures and restores one kind of thread-local context, such as a request ID.
+public protocol ContextPropagator {
+    /// The current thread's value of this context, if any.
+    func capture() -> String?
+
+    /// Replace the current thread's value of this context.
+    func restore(_ value: String?)
+}
+
+private let __private__contextPropagatorsLock = NSLock()
+private var __private__contextPropagators: [(String, ContextPropagator)] = []
+
+/// Register a propagator for the context with the given name.
+///
+/// Rust registers its propagator for the same context with
+/// `swift_bridge::context::register_context_propagator`.
+public func registerContextPropagator(name: String, _ propagator: ContextPropagator) {
+    __private__contextPropagatorsLock.lock()
+    defer { __private__contextPropagatorsLock.unlock() }
+
+    __private__contextPropagators.append((name, propagator))
+}
+
+private func __private__registeredContextPropagators() -> [(String, ContextPropagator)] {
+    __private__contextPropagatorsLock.lock()
+    defer { __private__contextPropagatorsLock.unlock() }
+
+    return __private__contextPropagators
+}
+
+func __private__withContextPropagatedToRust<T>(_ body: () throws -> T) rethrows -> T {
+    __swift_bridge__$context$push_frame()
+    defer { __swift_bridge__$context$pop_frame() }
+
+    for (name, propagator) in __private__registeredContextPropagators() {
+        if let value = propagator.capture() {
+            name.toRustStr({ nameAsRustStr in
+                value.toRustStr({ valueAsRustStr in
+                    __swift_bridge__$context$frame_insert(nameAsRustStr, valueAsRustStr)
+                })
+            })
+        }
+    }
+
+    return try body()
+}
+
+func __private__withContextFromRust<T>(_ body: () throws -> T) rethrows -> T {
+    var previous: [(ContextPropagator, String?)] = []
+    for (name, propagator) in __private__registeredContextPropagators() {
+        let value: UnsafeMutableRawPointer? = name.toRustStr({ nameAsRustStr in
+            __swift_bridge__$context$frame_get(nameAsRustStr)
+        })
+        if let value = value {
+            previous.append((propagator, propagator.capture()))
+            propagator.restore(RustString(ptr: value).toString())
+        }
+    }
+    defer {
+        for (propagator, value) in previous.reversed() {
+            propagator.restore(value)
+        }
+    }
+
+    return try body()
+}
+"#;
+
+pub const C_CONTEXT_SUPPORT: &str = r#"
+void __swift_bridge__$context$push_frame(void);
+void __swift_bridge__$context$pop_frame(void);
+void __swift_bridge__$context$frame_insert(struct RustStr name, struct RustStr value);
+void* __swift_bridge__$context$frame_get(struct RustStr name);
+"#;
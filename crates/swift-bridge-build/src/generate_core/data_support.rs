@@ -0,0 +1,51 @@
+/// Converts between `Data` and `RustVec<UInt8>`/`UnsafeBufferPointer<UInt8>` for functions that
+/// use `#[swift_bridge(swift_data)]`.
+///
+/// Returned vectors are wrapped in a `Data` that keeps the `RustVec` alive instead of copying it.
+pub const SWIFT_DATA_SUPPORT: &str = r#"
+extension RustVec where T == UInt8 {
+    /// Wraps the vector's bytes in a `Data` without copying them.
+    ///
+    /// The `Data` keeps the vector alive, so the vector must not be mutated while the `Data` is in use.
+    public func toData() -> Data {
+        let len = self.len()
+        if len == 0 {
+            return Data()
+        }
+
+        return Data(
+            bytesNoCopy: UnsafeMutableRawPointer(mutating: self.as_ptr()),
+            count: len,
+            deallocator: .custom({ _, _ in withExtendedLifetime(self, {}) })
+        )
+    }
+}
+
+extension Data {
+    /// Copies the bytes into a new Rust `Vec<u8>`.
+    public func toRustVec() -> RustVec<UInt8> {
+        RustVec(ptr: __private__withUInt8Buffer({ buffer in
+            __swift_bridge__$Vec_u8$from_slice(buffer.toFfiSlice())
+        }))
+    }
+
+    func __private__withUInt8Buffer<T>(_ body: (UnsafeBufferPointer<UInt8>) throws -> T) rethrows -> T {
+        try withUnsafeBytes({ (bytes: UnsafeRawBufferPointer) in
+            let buffer = bytes.bindMemory(to: UInt8.self)
+            if buffer.baseAddress != nil {
+                return try body(buffer)
+            }
+
+            // Rust requires a non-null pointer, even for an empty slice.
+            var empty: UInt8 = 0
+            return try withUnsafeMutablePointer(to: &empty, { emptyPtr in
+                try body(UnsafeBufferPointer(start: emptyPtr, count: 0))
+            })
+        })
+    }
+}
+"#;
+
+pub const C_DATA_SUPPORT: &str = r#"
+void* __swift_bridge__$Vec_u8$from_slice(__private__FfiSlice slice);
+"#;
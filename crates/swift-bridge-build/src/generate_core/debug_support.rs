@@ -0,0 +1,74 @@
+/// Swift's side of `swift_bridge::debug`, which reports the state that swift-bridge's runtime
+/// manages.
+///
+/// Only generated for `DEBUG` builds, since it is meant for debug menus.
+pub const SWIFT_DEBUG_SUPPORT: &str = r#"
+#if DEBUG
+/// A snapshot of the state that swift-bridge's Rust runtime manages, for diagnosing leaks and
+/// stuck work.
+public struct RustBridgeReport: Equatable, CustomStringConvertible {
+    /// The number of values of each `#[swift_bridge(hardened)]` type that Swift holds handles to,
+    /// by Rust type name.
+    public var liveHandles: [String: Int] = [:]
+    /// The callbacks that Rust registered with `swift_bridge::memory_pressure`.
+    public var purgeCallbacks: Int = 0
+    /// The handlers that Rust registered with `swift_bridge::lifecycle`.
+    public var lifecycleHandlers: Int = 0
+    /// The context propagators that Rust registered with `swift_bridge::context`.
+    public var contextPropagators: Int = 0
+    /// The `async` Rust functions that were called and have not finished yet.
+    public var pendingAsyncTasks: Int = 0
+    /// The Rust values that are waiting for a `withRustScope` on the current thread to end.
+    public var deferredFrees: Int = 0
+
+    public var description: String {
+        var lines = ["Live handles:"]
+        if liveHandles.isEmpty {
+            lines.append("    none")
+        }
+        for (typeName, count) in liveHandles.sorted(by: { $0.key < $1.key }) {
+            lines.append("    \(typeName): \(count)")
+        }
+        lines.append("Purge callbacks: \(purgeCallbacks)")
+        lines.append("Lifecycle handlers: \(lifecycleHandlers)")
+        lines.append("Context propagators: \(contextPropagators)")
+        lines.append("Pending async tasks: \(pendingAsyncTasks)")
+        lines.append("Deferred frees: \(deferredFrees)")
+        return lines.joined(separator: "\n")
+    }
+}
+
+/// Take a snapshot of the state that swift-bridge's Rust runtime manages.
+public func rustBridgeReport() -> RustBridgeReport {
+    let entries = RustString(ptr: __swift_bridge__$debug$report()).toString()
+
+    var report = RustBridgeReport()
+    for line in entries.split(separator: "\n") {
+        let fields = line.split(separator: "\t").map(String.init)
+        let count = Int(fields.last!)!
+
+        switch fields[0] {
+        case "live_handles":
+            report.liveHandles[fields[1]] = count
+        case "purge_callbacks":
+            report.purgeCallbacks = count
+        case "lifecycle_handlers":
+            report.lifecycleHandlers = count
+        case "context_propagators":
+            report.contextPropagators = count
+        case "pending_async_tasks":
+            report.pendingAsyncTasks = count
+        case "deferred_frees":
+            report.deferredFrees = count
+        default:
+            break
+        }
+    }
+    return report
+}
+#endif
+"#;
+
+pub const C_DEBUG_SUPPORT: &str = r#"
+void* __swift_bridge__$debug$report(void);
+"#;
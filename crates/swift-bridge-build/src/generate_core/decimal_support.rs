@@ -0,0 +1,46 @@
+/// Converts between Foundation's `Decimal` and the FFI representation of a
+/// `rust_decimal::Decimal`, `swift_bridge::decimal::FfiDecimal`.
+///
+/// Both hold a mantissa, an exponent and a sign, so the conversion copies them instead of
+/// formatting and parsing a string.
+pub const SWIFT_DECIMAL_SUPPORT: &str = r#"
+extension Decimal {
+    @inline(__always)
+    func intoFfiRepr() -> __private__FfiDecimal {
+        precondition(!isNaN, "NaN can not be passed to Rust as a rust_decimal::Decimal")
+
+        let mantissa = _mantissa
+        let low = UInt64(mantissa.0) | UInt64(mantissa.1) << 16 | UInt64(mantissa.2) << 32 | UInt64(mantissa.3) << 48
+        let high = UInt64(mantissa.4) | UInt64(mantissa.5) << 16 | UInt64(mantissa.6) << 32 | UInt64(mantissa.7) << 48
+
+        return __private__FfiDecimal(mantissa_low: low, mantissa_high: high, exponent: _exponent, is_negative: _isNegative != 0)
+    }
+}
+
+extension __private__FfiDecimal {
+    @inline(__always)
+    func intoSwiftRepr() -> Decimal {
+        let words: [UInt16] = (0..<8).map { idx in
+            UInt16(truncatingIfNeeded: (idx < 4 ? mantissa_low : mantissa_high) >> UInt64(idx % 4 * 16))
+        }
+        let length = (words.lastIndex(where: { $0 != 0 }) ?? -1) + 1
+
+        var decimal = Decimal(
+            _exponent: exponent,
+            _length: UInt32(length),
+            // A zero length decimal that is negative is NaN.
+            _isNegative: is_negative && length > 0 ? 1 : 0,
+            _isCompact: 0,
+            _reserved: 0,
+            _mantissa: (words[0], words[1], words[2], words[3], words[4], words[5], words[6], words[7])
+        )
+        NSDecimalCompact(&decimal)
+
+        return decimal
+    }
+}
+"#;
+
+pub const C_DECIMAL_SUPPORT: &str = r#"
+typedef struct __private__FfiDecimal { uint64_t mantissa_low; uint64_t mantissa_high; int32_t exponent; bool is_negative; } __private__FfiDecimal;
+"#;
@@ -0,0 +1,201 @@
+/// The key types that `HashMap<K, V>` supports, as `(Rust type, Swift type, C type)`.
+pub(super) const KEY_TYPES: [(&str, &str, &str); 11] = [
+    ("String", "String", "struct RustStr"),
+    ("u8", "UInt8", "uint8_t"),
+    ("u16", "UInt16", "uint16_t"),
+    ("u32", "UInt32", "uint32_t"),
+    ("u64", "UInt64", "uint64_t"),
+    ("usize", "UInt", "uintptr_t"),
+    ("i8", "Int8", "int8_t"),
+    ("i16", "Int16", "int16_t"),
+    ("i32", "Int32", "int32_t"),
+    ("i64", "Int64", "int64_t"),
+    ("isize", "Int", "intptr_t"),
+];
+
+/// The value types that `HashMap<K, V>` supports, as
+/// `(Rust type, Swift type, C type, C type of an optional value)`.
+const VALUE_TYPES: [(&str, &str, &str, &str); 14] = [
+    ("u8", "UInt8", "uint8_t", "struct __private__OptionU8"),
+    ("u16", "UInt16", "uint16_t", "struct __private__OptionU16"),
+    ("u32", "UInt32", "uint32_t", "struct __private__OptionU32"),
+    ("u64", "UInt64", "uint64_t", "struct __private__OptionU64"),
+    (
+        "usize",
+        "UInt",
+        "uintptr_t",
+        "struct __private__OptionUsize",
+    ),
+    ("i8", "Int8", "int8_t", "struct __private__OptionI8"),
+    ("i16", "Int16", "int16_t", "struct __private__OptionI16"),
+    ("i32", "Int32", "int32_t", "struct __private__OptionI32"),
+    ("i64", "Int64", "int64_t", "struct __private__OptionI64"),
+    ("isize", "Int", "intptr_t", "struct __private__OptionIsize"),
+    ("f32", "Float", "float", "struct __private__OptionF32"),
+    ("f64", "Double", "double", "struct __private__OptionF64"),
+    ("bool", "Bool", "bool", "struct __private__OptionBool"),
+    ("String", "String", "struct RustStr", "void*"),
+];
+
+/// Swift's side of `HashMap<K, V>`.
+///
+/// `RustMap` is generic over the Swift key and value types, but every key and value type pair
+/// has its own Rust functions. So each pair gets an initializer that hands `RustMap` the functions
+/// to call.
+pub(super) const SWIFT_RUST_MAP: &str = r#"
+public class RustMap<K, V> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let vtable: __private__RustMapVTable<K, V>
+
+    init(ptr: UnsafeMutableRawPointer, vtable: __private__RustMapVTable<K, V>) {
+        self.ptr = ptr
+        self.vtable = vtable
+    }
+
+    deinit {
+        if isOwned {
+            vtable.free(ptr)
+        }
+    }
+
+    /// The number of entries in the map.
+    public var count: Int {
+        Int(vtable.len(ptr))
+    }
+
+    public subscript(key: K) -> V? {
+        vtable.get(ptr, key)
+    }
+
+    /// Inserts the value, replacing the existing value for the key if there is one.
+    public func insert(_ key: K, _ value: V) {
+        vtable.insert(ptr, key, value)
+    }
+
+    /// A copy of the map's keys, in no particular order.
+    public var keys: [K] {
+        vtable.keys(ptr)
+    }
+}
+
+extension RustMap: Sequence {
+    public func makeIterator() -> RustMapIterator<K, V> {
+        RustMapIterator(self)
+    }
+}
+
+public struct RustMapIterator<K, V>: IteratorProtocol {
+    let map: RustMap<K, V>
+    var keys: IndexingIterator<[K]>
+
+    init(_ map: RustMap<K, V>) {
+        self.map = map
+        self.keys = map.keys.makeIterator()
+    }
+
+    public mutating func next() -> (key: K, value: V)? {
+        guard let key = keys.next() else {
+            return nil
+        }
+        return (key: key, value: map[key]!)
+    }
+}
+
+public struct __private__RustMapVTable<K, V> {
+    let free: (UnsafeMutableRawPointer) -> ()
+    let len: (UnsafeMutableRawPointer) -> UInt
+    let get: (UnsafeMutableRawPointer, K) -> V?
+    let insert: (UnsafeMutableRawPointer, K, V) -> ()
+    let keys: (UnsafeMutableRawPointer) -> [K]
+}
+
+func __private__optionalRustStringToString(_ ptr: UnsafeMutableRawPointer?) -> String? {
+    guard let ptr = ptr else {
+        return nil
+    }
+    return RustString(ptr: ptr).toString()
+}
+"#;
+
+/// The initializers that let `RustMap` call the Rust functions for each key and value type pair.
+pub(super) fn swift_rust_map_initializers() -> String {
+    let mut swift = "".to_string();
+
+    for (rust_key, swift_key, _) in KEY_TYPES {
+        for (rust_value, swift_value, _, _) in VALUE_TYPES {
+            let prefix = format!("__swift_bridge__$HashMap_{rust_key}_{rust_value}");
+
+            let get_value = format!("{prefix}$get(map, key)");
+            let get_value = if rust_value == "String" {
+                format!("__private__optionalRustStringToString({get_value})")
+            } else {
+                format!("{get_value}.intoSwiftRepr()")
+            };
+            let insert = format!("{prefix}$insert(map, key, value)");
+            let insert = if rust_value == "String" {
+                format!("value.toRustStr({{ value in {insert} }})")
+            } else {
+                insert
+            };
+            let (get_value, insert) = if rust_key == "String" {
+                (
+                    format!("key.toRustStr({{ key in {get_value} }})"),
+                    format!("key.toRustStr({{ key in {insert} }})"),
+                )
+            } else {
+                (get_value, insert)
+            };
+            let keys = if rust_key == "String" {
+                format!("RustVec<RustString>(ptr: {prefix}$keys(map)).map({{ $0.as_str().toString() }})")
+            } else {
+                format!("Array(RustVec<{swift_key}>(ptr: {prefix}$keys(map)))")
+            };
+
+            swift += &format!(
+                r#"
+extension RustMap where K == {swift_key}, V == {swift_value} {{
+    public convenience init() {{
+        self.init(ptr: {prefix}$new())
+    }}
+
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, vtable: __private__RustMapVTable(
+            free: {{ map in {prefix}$_free(map) }},
+            len: {{ map in {prefix}$len(map) }},
+            get: {{ map, key in {get_value} }},
+            insert: {{ map, key, value in {insert} }},
+            keys: {{ map in {keys} }}
+        ))
+    }}
+}}
+"#
+            );
+        }
+    }
+
+    swift
+}
+
+/// Headers for `HashMap<K, V>` for every supported key and value type pair.
+pub(super) fn c_rust_map_headers() -> String {
+    let mut header = "".to_string();
+
+    for (rust_key, _, c_key) in KEY_TYPES {
+        for (rust_value, _, c_value, c_option_value) in VALUE_TYPES {
+            let prefix = format!("__swift_bridge__$HashMap_{rust_key}_{rust_value}");
+
+            header += &format!(
+                r#"void* {prefix}$new(void);
+void {prefix}$_free(void* const map);
+uintptr_t {prefix}$len(void* const map);
+{c_option_value} {prefix}$get(void* const map, {c_key} key);
+void {prefix}$insert(void* const map, {c_key} key, {c_value} value);
+void* {prefix}$keys(void* const map);
+"#
+            );
+        }
+    }
+
+    header
+}
@@ -0,0 +1,138 @@
+use super::hash_map_support::KEY_TYPES;
+
+/// Swift's side of `HashSet<T>`.
+///
+/// Like `RustMap`, every element type has its own Rust functions, so each element type gets an
+/// initializer that hands `RustSet` the functions to call.
+pub(super) const SWIFT_RUST_SET: &str = r#"
+public class RustSet<T> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let vtable: __private__RustSetVTable<T>
+
+    init(ptr: UnsafeMutableRawPointer, vtable: __private__RustSetVTable<T>) {
+        self.ptr = ptr
+        self.vtable = vtable
+    }
+
+    deinit {
+        if isOwned {
+            vtable.free(ptr)
+        }
+    }
+
+    /// The number of elements in the set.
+    public var count: Int {
+        Int(vtable.len(ptr))
+    }
+
+    public func contains(_ element: T) -> Bool {
+        vtable.contains(ptr, element)
+    }
+
+    /// Returns `false` if the set already contained the element.
+    @discardableResult
+    public func insert(_ element: T) -> Bool {
+        vtable.insert(ptr, element)
+    }
+
+    /// Returns `false` if the set did not contain the element.
+    @discardableResult
+    public func remove(_ element: T) -> Bool {
+        vtable.remove(ptr, element)
+    }
+
+    /// A copy of the set's elements, in no particular order.
+    public var elements: [T] {
+        vtable.elements(ptr)
+    }
+}
+
+extension RustSet: Sequence {
+    public func makeIterator() -> IndexingIterator<[T]> {
+        elements.makeIterator()
+    }
+}
+
+public struct __private__RustSetVTable<T> {
+    let free: (UnsafeMutableRawPointer) -> ()
+    let len: (UnsafeMutableRawPointer) -> UInt
+    let contains: (UnsafeMutableRawPointer, T) -> Bool
+    let insert: (UnsafeMutableRawPointer, T) -> Bool
+    let remove: (UnsafeMutableRawPointer, T) -> Bool
+    let elements: (UnsafeMutableRawPointer) -> [T]
+}
+"#;
+
+/// The initializers that let `RustSet` call the Rust functions for each element type.
+pub(super) fn swift_rust_set_initializers() -> String {
+    let mut swift = "".to_string();
+
+    // A set supports the same element types as a map supports keys.
+    for (rust_ty, swift_ty, _) in KEY_TYPES {
+        let prefix = format!("__swift_bridge__$HashSet_{rust_ty}");
+
+        let with_element = |call: &str| {
+            if rust_ty == "String" {
+                format!("element.toRustStr({{ element in {call} }})")
+            } else {
+                call.to_string()
+            }
+        };
+        let contains = with_element(&format!("{prefix}$contains(set, element)"));
+        let insert = with_element(&format!("{prefix}$insert(set, element)"));
+        let remove = with_element(&format!("{prefix}$remove(set, element)"));
+        let elements = if rust_ty == "String" {
+            format!(
+                "RustVec<RustString>(ptr: {prefix}$elements(set)).map({{ $0.as_str().toString() }})"
+            )
+        } else {
+            format!("Array(RustVec<{swift_ty}>(ptr: {prefix}$elements(set)))")
+        };
+
+        swift += &format!(
+            r#"
+extension RustSet where T == {swift_ty} {{
+    public convenience init() {{
+        self.init(ptr: {prefix}$new())
+    }}
+
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, vtable: __private__RustSetVTable(
+            free: {{ set in {prefix}$_free(set) }},
+            len: {{ set in {prefix}$len(set) }},
+            contains: {{ set, element in {contains} }},
+            insert: {{ set, element in {insert} }},
+            remove: {{ set, element in {remove} }},
+            elements: {{ set in {elements} }}
+        ))
+    }}
+}}
+"#
+        );
+    }
+
+    swift
+}
+
+/// Headers for `HashSet<T>` for every supported element type.
+pub(super) fn c_rust_set_headers() -> String {
+    let mut header = "".to_string();
+
+    for (rust_ty, _, c_ty) in KEY_TYPES {
+        let prefix = format!("__swift_bridge__$HashSet_{rust_ty}");
+
+        header += &format!(
+            r#"void* {prefix}$new(void);
+void {prefix}$_free(void* const set);
+uintptr_t {prefix}$len(void* const set);
+bool {prefix}$contains(void* const set, {c_ty} element);
+bool {prefix}$insert(void* const set, {c_ty} element);
+bool {prefix}$remove(void* const set, {c_ty} element);
+void* {prefix}$elements(void* const set);
+"#
+        );
+    }
+
+    header
+}
@@ -0,0 +1,185 @@
+/// Swift's side of `swift_bridge::image::ImageBuffer`, which describes pixel memory that Swift
+/// owns so that Rust can read and write it without copying.
+pub const SWIFT_IMAGE_SUPPORT: &str = r#"
+/// The layout of the pixels in an `ImageBuffer`.
+public enum ImageBufferPixelFormat: UInt32 {
+    /// Four bytes per pixel, in blue, green, red, alpha order.
+    case bgra8 = 0
+    /// Four bytes per pixel, in red, green, blue, alpha order.
+    case rgba8 = 1
+    /// One byte per pixel.
+    case gray8 = 2
+}
+
+/// Why Swift could not hand an image to Rust.
+public enum ImageBufferError: Error {
+    /// The pixel buffer's format, or the fact that it is planar, is not supported.
+    case unsupportedPixelFormat(UInt32)
+    /// Locking the pixel buffer's base address failed with the given `CVReturn`.
+    case lockFailed(Int32)
+    /// The image could not be drawn into a bitmap.
+    case drawFailed
+}
+
+public class ImageBuffer: ImageBufferRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$ImageBuffer$_free(ptr)
+        }
+    }
+
+    /// Describe `height` rows of pixels starting at `baseAddress` and pass them to `body`.
+    ///
+    /// The memory must stay valid until `body` returns. Do not keep the image buffer around after
+    /// that.
+    public static func withUnsafeMemory<R>(
+        _ baseAddress: UnsafeMutableRawPointer,
+        width: Int,
+        height: Int,
+        bytesPerRow: Int,
+        pixelFormat: ImageBufferPixelFormat,
+        _ body: (ImageBufferRefMut) throws -> R
+    ) rethrows -> R {
+        let image = ImageBuffer(ptr: __swift_bridge__$ImageBuffer$new(baseAddress, UInt(width), UInt(height), UInt(bytesPerRow), pixelFormat.rawValue))
+        return try withExtendedLifetime(image) { try body(image) }
+    }
+}
+
+public class ImageBufferRefMut: ImageBufferRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+
+public class ImageBufferRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var width: Int {
+        Int(__swift_bridge__$ImageBuffer$width(ptr))
+    }
+
+    public var height: Int {
+        Int(__swift_bridge__$ImageBuffer$height(ptr))
+    }
+
+    public var bytesPerRow: Int {
+        Int(__swift_bridge__$ImageBuffer$bytes_per_row(ptr))
+    }
+
+    public var pixelFormat: ImageBufferPixelFormat {
+        ImageBufferPixelFormat(rawValue: __swift_bridge__$ImageBuffer$pixel_format(ptr))!
+    }
+}
+
+#if canImport(CoreVideo)
+import CoreVideo
+
+extension ImageBufferPixelFormat {
+    /// The format of a `CVPixelBuffer`, or `nil` if it has no matching format.
+    public init?(cvPixelFormat: OSType) {
+        switch cvPixelFormat {
+        case kCVPixelFormatType_32BGRA:
+            self = .bgra8
+        case kCVPixelFormatType_32RGBA:
+            self = .rgba8
+        case kCVPixelFormatType_OneComponent8:
+            self = .gray8
+        default:
+            return nil
+        }
+    }
+}
+
+extension ImageBuffer {
+    /// Lock the pixel buffer's memory and pass it to `body`.
+    ///
+    /// Throws `ImageBufferError.unsupportedPixelFormat` for planar pixel buffers, such as the
+    /// YpCbCr formats, and for formats that `ImageBufferPixelFormat` has no case for.
+    public static func withPixelBuffer<R>(
+        _ pixelBuffer: CVPixelBuffer,
+        _ body: (ImageBufferRefMut) throws -> R
+    ) throws -> R {
+        let cvPixelFormat = CVPixelBufferGetPixelFormatType(pixelBuffer)
+        guard !CVPixelBufferIsPlanar(pixelBuffer), let pixelFormat = ImageBufferPixelFormat(cvPixelFormat: cvPixelFormat) else {
+            throw ImageBufferError.unsupportedPixelFormat(cvPixelFormat)
+        }
+
+        let status = CVPixelBufferLockBaseAddress(pixelBuffer, [])
+        guard status == kCVReturnSuccess else {
+            throw ImageBufferError.lockFailed(status)
+        }
+        defer { CVPixelBufferUnlockBaseAddress(pixelBuffer, []) }
+
+        guard let baseAddress = CVPixelBufferGetBaseAddress(pixelBuffer) else {
+            throw ImageBufferError.lockFailed(kCVReturnError)
+        }
+
+        return try withUnsafeMemory(
+            baseAddress,
+            width: CVPixelBufferGetWidth(pixelBuffer),
+            height: CVPixelBufferGetHeight(pixelBuffer),
+            bytesPerRow: CVPixelBufferGetBytesPerRow(pixelBuffer),
+            pixelFormat: pixelFormat,
+            body
+        )
+    }
+}
+#endif
+
+#if canImport(CoreGraphics)
+import CoreGraphics
+
+extension ImageBuffer {
+    /// Draw the image into a temporary `.rgba8` bitmap with premultiplied alpha and pass it to
+    /// `body`.
+    ///
+    /// A `CGImage`'s own memory is read-only, so Rust works on a copy of it.
+    public static func withCopy<R>(
+        of image: CGImage,
+        _ body: (ImageBufferRefMut) throws -> R
+    ) throws -> R {
+        let context = CGContext(
+            data: nil,
+            width: image.width,
+            height: image.height,
+            bitsPerComponent: 8,
+            bytesPerRow: image.width * 4,
+            space: CGColorSpaceCreateDeviceRGB(),
+            bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue | CGBitmapInfo.byteOrder32Big.rawValue
+        )
+        guard let context = context, let data = context.data else {
+            throw ImageBufferError.drawFailed
+        }
+        context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+
+        return try withUnsafeMemory(
+            data,
+            width: image.width,
+            height: image.height,
+            bytesPerRow: context.bytesPerRow,
+            pixelFormat: .rgba8,
+            body
+        )
+    }
+}
+#endif
+"#;
+
+pub const C_IMAGE_SUPPORT: &str = r#"
+void* __swift_bridge__$ImageBuffer$new(void* ptr, uintptr_t width, uintptr_t height, uintptr_t bytes_per_row, uint32_t pixel_format);
+uintptr_t __swift_bridge__$ImageBuffer$width(void* image);
+uintptr_t __swift_bridge__$ImageBuffer$height(void* image);
+uintptr_t __swift_bridge__$ImageBuffer$bytes_per_row(void* image);
+uint32_t __swift_bridge__$ImageBuffer$pixel_format(void* image);
+void __swift_bridge__$ImageBuffer$_free(void* image);
+"#;
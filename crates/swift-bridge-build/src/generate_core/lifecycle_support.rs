@@ -0,0 +1,42 @@
+/// Lets Swift forward app lifecycle events to the handlers that Rust registered with
+/// `swift_bridge::lifecycle::register_lifecycle_handler`.
+pub const SWIFT_LIFECYCLE_SUPPORT: &str = r#"
+/// An app lifecycle event that can be forwarded to `swift_bridge::lifecycle` handlers.
+public enum RustLifecycleEvent: UInt8 {
+    case didEnterBackground = 0
+    case willEnterForeground = 1
+    case willTerminate = 2
+}
+
+/// Call every lifecycle handler that Rust registered with `swift_bridge::lifecycle`.
+public func notifyRustLifecycleEvent(_ event: RustLifecycleEvent) {
+    __swift_bridge__$lifecycle$dispatch(event.rawValue)
+}
+
+#if canImport(UIKit)
+import UIKit
+
+/// Forward UIKit's background, foreground and termination notifications to Rust.
+///
+/// Call this once, for example when the app launches. Pass the returned observers to
+/// `NotificationCenter.default.removeObserver` to stop forwarding.
+@discardableResult
+public func forwardLifecycleEventsToRust() -> [NSObjectProtocol] {
+    let notifications: [(Notification.Name, RustLifecycleEvent)] = [
+        (UIApplication.didEnterBackgroundNotification, .didEnterBackground),
+        (UIApplication.willEnterForegroundNotification, .willEnterForeground),
+        (UIApplication.willTerminateNotification, .willTerminate),
+    ]
+
+    return notifications.map { (name, event) in
+        NotificationCenter.default.addObserver(forName: name, object: nil, queue: nil) { _ in
+            notifyRustLifecycleEvent(event)
+        }
+    }
+}
+#endif
+"#;
+
+pub const C_LIFECYCLE_SUPPORT: &str = r#"
+void __swift_bridge__$lifecycle$dispatch(uint8_t event);
+"#;
@@ -0,0 +1,89 @@
+/// Swift's side of `swift_bridge::locale::SwiftLocale`, which copies the parts of a `Locale` that
+/// Rust needs for formatting.
+pub const SWIFT_LOCALE_SUPPORT: &str = r#"
+public class SwiftLocale: SwiftLocaleRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public convenience init(_ locale: Locale) {
+        let calendar = "\(locale.calendar.identifier)"
+        let currencyCode = locale.currencyCode ?? ""
+
+        self.init(ptr: locale.identifier.toRustStr({ identifier in
+            (locale.decimalSeparator ?? ".").toRustStr({ decimalSeparator in
+                (locale.groupingSeparator ?? ",").toRustStr({ groupingSeparator in
+                    calendar.toRustStr({ calendar in
+                        currencyCode.toRustStr({ currencyCode in
+                            __swift_bridge__$SwiftLocale$new(identifier, decimalSeparator, groupingSeparator, calendar, currencyCode)
+                        })
+                    })
+                })
+            })
+        }))
+    }
+
+    /// The user's current locale.
+    public static var current: SwiftLocale {
+        SwiftLocale(Locale.current)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$SwiftLocale$_free(ptr)
+        }
+    }
+}
+
+public class SwiftLocaleRefMut: SwiftLocaleRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+
+public class SwiftLocaleRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var identifier: String {
+        RustString(ptr: __swift_bridge__$SwiftLocale$identifier(ptr)).toString()
+    }
+
+    public var decimalSeparator: String {
+        RustString(ptr: __swift_bridge__$SwiftLocale$decimal_separator(ptr)).toString()
+    }
+
+    public var groupingSeparator: String {
+        RustString(ptr: __swift_bridge__$SwiftLocale$grouping_separator(ptr)).toString()
+    }
+
+    /// The identifier of the locale's calendar, such as "gregorian" or "japanese".
+    public var calendarIdentifier: String {
+        RustString(ptr: __swift_bridge__$SwiftLocale$calendar(ptr)).toString()
+    }
+
+    public var currencyCode: String? {
+        __swift_bridge__$SwiftLocale$currency_code(ptr).map { RustString(ptr: $0).toString() }
+    }
+
+    /// A `Locale` with this locale's identifier.
+    public var locale: Locale {
+        Locale(identifier: identifier)
+    }
+}
+"#;
+
+pub const C_LOCALE_SUPPORT: &str = r#"
+void* __swift_bridge__$SwiftLocale$new(struct RustStr identifier, struct RustStr decimal_separator, struct RustStr grouping_separator, struct RustStr calendar, struct RustStr currency_code);
+void* __swift_bridge__$SwiftLocale$identifier(void* locale);
+void* __swift_bridge__$SwiftLocale$decimal_separator(void* locale);
+void* __swift_bridge__$SwiftLocale$grouping_separator(void* locale);
+void* __swift_bridge__$SwiftLocale$calendar(void* locale);
+void* __swift_bridge__$SwiftLocale$currency_code(void* locale);
+void __swift_bridge__$SwiftLocale$_free(void* locale);
+"#;
@@ -0,0 +1,31 @@
+/// Lets Swift run the purge callbacks that Rust registered with
+/// `swift_bridge::memory_pressure::register_purge_callback`.
+pub const SWIFT_MEMORY_PRESSURE_SUPPORT: &str = r#"
+/// Run every purge callback that Rust registered with `swift_bridge::memory_pressure`.
+public func purgeRustCaches() {
+    __swift_bridge__$memory_pressure$purge()
+}
+
+#if canImport(UIKit)
+import UIKit
+
+/// Run `purgeRustCaches()` whenever the app receives a memory warning.
+///
+/// Call this once, for example when the app launches. Pass the returned observer to
+/// `NotificationCenter.default.removeObserver` to stop purging.
+@discardableResult
+public func purgeRustCachesOnMemoryWarning() -> NSObjectProtocol {
+    NotificationCenter.default.addObserver(
+        forName: UIApplication.didReceiveMemoryWarningNotification,
+        object: nil,
+        queue: nil
+    ) { _ in
+        purgeRustCaches()
+    }
+}
+#endif
+"#;
+
+pub const C_MEMORY_PRESSURE_SUPPORT: &str = r#"
+void __swift_bridge__$memory_pressure$purge(void);
+"#;
@@ -0,0 +1,43 @@
+/// Receives the records that the `swift_bridge::os_log::OsLogger` forwards from Rust.
+///
+/// Rust only links against this function when the `os_log` feature is enabled.
+pub const SWIFT_OS_LOG_SUPPORT: &str = r#"
+#if canImport(os)
+import os
+
+private let __private__osLogsLock = NSLock()
+private var __private__osLogs: [String: OSLog] = [:]
+
+private func __private__osLog(subsystem: String, category: String) -> OSLog {
+    __private__osLogsLock.lock()
+    defer { __private__osLogsLock.unlock() }
+
+    let key = subsystem + "\u{0}" + category
+    if let log = __private__osLogs[key] {
+        return log
+    }
+    let log = OSLog(subsystem: subsystem, category: category)
+    __private__osLogs[key] = log
+    return log
+}
+
+@_cdecl("__swift_bridge__$os_log$log")
+func __swift_bridge__os_log_log(_ level: UInt8, _ subsystem: RustStr, _ category: RustStr, _ message: RustStr) {
+    let type: OSLogType
+    switch level {
+    case 0: type = .error
+    case 1: type = .default
+    case 2: type = .info
+    default: type = .debug
+    }
+
+    let log = __private__osLog(subsystem: subsystem.toString(), category: category.toString())
+    let message = message.toString()
+    if #available(macOS 11.0, iOS 14.0, tvOS 14.0, watchOS 7.0, *) {
+        Logger(log).log(level: type, "\(message, privacy: .public)")
+    } else {
+        os_log("%{public}@", log: log, type: type, message)
+    }
+}
+#endif
+"#;
@@ -0,0 +1,73 @@
+/// Swift's side of `swift_bridge::reader::SwiftReader`, which lets Rust read from any Swift value
+/// that conforms to `RandomAccessReader`.
+///
+/// The reader is boxed and retained while Rust owns the `SwiftReader`. Rust reads by handing us a
+/// buffer to copy the requested bytes into, and releases the reader when it drops the
+/// `SwiftReader`.
+pub const SWIFT_READER_SUPPORT: &str = r#"
+public protocol RandomAccessReader {
+    /// The total number of bytes that can be read.
+    var length: UInt64 { get }
+
+    /// Read at most `length` bytes starting at `offset`.
+    ///
+    /// Only returns fewer than `length` bytes when the end of the data was reached.
+    func read(at offset: UInt64, length: Int) -> Data
+}
+
+public class SwiftReader: SwiftReaderRefMut {
+    var isOwned: Bool = true
+
+    public init(_ reader: RandomAccessReader) {
+        let box = __private__SwiftReaderBox(reader)
+        super.init(ptr: __swift_bridge__$SwiftReader$new(Unmanaged.passRetained(box).toOpaque(), reader.length, { context, offset, buf, len in
+            let box = Unmanaged<__private__SwiftReaderBox>.fromOpaque(context!).takeUnretainedValue()
+            let data = box.reader.read(at: offset, length: Int(len))
+            let count = min(data.count, Int(len))
+            data.copyBytes(to: buf!, count: count)
+            return UInt(count)
+        }, { context in
+            Unmanaged<__private__SwiftReaderBox>.fromOpaque(context!).release()
+        }))
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$SwiftReader$_free(ptr)
+        }
+    }
+}
+
+public class SwiftReaderRefMut: SwiftReaderRef {
+    override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+
+public class SwiftReaderRef {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// The total number of bytes that can be read.
+    public var length: UInt64 {
+        __swift_bridge__$SwiftReader$len(ptr)
+    }
+}
+
+class __private__SwiftReaderBox {
+    let reader: RandomAccessReader
+
+    init(_ reader: RandomAccessReader) {
+        self.reader = reader
+    }
+}
+"#;
+
+pub const C_READER_SUPPORT: &str = r#"
+void* __swift_bridge__$SwiftReader$new(void* context, uint64_t len, uintptr_t (*read)(void* context, uint64_t offset, uint8_t* buf, uintptr_t len), void (*free)(void* context));
+void __swift_bridge__$SwiftReader$_free(void* reader);
+uint64_t __swift_bridge__$SwiftReader$len(void* reader);
+"#;
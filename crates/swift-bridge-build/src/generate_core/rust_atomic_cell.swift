@@ -0,0 +1,47 @@
+/// A primitive value that both Swift and Rust can read and write atomically.
+///
+/// Reads and writes go straight to the value's memory, so they do not call into Rust.
+public class RustAtomicCell<T: AtomicCellable> {
+    var ptr: UnsafeMutableRawPointer
+    var valuePtr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+        self.valuePtr = T.atomicCellOfSelfValuePtr(cellPtr: ptr)
+    }
+
+    public convenience init(_ value: T) {
+        self.init(ptr: T.atomicCellOfSelfNew(value: value))
+    }
+
+    public func load() -> T {
+        T.atomicCellOfSelfLoad(valuePtr: valuePtr)
+    }
+
+    public func store(_ value: T) {
+        T.atomicCellOfSelfStore(valuePtr: valuePtr, value: value)
+    }
+
+    /// A new Rust handle to the same value, for passing this cell to Rust.
+    func cloneForRust() -> UnsafeMutableRawPointer {
+        T.atomicCellOfSelfClone(cellPtr: ptr)
+    }
+
+    deinit {
+        T.atomicCellOfSelfFree(cellPtr: ptr)
+    }
+}
+
+public protocol AtomicCellable {
+    static func atomicCellOfSelfNew(value: Self) -> UnsafeMutableRawPointer
+
+    static func atomicCellOfSelfClone(cellPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func atomicCellOfSelfValuePtr(cellPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func atomicCellOfSelfFree(cellPtr: UnsafeMutableRawPointer)
+
+    static func atomicCellOfSelfLoad(valuePtr: UnsafeMutableRawPointer) -> Self
+
+    static func atomicCellOfSelfStore(valuePtr: UnsafeMutableRawPointer, value: Self)
+}
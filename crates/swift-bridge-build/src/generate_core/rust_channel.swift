@@ -0,0 +1,84 @@
+public class RustSender<T: Channelable> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// Returns false if the receiver has been dropped, in which case the value is discarded.
+    @discardableResult
+    public func send(_ value: T) -> Bool {
+        T.senderOfSelfSend(senderPtr: ptr, value: value)
+    }
+
+    /// Create another sender for the same channel.
+    public func clone() -> RustSender<T> {
+        RustSender(ptr: T.senderOfSelfClone(senderPtr: ptr))
+    }
+
+    deinit {
+        if isOwned {
+            T.senderOfSelfFree(senderPtr: ptr)
+        }
+    }
+}
+
+/// A `RustReceiver` must not be used from more than one thread at a time.
+public class RustReceiver<T: Channelable> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// Blocks until a value is received. Returns nil once every sender has been dropped.
+    public func recv() -> Optional<T> {
+        T.receiverOfSelfRecv(receiverPtr: ptr)
+    }
+
+    /// Returns nil if there is no value waiting to be received.
+    public func tryRecv() -> Optional<T> {
+        T.receiverOfSelfTryRecv(receiverPtr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            T.receiverOfSelfFree(receiverPtr: ptr)
+        }
+    }
+}
+
+extension RustReceiver: Sequence {
+    public func makeIterator() -> RustReceiverIterator<T> {
+        return RustReceiverIterator(self)
+    }
+}
+
+/// Blocks on each call to `next()` until a value is received or every sender has been dropped.
+public struct RustReceiverIterator<T: Channelable>: IteratorProtocol {
+    var rustReceiver: RustReceiver<T>
+
+    init (_ rustReceiver: RustReceiver<T>) {
+        self.rustReceiver = rustReceiver
+    }
+
+    public mutating func next() -> T? {
+        rustReceiver.recv()
+    }
+}
+
+public protocol Channelable {
+    static func senderOfSelfSend(senderPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func senderOfSelfClone(senderPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func senderOfSelfFree(senderPtr: UnsafeMutableRawPointer)
+
+    static func receiverOfSelfRecv(receiverPtr: UnsafeMutableRawPointer) -> Optional<Self>
+
+    static func receiverOfSelfTryRecv(receiverPtr: UnsafeMutableRawPointer) -> Optional<Self>
+
+    static func receiverOfSelfFree(receiverPtr: UnsafeMutableRawPointer)
+}
@@ -0,0 +1,192 @@
+/// Swift's side of `swift_bridge::rust_io::{RustWriter, RustReader}`, which let Swift write to a
+/// Rust `Write` and read from a Rust `Read`.
+///
+/// The `write(_:maxLength:)` and `read(_:maxLength:)` methods follow `OutputStream` and
+/// `InputStream` by returning -1 on failure and exposing the failure through `streamError`.
+/// The other methods throw instead.
+pub const SWIFT_RUST_IO_SUPPORT: &str = r#"
+/// An error that a Rust `Write` or `Read` returned.
+public struct RustIoError: Error, CustomStringConvertible {
+    public let message: String
+
+    public var description: String {
+        message
+    }
+}
+
+public class RustWriter: RustWriterRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$RustWriter$_free(ptr)
+        }
+    }
+}
+
+public class RustWriterRefMut: RustWriterRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    /// Write at most `len` bytes, and return the number of bytes that were written or -1 if the
+    /// write failed.
+    public func write(_ buffer: UnsafePointer<UInt8>, maxLength len: Int) -> Int {
+        __swift_bridge__$RustWriter$write(ptr, buffer, UInt(len))
+    }
+
+    /// Write all of `data`.
+    public func write(_ data: Data) throws {
+        try data.withUnsafeBytes { (bytes: UnsafeRawBufferPointer) in
+            guard let start = bytes.bindMemory(to: UInt8.self).baseAddress else {
+                return
+            }
+            try writeAll(start, count: bytes.count)
+        }
+    }
+
+    /// Write everything that can be read from `stream`, a chunk at a time.
+    ///
+    /// The stream is opened if it is not open yet.
+    public func write(contentsOf stream: InputStream, chunkSize: Int = 4096) throws {
+        if stream.streamStatus == .notOpen {
+            stream.open()
+        }
+
+        let buffer = UnsafeMutablePointer<UInt8>.allocate(capacity: chunkSize)
+        defer {
+            buffer.deallocate()
+        }
+
+        while true {
+            let read = stream.read(buffer, maxLength: chunkSize)
+            if read < 0 {
+                throw stream.streamError ?? RustIoError(message: "failed to read from the input stream")
+            }
+            if read == 0 {
+                return
+            }
+
+            try writeAll(buffer, count: read)
+        }
+    }
+
+    public func flush() throws {
+        if !__swift_bridge__$RustWriter$flush(ptr) {
+            throw streamError!
+        }
+    }
+
+    func writeAll(_ buffer: UnsafePointer<UInt8>, count: Int) throws {
+        var written = 0
+        while written < count {
+            let result = write(buffer + written, maxLength: count - written)
+            if result < 0 {
+                throw streamError!
+            }
+            if result == 0 {
+                throw RustIoError(message: "failed to write whole buffer")
+            }
+            written += result
+        }
+    }
+}
+
+public class RustWriterRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// The error that the most recent failed write or flush returned.
+    public var streamError: RustIoError? {
+        __swift_bridge__$RustWriter$error(ptr).map { RustIoError(message: RustString(ptr: $0).toString()) }
+    }
+}
+
+public class RustReader: RustReaderRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$RustReader$_free(ptr)
+        }
+    }
+}
+
+public class RustReaderRefMut: RustReaderRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    /// Read at most `len` bytes into `buffer`, and return the number of bytes that were read,
+    /// 0 at the end of the data or -1 if the read failed.
+    public func read(_ buffer: UnsafeMutablePointer<UInt8>, maxLength len: Int) -> Int {
+        __swift_bridge__$RustReader$read(ptr, buffer, UInt(len))
+    }
+
+    /// Read at most `len` bytes. Returns empty `Data` at the end of the data.
+    public func read(maxLength len: Int) throws -> Data {
+        var data = Data(count: len)
+        let read = data.withUnsafeMutableBytes { (bytes: UnsafeMutableRawBufferPointer) in
+            self.read(bytes.bindMemory(to: UInt8.self).baseAddress!, maxLength: len)
+        }
+        if read < 0 {
+            throw streamError!
+        }
+
+        data.count = read
+        return data
+    }
+
+    /// Read until the end of the data, a chunk at a time.
+    public func readToEnd(chunkSize: Int = 4096) throws -> Data {
+        var data = Data()
+        while true {
+            let chunk = try read(maxLength: chunkSize)
+            if chunk.isEmpty {
+                return data
+            }
+            data.append(chunk)
+        }
+    }
+}
+
+public class RustReaderRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// Whether or not the end of the data has not been reached yet.
+    public var hasBytesAvailable: Bool {
+        __swift_bridge__$RustReader$has_bytes_available(ptr)
+    }
+
+    /// The error that the most recent failed read returned.
+    public var streamError: RustIoError? {
+        __swift_bridge__$RustReader$error(ptr).map { RustIoError(message: RustString(ptr: $0).toString()) }
+    }
+}
+"#;
+
+pub const C_RUST_IO_SUPPORT: &str = r#"
+intptr_t __swift_bridge__$RustWriter$write(void* writer, const uint8_t* buf, uintptr_t len);
+bool __swift_bridge__$RustWriter$flush(void* writer);
+void* __swift_bridge__$RustWriter$error(void* writer);
+void __swift_bridge__$RustWriter$_free(void* writer);
+intptr_t __swift_bridge__$RustReader$read(void* reader, uint8_t* buf, uintptr_t len);
+bool __swift_bridge__$RustReader$has_bytes_available(void* reader);
+void* __swift_bridge__$RustReader$error(void* reader);
+void __swift_bridge__$RustReader$_free(void* reader);
+"#;
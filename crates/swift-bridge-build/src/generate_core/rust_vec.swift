@@ -89,6 +89,12 @@ extension UnsafeBufferPointer {
     }
 }
 
+extension UnsafeMutableBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: self.baseAddress, len: UInt(self.count))
+    }
+}
+
 public protocol Vectorizable {
     associatedtype SelfRef
     associatedtype SelfRefMut
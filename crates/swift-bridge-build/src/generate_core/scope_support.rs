@@ -0,0 +1,20 @@
+/// Swift's side of `swift_bridge::scope`, which defers freeing Rust values until the end of a
+/// scope so that they are all dropped in one call.
+pub const SWIFT_SCOPE_SUPPORT: &str = r#"
+/// Run `body` in a scope that frees the Rust values that Swift is done with in bulk.
+///
+/// Rust values such as `RustString`s, `RustVec`s and opaque Rust types that are freed while
+/// `body` runs on this thread are dropped together when `body` returns, instead of one at a time.
+/// Values that are still in use when `body` returns are freed as usual.
+public func withRustScope<T>(_ body: () throws -> T) rethrows -> T {
+    __swift_bridge__$scope$open()
+    defer { __swift_bridge__$scope$close() }
+
+    return try body()
+}
+"#;
+
+pub const C_SCOPE_SUPPORT: &str = r#"
+void __swift_bridge__$scope$open(void);
+void __swift_bridge__$scope$close(void);
+"#;
@@ -71,10 +71,10 @@ func optionalStringIntoRustString<S: IntoRustString>(_ string: Optional<S>) -> R
 /// Used to safely get a pointer to a sequence of utf8 bytes, represented as a `RustStr`.
 ///
 /// For example, the Swift `String` implementation of the `ToRustStr` protocol does the following:
-/// 1. Use Swift's `String.utf8.withUnsafeBufferPointer` to get a pointer to the strings underlying
-///    utf8 bytes.
-/// 2. Construct a `RustStr` that points to these utf8 bytes. This is safe because `withUnsafeBufferPointer`
-///    guarantees that the buffer pointer will be valid for the duration of the `withUnsafeBufferPointer`
+/// 1. Use Swift's `String.withUTF8` to get a pointer to the strings underlying utf8 bytes.
+///    Native Swift strings are already stored as contiguous utf8, so this does not copy them.
+/// 2. Construct a `RustStr` that points to these utf8 bytes. This is safe because `withUTF8`
+///    guarantees that the buffer pointer will be valid for the duration of the `withUTF8`
 ///    callback.
 /// 3. Pass the `RustStr` to the closure that was passed into `RustStr.toRustStr`.
 public protocol ToRustStr {
@@ -85,17 +85,36 @@ extension String: ToRustStr {
     /// Safely get a scoped pointer to the String and then call the callback with a RustStr
     /// that uses that pointer.
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
-            let rustStr = RustStr(
-                start: UnsafeMutableRawPointer(mutating: bufferPtr.baseAddress!).assumingMemoryBound(to: UInt8.self),
-                // Subtract 1 because of the null termination character at the end
-                len: UInt(bufferPtr.count - 1)
-            )
-            return withUnsafeRustStr(rustStr)
+        var string = self
+        return string.withUTF8({ bufferPtr in
+            utf8BufferToRustStr(bufferPtr, withUnsafeRustStr)
         })
     }
 }
 
+extension Substring: ToRustStr {
+    /// Safely get a scoped pointer to the Substring and then call the callback with a RustStr
+    /// that uses that pointer.
+    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
+        var substring = self
+        return substring.withUTF8({ bufferPtr in
+            utf8BufferToRustStr(bufferPtr, withUnsafeRustStr)
+        })
+    }
+}
+
+func utf8BufferToRustStr<T>(_ bufferPtr: UnsafeBufferPointer<UInt8>, _ withUnsafeRustStr: (RustStr) -> T) -> T {
+    guard let start = bufferPtr.baseAddress else {
+        // Rust requires a non-null pointer, even for an empty str.
+        var empty: UInt8 = 0
+        return withUnsafeMutablePointer(to: &empty, { emptyPtr in
+            withUnsafeRustStr(RustStr(start: emptyPtr, len: 0))
+        })
+    }
+
+    return withUnsafeRustStr(RustStr(start: UnsafeMutablePointer(mutating: start), len: UInt(bufferPtr.count)))
+}
+
 extension RustStr: ToRustStr {
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
         return withUnsafeRustStr(self)
@@ -109,3 +128,46 @@ func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeR
         return withUnsafeRustStr(RustStr(start: nil, len: 0))
     }
 }
+
+extension String {
+    /// The index at a Rust byte offset into this string, such as an offset that was returned by
+    /// Rust's `str::find`.
+    ///
+    /// Rust byte offsets count UTF-8 bytes. Returns `nil` if the offset is past the end of the
+    /// string or is not at the start of a Unicode scalar, where Rust's string slicing would panic.
+    public func index(rustByteOffset offset: UInt) -> String.Index? {
+        guard offset <= UInt(utf8.count) else {
+            return nil
+        }
+
+        let index = utf8.index(utf8.startIndex, offsetBy: Int(offset))
+        guard index.samePosition(in: unicodeScalars) != nil else {
+            return nil
+        }
+
+        return index
+    }
+
+    /// The range between two Rust byte offsets into this string, such as the `start..end` of a
+    /// match that was found by Rust.
+    ///
+    /// Returns `nil` if either offset is not a valid index, see `index(rustByteOffset:)`.
+    public func range(rustByteOffsets range: Range<UInt>) -> Range<String.Index>? {
+        guard let start = index(rustByteOffset: range.lowerBound),
+              let end = index(rustByteOffset: range.upperBound) else {
+            return nil
+        }
+
+        return start..<end
+    }
+
+    /// The Rust byte offset of an index into this string, which can be passed to Rust string APIs.
+    public func rustByteOffset(of index: String.Index) -> UInt {
+        UInt(utf8.distance(from: utf8.startIndex, to: index))
+    }
+
+    /// The Rust byte offsets of a range of this string, which can be passed to Rust string APIs.
+    public func rustByteOffsets(of range: Range<String.Index>) -> Range<UInt> {
+        rustByteOffset(of: range.lowerBound)..<rustByteOffset(of: range.upperBound)
+    }
+}
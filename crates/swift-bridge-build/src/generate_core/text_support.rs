@@ -0,0 +1,98 @@
+/// Swift's side of `swift_bridge::text::TextMatches`, which converts the Rust byte ranges of each
+/// match into `Range<String.Index>`s of the string that was searched.
+pub const SWIFT_TEXT_SUPPORT: &str = r#"
+/// One match that Rust found in a string.
+///
+/// The byte ranges are Rust byte offsets into the string that was searched. Use `range(in:)` and
+/// `range(of:in:)` to get ranges of that string.
+public struct TextMatch {
+    /// The Rust byte offsets of the whole match.
+    public let byteRange: Range<UInt>
+    /// The Rust byte offsets of the named capture groups that took part in the match.
+    public let captureByteRanges: [String: Range<UInt>]
+
+    public init(byteRange: Range<UInt>, captureByteRanges: [String: Range<UInt>] = [:]) {
+        self.byteRange = byteRange
+        self.captureByteRanges = captureByteRanges
+    }
+
+    /// The range of the whole match in the string that was searched.
+    public func range(in string: String) -> Range<String.Index>? {
+        string.range(rustByteOffsets: byteRange)
+    }
+
+    /// The range of a named capture group in the string that was searched, or `nil` if the group
+    /// did not take part in the match.
+    public func range(of capture: String, in string: String) -> Range<String.Index>? {
+        captureByteRanges[capture].flatMap { string.range(rustByteOffsets: $0) }
+    }
+}
+
+public class TextMatches: TextMatchesRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$TextMatches$_free(ptr)
+        }
+    }
+}
+
+public class TextMatchesRefMut: TextMatchesRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+
+public class TextMatchesRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    /// The number of matches.
+    public var count: Int {
+        Int(__swift_bridge__$TextMatches$len(ptr))
+    }
+
+    /// Every match, in the order that Rust found them.
+    public var matches: [TextMatch] {
+        (0..<__swift_bridge__$TextMatches$len(ptr)).map { idx in
+            var captures: [String: Range<UInt>] = [:]
+            for captureIdx in 0..<__swift_bridge__$TextMatches$capture_count(ptr, idx) {
+                if __swift_bridge__$TextMatches$capture_is_some(ptr, idx, captureIdx) {
+                    let name = RustString(ptr: __swift_bridge__$TextMatches$capture_name(ptr, idx, captureIdx)).toString()
+                    captures[name] = __swift_bridge__$TextMatches$capture_start(ptr, idx, captureIdx)..<__swift_bridge__$TextMatches$capture_end(ptr, idx, captureIdx)
+                }
+            }
+
+            return TextMatch(
+                byteRange: __swift_bridge__$TextMatches$start(ptr, idx)..<__swift_bridge__$TextMatches$end(ptr, idx),
+                captureByteRanges: captures
+            )
+        }
+    }
+
+    /// The range of every match in the string that was searched.
+    public func ranges(in string: String) -> [Range<String.Index>] {
+        matches.compactMap { $0.range(in: string) }
+    }
+}
+"#;
+
+pub const C_TEXT_SUPPORT: &str = r#"
+uintptr_t __swift_bridge__$TextMatches$len(void* matches);
+uintptr_t __swift_bridge__$TextMatches$start(void* matches, uintptr_t idx);
+uintptr_t __swift_bridge__$TextMatches$end(void* matches, uintptr_t idx);
+uintptr_t __swift_bridge__$TextMatches$capture_count(void* matches, uintptr_t idx);
+void* __swift_bridge__$TextMatches$capture_name(void* matches, uintptr_t idx, uintptr_t capture_idx);
+bool __swift_bridge__$TextMatches$capture_is_some(void* matches, uintptr_t idx, uintptr_t capture_idx);
+uintptr_t __swift_bridge__$TextMatches$capture_start(void* matches, uintptr_t idx, uintptr_t capture_idx);
+uintptr_t __swift_bridge__$TextMatches$capture_end(void* matches, uintptr_t idx, uintptr_t capture_idx);
+void __swift_bridge__$TextMatches$_free(void* matches);
+"#;
@@ -0,0 +1,82 @@
+/// Support for functions that use the `#[swift_bridge(timeout)]` attribute.
+///
+/// The Rust function runs on a background thread. Whichever of the Rust function, the timeout and
+/// the task's cancellation finishes first resumes the continuation. If the Rust function loses, its
+/// return value is dropped as soon as it returns, which frees any Rust memory that it owns.
+///
+/// This is only written into SwiftBridgeCore.swift if a function uses the attribute, since
+/// `withTaskCancellationHandler(operation:onCancel:)` needs Swift 5.7 (Xcode 14).
+pub const SWIFT_TIMEOUT_SUPPORT: &str = r#"
+/// Thrown when a `#[swift_bridge(timeout)]` function does not return in time.
+public struct RustTimeoutError: Error {
+    public let seconds: Double
+}
+
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+final class __private__TimeoutRace<T> {
+    private let lock = NSLock()
+    private var continuation: CheckedContinuation<T, Error>?
+    private var result: Result<T, Error>?
+    private var finished = false
+
+    func setContinuation(_ continuation: CheckedContinuation<T, Error>) {
+        lock.lock()
+        if let result = result {
+            lock.unlock()
+            continuation.resume(with: result)
+        } else {
+            self.continuation = continuation
+            lock.unlock()
+        }
+    }
+
+    /// Returns false if another result already finished the race.
+    @discardableResult
+    func finish(_ result: Result<T, Error>) -> Bool {
+        lock.lock()
+        if finished {
+            lock.unlock()
+            return false
+        }
+        finished = true
+
+        if let continuation = continuation {
+            self.continuation = nil
+            lock.unlock()
+            continuation.resume(with: result)
+        } else {
+            self.result = result
+            lock.unlock()
+        }
+        return true
+    }
+}
+
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+func __private__withTimeout<T>(
+    _ seconds: Double,
+    _ work: @escaping (CancellationToken) throws -> T
+) async throws -> T {
+    let token = CancellationToken()
+    let race = __private__TimeoutRace<T>()
+
+    return try await withTaskCancellationHandler(operation: {
+        try await withCheckedThrowingContinuation { (continuation: CheckedContinuation<T, Error>) in
+            race.setContinuation(continuation)
+
+            DispatchQueue.global().async {
+                race.finish(Result { try work(token) })
+            }
+            DispatchQueue.global().asyncAfter(deadline: .now() + seconds) {
+                if race.finish(.failure(RustTimeoutError(seconds: seconds))) {
+                    token.cancel()
+                }
+            }
+        }
+    }, onCancel: {
+        if race.finish(.failure(CancellationError())) {
+            token.cancel()
+        }
+    })
+}
+"#;
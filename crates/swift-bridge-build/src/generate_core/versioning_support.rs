@@ -0,0 +1,38 @@
+/// Support for functions that use the `#[swift_bridge(since = "...")]` attribute.
+///
+/// Versioned functions are weakly linked, so the app still loads when it loads an older Rust
+/// library at runtime. Each bridge module adds its versioned functions to `RustApi`, and Swift
+/// looks up a function's symbol in the loaded images to find out whether it can be called.
+pub const SWIFT_VERSIONING_SUPPORT: &str = r#"
+/// The Rust functions that use `#[swift_bridge(since = "...")]`.
+///
+/// Check `RustApi.some_function.isAvailable` before calling a function that might be missing from
+/// the Rust library that was loaded at runtime.
+public enum RustApi {}
+
+/// A Rust function that was added in a version of the Rust library.
+public struct RustApiFunction {
+    /// The function's name, such as "some_function" or "SomeType.some_method".
+    public let name: String
+    /// The version of the Rust library that added the function.
+    public let since: String
+    /// Whether the Rust library that was loaded at runtime has the function.
+    public let isAvailable: Bool
+
+    init(name: String, since: String, symbol: String) {
+        self.name = name
+        self.since = since
+        self.isAvailable = __private__symbolIsLoaded(symbol)
+    }
+}
+
+func __private__symbolIsLoaded(_ symbol: String) -> Bool {
+    #if canImport(Darwin)
+    // RTLD_DEFAULT
+    let handle = UnsafeMutableRawPointer(bitPattern: -2)
+    #else
+    let handle: UnsafeMutableRawPointer? = nil
+    #endif
+    return dlsym(handle, symbol) != nil
+}
+"#;
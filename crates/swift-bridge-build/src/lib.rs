@@ -3,13 +3,24 @@
 
 #![deny(missing_docs)]
 
+mod api_manifest;
+mod bridge_features;
+mod bridge_info;
 mod package;
+mod size_report;
+mod symbols;
+use crate::bridge_features::{cargo_feature_is_enabled, generate_bridge_features_swift};
+use crate::bridge_info::{generate_bridge_info_swift, BridgeInfo};
 use crate::generate_core::write_core_swift_and_c;
+pub use api_manifest::*;
 pub use package::*;
+pub use size_report::*;
+use std::collections::BTreeSet;
 use std::path::Path;
 use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+pub use symbols::*;
 use syn::__private::ToTokens;
-use syn::{File, Item};
+use syn::{File, Item, ItemMod};
 
 mod generate_core;
 
@@ -20,11 +31,21 @@ pub fn parse_bridges(
 ) -> GeneratedCode {
     let mut generated_code = GeneratedCode::new();
 
-    for rust_file in rust_source_files.into_iter() {
+    let rust_source_files: Vec<_> = rust_source_files.into_iter().collect();
+
+    // A bridge module can use opaque types that bridge modules in other files declare, so we
+    // parse every file once before generating code to register all of the declared types.
+    for rust_file in rust_source_files.iter() {
+        if let Ok(file) = std::fs::read_to_string(rust_file.as_ref()) {
+            register_declared_types(&file);
+        }
+    }
+
+    for rust_file in rust_source_files.iter() {
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file_contents(&file) {
+        let mut gen = match parse_file_contents(&file) {
             Ok(generated) => generated,
             Err(e) => {
                 // TODO: Return an error...
@@ -38,6 +59,10 @@ Error while parsing {:?}
             }
         };
 
+        for module_size in gen.module_sizes.iter_mut() {
+            module_size.file = rust_file.to_path_buf();
+        }
+
         generated_code.generated.push(gen);
     }
 
@@ -47,27 +72,78 @@ Error while parsing {:?}
 /// Generated Swift files and C headers.
 pub struct GeneratedCode {
     generated: Vec<GeneratedFromSwiftBridgeModule>,
+    exposed_features: Vec<String>,
+    bridge_info: Option<BridgeInfo>,
 }
 
 impl GeneratedCode {
     fn new() -> Self {
-        GeneratedCode { generated: vec![] }
+        GeneratedCode {
+            generated: vec![],
+            exposed_features: vec![],
+            bridge_info: None,
+        }
     }
 }
 
 impl GeneratedCode {
+    /// Let Swift check whether or not each of the given cargo features of the crate that is being
+    /// built is enabled.
+    ///
+    /// The generated Swift gets a `BridgeFeatures` enum with a static `Bool` for every feature.
+    /// Dashes in feature names become underscores.
+    ///
+    /// ```no_run
+    /// swift_bridge_build::parse_bridges(vec!["src/lib.rs"])
+    ///     .expose_cargo_features(["decimal", "metal-renderer"])
+    ///     .write_all_concatenated("./generated", "my_crate");
+    /// ```
+    ///
+    /// ```swift
+    /// if BridgeFeatures.metal_renderer {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn expose_cargo_features(
+        mut self,
+        features: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.exposed_features
+            .extend(features.into_iter().map(|feature| feature.into()));
+        self
+    }
+
+    /// Let Swift check which build of the Rust crate it is running, for example to show it on an
+    /// about screen or to attach it to logs and crash reports.
+    ///
+    /// The generated Swift gets a `bridgeInfo()` function that returns the crate's name and
+    /// version, the git hash of the code that was built, the enabled cargo features and the
+    /// [`swift_bridge_ir::BRIDGE_ABI_VERSION`].
+    ///
+    /// The git hash is read from the `SWIFT_BRIDGE_GIT_HASH` environment variable, or from
+    /// `git rev-parse --short HEAD` if it is not set.
+    ///
+    /// ```no_run
+    /// swift_bridge_build::parse_bridges(vec!["src/lib.rs"])
+    ///     .expose_bridge_info()
+    ///     .write_all_concatenated("./generated", "my_crate");
+    /// ```
+    ///
+    /// ```swift
+    /// print("Running \(bridgeInfo())")
+    /// ```
+    pub fn expose_bridge_info(mut self) -> Self {
+        self.bridge_info = Some(BridgeInfo::from_build_env());
+        self
+    }
+
     /// Write all of the generated Swift to a single Swift file and all of the generated C headers
     /// to a single header file.
     pub fn write_all_concatenated(&self, swift_bridge_out_dir: impl AsRef<Path>, crate_name: &str) {
         let swift_bridge_out_dir = swift_bridge_out_dir.as_ref();
 
-        let mut concatenated_swift = "".to_string();
-        let mut concatenated_c = "".to_string();
-
-        for gen in &self.generated {
-            concatenated_swift += &gen.swift;
-            concatenated_c += &gen.c_header;
-        }
+        let concatenated_swift = self.concat_swift();
+        let concatenated_c = self.concat_c();
 
         let out = swift_bridge_out_dir.join(&crate_name);
         match std::fs::create_dir_all(&out) {
@@ -78,16 +154,38 @@ impl GeneratedCode {
         std::fs::write(out.join(format!("{}.h", crate_name)), concatenated_c).unwrap();
         std::fs::write(
             out.join(format!("{}.swift", crate_name)),
-            concatenated_swift,
+            &concatenated_swift,
         )
         .unwrap();
 
-        write_core_swift_and_c(swift_bridge_out_dir.as_ref());
+        write_core_swift_and_c(swift_bridge_out_dir.as_ref(), &concatenated_swift);
+    }
+
+    /// Write a Swift Package that links against the crate's static library, so that Swift code
+    /// can depend on it and `swift build` without any further setup.
+    ///
+    /// ```no_run
+    /// # use swift_bridge_build::StaticLibPackageConfig;
+    /// let bridges = vec!["src/lib.rs"];
+    /// swift_bridge_build::parse_bridges(bridges).write_swift_package(StaticLibPackageConfig::new(
+    ///     "MyRustLib".into(),
+    ///     "MyRustLib".to_string(),
+    ///     "target/debug/libmy_rust_lib.a".into(),
+    /// ));
+    /// ```
+    ///
+    /// - Also see the [relevant book chapter](https://chinedufn.github.io/swift-bridge/building/swift-packages/index.html)
+    pub fn write_swift_package(&self, config: StaticLibPackageConfig) {
+        package::write_static_lib_package(&config, &self.concat_swift(), &self.concat_c());
     }
 
     /// Concatenate all of the generated Swift code into one file.
     pub fn concat_swift(&self) -> String {
-        let mut swift = "".to_string();
+        let mut swift = self.swift_namespace_declarations();
+        swift += &generate_bridge_features_swift(&self.exposed_features, cargo_feature_is_enabled);
+        if let Some(bridge_info) = &self.bridge_info {
+            swift += &generate_bridge_info_swift(bridge_info);
+        }
 
         for gen in &self.generated {
             swift += &gen.swift;
@@ -106,6 +204,124 @@ impl GeneratedCode {
 
         c_header
     }
+
+    /// Declare the caseless enums that hold the types of every
+    /// `#[swift_bridge::bridge(swift_namespace = "...")]` module.
+    ///
+    /// Modules can share parent namespaces, so each enum is only declared once.
+    fn swift_namespace_declarations(&self) -> String {
+        let mut namespaces = BTreeSet::new();
+        for namespace in self
+            .generated
+            .iter()
+            .flat_map(|gen| gen.swift_namespaces.iter())
+        {
+            for idx in 1..=namespace.len() {
+                namespaces.insert(&namespace[..idx]);
+            }
+        }
+
+        let mut declarations = "".to_string();
+        for namespace in namespaces {
+            let (name, parents) = namespace.split_last().unwrap();
+
+            if parents.is_empty() {
+                declarations += &format!("public enum {} {{}}\n", name);
+            } else {
+                declarations += &format!(
+                    "extension {} {{\n    public enum {} {{}}\n}}\n",
+                    parents.join("."),
+                    name
+                );
+            }
+        }
+
+        declarations
+    }
+
+    /// Write an XCTest file that smoke tests every opaque Rust type that Swift can create.
+    ///
+    /// Each test creates an instance of the type, calls its methods with default arguments such
+    /// as `0`, `""` and `nil` and then frees it. This catches link errors, crashes and ownership
+    /// mistakes in the generated bindings. Add the file to your Swift test target.
+    ///
+    /// ```no_run
+    /// swift_bridge_build::parse_bridges(vec!["src/lib.rs"])
+    ///     .write_swift_smoke_tests("./generated/SwiftBridgeSmokeTests.swift", "MyApp");
+    /// ```
+    pub fn write_swift_smoke_tests(&self, path: impl AsRef<Path>, swift_module_name: &str) {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+
+        std::fs::write(path, self.concat_swift_smoke_tests(swift_module_name)).unwrap();
+    }
+
+    /// Concatenate the smoke tests of all of the bridge modules into one XCTest file.
+    ///
+    /// `swift_module_name` is the Swift module that the generated code gets compiled into. It gets
+    /// imported with `@testable import`.
+    pub fn concat_swift_smoke_tests(&self, swift_module_name: &str) -> String {
+        let tests: Vec<&str> = self
+            .generated
+            .iter()
+            .flat_map(|gen| gen.swift_smoke_tests.iter())
+            .map(|tests| tests.as_str())
+            .collect();
+
+        format!(
+            r#"import XCTest
+@testable import {swift_module_name}
+
+class SwiftBridgeSmokeTests: XCTestCase {{
+{tests}
+}}
+"#,
+            swift_module_name = swift_module_name,
+            tests = tests.join("\n\n")
+        )
+    }
+
+    /// Get how much code was generated for each bridge module.
+    pub fn size_report(&self) -> BridgeSizeReport {
+        BridgeSizeReport::new(
+            self.generated
+                .iter()
+                .flat_map(|gen| gen.module_sizes.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Get the public Swift API of all of the generated Swift code.
+    pub fn swift_api_manifest(&self) -> SwiftApiManifest {
+        SwiftApiManifest::from_generated_swift(&self.concat_swift())
+    }
+}
+
+fn register_declared_types(file: &str) {
+    let file: File = match syn::parse_str(file) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+
+    for item in file.items {
+        if let Item::Mod(module) = item {
+            if is_bridge_module(&module) {
+                // Parsing a module registers the types that it declares. Errors, such as uses of
+                // types that a later file declares, get reported when we generate code.
+                let _ = syn::parse2::<SwiftBridgeModule>(module.to_token_stream());
+            }
+        }
+    }
+}
+
+fn is_bridge_module(module: &ItemMod) -> bool {
+    module.attrs.iter().any(|a| {
+        let attrib = a.path.to_token_stream().to_string();
+        attrib == "swift_bridge :: bridge" || attrib == "swift_bridge_macro :: bridge"
+    })
 }
 
 fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
@@ -114,6 +330,9 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
     let mut generated = GeneratedFromSwiftBridgeModule {
         c_header: "".to_string(),
         swift: "".to_string(),
+        swift_namespaces: vec![],
+        module_sizes: vec![],
+        swift_smoke_tests: vec![],
     };
 
     for item in file.items {
@@ -121,29 +340,52 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
             Item::Mod(module) => {
                 // TODO: Move this check into the `impl Parse for SwiftBridgeModule`.. Modify our
                 //  tests in swift-bridge-ir to annotate modules with `#[swift_bridge::bridge]`
-                if module.attrs.iter().any(|a| {
-                    let attrib = a.path.to_token_stream().to_string();
-                    attrib == "swift_bridge :: bridge" || attrib == "swift_bridge_macro :: bridge"
-                }) {
+                if is_bridge_module(&module) {
+                    let module_name = module.ident.to_string();
                     let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
 
                     let config = CodegenConfig {
-                        crate_feature_lookup: Box::new(|feature_name| {
-                            let normalized_feature_name = feature_name.replace("-", "_");
-                            let normalized_feature_name = normalized_feature_name.to_uppercase();
-
-                            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
-                            std::env::var(env_var_name).is_ok()
-                        }),
+                        crate_feature_lookup: Box::new(cargo_feature_is_enabled),
                     };
                     let swift_and_c = module.generate_swift_code_and_c_header(config);
 
+                    let smoke_tests = module.generate_swift_smoke_tests(CodegenConfig {
+                        crate_feature_lookup: Box::new(cargo_feature_is_enabled),
+                    });
+                    if !smoke_tests.is_empty() {
+                        generated.swift_smoke_tests.push(smoke_tests);
+                    }
+
+                    if !module.swift_namespace().is_empty() && !swift_and_c.swift.is_empty() {
+                        generated
+                            .swift_namespaces
+                            .push(module.swift_namespace().to_vec());
+                    }
+
                     generated.c_header += &swift_and_c.c_header;
                     generated.c_header += "\n\n";
 
                     let swift = &swift_and_c.swift;
                     generated.swift += &swift;
                     generated.swift += "\n\n";
+
+                    // Modules that are disabled by a `cfg` attribute don't generate any code.
+                    let rust_shims = if swift_and_c.swift.is_empty() {
+                        0
+                    } else {
+                        module
+                            .to_token_stream()
+                            .to_string()
+                            .matches("export_name")
+                            .count()
+                    };
+                    generated.module_sizes.push(BridgeModuleSize {
+                        file: Default::default(),
+                        module: module_name,
+                        rust_shims,
+                        swift_lines: swift_and_c.swift.lines().count(),
+                        c_header_lines: swift_and_c.c_header.lines().count(),
+                    });
                 }
             }
             _ => {}
@@ -157,4 +399,146 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
     swift: String,
+    swift_namespaces: Vec<Vec<String>>,
+    module_sizes: Vec<BridgeModuleSize>,
+    swift_smoke_tests: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we put the smoke tests of every bridge module into one XCTest class.
+    #[test]
+    fn concatenates_swift_smoke_tests() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type Foo;
+
+        #[swift_bridge(init)]
+        fn new() -> Foo;
+    }
+}
+
+#[swift_bridge::bridge]
+mod ffi2 {
+    extern "Rust" {
+        type Bar;
+
+        #[swift_bridge(init)]
+        fn new() -> Bar;
+        fn clear(self: &mut Bar);
+    }
+}
+"#;
+        let mut generated_code = GeneratedCode::new();
+        generated_code
+            .generated
+            .push(parse_file_contents(file).unwrap());
+
+        let expected = r#"import XCTest
+@testable import MyApp
+
+class SwiftBridgeSmokeTests: XCTestCase {
+    func testFoo() throws {
+        _ = Foo()
+    }
+
+    func testBar() throws {
+        let val = Bar()
+        val.clear()
+    }
+}
+"#;
+        assert_eq!(generated_code.concat_swift_smoke_tests("MyApp"), expected);
+    }
+
+    /// Verify that a bridge module can use an opaque type that a bridge module in a later file
+    /// declares.
+    #[test]
+    fn uses_type_declared_in_later_file() {
+        let renderer = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn render(scene: &Scene);
+    }
+}
+"#;
+        let scene = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type Scene;
+    }
+}
+"#;
+        assert!(parse_file_contents(renderer).is_err());
+
+        register_declared_types(renderer);
+        register_declared_types(scene);
+
+        let generated = parse_file_contents(renderer).unwrap();
+        assert!(generated
+            .swift
+            .contains("public func render(_ scene: SceneRef)"));
+        assert!(!generated.swift.contains("public class Scene"));
+    }
+
+    /// Verify that we write a Swift Package that links against the static library.
+    #[test]
+    fn writes_swift_package() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn hello();
+    }
+}
+"#;
+        let mut generated_code = GeneratedCode::new();
+        generated_code
+            .generated
+            .push(parse_file_contents(file).unwrap());
+
+        let dir = tempfile::tempdir().unwrap();
+        let static_lib = dir.path().join("libmy_rust_lib.a");
+        std::fs::write(&static_lib, "").unwrap();
+        let package_dir = dir.path().join("MyRustLib");
+
+        generated_code.write_swift_package(StaticLibPackageConfig::new(
+            package_dir.clone(),
+            "MyRustLib".to_string(),
+            static_lib,
+        ));
+
+        let read = |path: &str| std::fs::read_to_string(package_dir.join(path)).unwrap();
+
+        assert!(package_dir.join("lib/libmy_rust_lib.a").exists());
+        assert!(read("Package.swift").contains(
+            r#".systemLibrary(
+			name: "RustStaticLib",
+			path: "Sources/RustStaticLib"
+		)"#
+        ));
+        assert_eq!(
+            read("Sources/RustStaticLib/module.modulemap"),
+            r#"module RustStaticLib {
+    header "SwiftBridgeCore.h"
+    header "MyRustLib.h"
+    link "my_rust_lib"
+    export *
+}
+"#
+        );
+        assert!(read("Sources/RustStaticLib/MyRustLib.h").contains("__swift_bridge__$hello"));
+        assert!(read("Sources/RustStaticLib/SwiftBridgeCore.h").contains("RustStr"));
+        assert!(read("Sources/MyRustLib/MyRustLib.swift")
+            .starts_with("import RustStaticLib\npublic func hello()"));
+        assert!(
+            read("Sources/MyRustLib/SwiftBridgeCore.swift").starts_with("import RustStaticLib\n")
+        );
+    }
 }
@@ -18,6 +18,9 @@ pub struct CreatePackageConfig {
     pub out_dir: PathBuf,
     /// The name for the Swift package
     pub package_name: String,
+    /// Files or directories that are copied into the package's resource bundle, such as models or
+    /// dictionaries. Rust looks them up by file name with `swift_bridge::resources::path`.
+    pub resources: Vec<PathBuf>,
 }
 
 impl CreatePackageConfig {
@@ -33,6 +36,31 @@ impl CreatePackageConfig {
             paths,
             out_dir,
             package_name,
+            resources: vec![],
+        }
+    }
+}
+
+/// Config for writing a Swift Package that links against a Rust static library.
+///
+/// See [`crate::GeneratedCode::write_swift_package`].
+pub struct StaticLibPackageConfig {
+    /// The directory where the package will be saved
+    pub out_dir: PathBuf,
+    /// The name for the Swift package, which is also the name of the Swift module that it exports
+    pub package_name: String,
+    /// The Rust static library, e.g. `target/debug/libmy_rust_lib.a`
+    pub static_lib: PathBuf,
+}
+
+impl StaticLibPackageConfig {
+    /// Creates a new `StaticLibPackageConfig` for writing a Swift Package that links against a
+    /// Rust static library.
+    pub fn new(out_dir: PathBuf, package_name: String, static_lib: PathBuf) -> Self {
+        Self {
+            out_dir,
+            package_name,
+            static_lib,
         }
     }
 }
@@ -309,6 +337,39 @@ fn gen_package(output_dir: &Path, config: &CreatePackageConfig) {
     )
     .expect("Couldn't copy project's bridging swift file to the package");
 
+    // Copy resources
+    let mut resource_declarations = "".to_string();
+    if !config.resources.is_empty() {
+        let resources_dir = sources_dir.join("Resources");
+        if resources_dir.exists() {
+            fs::remove_dir_all(&resources_dir).expect("Couldn't delete previous resources");
+        }
+        fs::create_dir_all(&resources_dir).expect("Couldn't create directory for resources");
+
+        for resource in &config.resources {
+            let file_name = resource
+                .file_name()
+                .expect("Resource paths must end in a file or directory name");
+            copy_resource(resource, &resources_dir.join(file_name));
+
+            resource_declarations += &format!(
+                "\n\t\t\t\t.copy(\"Resources/{}\"),",
+                file_name.to_str().unwrap()
+            );
+        }
+
+        fs::write(
+            sources_dir.join("SwiftBridgeResources.swift"),
+            SWIFT_RESOURCES_SUPPORT,
+        )
+        .expect("Couldn't write resources swift file");
+    }
+    let resources = if resource_declarations.is_empty() {
+        "".to_string()
+    } else {
+        format!(",\n\t\t\tresources: [{}\n\t\t\t]", resource_declarations)
+    };
+
     // Generate Package.swift
     let package_name = &config.package_name;
     let package_swift = format!(
@@ -329,7 +390,7 @@ let package = Package(
 		),
 		.target(
 			name: "{package_name}",
-			dependencies: ["RustXcframework"])
+			dependencies: ["RustXcframework"]{resources})
 	]
 )
 	"#
@@ -338,3 +399,165 @@ let package = Package(
     fs::write(output_dir.join("Package.swift"), package_swift)
         .expect("Couldn't write Package.swift file");
 }
+
+/// Writes a Swift Package that links against a Rust static library.
+///
+/// The headers and the static library are exposed through a `RustStaticLib` system library
+/// target, and the generated Swift code goes into a target that is named after the package. Like
+/// in [`gen_package`], the Swift files import `RustStaticLib` instead of re-exporting it.
+///
+/// The static library is copied into the package's `lib` directory, and the manifest finds it
+/// relative to itself, so the package can be moved around.
+pub(crate) fn write_static_lib_package(
+    config: &StaticLibPackageConfig,
+    concatenated_swift: &str,
+    concatenated_c: &str,
+) {
+    let output_dir: &Path = config.out_dir.as_ref();
+    let package_name = &config.package_name;
+
+    let lib_file_name = config
+        .static_lib
+        .file_name()
+        .and_then(|name| name.to_str())
+        .expect("Static library path must end in a file name");
+    let link_name = lib_file_name
+        .strip_prefix("lib")
+        .and_then(|name| name.strip_suffix(".a"))
+        .unwrap_or_else(|| {
+            panic!(
+                "Static library {:?} must be named `lib<name>.a`",
+                config.static_lib
+            )
+        });
+
+    // Copy the static library //
+    let lib_dir = output_dir.join("lib");
+    fs::create_dir_all(&lib_dir).expect("Couldn't create directory for the static library");
+    if let Err(err) = fs::copy(&config.static_lib, lib_dir.join(lib_file_name)) {
+        panic!(
+            "Couldn't copy static library {:?} - {}",
+            config.static_lib, err
+        );
+    }
+
+    // Write the system library target //
+    let c_dir = output_dir.join("Sources").join("RustStaticLib");
+    fs::create_dir_all(&c_dir).expect("Couldn't create directory for the headers");
+
+    let core_dir = tempdir().expect("Couldn't create temporary directory");
+    crate::generate_core::write_core_swift_and_c(core_dir.path(), concatenated_swift);
+    fs::copy(
+        core_dir.path().join("SwiftBridgeCore.h"),
+        c_dir.join("SwiftBridgeCore.h"),
+    )
+    .expect("Couldn't copy SwiftBridgeCore header file");
+    fs::write(c_dir.join(format!("{}.h", package_name)), concatenated_c)
+        .expect("Couldn't write project's header file");
+    fs::write(
+        c_dir.join("module.modulemap"),
+        format!(
+            r#"module RustStaticLib {{
+    header "SwiftBridgeCore.h"
+    header "{package_name}.h"
+    link "{link_name}"
+    export *
+}}
+"#
+        ),
+    )
+    .expect("Couldn't write modulemap file");
+
+    // Write the Swift target //
+    let sources_dir = output_dir.join("Sources").join(package_name);
+    fs::create_dir_all(&sources_dir).expect("Couldn't create directory for source files");
+
+    let core_swift = fs::read_to_string(core_dir.path().join("SwiftBridgeCore.swift"))
+        .expect("Couldn't read core bridging swift file");
+    fs::write(
+        sources_dir.join("SwiftBridgeCore.swift"),
+        format!("import RustStaticLib\n{}", core_swift),
+    )
+    .expect("Couldn't write core bridging swift file");
+    fs::write(
+        sources_dir.join(format!("{}.swift", package_name)),
+        format!("import RustStaticLib\n{}", concatenated_swift),
+    )
+    .expect("Couldn't write project's bridging swift file");
+
+    // Generate Package.swift
+    let package_swift = format!(
+        r#"// swift-tools-version:5.5.0
+import Foundation
+import PackageDescription
+
+let libDir = URL(fileURLWithPath: #filePath)
+	.deletingLastPathComponent()
+	.appendingPathComponent("lib")
+	.path
+
+let package = Package(
+	name: "{package_name}",
+	products: [
+		.library(
+			name: "{package_name}",
+			targets: ["{package_name}"]),
+	],
+	dependencies: [],
+	targets: [
+		.systemLibrary(
+			name: "RustStaticLib",
+			path: "Sources/RustStaticLib"
+		),
+		.target(
+			name: "{package_name}",
+			dependencies: ["RustStaticLib"],
+			linkerSettings: [
+				.unsafeFlags(["-L", libDir]),
+				.linkedLibrary("pthread", .when(platforms: [.linux])),
+				.linkedLibrary("dl", .when(platforms: [.linux])),
+				.linkedLibrary("m", .when(platforms: [.linux])),
+			])
+	]
+)
+"#
+    );
+
+    fs::write(output_dir.join("Package.swift"), package_swift)
+        .expect("Couldn't write Package.swift file");
+}
+
+/// Copy a resource file, or a resource directory and all of its contents.
+fn copy_resource(from: &Path, to: &Path) {
+    if from.is_dir() {
+        fs::create_dir_all(to).expect("Couldn't create resource directory");
+
+        for entry in fs::read_dir(from).expect("Couldn't read resource directory") {
+            let entry = entry.unwrap().path();
+            copy_resource(&entry, &to.join(entry.file_name().unwrap()));
+        }
+    } else {
+        if let Err(err) = fs::copy(from, to) {
+            panic!("Couldn't copy resource {:?} - {}", from, err);
+        }
+    }
+}
+
+/// Hands Rust the paths of the package's resources, which Swift Package Manager copies into the
+/// package's resource bundle.
+///
+/// Rust links against this function when the `resources` feature is enabled.
+const SWIFT_RESOURCES_SUPPORT: &str = r#"import Foundation
+import RustXcframework
+
+@_cdecl("__swift_bridge__$resources$path")
+func __swift_bridge__resources_path(_ name: RustStr) -> UnsafeMutableRawPointer? {
+    guard let url = Bundle.module.url(forResource: name.toString(), withExtension: nil) else {
+        return nil
+    }
+
+    let rustString = url.path.intoRustString()
+    rustString.isOwned = false
+    return rustString.ptr
+}
+"#;
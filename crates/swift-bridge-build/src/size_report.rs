@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+// Rough averages for an optimized build, used to compare bridge modules with each other.
+const ESTIMATED_BYTES_PER_RUST_SHIM: usize = 200;
+const ESTIMATED_BYTES_PER_SWIFT_LINE: usize = 50;
+
+/// How much code was generated for each bridge module.
+///
+/// Helps find which bridge modules contribute the most to the size of the final binary.
+///
+/// ```no_run
+/// let generated = swift_bridge_build::parse_bridges(vec!["src/lib.rs"]);
+///
+/// for module in generated.size_report().modules() {
+///     println!("cargo:warning={}: {} Rust shims", module.module, module.rust_shims);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BridgeSizeReport {
+    modules: Vec<BridgeModuleSize>,
+}
+
+/// The amount of code that was generated for one bridge module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgeModuleSize {
+    /// The file that contains the bridge module.
+    pub file: PathBuf,
+    /// The name of the bridge module, such as `ffi`.
+    pub module: String,
+    /// The number of `extern "C"` functions that were generated on the Rust side.
+    pub rust_shims: usize,
+    /// The number of lines of generated Swift.
+    pub swift_lines: usize,
+    /// The number of lines of the generated C header.
+    pub c_header_lines: usize,
+}
+
+impl BridgeSizeReport {
+    pub(crate) fn new(mut modules: Vec<BridgeModuleSize>) -> Self {
+        modules.sort_by_key(|module| std::cmp::Reverse(module.estimated_binary_bytes()));
+        BridgeSizeReport { modules }
+    }
+
+    /// The bridge modules, largest first.
+    pub fn modules(&self) -> &[BridgeModuleSize] {
+        &self.modules
+    }
+}
+
+impl BridgeModuleSize {
+    /// A rough estimate of how many bytes the module's generated Rust and Swift add to an
+    /// optimized binary.
+    ///
+    /// Only meant for comparing bridge modules with each other. Measure the binary itself when
+    /// you need exact numbers.
+    pub fn estimated_binary_bytes(&self) -> usize {
+        self.rust_shims * ESTIMATED_BYTES_PER_RUST_SHIM
+            + self.swift_lines * ESTIMATED_BYTES_PER_SWIFT_LINE
+    }
+}
+
+impl Display for BridgeSizeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>10} {:>12} {:>15}  module",
+            "rust shims", "swift lines", "estimated bytes"
+        )?;
+
+        for module in &self.modules {
+            writeln!(
+                f,
+                "{:>10} {:>12} {:>15}  {}::{}",
+                module.rust_shims,
+                module.swift_lines,
+                module.estimated_binary_bytes(),
+                module.file.display(),
+                module.module,
+            )?;
+        }
+
+        writeln!(
+            f,
+            "{:>10} {:>12} {:>15}  total",
+            self.modules.iter().map(|m| m.rust_shims).sum::<usize>(),
+            self.modules.iter().map(|m| m.swift_lines).sum::<usize>(),
+            self.modules
+                .iter()
+                .map(|m| m.estimated_binary_bytes())
+                .sum::<usize>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that the report lists the largest modules first, followed by the total.
+    #[test]
+    fn largest_modules_first() {
+        let small = BridgeModuleSize {
+            file: PathBuf::from("src/small.rs"),
+            module: "ffi".to_string(),
+            rust_shims: 1,
+            swift_lines: 10,
+            c_header_lines: 5,
+        };
+        let large = BridgeModuleSize {
+            file: PathBuf::from("src/large.rs"),
+            module: "ffi".to_string(),
+            rust_shims: 20,
+            swift_lines: 300,
+            c_header_lines: 40,
+        };
+
+        let report = BridgeSizeReport::new(vec![small.clone(), large.clone()]);
+        assert_eq!(report.modules(), &[large, small]);
+
+        let expected = r#"rust shims  swift lines estimated bytes  module
+        20          300           19000  src/large.rs::ffi
+         1           10             700  src/small.rs::ffi
+        21          310           19700  total
+"#;
+        assert_eq!(report.to_string(), expected);
+    }
+}
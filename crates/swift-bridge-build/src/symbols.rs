@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+
+const SYMBOL_PREFIX: &str = "__swift_bridge__$";
+
+/// Get the `swift-bridge` symbols that a linked binary contains, such as `Foo::bar` for the
+/// `__swift_bridge__$Foo$bar` shim.
+///
+/// Every bridged function is its own `extern "C"` symbol that nothing else refers to, so linking
+/// with dead code stripping (`-dead_strip`, which Xcode enables for release builds) removes the
+/// bridged functions that your Swift code never calls.
+/// Use this to verify which ones are left over.
+///
+/// Uses the `nm` command, or the command in the `NM` environment variable if it is set.
+pub fn linked_bridge_symbols(binary: impl AsRef<Path>) -> std::io::Result<BTreeSet<String>> {
+    let nm = std::env::var("NM").unwrap_or_else(|_| "nm".to_string());
+
+    let output = Command::new(nm).arg(binary.as_ref()).output()?;
+    if !output.status.success() {
+        return Err(std::io::Error::other(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(parse_nm_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Get the symbols that are not matched by any of the patterns in the allow list.
+///
+/// A pattern is either a symbol such as `Foo::bar`, or a prefix followed by a `*` such as
+/// `Foo::*`.
+pub fn disallowed_bridge_symbols<'a>(
+    symbols: &'a BTreeSet<String>,
+    allow_list: &[String],
+) -> Vec<&'a str> {
+    symbols
+        .iter()
+        .filter(|symbol| {
+            !allow_list
+                .iter()
+                .any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => symbol.starts_with(prefix),
+                    None => *symbol == pattern,
+                })
+        })
+        .map(|symbol| symbol.as_str())
+        .collect()
+}
+
+// Each line is `[address] type name`. Undefined symbols don't have an address.
+fn parse_nm_output(output: &str) -> BTreeSet<String> {
+    let mut symbols = BTreeSet::new();
+
+    for line in output.lines() {
+        let mut columns = line.split_whitespace().rev();
+
+        let name = match columns.next() {
+            Some(name) => name,
+            None => continue,
+        };
+        if columns.next() == Some("U") {
+            continue;
+        }
+
+        // Mach-O prefixes C symbols with an underscore.
+        let name = name
+            .strip_prefix('_')
+            .filter(|n| n.starts_with(SYMBOL_PREFIX))
+            .unwrap_or(name);
+        if let Some(symbol) = name.strip_prefix(SYMBOL_PREFIX) {
+            symbols.insert(symbol.replace('$', "::"));
+        }
+    }
+
+    symbols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we collect the defined swift-bridge symbols from Mach-O and ELF `nm` output.
+    #[test]
+    fn parses_nm_output() {
+        let output = r#"
+0000000100003f50 T ___swift_bridge__$Foo$bar
+0000000100003f60 T ___swift_bridge__$Foo$_free
+                 U ___swift_bridge__$some_swift_fn
+0000000000001130 T __swift_bridge__$top_level
+0000000000001140 T _main
+"#;
+
+        let symbols: Vec<String> = parse_nm_output(output).into_iter().collect();
+        assert_eq!(symbols, vec!["Foo::_free", "Foo::bar", "top_level"]);
+    }
+
+    /// Verify that symbols are allowed by exact matches or by prefixes.
+    #[test]
+    fn disallowed_symbols() {
+        let symbols: BTreeSet<String> = ["Foo::_free", "Foo::bar", "Vec_Foo::new", "top_level"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let allow_list = vec!["Foo::*".to_string(), "top_level".to_string()];
+
+        assert_eq!(
+            disallowed_bridge_symbols(&symbols, &allow_list),
+            vec!["Vec_Foo::new"]
+        );
+    }
+}
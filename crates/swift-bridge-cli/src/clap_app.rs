@@ -8,6 +8,8 @@ pub fn cli() -> Command<'static> {
         .subcommand_required(true)
         .subcommand(create_package_command())
         .subcommand(create_bridges_command())
+        .subcommand(create_check_api_command())
+        .subcommand(create_check_symbols_command())
 }
 
 /// The command for creating a Swift Package
@@ -101,6 +103,13 @@ fn create_package_command() -> Command<'static> {
                 .required(true)
                 .help("The name for the Swift Package"),
         )
+        .arg(
+            Arg::new("resource")
+                .long("resource")
+                .action(ArgAction::Append)
+                .value_name("PATH")
+                .help("A file or directory to bundle as a resource of the Swift Package"),
+        )
 }
 
 fn create_bridges_command() -> Command<'static> {
@@ -134,3 +143,53 @@ fn create_bridges_command() -> Command<'static> {
                 .required(true),
         )
 }
+
+fn create_check_api_command() -> Command<'static> {
+    Command::new("check-api")
+        .about("Report breaking changes to the generated Swift API since a recorded API manifest")
+        .arg(
+            Arg::new("source-file")
+                .action(ArgAction::Append)
+                .help("source file(s) containing #[swift_bridge::bridge] macro")
+                .long("file")
+                .short('f')
+                .required(true),
+        )
+        .arg(
+            Arg::new("manifest")
+                .action(ArgAction::Set)
+                .help("The previously recorded API manifest")
+                .long("manifest")
+                .value_name("PATH")
+                .required(true),
+        )
+        .arg(
+            Arg::new("update")
+                .action(ArgAction::SetTrue)
+                .help("Record the current API to the manifest instead of checking against it")
+                .long("update"),
+        )
+}
+
+fn create_check_symbols_command() -> Command<'static> {
+    Command::new("check-symbols")
+        .about("List the bridge symbols that a linked binary contains")
+        .arg(
+            Arg::new("binary")
+                .action(ArgAction::Set)
+                .help("The linked binary, such as an app's executable")
+                .long("binary")
+                .value_name("PATH")
+                .required(true),
+        )
+        .arg(
+            Arg::new("allow-list")
+                .action(ArgAction::Set)
+                .help(
+                    "A file with one allowed symbol per line, such as `Foo::bar` or `Foo::*`; \
+                          fails if the binary contains any other bridge symbols",
+                )
+                .long("allow-list")
+                .value_name("PATH"),
+        )
+}
@@ -1,7 +1,10 @@
 use clap::ArgMatches;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use swift_bridge_build::{create_package, parse_bridges, ApplePlatform, CreatePackageConfig};
+use swift_bridge_build::{
+    create_package, disallowed_bridge_symbols, linked_bridge_symbols, parse_bridges, ApplePlatform,
+    CreatePackageConfig, SwiftApiManifest,
+};
 
 /// Executes the correct function depending on the cli input
 pub fn handle_matches(matches: ArgMatches) {
@@ -12,6 +15,10 @@ pub fn handle_matches(matches: ArgMatches) {
         Some(cmd @ "parse-bridges") => {
             handle_parse_bridges(matches.subcommand_matches(cmd).unwrap())
         }
+        Some(cmd @ "check-api") => handle_check_api(matches.subcommand_matches(cmd).unwrap()),
+        Some(cmd @ "check-symbols") => {
+            handle_check_symbols(matches.subcommand_matches(cmd).unwrap())
+        }
         _ => unreachable!("No subcommand or unknown subcommand given"), // Shouldn't happen
     }
 }
@@ -27,6 +34,10 @@ fn handle_create_package(matches: &ArgMatches) {
         paths: HashMap::new(),
         out_dir: out_dir.to_path_buf(),
         package_name: name.to_string(),
+        resources: matches
+            .get_many::<String>("resource")
+            .map(|resources| resources.map(PathBuf::from).collect())
+            .unwrap_or_default(),
     };
 
     for platform in ApplePlatform::ALL {
@@ -46,3 +57,78 @@ fn handle_parse_bridges(matches: &ArgMatches) {
 
     parse_bridges(source_files.iter().map(Path::new)).write_all_concatenated(output, crate_name);
 }
+
+/// Executes the `check-api` command
+fn handle_check_api(matches: &ArgMatches) {
+    let source_files: Vec<&String> = matches.get_many("source-file").unwrap().collect(); // required
+    let manifest = matches
+        .get_one::<String>("manifest")
+        .map(Path::new)
+        .unwrap(); // required
+
+    let current = parse_bridges(source_files.iter().map(Path::new)).swift_api_manifest();
+
+    if matches.get_flag("update") {
+        std::fs::write(manifest, current.to_string()).unwrap();
+        return;
+    }
+
+    let previous = match std::fs::read_to_string(manifest) {
+        Ok(previous) => SwiftApiManifest::parse(&previous),
+        Err(e) => {
+            eprintln!("Could not read API manifest {:?}: {}", manifest, e);
+            std::process::exit(1);
+        }
+    };
+
+    let breaking_changes = current.breaking_changes(&previous);
+    if breaking_changes.is_empty() {
+        return;
+    }
+
+    eprintln!("Breaking changes to the generated Swift API:");
+    for change in breaking_changes {
+        eprintln!("  {}", change);
+    }
+    std::process::exit(1);
+}
+
+/// Executes the `check-symbols` command
+fn handle_check_symbols(matches: &ArgMatches) {
+    let binary = matches.get_one::<String>("binary").unwrap(); // required
+
+    let symbols = match linked_bridge_symbols(binary) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            eprintln!("Could not read the symbols of {:?}: {}", binary, e);
+            std::process::exit(1);
+        }
+    };
+
+    let allow_list = match matches.get_one::<String>("allow-list") {
+        Some(allow_list) => std::fs::read_to_string(allow_list).unwrap(),
+        None => {
+            for symbol in symbols {
+                println!("{}", symbol);
+            }
+            return;
+        }
+    };
+    let allow_list: Vec<String> = allow_list
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .map(|line| line.to_string())
+        .collect();
+
+    let disallowed = disallowed_bridge_symbols(&symbols, &allow_list);
+    if disallowed.is_empty() {
+        return;
+    }
+
+    eprintln!("The binary contains bridge symbols that are not in the allow list:");
+    for symbol in disallowed {
+        eprintln!("  {}", symbol);
+    }
+    std::process::exit(1);
+}
@@ -1,6 +1,6 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{LitStr, Path, Token};
 
 /// The `...` in
 /// `#\[swift_bridge::bridge(...)\]`
@@ -15,6 +15,29 @@ pub enum SwiftBridgeModuleAttr {
     /// as `RustString`.
     /// `#\[swift_bridge::bridge(swift_bridge_path = swift_bridge)\]`
     SwiftBridgePath(Path),
+    /// Records a breadcrumb every time Swift calls one of the module's Rust functions.
+    /// `#\[swift_bridge::bridge(breadcrumbs)\]`
+    Breadcrumbs,
+    /// Propagates the context that was registered with `swift_bridge::context` across every call
+    /// between Swift and the module's functions.
+    /// `#\[swift_bridge::bridge(propagate_context)\]`
+    PropagateContext,
+    /// Mirrors a Rust module path in the generated Swift, so that the module's types are nested
+    /// inside of caseless enums such as `Engine.Audio.Mixer`.
+    /// `#\[swift_bridge::bridge(swift_namespace = "engine::audio")\]`
+    SwiftNamespace(LitStr),
+    /// Lets Rust tests replace the module's `extern "Swift"` functions with closures, so that they
+    /// can run without linking any Swift.
+    /// `#\[swift_bridge::bridge(swift_mocks)\]`
+    SwiftMocks,
+    /// Makes the module's part of the generated C header also usable from C++, optionally with an
+    /// `enum class` for every transparent enum that has no fields.
+    /// `#\[swift_bridge::bridge(cxx_header)\]`
+    /// `#\[swift_bridge::bridge(cxx_header(enum_class))\]`
+    CxxHeader {
+        /// Whether or not `enum_class` was specified.
+        enum_class: bool,
+    },
 }
 
 impl Parse for SwiftBridgeModuleAttrs {
@@ -34,12 +57,69 @@ impl Parse for SwiftBridgeModuleAttrs {
 impl Parse for SwiftBridgeModuleAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let key: Ident = input.parse()?;
-        let _equals = input.parse::<Token![=]>()?;
 
         let attr = match key.to_string().as_str() {
-            "swift_bridge_path" => SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?),
+            "swift_bridge_path" => {
+                input.parse::<Token![=]>()?;
+                SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?)
+            }
+            "breadcrumbs" => SwiftBridgeModuleAttr::Breadcrumbs,
+            "propagate_context" => SwiftBridgeModuleAttr::PropagateContext,
+            "swift_mocks" => SwiftBridgeModuleAttr::SwiftMocks,
+            "cxx_header" => {
+                let mut enum_class = false;
+
+                if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+
+                    let options =
+                        syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated(
+                            &content,
+                        )?;
+                    for option in options {
+                        match option.to_string().as_str() {
+                            "enum_class" => enum_class = true,
+                            _ => {
+                                return Err(syn::Error::new(
+                                    option.span(),
+                                    format!(r#"Unrecognized cxx_header option "{}"."#, option),
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                SwiftBridgeModuleAttr::CxxHeader { enum_class }
+            }
+            "swift_namespace" => {
+                input.parse::<Token![=]>()?;
+                let path: LitStr = input.parse()?;
+
+                let is_valid = path.value().split("::").all(|segment| {
+                    let mut chars = segment.chars();
+                    match chars.next() {
+                        Some(first) => {
+                            (first.is_ascii_alphabetic() || first == '_')
+                                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+                        }
+                        None => false,
+                    }
+                });
+                if !is_valid {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        "The Swift namespace must be a Rust module path such as \"engine::audio\".",
+                    ));
+                }
+
+                SwiftBridgeModuleAttr::SwiftNamespace(path)
+            }
             _ => {
-                return Err(syn::Error::new(input.span(), "Unknown attribute."));
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(r#"Unrecognized attribute "{}"."#, key),
+                ));
             }
         };
 
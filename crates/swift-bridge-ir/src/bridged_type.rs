@@ -7,8 +7,15 @@ use quote::ToTokens;
 use quote::{quote, quote_spanned};
 use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
 
-pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
+pub(crate) use self::bridged_opaque_type::{swift_instance_from_opaque, OpaqueForeignType};
 use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::bridgeable_arc::BridgeableArc;
+use crate::bridged_type::bridgeable_atomic_cell::BridgeableAtomicCell;
+use crate::bridged_type::bridgeable_boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::bridgeable_channel::BridgeableChannelEnd;
+use crate::bridged_type::bridgeable_decimal::BridgeableDecimal;
+use crate::bridged_type::bridgeable_hash_map::BridgeableHashMap;
+use crate::bridged_type::bridgeable_hash_set::BridgeableHashSet;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
 use crate::bridged_type::bridgeable_result::BuiltInResult;
 use crate::bridged_type::bridgeable_string::BridgedString;
@@ -21,8 +28,15 @@ pub(crate) use self::shared_enum::{DeriveAttrs, EnumVariant, SharedEnum};
 pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
 
 pub(crate) mod boxed_fn;
+pub(crate) mod bridgeable_arc;
+mod bridgeable_atomic_cell;
+mod bridgeable_boxed_fn;
+mod bridgeable_channel;
+mod bridgeable_decimal;
+mod bridgeable_hash_map;
+mod bridgeable_hash_set;
 mod bridgeable_pointer;
-mod bridgeable_result;
+pub(crate) mod bridgeable_result;
 pub mod bridgeable_str;
 pub mod bridgeable_string;
 pub mod bridged_opaque_type;
@@ -207,7 +221,11 @@ pub(crate) trait BridgeableType: Debug {
     ) -> TokenStream;
 
     /// Convert an Option<Self> FFI representation to the Rust representation.
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream;
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream;
 
     /// Convert an FFI expression to this type's Swift representation.
     fn convert_ffi_expression_to_swift_type(
@@ -337,6 +355,29 @@ pub(crate) fn bridgeable_type_from_token_stream_str(
     if BridgedString::can_parse_token_stream_str(tokens) {
         return BridgedString::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
     }
+    if BridgeableAtomicCell::can_parse_token_stream_str(tokens) {
+        return BridgeableAtomicCell::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+    if BridgeableDecimal::can_parse_token_stream_str(tokens) {
+        return BridgeableDecimal::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BridgeableChannelEnd::can_parse_token_stream_str(tokens) {
+        return BridgeableChannelEnd::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+    if BridgeableBoxedFn::can_parse_token_stream_str(tokens) {
+        return BridgeableBoxedFn::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BridgeableHashMap::can_parse_token_stream_str(tokens) {
+        return BridgeableHashMap::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BridgeableHashSet::can_parse_token_stream_str(tokens) {
+        return BridgeableHashSet::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BridgeableArc::can_parse_token_stream_str(tokens) {
+        return BridgeableArc::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
 
     OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _)
 }
@@ -413,10 +454,11 @@ pub(crate) enum TypePosition {
     SwiftCallsRustAsyncOnCompleteReturnTy,
 }
 
-/// &[T]
+/// &[T] or &mut [T]
 #[derive(Debug)]
 pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
+    pub mutable: bool,
 }
 
 /// Vec<T>
@@ -425,6 +467,17 @@ pub(crate) struct BuiltInVec {
     pub ty: Box<BridgedType>,
 }
 
+impl BuiltInRefSlice {
+    /// The Swift type that we use to represent the slice.
+    fn swift_buffer_pointer(&self) -> &'static str {
+        if self.mutable {
+            "UnsafeMutableBufferPointer"
+        } else {
+            "UnsafeBufferPointer"
+        }
+    }
+}
+
 impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
@@ -542,7 +595,7 @@ impl BridgeableType for BridgedType {
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
         }
     }
 
@@ -641,7 +694,11 @@ impl BridgeableType for BridgedType {
         todo!()
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -780,7 +837,10 @@ impl BridgedType {
                     }
                 }
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
+                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                        ty: Box::new(ty),
+                        mutable: ty_ref.mutability.is_some(),
+                    }))
                 }),
                 _ => None,
             },
@@ -916,7 +976,11 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
-                    quote! { &[#ty]}
+                    if ref_slice.mutable {
+                        quote! { &mut [#ty]}
+                    } else {
+                        quote! { &[#ty]}
+                    }
                 }
                 StdLibType::Str => quote! { &str },
                 StdLibType::Vec(v) => {
@@ -1165,7 +1229,8 @@ impl BridgedType {
                                 "__private__FfiSlice".to_string()
                             } else {
                                 format!(
-                                    "UnsafeBufferPointer<{}>",
+                                    "{}<{}>",
+                                    slice.swift_buffer_pointer(),
                                     slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
                                 )
                             }
@@ -1469,8 +1534,12 @@ impl BridgedType {
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
-                StdLibType::RefSlice(_reference) => {
-                    quote_spanned! {span=> #value.as_slice() }
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote_spanned! {span=> #value.as_mut_slice() }
+                    } else {
+                        quote_spanned! {span=> #value.as_slice() }
+                    }
                 }
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
@@ -1481,7 +1550,7 @@ impl BridgedType {
                     }
                 }
                 StdLibType::Option(bridged_option) => {
-                    bridged_option.convert_ffi_expression_to_rust_type(value)
+                    bridged_option.convert_ffi_expression_to_rust_type(value, swift_bridge_path)
                 }
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
@@ -1573,8 +1642,9 @@ impl BridgedType {
                 },
                 StdLibType::RefSlice(ty) => {
                     format!(
-                        "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
+                        "let slice = {value}; return {buffer_pointer}(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
                         value = expression,
+                        buffer_pointer = ty.swift_buffer_pointer(),
                         ty = ty.ty.to_swift_type(type_pos,types,swift_bridge_path)
                        )
                 }
@@ -1943,15 +2013,20 @@ impl BridgedType {
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
                 StdLibType::F32 => "F32".to_string(),
                 StdLibType::F64 => "F64".to_string(),
                 StdLibType::Tuple(ty) => ty.to_alpha_numeric_underscore_name(types),
+                StdLibType::Option(opt) => {
+                    format!("Option{}", opt.ty.to_alpha_numeric_underscore_name(types))
+                }
                 _ => todo!(),
             },
             BridgedType::Foreign(ty) => match ty {
@@ -1997,4 +2072,25 @@ mod tests {
             _ => panic!(),
         };
     }
+
+    /// Verify that we only bridge `HashMap`s whose keys and values have runtime support.
+    #[test]
+    fn hash_map_key_and_value_types() {
+        let types = TypeDeclarations::default();
+
+        assert!(BridgedType::new_with_str("HashMap < String , u32 >", &types).is_some());
+        assert!(BridgedType::new_with_str("HashMap < u8 , f64 >", &types).is_some());
+        assert!(BridgedType::new_with_str("HashMap < f64 , u8 >", &types).is_none());
+        assert!(BridgedType::new_with_str("HashMap < String , Vec < u8 > >", &types).is_none());
+    }
+
+    /// Verify that we only bridge `HashSet`s whose elements have runtime support.
+    #[test]
+    fn hash_set_element_types() {
+        let types = TypeDeclarations::default();
+
+        assert!(BridgedType::new_with_str("HashSet < String >", &types).is_some());
+        assert!(BridgedType::new_with_str("HashSet < i32 >", &types).is_some());
+        assert!(BridgedType::new_with_str("HashSet < f32 >", &types).is_none());
+    }
 }
@@ -0,0 +1,322 @@
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclaration;
+use crate::TypeDeclarations;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// `Arc<T>` from `std::sync`, where `T` is an opaque Rust type.
+///
+/// Swift sees an `Arc<SomeType>` as a `SomeTypeArc`, which holds one strong reference to the
+/// Rust object and can call its `&self` methods.
+#[derive(Debug)]
+pub(crate) struct BridgeableArc {
+    pub ty: Ident,
+    /// The name of `T` on the Swift side.
+    pub swift_name: String,
+}
+
+impl BridgeableArc {
+    fn swift_class(&self) -> String {
+        format!("{}Arc", self.swift_name)
+    }
+}
+
+impl BridgeableType for BridgeableArc {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+
+        quote! { std::sync::Arc<super::#ty> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_class(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! { *const super::#ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { std::sync::Arc::into_raw( #expression ) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        // Swift keeps its own strong reference, and Rust takes another one when it receives the
+        // pointer.
+        format!("{}.ptr", expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            {
+                let ptr = #expression;
+                unsafe {
+                    std::sync::Arc::increment_strong_count(ptr);
+                    std::sync::Arc::from_raw(ptr)
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        let class = self.swift_class();
+
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("{}(ptr: {})", class, expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", class, expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! {
+            unsafe { std::sync::Arc::from_raw(#result.ok_or_err as *const super::#ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! {
+            unsafe { std::sync::Arc::from_raw(#result.ok_or_err as *const super::#ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<Arc<T>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens
+            .strip_prefix("Arc")
+            .map(|rest| rest.trim_start().starts_with('<'))
+            .unwrap_or(false)
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty = tokens
+            .strip_prefix("Arc")?
+            .trim()
+            .strip_prefix('<')?
+            .strip_suffix('>')?
+            .trim();
+
+        // Swift can only call `&self` methods through a shared reference, which the `SomeTypeRef`
+        // class of an opaque Rust type that isn't `Copy` or a Swift struct provides.
+        // Trait objects are already boxed, so they aren't supported.
+        match types.get(ty)? {
+            TypeDeclaration::Opaque(opaque)
+                if opaque.host_lang.is_rust()
+                    && opaque.attributes.copy.is_none()
+                    && !opaque.attributes.swift_repr_struct
+                    && !opaque.attributes.trait_object
+                    && opaque.generics.is_empty() =>
+            {
+                Some(BridgeableArc {
+                    ty: opaque.ty.clone(),
+                    swift_name: opaque.swift_name_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Arc_{}", self.ty)
+    }
+}
@@ -0,0 +1,564 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::str::FromStr;
+use syn::{GenericArgument, Path, PathArguments, ReturnType, Type, TypeParamBound};
+
+/// `Box<dyn Fn(A, B) -> C>` in the arguments of a function.
+///
+/// When Swift passes a closure to a Rust function it passes a struct that holds a
+/// `__private__FfiClosure`, which owns a retained pointer to the Swift closure, along with a
+/// C function pointer that calls it.
+/// Rust wraps it in a `Box<dyn Fn(A, B) -> C>` that releases the Swift closure when dropped.
+///
+/// When Rust passes a boxed closure to a Swift function it passes the same struct, holding the
+/// boxed closure along with a trampoline that calls it.
+/// Swift wraps it in a closure that frees the Rust closure when it gets deinitialized.
+///
+/// The struct is generated for each closure signature so that the function pointer that calls the
+/// closure is always stored and called as a function pointer of its exact type. Casting it to and
+/// from a data pointer would strip or break its signature on arm64e, where function pointers are
+/// signed using pointer authentication.
+#[derive(Debug)]
+pub(crate) struct BridgeableBoxedFn {
+    /// The closure's parameters.
+    pub params: Vec<BridgedType>,
+    /// The closure's return type.
+    pub ret: Box<BridgedType>,
+}
+
+impl BridgeableBoxedFn {
+    /// (UInt32, SomeType) -> ()
+    fn swift_closure_type(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|p| {
+                p.to_swift_type(
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                )
+            })
+            .collect();
+        let ret = self.ret.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+        format!("({}) -> {}", params.join(", "), ret)
+    }
+
+    /// The parameters and return type, joined into a name.
+    ///
+    /// Box<dyn Fn(u32, SomeType)> becomes ["u32", "SomeType", "Void"]
+    fn signature_names(&self, types: &TypeDeclarations) -> Vec<String> {
+        self.params
+            .iter()
+            .chain(std::iter::once(self.ret.as_ref()))
+            .map(|ty| ty.to_alpha_numeric_underscore_name(types))
+            .collect()
+    }
+
+    /// __swift_bridge__FnClosure_u32_SomeType_Void
+    fn rust_ffi_struct_name(&self, types: &TypeDeclarations) -> Ident {
+        format_ident!(
+            "{}FnClosure_{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.signature_names(types).join("_")
+        )
+    }
+
+    /// __swift_bridge__$FnClosure$u32$SomeType$Void
+    fn c_ffi_struct_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$FnClosure${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.signature_names(types).join("$")
+        )
+    }
+}
+
+impl BridgeableType for BridgeableBoxedFn {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let struct_name = self.rust_ffi_struct_name(types);
+        let params: Vec<TokenStream> = self
+            .params
+            .iter()
+            .map(|p| p.to_ffi_compatible_rust_type(swift_bridge_path, types))
+            .collect();
+        let ret = self
+            .ret
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+        Some(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #struct_name {
+                closure: #swift_bridge_path::boxed_fn_support::FfiClosure,
+                call: extern "C" fn(*mut std::ffi::c_void, #(#params),*) -> #ret,
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let struct_name = self.c_ffi_struct_name(types);
+        let mut params = vec!["void*".to_string()];
+        params.extend(self.params.iter().map(|p| p.to_c(types)));
+        let ret = self.ret.to_c(types);
+
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "typedef struct {struct_name} {{ struct __private__FfiClosure closure; {ret} (*call)({params}); }} {struct_name};",
+                params = params.join(", ")
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let params: Vec<TokenStream> = self
+            .params
+            .iter()
+            .map(|p| p.to_rust_type_path(types))
+            .collect();
+        let ret = self.ret.to_rust_type_path(types);
+
+        quote! { Box<dyn Fn(#(#params),*) -> #ret> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(HostLang::Rust, _) => {
+                format!(
+                    "@escaping {}",
+                    self.swift_closure_type(types, swift_bridge_path)
+                )
+            }
+            TypePosition::FnArg(HostLang::Swift, _) => self.c_ffi_struct_name(types),
+            _ => todo!("Box<dyn Fn> is only supported as a function argument"),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.c_ffi_struct_name(types))
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let struct_name = self.rust_ffi_struct_name(types);
+        quote! { #struct_name }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let mut ffi_params = vec![];
+        let mut call_args = vec![];
+        for (idx, param) in self.params.iter().enumerate() {
+            let arg = Ident::new(&format!("arg{}", idx), span);
+            let ffi_ty = param.to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+            ffi_params.push(quote! { #arg: #ffi_ty });
+            call_args.push(param.convert_ffi_expression_to_rust_type(
+                &arg.to_token_stream(),
+                span,
+                swift_bridge_path,
+                types,
+            ));
+        }
+
+        let ffi_ret = self
+            .ret
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let ret = self.ret.convert_rust_expression_to_ffi_type(
+            &quote! { closure(#(#call_args),*) },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        let fn_ty = self.to_rust_type_path(types);
+        let struct_name = self.rust_ffi_struct_name(types);
+
+        quote_spanned! {span=>
+            {
+                extern "C" fn call(context: *mut std::ffi::c_void, #(#ffi_params),*) -> #ffi_ret {
+                    let closure = unsafe { &*(context as *const #fn_ty) };
+                    #ret
+                }
+                extern "C" fn free(context: *mut std::ffi::c_void) {
+                    drop(unsafe { Box::from_raw(context as *mut #fn_ty) });
+                }
+
+                #struct_name {
+                    closure: #swift_bridge_path::boxed_fn_support::FfiClosure::new(
+                        Box::into_raw(Box::new(#expression)) as *mut std::ffi::c_void,
+                        free,
+                    ),
+                    call,
+                }
+            }
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        // The Swift representation of a type never depends on the path to the `swift_bridge`
+        // crate.
+        let swift_bridge_path: Path = syn::parse_quote!(swift_bridge);
+        let swift_bridge_path = &swift_bridge_path;
+
+        let mut thunk_params = vec!["context".to_string()];
+        let mut call_args = vec![];
+        for (idx, param) in self.params.iter().enumerate() {
+            let arg = format!("arg{}", idx);
+            call_args.push(param.convert_ffi_expression_to_swift_type(
+                &arg,
+                TypePosition::FnArg(HostLang::Swift, idx),
+                types,
+                swift_bridge_path,
+            ));
+            thunk_params.push(arg);
+        }
+
+        let call = format!(
+            "__private__SwiftClosureBox<{closure_ty}>.fromContext(context)({args})",
+            closure_ty = self.swift_closure_type(types, swift_bridge_path),
+            args = call_args.join(", ")
+        );
+        let call = if self.ret.is_null() {
+            call
+        } else {
+            format!(
+                "let ret = {call}; return {ret}",
+                ret = self.ret.convert_swift_expression_to_ffi_type(
+                    "ret",
+                    types,
+                    TypePosition::FnReturn(HostLang::Swift),
+                )
+            )
+        };
+
+        format!(
+            "{struct_name}(closure: __private__SwiftClosureBox({expression}).toFfiClosure(), call: {{ {params} in {call} }})",
+            struct_name = self.c_ffi_struct_name(types),
+            params = thunk_params.join(", "),
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let mut closure_params = vec![];
+        let mut ffi_args = vec![];
+        for (idx, param) in self.params.iter().enumerate() {
+            let arg = Ident::new(&format!("arg{}", idx), span);
+            let rust_ty = param.to_rust_type_path(types);
+
+            closure_params.push(quote! { #arg: #rust_ty });
+            ffi_args.push(param.convert_rust_expression_to_ffi_type(
+                &arg.to_token_stream(),
+                swift_bridge_path,
+                types,
+                span,
+            ));
+        }
+
+        let ret = self.ret.convert_ffi_expression_to_rust_type(
+            &quote! { call(closure.context(), #(#ffi_args),*) },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        let fn_ty = self.to_rust_type_path(types);
+
+        quote_spanned! {span=>
+            {
+                let ffi_closure = #expression;
+                let call = ffi_closure.call;
+                let closure = ffi_closure.closure;
+                Box::new(move |#(#closure_params),*| #ret) as #fn_ty
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let mut params = vec![];
+        let mut call_args = vec!["closure.context".to_string()];
+        for (idx, param) in self.params.iter().enumerate() {
+            let arg = format!("arg{}", idx);
+            let ty = param.to_swift_type(
+                TypePosition::FnArg(HostLang::Rust, idx),
+                types,
+                swift_bridge_path,
+            );
+
+            call_args.push(param.convert_swift_expression_to_ffi_type(
+                &arg,
+                types,
+                TypePosition::FnArg(HostLang::Rust, idx),
+            ));
+            params.push(format!("{arg}: {ty}"));
+        }
+
+        let ret_ty = self.ret.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+        let call = self.ret.convert_ffi_expression_to_swift_type(
+            &format!("ffi.call({})", call_args.join(", ")),
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+        format!(
+            "{{ let ffi = {expression}; let closure = __private__RustFnClosure(ffi.closure); return {{ ({params}) -> {ret_ty} in {call} }} }}()",
+            params = params.join(", "),
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<Box<dyn Fn>, E> is not yet supported")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<T, Box<dyn Fn>> is not yet supported")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<Box<dyn Fn>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < dyn Fn (") || tokens.starts_with("Box < dyn Fn(")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+
+        let boxed = match ty {
+            Type::Path(path) => path.path.segments.into_iter().last()?,
+            _ => return None,
+        };
+        let inner = match boxed.arguments {
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.into_iter().next()? {
+                    GenericArgument::Type(Type::TraitObject(inner)) => inner,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        // Only plain `dyn Fn(..)` is supported. The Swift closure can't be sent across threads
+        // since it isn't `Sendable`.
+        if inner.bounds.len() != 1 {
+            return None;
+        }
+        let func = match inner.bounds.into_iter().next()? {
+            TypeParamBound::Trait(func) => func.path.segments.into_iter().last()?,
+            _ => return None,
+        };
+        if func.ident != "Fn" {
+            return None;
+        }
+        let signature = match func.arguments {
+            PathArguments::Parenthesized(signature) => signature,
+            _ => return None,
+        };
+
+        let mut params = Vec::with_capacity(signature.inputs.len());
+        for param in signature.inputs.iter() {
+            params.push(BridgedType::new_with_type(param, types)?);
+        }
+
+        let ret = match &signature.output {
+            ReturnType::Default => BridgedType::StdLib(StdLibType::Null),
+            ReturnType::Type(_, ret) => BridgedType::new_with_type(ret, types)?,
+        };
+
+        Some(BridgeableBoxedFn {
+            params,
+            ret: Box::new(ret),
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|p| p.to_alpha_numeric_underscore_name(types))
+            .collect();
+
+        format!(
+            "Fn_{}_{}",
+            params.join("_"),
+            self.ret.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
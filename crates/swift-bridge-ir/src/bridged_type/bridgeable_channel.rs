@@ -0,0 +1,345 @@
+use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// `Sender<T>` or `Receiver<T>` from `std::sync::mpsc`, where `T` is a primitive such as `u32`.
+#[derive(Debug)]
+pub(crate) struct BridgeableChannelEnd {
+    pub kind: ChannelEndKind,
+    pub ty: Box<BridgedType>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ChannelEndKind {
+    Sender,
+    Receiver,
+}
+
+impl ChannelEndKind {
+    fn rust_name(&self) -> &'static str {
+        match self {
+            ChannelEndKind::Sender => "Sender",
+            ChannelEndKind::Receiver => "Receiver",
+        }
+    }
+
+    fn swift_class_name(&self) -> &'static str {
+        match self {
+            ChannelEndKind::Sender => "RustSender",
+            ChannelEndKind::Receiver => "RustReceiver",
+        }
+    }
+}
+
+impl BridgeableChannelEnd {
+    fn swift_class(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        format!(
+            "{}<{}>",
+            self.kind.swift_class_name(),
+            self.ty.to_swift_type(
+                TypePosition::FnReturn(crate::parse::HostLang::Rust),
+                types,
+                swift_bridge_path
+            )
+        )
+    }
+}
+
+impl BridgeableType for BridgeableChannelEnd {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+
+        match self.kind {
+            ChannelEndKind::Sender => quote! { std::sync::mpsc::Sender<#ty> },
+            ChannelEndKind::Receiver => quote! { std::sync::mpsc::Receiver<#ty> },
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class(types, swift_bridge_path)
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_class(types, swift_bridge_path),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(Box::new( #expression )) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("{}(ptr: {})", self.kind.swift_class_name(), expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", self.kind.swift_class_name(), expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { * Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { * Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<Sender<T>> and Option<Receiver<T>> are not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Sender < ") || tokens.starts_with("Receiver < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let (kind, inner) = if let Some(inner) = tokens.strip_prefix("Sender < ") {
+            (ChannelEndKind::Sender, inner)
+        } else if let Some(inner) = tokens.strip_prefix("Receiver < ") {
+            (ChannelEndKind::Receiver, inner)
+        } else {
+            return None;
+        };
+        let inner = inner.strip_suffix(" >")?;
+
+        let ty = BridgedType::new_with_str(inner, types)?;
+
+        // Only channels of primitives have runtime support in the `swift-bridge` crate.
+        match &ty {
+            BridgedType::StdLib(StdLibType::Null) => return None,
+            BridgedType::StdLib(stdlib_ty) => {
+                BuiltInPrimitive::new_with_stdlib_type(stdlib_ty)?;
+            }
+            _ => return None,
+        };
+
+        Some(BridgeableChannelEnd {
+            kind,
+            ty: Box::new(ty),
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}_{}",
+            self.kind.rust_name(),
+            self.ty.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
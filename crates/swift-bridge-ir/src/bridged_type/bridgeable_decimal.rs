@@ -0,0 +1,256 @@
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// `rust_decimal::Decimal`, which Swift sees as Foundation's `Decimal`.
+///
+/// It crosses the FFI boundary by value as a `swift_bridge::decimal::FfiDecimal`, which holds the
+/// decimal's mantissa, exponent and sign.
+#[derive(Debug)]
+pub(crate) struct BridgeableDecimal;
+
+impl BridgeableType for BridgeableDecimal {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { swift_bridge::decimal::Decimal }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "Decimal".to_string()
+                } else {
+                    "__private__FfiDecimal".to_string()
+                }
+            }
+            TypePosition::SharedStructField => "Decimal".to_string(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "__private__FfiDecimal".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiDecimal".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::decimal::FfiDecimal }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::decimal::FfiDecimal::from_decimal( #expression ) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}.intoFfiRepr()", expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression.into_decimal()
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        format!("{}.intoSwiftRepr()", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<Decimal, E> is not yet supported")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<T, Decimal> is not yet supported")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<Decimal> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "Decimal" || tokens == "rust_decimal :: Decimal"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(_tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(BridgeableDecimal)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "Decimal".to_string()
+    }
+}
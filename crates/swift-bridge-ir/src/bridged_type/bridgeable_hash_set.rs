@@ -0,0 +1,305 @@
+use crate::bridged_type::bridgeable_hash_map::MapElement;
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// `HashSet<T>` from `std::collections`, where `T` is a `String` or an integer such as `u32`.
+#[derive(Debug)]
+pub(crate) struct BridgeableHashSet {
+    // Set elements have the same requirements as map keys.
+    pub elem: MapElement,
+}
+
+impl BridgeableHashSet {
+    fn swift_class(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        format!(
+            "RustSet<{}>",
+            self.elem.to_swift_type(types, swift_bridge_path)
+        )
+    }
+}
+
+impl BridgeableType for BridgeableHashSet {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&super::bridged_option::BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let elem = self.elem.to_rust_type_path(types);
+
+        quote! { std::collections::HashSet<#elem> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class(types, swift_bridge_path)
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_class(types, swift_bridge_path),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(Box::new( #expression )) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    // The class is generic over the element type, and each element type has its own
+    // initializer, so we spell out the type instead of letting Swift infer it.
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let class = self.swift_class(types, swift_bridge_path);
+
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("{}(ptr: {})", class, expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", class, expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { * Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { * Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Option<HashSet<T>> is not yet supported")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens
+            .strip_prefix("HashSet")
+            .map(|rest| rest.trim_start().starts_with('<'))
+            .unwrap_or(false)
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let elem = tokens
+            .strip_prefix("HashSet")?
+            .trim()
+            .strip_prefix('<')?
+            .strip_suffix('>')?;
+
+        Some(BridgeableHashSet {
+            elem: MapElement::parse(elem.trim(), types, true)?,
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashSet_{}",
+            self.elem.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
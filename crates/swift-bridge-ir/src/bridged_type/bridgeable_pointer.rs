@@ -177,7 +177,11 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -1,8 +1,8 @@
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition};
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
-use syn::Path;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
 
 /// Rust: Result<T, E>
 /// Swift: RustResult<T, E>
@@ -306,6 +306,48 @@ impl BuiltInResult {
         )
     }
 
+    /// Generate the body of a Swift throwing initializer, i.e. `init() throws`, for a function
+    /// that returns a `Result<Self, E>`.
+    ///
+    /// The `Ok` pointer gets passed to `self.init(ptr:)` and the `Err` value gets thrown.
+    pub fn convert_ffi_value_to_swift_throwing_initializer(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.is_custom_result_type() {
+            let err = self.err_ty.convert_ffi_expression_to_swift_type(
+                "val.payload.err",
+                type_pos,
+                types,
+                swift_bridge_path,
+            );
+
+            return format!(
+                "let val = {expression}; switch val.tag {{ case {c_ok_name}: self.init(ptr: val.payload.ok) case {c_err_name}: throw {err} default: fatalError() }}",
+                expression = expression,
+                c_ok_name = self.c_ok_tag_name(types),
+                c_err_name = self.c_err_tag_name(types),
+                err = err
+            );
+        }
+
+        let err = self.err_ty.convert_ffi_expression_to_swift_type(
+            "val.ok_or_err!",
+            type_pos,
+            types,
+            swift_bridge_path,
+        );
+
+        format!(
+            "let val = {expression}; if val.is_ok {{ self.init(ptr: val.ok_or_err!) }} else {{ throw {err} }}",
+            expression = expression,
+            err = err
+        )
+    }
+
     pub fn convert_swift_expression_to_ffi_compatible(
         &self,
         expression: &str,
@@ -535,18 +577,11 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
 impl BuiltInResult {
     /// Go from `Result < A , B >` to a `BuiltInResult`.
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
-        // A , B >
-        let trimmed = string.trim_start_matches("Result < ");
-        // A , B
-        let trimmed = trimmed.trim_end_matches(" >");
-
-        // [A, B]
-        let ok_and_err = trimmed.rsplit_once(",")?;
-        let ok = ok_and_err.0.trim();
-        let err = ok_and_err.1.trim();
+        let ty: Type = syn::parse_str(string).ok()?;
+        let (ok, err) = result_ok_and_err_types(&ty)?;
 
-        let ok = BridgedType::new_with_str(ok, types)?;
-        let err = BridgedType::new_with_str(err, types)?;
+        let ok = BridgedType::new_with_str(&ok.to_token_stream().to_string(), types)?;
+        let err = BridgedType::new_with_str(&err.to_token_stream().to_string(), types)?;
 
         Some(BuiltInResult {
             ok_ty: Box::new(ok),
@@ -555,6 +590,34 @@ impl BuiltInResult {
     }
 }
 
+/// The `A` and `B` of a `Result<A, B>`.
+///
+/// Either type can contain commas, such as `Result<(u8, u16), SomeGeneric<u32, u64>>`, so we
+/// look at the parsed generic arguments instead of splitting the tokens.
+pub(crate) fn result_ok_and_err_types(ty: &Type) -> Option<(&Type, &Type)> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return None,
+    };
+    let mut types = args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+
+    match (types.next(), types.next(), types.next()) {
+        (Some(ok), Some(err), None) => Some((ok, err)),
+        _ => None,
+    }
+}
+
 impl BuiltInResult {
     fn custom_c_struct_name(&self, types: &TypeDeclarations) -> String {
         let ok = &self.ok_ty;
@@ -128,7 +128,7 @@ impl BridgeableType for BridgedString {
                 todo!()
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                todo!()
+                "UnsafeMutableRawPointer?".to_string()
             }
         }
     }
@@ -183,7 +183,7 @@ impl BridgeableType for BridgedString {
         type_pos: TypePosition,
     ) -> String {
         match type_pos {
-            TypePosition::FnArg(_func_host_lang, _) => {
+            TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
                     "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
                     expression = expression
@@ -199,9 +199,6 @@ impl BridgeableType for BridgedString {
                     )
                 }
             }
-            TypePosition::SharedStructField => {
-                todo!("Option<String> fields in structs are not yet supported.")
-            }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 unimplemented!()
             }
@@ -220,7 +217,11 @@ impl BridgeableType for BridgedString {
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         quote! {
             {
                 let val = #expression;
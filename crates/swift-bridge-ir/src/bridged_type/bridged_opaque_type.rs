@@ -16,6 +16,15 @@ pub(crate) struct OpaqueForeignType {
     pub reference: bool,
     pub mutable: bool,
     pub has_swift_bridge_copy_annotation: bool,
+    /// `#[swift_bridge(swift_repr = "struct")]`
+    pub swift_repr_struct: bool,
+    /// `#[swift_bridge(trait_object)]`
+    pub trait_object: bool,
+    /// `#[swift_bridge(hardened)]`
+    pub hardened: bool,
+    /// The name of the type on the Swift side.
+    /// `#[swift_bridge(swift_name = "...")]`, or else the Rust name.
+    pub swift_name: String,
     pub generics: OpaqueRustTypeGenerics,
 }
 
@@ -63,8 +72,13 @@ impl BridgeableType for OpaqueForeignType {
             .angle_bracketed_concrete_generics_tokens(types);
 
         if self.host_lang.is_rust() {
+            let rust_ty = self.rust_type_tokens();
             quote! {
-                super:: #ty_name #generics
+                #rust_ty #generics
+            }
+        } else if self.trait_object {
+            quote! {
+                Box<dyn super::#ty_name>
             }
         } else {
             quote! {
@@ -83,9 +97,9 @@ impl BridgeableType for OpaqueForeignType {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
                     if func_host_lang.is_rust() {
-                        let mut class_name = self.ty.to_string();
+                        let mut class_name = self.swift_name.clone();
 
-                        if !self.has_swift_bridge_copy_annotation {
+                        if !self.has_swift_bridge_copy_annotation && !self.swift_repr_struct {
                             if self.reference {
                                 class_name += "Ref";
                             }
@@ -109,7 +123,7 @@ impl BridgeableType for OpaqueForeignType {
                     }
                 }
                 TypePosition::SharedStructField => {
-                    let class_name = self.ty.to_string();
+                    let class_name = self.swift_name.clone();
                     if !self.has_swift_bridge_copy_annotation {
                         if self.mutable || self.reference {
                             todo!();
@@ -134,7 +148,7 @@ impl BridgeableType for OpaqueForeignType {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
                     if func_host_lang.is_rust() {
-                        self.ty.to_string()
+                        self.swift_name.clone()
                     } else {
                         "UnsafeMutableRawPointer".to_string()
                     }
@@ -189,9 +203,11 @@ impl BridgeableType for OpaqueForeignType {
                         quote! { *const }
                     };
 
-                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name }
+                    let rust_ty = self.rust_type_tokens();
+                    quote_spanned! {ty_name.span()=> #ptr #rust_ty }
                 } else {
-                    quote! { *mut super::#ty_name #generics }
+                    let rust_ty = self.rust_type_tokens();
+                    quote! { *mut #rust_ty #generics }
                 }
             } else {
                 quote! { #ty_name }
@@ -204,20 +220,19 @@ impl BridgeableType for OpaqueForeignType {
         _swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let type_name = &self.ty;
-
         if self.has_swift_bridge_copy_annotation {
             let option_ty = self.option_copy_rust_repr_type();
             quote! { #option_ty }
         } else {
+            let rust_ty = self.rust_type_tokens();
             let generics = self
                 .generics
                 .angle_bracketed_concrete_generics_tokens(types);
 
             if self.reference {
-                quote! { *const super::#type_name #generics }
+                quote! { *const #rust_ty #generics }
             } else {
-                quote! { *mut super::#type_name #generics }
+                quote! { *mut #rust_ty #generics }
             }
         }
     }
@@ -242,11 +257,11 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_rust_expression_to_ffi_type(
         &self,
         expression: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         types: &TypeDeclarations,
         span: Span,
     ) -> TokenStream {
-        let ty_name = &self.ty;
+        let rust_ty = self.rust_type_tokens();
 
         if self.host_lang.is_rust() {
             if self.has_swift_bridge_copy_annotation {
@@ -262,7 +277,15 @@ impl BridgeableType for OpaqueForeignType {
                 };
 
                 quote! {
-                    #expression as #ptr super::#ty_name
+                    #expression as #ptr #rust_ty
+                }
+            } else if self.hardened {
+                let handle_table = self.handle_table_tokens(swift_bridge_path);
+                quote_spanned! {span=>
+                    #handle_table.insert({
+                        let val: #rust_ty = #expression;
+                        val
+                    })
                 }
             } else {
                 let generics = self
@@ -270,9 +293,9 @@ impl BridgeableType for OpaqueForeignType {
                     .angle_bracketed_concrete_generics_tokens(types);
                 quote_spanned! {span=>
                     Box::into_raw(Box::new({
-                        let val: super::#ty_name #generics = #expression;
+                        let val: #rust_ty #generics = #expression;
                         val
-                    })) as *mut super::#ty_name #generics
+                    })) as *mut #rust_ty #generics
                 }
             }
         } else {
@@ -285,7 +308,7 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_option_rust_expression_to_ffi_type(
         &self,
         expression: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
     ) -> TokenStream {
         if self.has_swift_bridge_copy_annotation {
             let copy_repr = self.copy_rust_repr_type();
@@ -305,17 +328,27 @@ impl BridgeableType for OpaqueForeignType {
                 }
             }
         } else if self.reference {
-            let ty = &self.ty;
+            let rust_ty = self.rust_type_tokens();
 
             quote! {
                 if let Some(val) = #expression {
-                    val as *const super::#ty
+                    val as *const #rust_ty
                 } else {
                     std::ptr::null()
                 }
             }
         } else {
             match self.host_lang {
+                HostLang::Rust if self.hardened => {
+                    let handle_table = self.handle_table_tokens(swift_bridge_path);
+                    quote! {
+                        if let Some(val) = #expression {
+                            #handle_table.insert(val)
+                        } else {
+                            std::ptr::null_mut()
+                        }
+                    }
+                }
                 HostLang::Rust => {
                     quote! {
                         if let Some(val) = #expression {
@@ -356,13 +389,15 @@ impl BridgeableType for OpaqueForeignType {
         _types: &TypeDeclarations,
         type_pos: TypePosition,
     ) -> String {
-        let ty_name = &self.ty;
+        let ty_name = &self.swift_name;
 
         if self.host_lang.is_rust() {
             if self.has_swift_bridge_copy_annotation {
                 format!("{}.intoFfiRepr()", expression)
             } else if self.reference {
                 format!("{}.ptr", expression)
+            } else if self.swift_repr_struct {
+                format!("{}.cloneForRust()", expression)
             } else {
                 match type_pos {
                     TypePosition::FnArg(func_host_lang, _)
@@ -402,18 +437,12 @@ impl BridgeableType for OpaqueForeignType {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) => {
                     if func_host_lang.is_rust() {
-                        format!("Unmanaged.passRetained({}).toOpaque()", expression)
+                        self.swift_pass_retained(expression)
                     } else {
-                        format!(
-                            "Unmanaged<{type_name}>.fromOpaque({value}).takeRetainedValue()",
-                            type_name = ty_name,
-                            value = expression
-                        )
+                        swift_instance_from_opaque(ty_name, self.trait_object, expression, true)
                     }
                 }
-                TypePosition::FnReturn(_func_host_lang) => {
-                    format!("Unmanaged.passRetained({}).toOpaque()", expression)
-                }
+                TypePosition::FnReturn(_func_host_lang) => self.swift_pass_retained(expression),
                 TypePosition::SharedStructField => {
                     todo!("Opaque types in shared struct fields are not yet supported")
                 }
@@ -444,11 +473,15 @@ impl BridgeableType for OpaqueForeignType {
             )
         } else {
             match self.host_lang {
+                HostLang::Rust if self.swift_repr_struct => {
+                    format!("{{ if let val = {expression} {{ return val.cloneForRust() }} else {{ return nil }} }}()", expression = expression,)
+                }
                 HostLang::Rust => {
                     format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression,)
                 }
                 HostLang::Swift => {
-                    format!("{{ if let val = {expression} {{ return Unmanaged.passRetained(val).toOpaque() }} else {{ return nil }} }}()")
+                    let val = self.swift_pass_retained("val");
+                    format!("{{ if let val = {expression} {{ return {val} }} else {{ return nil }} }}()")
                 }
             }
         }
@@ -458,7 +491,7 @@ impl BridgeableType for OpaqueForeignType {
         &self,
         expression: &TokenStream,
         _span: Span,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         if self.host_lang.is_rust() {
@@ -478,8 +511,20 @@ impl BridgeableType for OpaqueForeignType {
                     quote! {}
                 };
 
+                if self.hardened {
+                    let handle_table = self.handle_table_tokens(swift_bridge_path);
+                    quote! {
+                        & #maybe_mut * #handle_table.resolve(#expression)
+                    }
+                } else {
+                    quote! {
+                        unsafe {  & #maybe_mut * #expression }
+                    }
+                }
+            } else if self.hardened {
+                let handle_table = self.handle_table_tokens(swift_bridge_path);
                 quote! {
-                    unsafe {  & #maybe_mut * #expression }
+                    #handle_table.remove(#expression)
                 }
             } else {
                 quote! {
@@ -490,14 +535,18 @@ impl BridgeableType for OpaqueForeignType {
             if self.reference {
                 todo!("Handle referenced self Swift types")
             } else {
-                quote! {
-                    #expression
-                }
+                self.maybe_box_swift_trait_object(quote! { #expression })
             }
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let handle_table = self.handle_table_tokens(swift_bridge_path);
+
         if self.has_swift_bridge_copy_annotation {
             quote! {
                 if #expression.is_some {
@@ -506,6 +555,15 @@ impl BridgeableType for OpaqueForeignType {
                     None
                 }
             }
+        } else if self.reference && self.hardened {
+            quote! {
+                if #expression.is_null() {
+                    None
+                } else {
+                    Some(#handle_table.resolve(#expression))
+                }
+                .as_deref()
+            }
         } else if self.reference {
             quote! {
                 if #expression.is_null() {
@@ -516,6 +574,15 @@ impl BridgeableType for OpaqueForeignType {
             }
         } else {
             match self.host_lang {
+                HostLang::Rust if self.hardened => {
+                    quote! {
+                        if #expression.is_null() {
+                            None
+                        } else {
+                            Some(#handle_table.remove(#expression))
+                        }
+                    }
+                }
                 HostLang::Rust => {
                     quote! {
                         if #expression.is_null() {
@@ -527,13 +594,16 @@ impl BridgeableType for OpaqueForeignType {
                 }
                 HostLang::Swift => {
                     let ty = &self.ty;
+                    let val = self.maybe_box_swift_trait_object(quote! {
+                        #ty(val as *mut std::ffi::c_void)
+                    });
                     quote! {
                         {
                             let val = #expression;
                             if val.is_null() {
                                 None
                             } else {
-                                Some(#ty(val as *mut std::ffi::c_void))
+                                Some(#val)
                             }
                         }
                     }
@@ -549,7 +619,20 @@ impl BridgeableType for OpaqueForeignType {
         _types: &TypeDeclarations,
         _swift_bridge_path: &Path,
     ) -> String {
-        let mut ty_name = self.ty.to_string();
+        let mut ty_name = self.swift_name.clone();
+
+        if self.host_lang.is_rust() && self.swift_repr_struct {
+            // Swift gets its own copy of borrowed values so that it never holds onto a reference.
+            return if self.reference {
+                format!(
+                    "{ty_name}(ptr: {clone}({value}))",
+                    clone = self.clone_ffi_name(),
+                    value = expression
+                )
+            } else {
+                format!("{ty_name}(ptr: {value})", value = expression)
+            };
+        }
 
         if self.reference {
             ty_name += "Ref";
@@ -580,11 +663,7 @@ impl BridgeableType for OpaqueForeignType {
                 }
             }
         } else {
-            format!(
-                "Unmanaged<{ty_name}>.fromOpaque({value}).takeRetainedValue()",
-                ty_name = ty_name,
-                value = expression
-            )
+            swift_instance_from_opaque(&ty_name, self.trait_object, expression, true)
         }
     }
 
@@ -599,6 +678,19 @@ impl BridgeableType for OpaqueForeignType {
         } else {
             let type_name = self.swift_name();
             match self.host_lang {
+                HostLang::Rust if self.swift_repr_struct => {
+                    let value = if self.reference {
+                        format!("{}(val!)", self.clone_ffi_name())
+                    } else {
+                        "val!".to_string()
+                    };
+                    format!(
+                        "{{ let val = {expression}; if val != nil {{ return {type_name}(ptr: {value}) }} else {{ return nil }} }}()",
+                        expression = expression,
+                        type_name = self.swift_name,
+                        value = value
+                    )
+                }
                 HostLang::Rust => {
                     format!(
                         "{{ let val = {expression}; if val != nil {{ return {type_name}(ptr: val!) }} else {{ return nil }} }}()",
@@ -607,8 +699,10 @@ impl BridgeableType for OpaqueForeignType {
                     )
                 }
                 HostLang::Swift => {
+                    let val =
+                        swift_instance_from_opaque(&type_name, self.trait_object, "val", true);
                     format!(
-                        "{{ if let val = {expression} {{ return Unmanaged<{type_name}>.fromOpaque(val).takeRetainedValue() }} else {{ return nil }} }}()"
+                        "{{ if let val = {expression} {{ return {val} }} else {{ return nil }} }}()"
                     )
                 }
             }
@@ -618,55 +712,67 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_ffi_result_ok_value_to_rust_value(
         &self,
         result: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         let ty = &self.ty;
 
         match self.host_lang {
-            HostLang::Rust => {
+            HostLang::Rust if self.hardened => {
+                let rust_ty = self.rust_type_tokens();
+                let handle_table = self.handle_table_tokens(swift_bridge_path);
                 quote! {
-                    unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
+                    #handle_table.remove(#result.ok_or_err as *mut #rust_ty)
                 }
             }
-            HostLang::Swift => {
+            HostLang::Rust => {
+                let rust_ty = self.rust_type_tokens();
                 quote! {
-                    unsafe { #ty(#result.ok_or_err) }
+                    unsafe { *Box::from_raw(#result.ok_or_err as *mut #rust_ty) }
                 }
             }
+            HostLang::Swift => self.maybe_box_swift_trait_object(quote! {
+                unsafe { #ty(#result.ok_or_err) }
+            }),
         }
     }
 
     fn convert_ffi_result_err_value_to_rust_value(
         &self,
         result: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> TokenStream {
         let ty = &self.ty;
 
         match self.host_lang {
-            HostLang::Rust => {
+            HostLang::Rust if self.hardened => {
+                let rust_ty = self.rust_type_tokens();
+                let handle_table = self.handle_table_tokens(swift_bridge_path);
                 quote! {
-                    unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
+                    #handle_table.remove(#result.ok_or_err as *mut #rust_ty)
                 }
             }
-            HostLang::Swift => {
+            HostLang::Rust => {
+                let rust_ty = self.rust_type_tokens();
                 quote! {
-                    unsafe { #ty(#result.ok_or_err) }
+                    unsafe { *Box::from_raw(#result.ok_or_err as *mut #rust_ty) }
                 }
             }
+            HostLang::Swift => self.maybe_box_swift_trait_object(quote! {
+                unsafe { #ty(#result.ok_or_err) }
+            }),
         }
     }
 
     fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
-        let ty_name = &self.ty;
+        let rust_ty = self.rust_type_tokens();
 
         if self.reference {
             todo!("Support returning Option<&T> where T is an opaque type")
         } else {
             UnusedOptionNoneValue {
-                rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
+                rust: quote! { std::ptr::null::<#rust_ty>() as *mut #rust_ty },
                 swift: "TODO..Support Swift Option<T>::None value".into(),
             }
         }
@@ -747,18 +853,102 @@ impl BridgeableType for OpaqueForeignType {
     }
 }
 
+/// Get the Swift instance that an opaque pointer points to.
+///
+/// Unmanaged<SomeType>.fromOpaque(ptr).takeRetainedValue()
+///
+/// `Unmanaged` only works with classes, so the protocol of a `#[swift_bridge(trait_object)]` Swift
+/// type is cast from `AnyObject` instead.
+///
+/// (Unmanaged<AnyObject>.fromOpaque(ptr).takeRetainedValue() as! SomeProtocol)
+pub(crate) fn swift_instance_from_opaque(
+    swift_name: &str,
+    is_protocol: bool,
+    ptr: &str,
+    retained: bool,
+) -> String {
+    let take = if retained {
+        "takeRetainedValue"
+    } else {
+        "takeUnretainedValue"
+    };
+
+    if is_protocol {
+        format!("(Unmanaged<AnyObject>.fromOpaque({ptr}).{take}() as! {swift_name})")
+    } else {
+        format!("Unmanaged<{swift_name}>.fromOpaque({ptr}).{take}()")
+    }
+}
+
 impl OpaqueForeignType {
+    /// The name of the function used to clone a `#[swift_bridge(swift_repr = "struct")]` type.
+    ///
+    /// __swift_bridge__$SomeType$_clone
+    fn clone_ffi_name(&self) -> String {
+        format!("{}${}$_clone", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
     pub fn swift_name(&self) -> String {
         if self.reference {
-            format!("{}Ref", self.ty)
+            format!("{}Ref", self.swift_name)
         } else {
-            format!("{}", self.ty)
+            self.swift_name.clone()
         }
     }
 
     /// The name of the type used to pass a `#[swift_bridge(Copy(...))]` type over FFI
     ///
     /// __swift_bridge__SomeType
+    /// The Rust type that backs an opaque Rust type, without its generics.
+    /// super::SomeType, or Box<dyn super::SomeTrait> for `#[swift_bridge(trait_object)]` types.
+    fn rust_type_tokens(&self) -> TokenStream {
+        let ty_name = &self.ty;
+
+        if self.trait_object {
+            quote! { Box<dyn super::#ty_name> }
+        } else {
+            quote! { super::#ty_name }
+        }
+    }
+
+    /// Rust sees a `#[swift_bridge(trait_object)]` Swift type as a `Box<dyn super::SomeTrait>`, which
+    /// the generated type implements by calling Swift.
+    fn maybe_box_swift_trait_object(&self, value: TokenStream) -> TokenStream {
+        if self.trait_object {
+            let ty = &self.ty;
+            quote! {
+                Box::new(#value) as Box<dyn super::#ty>
+            }
+        } else {
+            value
+        }
+    }
+
+    /// Unmanaged.passRetained(value).toOpaque()
+    ///
+    /// A `#[swift_bridge(trait_object)]` Swift type is a class-bound protocol, so its instances are
+    /// passed as `AnyObject`.
+    fn swift_pass_retained(&self, expression: &str) -> String {
+        if self.trait_object {
+            format!(
+                "Unmanaged.passRetained({} as AnyObject).toOpaque()",
+                expression
+            )
+        } else {
+            format!("Unmanaged.passRetained({}).toOpaque()", expression)
+        }
+    }
+
+    /// The table that owns the values of a `#[swift_bridge(hardened)]` type.
+    ///
+    /// <super::SomeType as swift_bridge::handle_table::Hardened>::handle_table()
+    fn handle_table_tokens(&self, swift_bridge_path: &Path) -> TokenStream {
+        let rust_ty = self.rust_type_tokens();
+        quote! {
+            <#rust_ty as #swift_bridge_path::handle_table::Hardened>::handle_table()
+        }
+    }
+
     pub fn copy_rust_repr_type(&self) -> Ident {
         let ty = format!(
             "{}{}{}",
@@ -129,9 +129,12 @@ impl BridgedOption {
     pub(super) fn convert_ffi_expression_to_rust_type(
         &self,
         expression: &TokenStream,
+        swift_bridge_path: &Path,
     ) -> TokenStream {
         match self.ty.deref() {
-            BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
+            BridgedType::Bridgeable(b) => {
+                b.convert_ffi_option_expression_to_rust_type(expression, swift_bridge_path)
+            }
             BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                 StdLibType::Null => {
                     todo!("Option<()> is not yet supported")
@@ -370,7 +373,7 @@ impl BridgedOption {
         match type_pos {
             TypePosition::FnArg(func_host_lang, _) => {
                 if func_host_lang.is_swift() {
-                    self.to_ffi_compatible_swift_type(type_pos, swift_bridge_path, &types)
+                    self.to_ffi_compatible_swift_type(type_pos, swift_bridge_path, types)
                 } else {
                     format!(
                         "Optional<{}>",
@@ -380,7 +383,7 @@ impl BridgedOption {
             }
             TypePosition::FnReturn(func_host_lang) => {
                 if func_host_lang.is_swift() {
-                    self.to_ffi_compatible_swift_type(type_pos, swift_bridge_path, &types)
+                    self.to_ffi_compatible_swift_type(type_pos, swift_bridge_path, types)
                 } else {
                     format!(
                         "Optional<{}>",
@@ -395,7 +398,7 @@ impl BridgedOption {
                 )
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                unimplemented!()
+                self.to_ffi_compatible_swift_type(type_pos, swift_bridge_path, types)
             }
         }
     }
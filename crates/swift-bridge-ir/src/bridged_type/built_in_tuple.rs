@@ -259,7 +259,11 @@ impl BridgeableType for BuiltInTuple {
         };
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
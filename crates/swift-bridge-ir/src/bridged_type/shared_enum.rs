@@ -12,6 +12,7 @@ use super::StructFields;
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    pub clone: bool,
 }
 
 #[derive(Clone)]
@@ -20,6 +21,8 @@ pub(crate) struct SharedEnum {
     pub variants: Vec<EnumVariant>,
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
+    /// A handwritten Swift enum that must have the same cases as this enum.
+    pub swift_mirror: Option<LitStr>,
     pub derive: DeriveAttrs,
 }
 
@@ -111,6 +114,14 @@ impl SharedEnum {
     pub fn all_variants_empty(&self) -> bool {
         self.variants.iter().all(|v| v.fields.is_empty())
     }
+
+    /// Whether or not the enum can be used in a `Vec<T>`.
+    ///
+    /// Swift reads a `Vec` element by getting a copy of it, so an enum with data-carrying variants
+    /// needs to `#[derive(Clone)]`.
+    pub fn supports_vec(&self) -> bool {
+        self.all_variants_empty() || self.derive.clone
+    }
 }
 
 impl PartialEq for SharedEnum {
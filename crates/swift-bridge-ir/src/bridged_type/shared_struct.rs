@@ -1,6 +1,6 @@
 pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
-use self::struct_field::UnnamedStructField;
+use self::struct_field::{NamedStructField, UnnamedStructField};
 use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition};
 use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
@@ -257,12 +257,24 @@ pub(crate) struct SharedStruct {
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
     pub derives: StructDerives,
+    /// Whether or not the `#[swift_bridge(initialize)]` attribute was present on the struct.
+    pub initialize: bool,
+    /// Whether or not the `#[swift_bridge(patch)]` attribute was present on the struct.
+    pub patch: bool,
+    /// The field from `#[swift_bridge(Identifiable = "...")]` that is used as the struct's
+    /// Swift `Identifiable` id.
+    pub identifiable: Option<Ident>,
+    /// Whether or not the `#[swift_bridge(Codable)]` attribute was present on the struct.
+    pub codable: bool,
 }
 
 #[derive(Clone)]
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    pub partial_eq: bool,
+    pub eq: bool,
+    pub hash: bool,
 }
 
 impl SharedStruct {
@@ -305,6 +317,54 @@ impl SharedStruct {
         format!("{}$Option${}", SWIFT_BRIDGE_PREFIX, name,)
     }
 
+    /// The `SomeStructPatch` companion of a `#[swift_bridge(patch)]` struct, which has an
+    /// `Option` of each of the struct's fields.
+    pub(crate) fn patch_struct(&self) -> SharedStruct {
+        let fields = match &self.fields {
+            StructFields::Named(named) => StructFields::Named(
+                named
+                    .iter()
+                    .map(|field| {
+                        let ty = &field.ty;
+                        NamedStructField {
+                            name: field.name.clone(),
+                            ty: syn::parse_quote! { Option<#ty> },
+                        }
+                    })
+                    .collect(),
+            ),
+            StructFields::Unnamed(unnamed) => StructFields::Unnamed(
+                unnamed
+                    .iter()
+                    .map(|field| {
+                        let ty = &field.ty;
+                        UnnamedStructField {
+                            ty: syn::parse_quote! { Option<#ty> },
+                            idx: field.idx,
+                        }
+                    })
+                    .collect(),
+            ),
+            StructFields::Unit => StructFields::Unit,
+        };
+
+        SharedStruct {
+            name: format_ident!("{}Patch", self.name),
+            swift_repr: self.swift_repr,
+            fields,
+            swift_name: self
+                .swift_name
+                .as_ref()
+                .map(|name| LitStr::new(&format!("{}Patch", name.value()), name.span())),
+            already_declared: self.already_declared,
+            derives: self.derives.clone(),
+            initialize: false,
+            patch: false,
+            identifiable: None,
+            codable: false,
+        }
+    }
+
     /// Some if the struct has a single variant.
     /// TODO: If all of the struct's fields have an `OnlyEncoding`, then the struct has exactly
     ///  one encoding as well.
@@ -589,6 +649,7 @@ impl PartialEq for SharedStruct {
             && self.swift_name.as_ref().map(|l| l.value())
                 == other.swift_name.as_ref().map(|l| l.value())
             && self.already_declared == other.already_declared
+            && self.initialize == other.initialize
     }
 }
 
@@ -600,6 +661,7 @@ impl Debug for SharedStruct {
             .field("fields", &self.fields)
             .field("swift_name", &self.swift_name.as_ref().map(|l| l.value()))
             .field("already_declared", &self.already_declared)
+            .field("initialize", &self.initialize)
             .finish()
     }
 }
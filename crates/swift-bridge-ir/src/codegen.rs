@@ -1,9 +1,15 @@
 use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::bridgeable_arc::BridgeableArc;
+use crate::bridged_type::BridgeableType;
 use crate::SwiftBridgeModule;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::{FnArg, ReturnType};
 
 mod generate_c_header;
 mod generate_rust_tokens;
 mod generate_swift;
+mod generate_swift_smoke_tests;
 
 #[cfg(test)]
 mod codegen_tests;
@@ -58,4 +64,31 @@ impl SwiftBridgeModule {
 
         true
     }
+
+    /// The names of the opaque Rust types that the module's functions pass as `Arc<T>`.
+    ///
+    /// These types get functions that let Swift release and clone its strong references.
+    fn arc_opaque_types(&self) -> HashSet<String> {
+        let mut arc_types = HashSet::new();
+
+        for function in &self.functions {
+            let arg_types = function.sig.inputs.iter().filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) => Some(pat_ty.ty.as_ref()),
+                FnArg::Receiver(_) => None,
+            });
+            let return_ty = match &function.sig.output {
+                ReturnType::Type(_, ty) => Some(ty.as_ref()),
+                ReturnType::Default => None,
+            };
+
+            for ty in arg_types.chain(return_ty) {
+                let tokens = ty.to_token_stream().to_string();
+                if let Some(arc) = BridgeableArc::parse_token_stream_str(&tokens, &self.types) {
+                    arc_types.insert(arc.ty.to_string());
+                }
+            }
+        }
+
+        arc_types
+    }
 }
@@ -28,12 +28,18 @@ use crate::test_utils::{
 };
 
 mod already_declared_attribute_codegen_tests;
+mod arc_codegen_tests;
 mod argument_label_codegen_tests;
 mod async_function_codegen_tests;
+mod atomic_cell_codegen_tests;
+mod boxed_fn_codegen_tests;
 mod boxed_fnonce_codegen_tests;
+mod bridge_module_attribute_codegen_tests;
 mod built_in_tuple_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
+mod channel_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod decimal_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
@@ -41,15 +47,21 @@ mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod hash_map_codegen_tests;
+mod hash_set_codegen_tests;
+mod lifetime_codegen_tests;
+mod multiple_extern_blocks_codegen_tests;
 mod opaque_rust_type_codegen_tests;
 mod opaque_swift_type_codegen_tests;
 mod option_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
+mod slice_codegen_tests;
 mod string_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
+mod unsafe_function_codegen_tests;
 mod vec_codegen_tests;
 
 struct CodegenTest {
@@ -321,3 +321,140 @@ mod already_declared_rust_copy_type_methods {
         .test();
     }
 }
+
+/// Verify that we use the struct of an already declared opaque Swift type instead of declaring a
+/// second struct, and that we do not generate a second Swift function to free it.
+mod extern_swift_already_declared_type {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(already_declared)]
+                    type SomeSwiftType;
+
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                use super::SomeSwiftType;
+
+                impl SomeSwiftType {
+                    pub fn some_method(&self) {
+                        unsafe { __swift_bridge__SomeSwiftType_some_method(swift_bridge::PointerToSwiftType(self.0)) }
+                    }
+                }
+            }],
+            does_not_contain: vec![
+                quote! {
+                    pub struct SomeSwiftType
+                },
+                quote! {
+                    impl Drop for SomeSwiftType
+                },
+                quote! {
+                    fn __swift_bridge__SomeSwiftType__free
+                },
+            ],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+__swift_bridge__$SomeSwiftType$_free
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_already_declared_type() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a bridge module that uses an opaque type that another bridge module declared
+/// treats the type as already declared.
+mod type_declared_by_another_bridge_module {
+    use super::*;
+    use crate::test_utils::parse_ok;
+
+    fn declare_scene() {
+        parse_ok(quote! {
+            #[swift_bridge::bridge]
+            mod scene {
+                extern "Rust" {
+                    type Scene;
+                }
+            }
+        });
+    }
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod renderer {
+                extern "Rust" {
+                    fn render(scene: &Scene);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                pub extern "C" fn __swift_bridge__render(scene: *const super::Scene) {
+                    super::render(unsafe { &*scene })
+                }
+            }],
+            does_not_contain: vec![quote! {
+                fn __swift_bridge__Scene__free
+            }],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func render(_ scene: SceneRef) {
+    __swift_bridge__$render(scene.ptr)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void __swift_bridge__$render(void* scene);
+"#,
+        )
+    }
+
+    #[test]
+    fn type_declared_by_another_bridge_module() {
+        declare_scene();
+
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,197 @@
+//! See also: crates/swift-integration-tests/src/arc.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns an Arc<T> where T is an opaque Rust type.
+mod extern_rust_fn_return_arc_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function() -> Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> *const super::SomeType {
+                    std::sync::Arc::into_raw(super::some_function())
+                }
+            },
+            quote! {
+                const _: () = {
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$SomeType$_arc_free"]
+                    pub extern "C" fn _arc_free(this: *const super::SomeType) {
+                        unsafe { std::sync::Arc::decrement_strong_count(this) }
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$SomeType$_arc_clone"]
+                    pub extern "C" fn _arc_clone(this: *const super::SomeType) {
+                        unsafe { std::sync::Arc::increment_strong_count(this) }
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+func some_function() -> SomeTypeArc {
+    SomeTypeArc(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+public class SomeTypeArc: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        __swift_bridge__$SomeType$_arc_free(ptr)
+    }
+
+    /// Returns another strong reference to the same Rust value.
+    public func clone() -> SomeTypeArc {
+        __swift_bridge__$SomeType$_arc_clone(ptr)
+        return SomeTypeArc(ptr: ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$SomeType$_arc_free(void* self);
+void __swift_bridge__$SomeType$_arc_clone(void* self);
+"#,
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_arc_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that has an Arc<T> argument where T is an opaque Rust
+/// type.
+mod extern_rust_fn_arg_arc_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: Arc<SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(arg: *const super::SomeType) {
+                super::some_function({
+                    let ptr = arg;
+                    unsafe {
+                        std::sync::Arc::increment_strong_count(ptr);
+                        std::sync::Arc::from_raw(ptr)
+                    }
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: SomeTypeArc) {
+    __swift_bridge__$some_function(arg.ptr)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_arc_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we don't generate `Arc` support for opaque Rust types that aren't used in an
+/// `Arc<T>`.
+mod opaque_rust_type_without_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            _arc_free
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("SomeTypeArc")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("_arc_free")
+    }
+
+    #[test]
+    fn opaque_rust_type_without_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -115,3 +115,78 @@ void __swift_bridge__$some_function(int32_t parameter_name1, uint32_t parameter_
         .test();
     }
 }
+
+/// Verify that we call an extern "Swift" function with its argument labels, and that the `_`
+/// label passes an argument without a label.
+mod extern_swift_argument_labels {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn lookup(
+                        #[swift_bridge(label = "for")] id: u64,
+                        #[swift_bridge(label = "_")] limit: u32,
+                        verbose: bool,
+                    ) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    lookup(for: id, limit, verbose: verbose)
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_argument_labels() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we call an async extern "Swift" function with its argument labels.
+mod extern_swift_async_argument_labels {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    async fn lookup(#[swift_bridge(label = "for")] id: u64) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+        let val = await lookup(for: id)
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_async_argument_labels() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
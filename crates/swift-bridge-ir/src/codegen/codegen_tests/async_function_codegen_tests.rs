@@ -354,6 +354,98 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
     }
 }
 
+/// Verify that we generate the correct code for extern "Rust" async functions that returns an
+/// Option<String>.
+mod extern_rust_async_function_returns_option_string {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function() -> Option<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut swift_bridge::string::RustString) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = if let Some(val) = fut.await {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    };
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() async -> Optional<RustString> {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success({ let val = rustFnRetVal; if val != nil { return RustString(ptr: val!) } else { return nil } }()))
+    }
+
+    return await withCheckedContinuation({ (continuation: CheckedContinuation<Optional<RustString>, Never>) in
+        let callback = { rustFnRetVal in
+            continuation.resume(with: rustFnRetVal)
+        }
+
+        let wrapper = CbWrapper$some_function(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        __swift_bridge__$some_function(wrapperPtr, onComplete)
+    })
+}
+class CbWrapper$some_function {
+    var cb: (Result<Optional<RustString>, Never>) -> ()
+
+    public init(cb: @escaping (Result<Optional<RustString>, Never>) -> ()) {
+        self.cb = cb
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, void* ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_option_string() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for extern "Rust" async functions that returns a struct.
 mod extern_rust_async_function_returns_struct {
     use super::*;
@@ -1049,3 +1141,152 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
         .test();
     }
 }
+
+/// Verify that we generate the correct code for extern "Swift" async functions that take an owned
+/// String and return a u32.
+mod extern_swift_async_function_returns_u32 {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    async fn some_function(arg: String) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: String) -> impl std::future::Future<Output = u32> {
+                    let (future, callback_wrapper) =
+                        swift_bridge::async_support::SwiftFuture::<u32>::new();
+
+                    extern "C" fn callback(callback_wrapper: *mut std::ffi::c_void, val: u32) {
+                        let completer = unsafe {
+                            swift_bridge::async_support::SwiftFutureCompleter::<u32>::from_raw(callback_wrapper)
+                        };
+                        completer.complete(val);
+                    }
+
+                    unsafe {
+                        __swift_bridge__some_function(
+                            callback_wrapper,
+                            callback,
+                            swift_bridge::string::RustString(arg).box_into_raw()
+                        )
+                    };
+
+                    future
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    callback_wrapper: *mut std::ffi::c_void,
+                    callback: extern "C" fn(*mut std::ffi::c_void, u32) -> (),
+                    arg: *mut swift_bridge::string::RustString
+                );
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callbackWrapper: UnsafeMutableRawPointer?, _ callback: @convention(c) (UnsafeMutableRawPointer?, UInt32) -> (), _ arg: UnsafeMutableRawPointer) {
+    let arg = RustString(ptr: arg)
+    Task {
+        let val = await some_function(arg: arg)
+        callback(callbackWrapper, val)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_async_function_returns_u32() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Swift" async functions that do not
+/// return a value.
+mod extern_swift_async_function_returns_null {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    async fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function() -> impl std::future::Future<Output = ()> {
+                let (future, callback_wrapper) =
+                    swift_bridge::async_support::SwiftFuture::<()>::new();
+
+                extern "C" fn callback(callback_wrapper: *mut std::ffi::c_void) {
+                    let completer = unsafe {
+                        swift_bridge::async_support::SwiftFutureCompleter::<()>::from_raw(callback_wrapper)
+                    };
+                    completer.complete(());
+                }
+
+                unsafe { __swift_bridge__some_function(callback_wrapper, callback) };
+
+                future
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callbackWrapper: UnsafeMutableRawPointer?, _ callback: @convention(c) (UnsafeMutableRawPointer?) -> ()) {
+    Task {
+        await some_function()
+        callback(callbackWrapper)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_async_function_returns_null() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
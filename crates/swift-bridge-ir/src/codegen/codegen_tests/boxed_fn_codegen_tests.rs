@@ -0,0 +1,242 @@
+//! Tests for passing closures between Swift and Rust as a `Box<dyn Fn(A, B) -> C>`.
+//!
+//! Swift retains its closure, or Rust boxes its closure, and passes a `__private__FfiClosure`,
+//! which holds a pointer to the closure and a function that frees it.
+//! It gets passed alongside a `@convention(c)` thunk that calls the closure, in a struct that is
+//! generated for each closure signature so that the thunk keeps its exact function pointer type.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take a Swift closure that has a primitive argument and no
+/// return value.
+mod extern_rust_fn_takes_boxed_fn_primitive_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn for_each(callback: Box<dyn Fn(u32)>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__FnClosure_U32_Void {
+                    closure: swift_bridge::boxed_fn_support::FfiClosure,
+                    call: extern "C" fn(*mut std::ffi::c_void, u32) -> (),
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$for_each"]
+                pub extern "C" fn __swift_bridge__for_each(
+                    callback: __swift_bridge__FnClosure_U32_Void
+                ) {
+                    super::for_each({
+                        let ffi_closure = callback;
+                        let call = ffi_closure.call;
+                        let closure = ffi_closure.closure;
+                        Box::new(move |arg0: u32| call(closure.context(), arg0)) as Box<dyn Fn(u32) -> ()>
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func for_each(_ callback: @escaping (UInt32) -> ()) {
+    __swift_bridge__$for_each(__swift_bridge__$FnClosure$U32$Void(closure: __private__SwiftClosureBox(callback).toFfiClosure(), call: { context, arg0 in __private__SwiftClosureBox<(UInt32) -> ()>.fromContext(context)(arg0) }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$for_each(struct __swift_bridge__$FnClosure$U32$Void callback);
+typedef struct __swift_bridge__$FnClosure$U32$Void { struct __private__FfiClosure closure; void (*call)(void*, uint32_t); } __swift_bridge__$FnClosure$U32$Void;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_takes_boxed_fn_primitive_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take a Swift closure that takes and returns an opaque Rust
+/// type.
+mod extern_rust_fn_takes_boxed_fn_opaque_rust_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn transform(callback: Box<dyn Fn(SomeType) -> SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__FnClosure_SomeType_SomeType {
+                    closure: swift_bridge::boxed_fn_support::FfiClosure,
+                    call: extern "C" fn(*mut std::ffi::c_void, *mut super::SomeType) -> *mut super::SomeType,
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$transform"]
+                pub extern "C" fn __swift_bridge__transform(
+                    callback: __swift_bridge__FnClosure_SomeType_SomeType
+                ) {
+                    super::transform({
+                        let ffi_closure = callback;
+                        let call = ffi_closure.call;
+                        let closure = ffi_closure.closure;
+                        Box::new(move |arg0: super::SomeType| unsafe {
+                            *Box::from_raw(call(
+                                closure.context(),
+                                Box::into_raw(Box::new({
+                                    let val: super::SomeType = arg0;
+                                    val
+                                })) as *mut super::SomeType
+                            ))
+                        }) as Box<dyn Fn(super::SomeType) -> super::SomeType>
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func transform(_ callback: @escaping (SomeType) -> SomeType) {
+    __swift_bridge__$transform(__swift_bridge__$FnClosure$SomeType$SomeType(closure: __private__SwiftClosureBox(callback).toFfiClosure(), call: { context, arg0 in let ret = __private__SwiftClosureBox<(SomeType) -> SomeType>.fromContext(context)(SomeType(ptr: arg0)); return {ret.isOwned = false; return ret.ptr;}() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$transform(struct __swift_bridge__$FnClosure$SomeType$SomeType callback);
+typedef struct __swift_bridge__$FnClosure$SomeType$SomeType { struct __private__FfiClosure closure; void* (*call)(void*, void*); } __swift_bridge__$FnClosure$SomeType$SomeType;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_takes_boxed_fn_opaque_rust_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Rust can pass a boxed closure to a Swift function, which calls it through a
+/// trampoline and frees it when the Swift closure is released.
+mod extern_swift_fn_takes_boxed_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn set_delegate(callback: Box<dyn Fn(u32) -> u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn set_delegate(callback: Box<dyn Fn(u32) -> u8>) {
+                    unsafe {
+                        __swift_bridge__set_delegate({
+                            extern "C" fn call(context: *mut std::ffi::c_void, arg0: u32) -> u8 {
+                                let closure = unsafe { &*(context as *const Box<dyn Fn(u32) -> u8>) };
+                                closure(arg0)
+                            }
+                            extern "C" fn free(context: *mut std::ffi::c_void) {
+                                drop(unsafe { Box::from_raw(context as *mut Box<dyn Fn(u32) -> u8>) });
+                            }
+
+                            __swift_bridge__FnClosure_U32_U8 {
+                                closure: swift_bridge::boxed_fn_support::FfiClosure::new(
+                                    Box::into_raw(Box::new(callback)) as *mut std::ffi::c_void,
+                                    free,
+                                ),
+                                call,
+                            }
+                        })
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$set_delegate"]
+                fn __swift_bridge__set_delegate(callback: __swift_bridge__FnClosure_U32_U8);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$set_delegate")
+func __swift_bridge__set_delegate (_ callback: __swift_bridge__$FnClosure$U32$U8) {
+    set_delegate(callback: { let ffi = callback; let closure = __private__RustFnClosure(ffi.closure); return { (arg0: UInt32) -> UInt8 in ffi.call(closure.context, arg0) } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$FnClosure$U32$U8 { struct __private__FfiClosure closure; uint8_t (*call)(void*, uint32_t); } __swift_bridge__$FnClosure$U32$U8;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_takes_boxed_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,450 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `#[swift_bridge::bridge(breadcrumbs)]` attribute makes the module's Rust
+/// functions record breadcrumbs.
+mod bridge_module_breadcrumbs_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(breadcrumbs)]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() {
+                swift_bridge::breadcrumbs::record("some_function");
+                super::some_function()
+            }
+        })
+    }
+
+    #[test]
+    fn bridge_module_breadcrumbs_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge::bridge(propagate_context)]` attribute captures the caller's
+/// context and restores it on the callee's side, in both directions.
+mod bridge_module_propagate_context_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(propagate_context)]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: u8) -> u32;
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    fn some_swift_function(arg: u8) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(arg: u8) -> u32 {
+                    let _context = swift_bridge::context::restore_from_swift();
+                    super::some_function(arg)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *mut super::SomeType
+                ) {
+                    let _context = swift_bridge::context::restore_from_swift();
+                    (unsafe { &*this }).some_method()
+                }
+            },
+            quote! {
+                pub fn some_swift_function(arg: u8) -> u32 {
+                    let _context = swift_bridge::context::propagate_to_swift();
+                    unsafe { __swift_bridge__some_swift_function(arg) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt8) -> UInt32 {
+    __private__withContextPropagatedToRust({
+        __swift_bridge__$some_function(arg)
+    })
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func some_method() {
+        __private__withContextPropagatedToRust({
+            __swift_bridge__$SomeType$some_method(ptr)
+        })
+    }
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$some_swift_function")
+func __swift_bridge__some_swift_function (_ arg: UInt8) -> UInt32 {
+    __private__withContextFromRust({ some_swift_function(arg: arg) })
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn bridge_module_propagate_context_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not propagate context unless the module opts in.
+mod bridge_module_without_propagate_context_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+
+                extern "Swift" {
+                    fn some_swift_function();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bridge_module_without_propagate_context_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                swift_bridge::context
+            }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "__private__withContextPropagatedToRust",
+                "__private__withContextFromRust",
+            ]),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge::bridge(swift_namespace = "...")]` attribute nests the module's
+/// Swift types inside of the namespace while leaving the FFI symbols untouched.
+mod bridge_module_swift_namespace_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_namespace = "engine::audio_fx")]
+            mod ffi {
+                enum MixerMode {
+                    Mono,
+                    Stereo,
+                }
+
+                extern "Rust" {
+                    type Mixer;
+
+                    fn make_mixer() -> Mixer;
+                    fn mode(&self) -> MixerMode;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func make_mixer() -> __swift_bridge__Engine_AudioFx__Mixer {
+    __swift_bridge__Engine_AudioFx__Mixer(ptr: __swift_bridge__$make_mixer())
+}
+"#,
+            r#"
+public enum __swift_bridge__Engine_AudioFx__MixerMode {
+"#,
+            r#"
+public class __swift_bridge__Engine_AudioFx__Mixer: __swift_bridge__Engine_AudioFx__MixerRefMut {
+"#,
+            r#"
+extension __swift_bridge__Engine_AudioFx__MixerRef {
+    public func mode() -> __swift_bridge__Engine_AudioFx__MixerMode {
+        __swift_bridge__$Mixer$mode(ptr).intoSwiftRepr()
+    }
+}
+"#,
+            r#"
+extension Engine.AudioFx {
+    public typealias MixerMode = __swift_bridge__Engine_AudioFx__MixerMode
+    public typealias Mixer = __swift_bridge__Engine_AudioFx__Mixer
+    public typealias MixerRef = __swift_bridge__Engine_AudioFx__MixerRef
+    public typealias MixerRefMut = __swift_bridge__Engine_AudioFx__MixerRefMut
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn bridge_module_swift_namespace_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge::bridge(swift_mocks)]` attribute makes Rust tests call mocks
+/// instead of the module's `extern "Swift"` functions.
+mod bridge_module_swift_mocks_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_mocks)]
+            mod ffi {
+                extern "Swift" {
+                    type Greeter;
+
+                    fn add(a: u8, b: u8) -> u8;
+                    fn greet(&self, times: u32) -> String;
+                    async fn not_mocked();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![
+                quote! {
+                    #[cfg(not(test))]
+                    pub fn add(a: u8, b: u8) -> u8 {
+                        unsafe { __swift_bridge__add(a, b) }
+                    }
+                    #[cfg(test)]
+                    pub fn add(a: u8, b: u8) -> u8 {
+                        __swift_bridge__mock_storage_add.with(|mock| {
+                            let mut mock = mock.borrow_mut();
+                            let mock = mock.as_mut().expect(
+                                "The Swift function `add` was called without a mock. Set one with `swift_mocks::add`."
+                            );
+                            mock(a, b)
+                        })
+                    }
+                },
+                quote! {
+                    #[cfg(test)]
+                    pub fn greet(&self, times: u32) -> String {
+                        __swift_bridge__mock_storage_Greeter_greet.with(|mock| {
+                            let mut mock = mock.borrow_mut();
+                            let mock = mock.as_mut().expect(
+                                "The Swift function `Greeter::greet` was called without a mock. Set one with `swift_mocks::Greeter::greet`."
+                            );
+                            mock(self, times)
+                        })
+                    }
+                },
+                quote! {
+                    #[cfg(test)]
+                    impl Greeter {
+                        /// Create an instance to pass to and return from mocks.
+                        pub fn new_mock() -> Self {
+                            Greeter(std::ptr::null_mut())
+                        }
+                    }
+
+                    #[cfg(not(test))]
+                    impl Drop for Greeter {
+                        fn drop (&mut self) {
+                            unsafe { __swift_bridge__Greeter__free(self.0) }
+                        }
+                    }
+                },
+                quote! {
+                    #[cfg(test)]
+                    thread_local! {
+                        #[allow(non_upper_case_globals)]
+                        static __swift_bridge__mock_storage_add: std::cell::RefCell<Option<Box<dyn FnMut(u8, u8) -> u8>>> =
+                            std::cell::RefCell::new(None);
+                    }
+
+                    #[cfg(test)]
+                    pub fn __swift_bridge__mock_add(mock: impl FnMut(u8, u8) -> u8 + 'static) {
+                        __swift_bridge__mock_storage_add.with(|m| *m.borrow_mut() = Some(Box::new(mock)));
+                    }
+                },
+                quote! {
+                    pub fn __swift_bridge__mock_Greeter_greet(mock: impl FnMut(&Greeter, u32) -> String + 'static) {
+                        __swift_bridge__mock_storage_Greeter_greet.with(|m| *m.borrow_mut() = Some(Box::new(mock)));
+                    }
+                },
+                quote! {
+                    #[cfg(test)]
+                    pub mod swift_mocks {
+                        pub use super::__swift_bridge__mock_add as add;
+
+                        pub mod Greeter {
+                            pub use super::super::__swift_bridge__mock_Greeter_greet as greet;
+                        }
+                    }
+                },
+            ],
+            does_not_contain: vec![quote! {
+                __swift_bridge__mock_not_mocked
+            }],
+        }
+    }
+
+    #[test]
+    fn bridge_module_swift_mocks_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge::bridge(cxx_header)]` attribute wraps the module's C header
+/// declarations in `extern "C"` guards, while the includes stay outside of them.
+mod bridge_module_cxx_header_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(cxx_header)]
+            mod ffi {
+                enum SomeEnum {
+                    A,
+                    B,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: SomeEnum) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+#ifdef __cplusplus
+extern "C" {
+#endif
+typedef enum __swift_bridge__$SomeEnumTag { __swift_bridge__$SomeEnum$A, __swift_bridge__$SomeEnum$B, } __swift_bridge__$SomeEnumTag;
+"#,
+            r#"
+uint32_t __swift_bridge__$some_function(struct __swift_bridge__$SomeEnum arg);
+#ifdef __cplusplus
+}
+#endif
+"#,
+        ])
+    }
+
+    #[test]
+    fn bridge_module_cxx_header_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge::bridge(cxx_header(enum_class))]` attribute declares an
+/// `enum class` for transparent enums without fields, and skips enums that have fields.
+mod bridge_module_cxx_header_enum_class_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(cxx_header(enum_class))]
+            mod ffi {
+                enum SomeEnum {
+                    A,
+                    B,
+                }
+
+                enum EnumWithFields {
+                    Value(u8),
+                }
+            }
+        }
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        // The namespace ends right after `SomeEnum`, so `EnumWithFields` has no `enum class`.
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+#ifdef __cplusplus
+}
+#endif
+#ifdef __cplusplus
+namespace ffi {
+enum class SomeEnum { A = __swift_bridge__$SomeEnum$A, B = __swift_bridge__$SomeEnum$B };
+inline __swift_bridge__$SomeEnum into_ffi(SomeEnum value) { return __swift_bridge__$SomeEnum { static_cast<__swift_bridge__$SomeEnumTag>(value) }; }
+inline SomeEnum from_ffi(__swift_bridge__$SomeEnum value) { return static_cast<SomeEnum>(value.tag); }
+}
+#endif
+"#,
+        )
+    }
+
+    #[test]
+    fn bridge_module_cxx_header_enum_class_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
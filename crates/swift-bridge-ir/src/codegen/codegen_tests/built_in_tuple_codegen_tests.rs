@@ -275,6 +275,141 @@ struct __swift_bridge__$tuple$F32IntBool __swift_bridge__$some_function(struct _
     }
 }
 
+/// Verify that we can return a (u64, usize, i64) from a Rust function.
+mod extern_rust_tuple_u64_usize_i64 {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> (u64, usize, i64);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function () -> __swift_bridge__tuple_U64UIntI64 {
+                    { let val = super::some_function();
+                    __swift_bridge__tuple_U64UIntI64(val.0, val.1, val.2) }
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_U64UIntI64(u64, usize, i64);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> (UInt64, UInt, Int64) {
+    { let val = __swift_bridge__$some_function(); return (val._0, val._1, val._2); }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$tuple$U64UIntI64 { uint64_t _0; uintptr_t _1; int64_t _2; } __swift_bridge__$tuple$U64UIntI64;
+"#,
+            r#"
+struct __swift_bridge__$tuple$U64UIntI64 __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_tuple_u64_usize_i64() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can return a (Option<String>, primitive type) from a Rust function.
+mod extern_rust_tuple_option_string_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> (Option<String>, u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__tuple_OptionStringU8 {
+                    { let val = super::some_function();
+                    __swift_bridge__tuple_OptionStringU8(
+                        if let Some(val) = val.0 {
+                            swift_bridge::string::RustString(val).box_into_raw()
+                        } else {
+                            std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                        },
+                        val.1
+                    ) }
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_OptionStringU8(*mut swift_bridge::string::RustString, u8);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> (Optional<RustString>, UInt8) {
+    { let val = __swift_bridge__$some_function(); return ({ let val = val._0; if val != nil { return RustString(ptr: val!) } else { return nil } }(), val._1); }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$tuple$OptionStringU8 { void* _0; uint8_t _1; } __swift_bridge__$tuple$OptionStringU8;
+"#,
+            r#"
+struct __swift_bridge__$tuple$OptionStringU8 __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_tuple_option_string_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use a (primitive type, primitive type) as Swift function arg and return type.
 mod extern_swift_tuple_primitives {
     use super::*;
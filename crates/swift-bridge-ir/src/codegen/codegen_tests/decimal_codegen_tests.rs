@@ -0,0 +1,166 @@
+//! See also: crates/swift-integration-tests/src/decimal.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function passes `Decimal` arguments and returns by value as an
+/// `FfiDecimal`, which Swift converts to and from Foundation's `Decimal`.
+mod extern_rust_fn_decimal_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Decimal, other: rust_decimal::Decimal) -> Decimal;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::decimal::FfiDecimal,
+                other: swift_bridge::decimal::FfiDecimal
+            ) -> swift_bridge::decimal::FfiDecimal {
+                swift_bridge::decimal::FfiDecimal::from_decimal(
+                    super::some_function(arg.into_decimal(), other.into_decimal())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Decimal, _ other: Decimal) -> Decimal {
+    __swift_bridge__$some_function(arg.intoFfiRepr(), other.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiDecimal __swift_bridge__$some_function(struct __private__FfiDecimal arg, struct __private__FfiDecimal other);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_decimal_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Rust can call a Swift function that takes and returns a `Decimal`.
+mod extern_swift_fn_decimal_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Decimal) -> Decimal;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: swift_bridge::decimal::Decimal) -> swift_bridge::decimal::Decimal {
+                unsafe {
+                    __swift_bridge__some_function(swift_bridge::decimal::FfiDecimal::from_decimal(arg))
+                }.into_decimal()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiDecimal) -> __private__FfiDecimal {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_decimal_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `Decimal` field.
+mod shared_struct_decimal_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Invoice {
+                    total: Decimal,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Invoice {
+                    total: swift_bridge::decimal::FfiDecimal
+                }
+            },
+            quote! {
+                Invoice { total: val.total.into_decimal() }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public var total: Decimal",
+            "__swift_bridge__$Invoice(total: val.total.intoFfiRepr())",
+            "Invoice(total: val.total.intoSwiftRepr())",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Invoice { struct __private__FfiDecimal total; } __swift_bridge__$Invoice;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_decimal_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -293,3 +293,64 @@ void __swift_bridge__$some_function(struct __swift_bridge__$SomeType arg);
         .test();
     }
 }
+
+/// Verify that we generate the proper code for extern "Rust" methods that take a mutable
+/// reference to an opaque Rust argument.
+mod test_extern_rust_method_ref_mut_opaque_rust_type_argument {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn merge(&mut self, other: &mut SomeType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$merge"]
+            pub extern "C" fn __swift_bridge__SomeType_merge (
+                this: *mut super::SomeType,
+                other: *mut super::SomeType
+            ) {
+                (unsafe { &mut * this }).merge(unsafe { &mut * other })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRefMut {
+    public func merge(_ other: SomeTypeRefMut) {
+        __swift_bridge__$SomeType$merge(ptr, other.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$merge(void* self, void* other);
+            "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_ref_mut_opaque_type_argument() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
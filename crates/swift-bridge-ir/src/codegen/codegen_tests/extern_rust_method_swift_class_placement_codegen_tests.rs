@@ -51,11 +51,11 @@ public class SomeType: SomeTypeRefMut {
 }
 extension SomeType {
     public func a() {
-        __swift_bridge__$SomeType$a({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$a({__private__consume("SomeType.a()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}())
     }
 
     public func b() {
-        __swift_bridge__$SomeType$b({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$b({__private__consume("SomeType.b()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}())
     }
 }
 public class SomeTypeRefMut: SomeTypeRef {
@@ -259,6 +259,57 @@ extension AnotherTypeRef: Identifiable {}"#,
     }
 }
 
+/// Verify that an Identifiable function on a `swift_repr = "struct"` type adds the conformance to
+/// the generated struct, using the function's Swift name.
+mod protocol_identifiable_swift_repr_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_repr = "struct")]
+                    type SomeType;
+
+                    #[swift_bridge(Identifiable, swift_name = "someId")]
+                    fn some_id(&self) -> u64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Identifiable {
+    public var id: UInt64 {
+        return self.someId()
+    }
+}"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn protocol_identifiable_swift_repr_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use the get attribute
 mod get {
     use super::*;
@@ -467,3 +518,641 @@ func __swift_bridge__call_swift_from_rust () -> UnsafeMutableRawPointer {
         .test();
     }
 }
+
+/// Verify that the `#[swift_bridge(progress)]` attribute passes a progress closure from Swift to
+/// the Rust function as a `Box<dyn Fn(f64)>`.
+mod function_attribute_progress {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(progress)]
+                    fn some_function(arg: u8) -> u32;
+
+                    #[swift_bridge(progress)]
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__FnClosure_F64_Void {
+                    closure: swift_bridge::boxed_fn_support::FfiClosure,
+                    call: extern "C" fn(*mut std::ffi::c_void, f64) -> (),
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: u8,
+                    progress: __swift_bridge__FnClosure_F64_Void,
+                    progress_observed: bool
+                ) -> u32 {
+                    super::some_function(
+                        arg,
+                        &swift_bridge::progress::Progress::new(
+                            {
+                                let ffi_closure = progress;
+                                let call = ffi_closure.call;
+                                let closure = ffi_closure.closure;
+                                Box::new(move |arg0: f64| call(closure.context(), arg0)) as Box<dyn Fn(f64) -> ()>
+                            },
+                            progress_observed
+                        )
+                    )
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *mut super::SomeType,
+                    progress: __swift_bridge__FnClosure_F64_Void,
+                    progress_observed: bool
+                ) {
+                    (unsafe { &*this }).some_method(
+                        &swift_bridge::progress::Progress::new(
+                            {
+                                let ffi_closure = progress;
+                                let call = ffi_closure.call;
+                                let closure = ffi_closure.closure;
+                                Box::new(move |arg0: f64| call(closure.context(), arg0)) as Box<dyn Fn(f64) -> ()>
+                            },
+                            progress_observed
+                        )
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt8, progress: ((Double) -> ())? = nil) -> UInt32 {
+    __swift_bridge__$some_function(arg, __swift_bridge__$FnClosure$F64$Void(closure: __private__SwiftClosureBox(progress ?? { _ in }).toFfiClosure(), call: { context, arg0 in __private__SwiftClosureBox<(Double) -> ()>.fromContext(context)(arg0) }), progress != nil)
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func some_method(progress: ((Double) -> ())? = nil) {
+        __swift_bridge__$SomeType$some_method(ptr, __swift_bridge__$FnClosure$F64$Void(closure: __private__SwiftClosureBox(progress ?? { _ in }).toFfiClosure(), call: { context, arg0 in __private__SwiftClosureBox<(Double) -> ()>.fromContext(context)(arg0) }), progress != nil)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$FnClosure$F64$Void { struct __private__FfiClosure closure; void (*call)(void*, double); } __swift_bridge__$FnClosure$F64$Void;
+"#,
+            r#"
+uint32_t __swift_bridge__$some_function(uint8_t arg, struct __swift_bridge__$FnClosure$F64$Void progress, bool progress_observed);
+"#,
+            r#"
+void __swift_bridge__$SomeType$some_method(void* self, struct __swift_bridge__$FnClosure$F64$Void progress, bool progress_observed);
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_attribute_progress() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(cancellable)]` attribute passes a cancellation token from Swift
+/// to the Rust function.
+mod function_attribute_cancellable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(cancellable)]
+                    fn some_function(arg: u8) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: u8,
+                cancellation_token: *const swift_bridge::cancellation::CancellationToken
+            ) -> u32 {
+                super::some_function(
+                    arg,
+                    &unsafe {
+                        swift_bridge::cancellation::CancellationToken::from_ffi_ptr(
+                            cancellation_token
+                        )
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt8, cancellationToken: CancellationToken? = nil) -> UInt32 {
+    __swift_bridge__$some_function(arg, cancellationToken?.ptr)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$some_function(uint8_t arg, void* cancellation_token);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_attribute_cancellable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(timeout)]` attribute generates an async variant of the function
+/// that takes a timeout, and that the variant cancels cancellable functions when it times out.
+mod function_attribute_timeout {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(timeout)]
+                    fn some_function(arg: u8) -> u32;
+
+                    #[swift_bridge(timeout, cancellable)]
+                    fn cancellable_function();
+
+                    #[swift_bridge(timeout)]
+                    fn some_method(&self, #[swift_bridge(label = "withArg")] arg: u8) -> Result<u8, u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt8) -> UInt32 {
+    __swift_bridge__$some_function(arg)
+}
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+public func some_function(_ arg: UInt8, timeout: Double) async throws -> UInt32 {
+    return try await __private__withTimeout(timeout, { _ in
+        some_function(arg)
+    })
+}
+"#,
+            r#"
+@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+public func cancellable_function(timeout: Double) async throws {
+    try await __private__withTimeout(timeout, { cancellationToken in
+        cancellable_function(cancellationToken: cancellationToken)
+    })
+}
+"#,
+            r#"
+    @available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+    public func some_method(withArg arg: UInt8, timeout: Double) async throws -> UInt8 {
+        return try await __private__withTimeout(timeout, { _ in
+            try self.some_method(withArg: arg)
+        })
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_attribute_timeout() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(swift_data)]` attribute exposes `Vec<u8>` and `&[u8]` to Swift
+/// as `Data`.
+mod function_attribute_swift_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_data)]
+                    fn encode(value: u32) -> Vec<u8>;
+
+                    #[swift_bridge(swift_data)]
+                    fn decode(bytes: Vec<u8>) -> u32;
+
+                    #[swift_bridge(swift_data)]
+                    fn checksum(&self, bytes: &[u8], seed: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+public func encode(_ value: UInt32) -> Data {
+    RustVec<UInt8>(ptr: __swift_bridge__$encode(value)).toData()
+}
+public func decode(_ bytes: Data) -> UInt32 {
+    __swift_bridge__$decode({ let val = bytes.toRustVec(); val.isOwned = false; return val.ptr }())
+}
+"#,
+            r#"
+    public func checksum(_ bytes: Data, _ seed: UInt8) -> UInt8 {
+        return bytes.__private__withUInt8Buffer({ bytesAsUInt8Buffer in
+            __swift_bridge__$SomeType$checksum(ptr, bytesAsUInt8Buffer.toFfiSlice(), seed)
+        })
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_attribute_swift_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(non_reentrant)]` attribute guards the method against Swift
+/// re-entering the same object.
+mod function_attribute_non_reentrant {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(non_reentrant)]
+                    fn some_method(&mut self, arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *mut super::SomeType,
+                arg: u8
+            ) -> u8 {
+                let _reentrancy_guard = swift_bridge::reentrancy::enter(this, "SomeType::some_method");
+                (unsafe { &mut *this }).some_method(arg)
+            }
+        })
+    }
+
+    #[test]
+    fn function_attribute_non_reentrant() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(since = "...")]` attribute weakly links the function and adds
+/// it to `RustApi` so that Swift can check whether the loaded Rust library has it.
+mod function_attribute_since {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(since = "1.2")]
+                    fn some_function() -> u8;
+
+                    #[swift_bridge(since = "2.0.1")]
+                    fn some_method(&self);
+
+                    fn unversioned_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension RustApi {
+    public static let some_function = RustApiFunction(name: "some_function", since: "1.2", symbol: "__swift_bridge__$some_function")
+    public enum SomeType {
+        public static let some_method = RustApiFunction(name: "SomeType.some_method", since: "2.0.1", symbol: "__swift_bridge__$SomeType$some_method")
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"uint8_t __swift_bridge__$some_function(void) __attribute__((weak_import));"#,
+            r#"void __swift_bridge__$SomeType$some_method(void* self) __attribute__((weak_import));"#,
+            r#"void __swift_bridge__$unversioned_function(void);"#,
+        ])
+    }
+
+    #[test]
+    fn function_attribute_since() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(get)]` and `#[swift_bridge(set)]` attributes turn a getter and
+/// its setter into a single Swift computed property.
+mod function_attribute_property {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get)]
+                    fn volume(&self) -> u8;
+
+                    #[swift_bridge(set)]
+                    fn set_volume(&mut self, volume: u8);
+
+                    #[swift_bridge(get, swift_name = "displayName")]
+                    fn name(&self) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$set_volume"]
+            pub extern "C" fn __swift_bridge__SomeType_set_volume(
+                this: *mut super::SomeType,
+                volume: u8
+            ) {
+                (unsafe { &mut *this }).set_volume(volume)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeTypeRefMut: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override var volume: UInt8 {
+        get {
+            super.volume
+        }
+        set(volume) {
+            __swift_bridge__$SomeType$set_volume(ptr, volume)
+        }
+    }
+}
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var volume: UInt8 {
+        __swift_bridge__$SomeType$volume(ptr)
+    }
+
+    public var displayName: RustString {
+        RustString(ptr: __swift_bridge__$SomeType$name(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn function_attribute_property() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we read `static` declarations through exported functions, and that Swift reads a
+/// `#[swift_bridge(constant)]` once into a `static let` and a `static` every time through a
+/// `static var`.
+mod function_attribute_constant {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Server;
+
+                    #[swift_bridge(constant)]
+                    static MAX_USERS: u32;
+                    static VERSION: &'static str;
+                }
+
+                extern "Rust" {
+                    static BUILD_NUMBER: u64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Server$MAX_USERS"]
+                pub extern "C" fn __swift_bridge__Server_MAX_USERS() -> u32 {
+                    super::Server::MAX_USERS
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Server$VERSION"]
+                pub extern "C" fn __swift_bridge__Server_VERSION() -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str(super::Server::VERSION)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$BUILD_NUMBER"]
+                pub extern "C" fn __swift_bridge__BUILD_NUMBER() -> u64 {
+                    super::BUILD_NUMBER
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class ServerRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public static let MAX_USERS: UInt32 = __swift_bridge__$Server$MAX_USERS()
+
+    public static var VERSION: RustStr {
+        __swift_bridge__$Server$VERSION()
+    }
+}
+"#,
+            r#"
+public var BUILD_NUMBER: UInt64 {
+    __swift_bridge__$BUILD_NUMBER()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "uint32_t __swift_bridge__$Server$MAX_USERS(void);",
+            "struct RustStr __swift_bridge__$Server$VERSION(void);",
+            "uint64_t __swift_bridge__$BUILD_NUMBER(void);",
+        ])
+    }
+
+    #[test]
+    fn function_attribute_constant() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(throttle_ms = ...)]` attribute coalesces calls to the Swift
+/// function on the Rust side and runs them on the main queue.
+mod function_attribute_throttle {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(throttle_ms = 100)]
+                    fn some_function(reading: f64, label: String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(reading: f64, label: String) {
+                static THROTTLE: swift_bridge::throttle::Throttle =
+                    swift_bridge::throttle::Throttle::new(100u64);
+                THROTTLE.call(move || unsafe {
+                    __swift_bridge__some_function(
+                        reading,
+                        swift_bridge::string::RustString(label).box_into_raw()
+                    )
+                });
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"import Foundation"#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ reading: Double, _ label: UnsafeMutableRawPointer) {
+    DispatchQueue.main.async { some_function(reading: reading, label: RustString(ptr: label)) }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_attribute_throttle() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
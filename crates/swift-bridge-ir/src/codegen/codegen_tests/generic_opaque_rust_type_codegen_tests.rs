@@ -99,7 +99,7 @@ mod monomorphized_generic_opaque_rust_type {
                 this: *mut super::SomeType<u32>
             ) {
                 let this = unsafe { Box::from_raw(this) };
-                drop(this);
+                swift_bridge::scope::drop_or_defer(this);
             }
         })
     }
@@ -489,7 +489,7 @@ mod generic_opaque_rust_type_inner_opaque_ty {
                 this: *mut super::SomeType<super::AnotherType>
             ) {
                 let this = unsafe { Box::from_raw(this) };
-                drop(this);
+                swift_bridge::scope::drop_or_defer(this);
             }
         })
     }
@@ -513,3 +513,120 @@ mod generic_opaque_rust_type_inner_opaque_ty {
         .test();
     }
 }
+
+/// Verify that we can declare methods and initializers on a monomorphized generic opaque Rust type,
+/// and that the methods of each monomorphization are only available on that monomorphization.
+mod generic_opaque_rust_type_methods {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+
+                    type SomeType<u32>;
+                    #[swift_bridge(init)]
+                    fn new(value: u32) -> SomeType<u32>;
+                    fn value(self: &SomeType<u32>) -> u32;
+                    fn set_value(self: &mut SomeType<u32>, value: u32);
+
+                    type SomeType<String>;
+                    fn value(self: &SomeType<String>) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$new"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_new (
+                    value: u32
+                ) -> *mut super::SomeType<u32> {
+                    Box::into_raw(Box::new({
+                        let val: super::SomeType<u32> = super::SomeType::new(value);
+                        val
+                    })) as *mut super::SomeType<u32>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$value"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_value (
+                    this: *mut super::SomeType<u32>
+                ) -> u32 {
+                    (unsafe { &*this }).value()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$set_value"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_set_value (
+                    this: *mut super::SomeType<u32>,
+                    value: u32
+                ) {
+                    (unsafe { &mut *this }).set_value(value)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$String$value"]
+                pub extern "C" fn __swift_bridge__SomeType_String_value (
+                    this: *mut super::SomeType<String>
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString((unsafe { &*this }).value()).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType where A == UInt32 {
+    public convenience init(_ value: UInt32) {
+        self.init(ptr: __swift_bridge__$SomeType$u32$new(value))
+    }
+}
+extension SomeTypeRefMut where A == UInt32 {
+    public func set_value(_ value: UInt32) {
+        __swift_bridge__$SomeType$u32$set_value(ptr, value)
+    }
+}
+extension SomeTypeRef where A == UInt32 {
+    public func value() -> UInt32 {
+        __swift_bridge__$SomeType$u32$value(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef where A == RustString {
+    public func value() -> RustString {
+        RustString(ptr: __swift_bridge__$SomeType$String$value(ptr))
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$SomeType$u32$new(uint32_t value);",
+            "uint32_t __swift_bridge__$SomeType$u32$value(void* self);",
+            "void __swift_bridge__$SomeType$u32$set_value(void* self, uint32_t value);",
+            "void* __swift_bridge__$SomeType$String$value(void* self);",
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_methods() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,111 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the lifetimes and the where-clause of an extern "Rust" method are elided from the
+/// exported function.
+mod extern_rust_method_with_lifetimes {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_method<'a, 'b>(&'a self, arg: &'b str) -> &'a str where 'b: 'a;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *mut super::SomeType,
+                arg: swift_bridge::string::RustStr
+            ) -> swift_bridge::string::RustStr {
+                swift_bridge::string::RustStr::from_str(
+                    (unsafe { &*this }).some_method(unsafe { arg.to_str_unchecked() })
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public func some_method<GenericToRustStr: ToRustStr>(_ arg: GenericToRustStr) -> RustStr {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct RustStr __swift_bridge__$SomeType$some_method(void* self, struct RustStr arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_with_lifetimes() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the lifetimes of an extern "Swift" method are elided from the Rust method that
+/// calls Swift.
+mod extern_swift_method_with_lifetimes {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type RustType;
+                }
+
+                extern "Swift" {
+                    type SwiftType;
+
+                    fn some_method<'a>(&'a self, arg: &'a RustType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl SwiftType {
+                pub fn some_method(&self, arg: &super::RustType) {
+                    unsafe {
+                        __swift_bridge__SwiftType_some_method(
+                            swift_bridge::PointerToSwiftType(self.0),
+                            arg as *const super::RustType
+                        )
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn extern_swift_method_with_lifetimes() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,140 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::codegen::CodegenConfig;
+use crate::test_utils::parse_ok;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Verify that a module can have any number of extern blocks, and that the generated code
+/// follows the order in which the functions were declared.
+mod functions_generated_in_declaration_order {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn b_first(arg: &[u16]) -> (u8, u16);
+                }
+
+                extern "Swift" {
+                    fn swift_between(arg: (i32, bool));
+                }
+
+                extern "Rust" {
+                    fn a_second(arg: &[u8]) -> (String, u32);
+                }
+            }
+        }
+    }
+
+    /// The FFI representations of the tuples are sorted by name, and the functions follow the
+    /// order of the extern blocks.
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Exact(quote! {
+            #[allow(non_snake_case)]
+            mod ffi {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_I32Bool(i32, bool);
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_StringU32(*mut swift_bridge::string::RustString, u32);
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_U8U16(u8, u16);
+
+                #[export_name = "__swift_bridge__$b_first"]
+                pub extern "C" fn __swift_bridge__b_first(arg: swift_bridge::FfiSlice<u16>) -> __swift_bridge__tuple_U8U16 {
+                    {
+                        let val = super::b_first(arg.as_slice());
+                        __swift_bridge__tuple_U8U16(val.0, val.1)
+                    }
+                }
+
+                #[export_name = "__swift_bridge__$a_second"]
+                pub extern "C" fn __swift_bridge__a_second(arg: swift_bridge::FfiSlice<u8>) -> __swift_bridge__tuple_StringU32 {
+                    {
+                        let val = super::a_second(arg.as_slice());
+                        __swift_bridge__tuple_StringU32(swift_bridge::string::RustString(val.0).box_into_raw(), val.1)
+                    }
+                }
+
+                pub fn swift_between(arg: (i32, bool)) {
+                    unsafe {
+                        __swift_bridge__swift_between({
+                            let val = arg;
+                            __swift_bridge__tuple_I32Bool(val.0, val.1)
+                        })
+                    }
+                }
+
+                #[allow(improper_ctypes)]
+                extern "C" {
+                    #[link_name = "__swift_bridge__$swift_between"]
+                    fn __swift_bridge__swift_between(arg: __swift_bridge__tuple_I32Bool);
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ExactAfterTrim(
+            r#"
+public func b_first(_ arg: UnsafeBufferPointer<UInt16>) -> (UInt8, UInt16) {
+    { let val = __swift_bridge__$b_first(arg.toFfiSlice()); return (val._0, val._1); }()
+}
+@_cdecl("__swift_bridge__$swift_between")
+func __swift_bridge__swift_between (_ arg: __swift_bridge__$tuple$I32Bool) {
+    swift_between(arg: { let val = arg; return (val._0, val._1); }())
+}
+
+public func a_second(_ arg: UnsafeBufferPointer<UInt8>) -> (RustString, UInt32) {
+    { let val = __swift_bridge__$a_second(arg.toFfiSlice()); return (RustString(ptr: val._0), val._1); }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+struct __swift_bridge__$tuple$U8U16 __swift_bridge__$b_first(struct __private__FfiSlice arg);
+struct __swift_bridge__$tuple$StringU32 __swift_bridge__$a_second(struct __private__FfiSlice arg);
+typedef struct __swift_bridge__$tuple$U8U16 { uint8_t _0; uint16_t _1; } __swift_bridge__$tuple$U8U16;
+typedef struct __swift_bridge__$tuple$I32Bool { int32_t _0; bool _1; } __swift_bridge__$tuple$I32Bool;
+typedef struct __swift_bridge__$tuple$StringU32 { void* _0; uint32_t _1; } __swift_bridge__$tuple$StringU32;
+"#,
+        )
+    }
+
+    #[test]
+    fn functions_generated_in_declaration_order() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+
+    /// Verify that generating the code for the same module always gives the same output.
+    #[test]
+    fn generated_code_is_stable() {
+        let generate = || {
+            let module = parse_ok(bridge_module_tokens());
+            let config = CodegenConfig::no_features_enabled();
+            (
+                module.to_token_stream().to_string(),
+                module.generate_swift(&config),
+                module.generate_c_header(&config),
+            )
+        };
+
+        let first = generate();
+        for _ in 0..20 {
+            assert_eq!(generate(), first);
+        }
+    }
+}
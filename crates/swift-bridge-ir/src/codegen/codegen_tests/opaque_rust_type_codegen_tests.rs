@@ -25,7 +25,7 @@ mod extern_rust_type {
                 this: *mut super::SomeType
             ) {
                 let this = unsafe { Box::from_raw(this) };
-                drop(this);
+                swift_bridge::scope::drop_or_defer(this);
             }
         })
     }
@@ -129,6 +129,7 @@ extension HashableTypeRef: Hashable{
 uint64_t __swift_bridge__$HashableType$_hash(void* self);
     "#,
             r#"
+#include <stdint.h>
 "#,
         ])
     }
@@ -145,16 +146,17 @@ uint64_t __swift_bridge__$HashableType$_hash(void* self);
     }
 }
 
-/// Test code generation for an extern "Rust" type that implements Equatable.
-mod extern_rust_equatable_type {
+/// Test code generation for an extern "Rust" Copy type that implements Hashable.
+/// Copy types are passed by value instead of by pointer.
+mod extern_rust_copy_hashable_type {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Equatable)]
-                    type EquatableType;
+                    #[swift_bridge(Copy(4), Hashable, Equatable)]
+                    type HashableType;
                 }
             }
         }
@@ -162,12 +164,15 @@ mod extern_rust_equatable_type {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
-        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
-            lhs: *const super::EquatableType,
-            rhs: *const super::EquatableType
-        ) -> bool {
-            unsafe { &*lhs == &*rhs }
+        #[export_name = "__swift_bridge__$HashableType$_hash"]
+        pub extern "C" fn __swift_bridge__HashableType__hash (
+            this: __swift_bridge__HashableType,
+        ) -> u64 {
+            use std::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+            let mut s = DefaultHasher::new();
+            this.into_rust_repr().hash(&mut s);
+            s.finish()
         }
         })
     }
@@ -175,9 +180,9 @@ mod extern_rust_equatable_type {
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension EquatableTypeRef: Equatable {
-    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
-        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+extension HashableType: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(__swift_bridge__$HashableType$_hash(self.intoFfiRepr()))
     }
 }
 "#,
@@ -185,19 +190,16 @@ extension EquatableTypeRef: Equatable {
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
-            r#"
-bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
-    "#,
+        ExpectedCHeader::ContainsAfterTrim(
             r#"
-#include <stdint.h>
-#include <stdbool.h>
+typedef struct __swift_bridge__$Option$HashableType { bool is_some; __swift_bridge__$HashableType val; } __swift_bridge__$Option$HashableType;
+uint64_t __swift_bridge__$HashableType$_hash(__swift_bridge__$HashableType self);
 "#,
-        ])
+        )
     }
 
     #[test]
-    fn extern_rust_equatable_type() {
+    fn extern_rust_copy_hashable_type() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
@@ -208,104 +210,55 @@ bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
     }
 }
 
-/// Test code generation for an extern "Rust" type that implements Copy.
-mod extern_rust_copy_type {
+/// Test code generation for an extern "Rust" type that implements Comparable.
+mod extern_rust_comparable_type {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Copy(32))]
-                    type SomeType;
+                    #[swift_bridge(Equatable, Comparable)]
+                    type ComparableType;
                 }
             }
         }
     }
 
-    // We use a somewhat hacky approach to asserting that the Copy size is correct at compile time.
-    // In the future we'd prefer something like
-    //  `assert_eq!(std::mem::size_of::<super::SomeType>(), 32usize);`
-    // If compile time assertions are ever supported by Rust.
-    // https://github.com/rust-lang/rfcs/issues/2790
     fn expected_rust_tokens() -> ExpectedRustTokens {
-        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
-            contains: vec![
-                quote! {
-                    const _: () = {
-                        let _: [u8; std::mem::size_of::<super::SomeType>()] = [0; 32usize];
-                        fn _assert_copy() {
-                            swift_bridge::copy_support::assert_copy::<super::SomeType>();
-                        }
-                    };
-                },
-                quote! {
-                    #[repr(C)]
-                    #[doc(hidden)]
-                    pub struct __swift_bridge__SomeType([u8; 32usize]);
-                    impl __swift_bridge__SomeType {
-                        #[inline(always)]
-                        fn into_rust_repr(self) -> super::SomeType {
-                            unsafe { std::mem::transmute(self) }
-                        }
-
-                        #[inline(always)]
-                        fn from_rust_repr(repr: super::SomeType) -> Self {
-                            unsafe { std::mem::transmute(repr) }
-                        }
-                    }
-
-                    #[repr(C)]
-                    #[doc(hidden)]
-                    pub struct __swift_bridge__Option_SomeType {
-                        is_some: bool,
-                        val: std::mem::MaybeUninit<__swift_bridge__SomeType>
-                    }
-                },
-            ],
-            // Copy types don't need a function for freeing memory.
-            does_not_contain: vec![quote! {
-                __swift_bridge__SomeType__free
-            }],
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$ComparableType$_lt"]
+        pub extern "C" fn __swift_bridge__ComparableType__lt (
+            lhs: *const super::ComparableType,
+            rhs: *const super::ComparableType
+        ) -> bool {
+            (unsafe { &*lhs }) < (unsafe { &*rhs })
         }
+        })
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
-        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
-            r#"
-public struct SomeType {
-    fileprivate var bytes: __swift_bridge__$SomeType
-
-    func intoFfiRepr() -> __swift_bridge__$SomeType {
-        bytes
-    }
-}
-"#,
+        ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension __swift_bridge__$SomeType {
-    func intoSwiftRepr() -> SomeType {
-        SomeType(bytes: self)
+extension ComparableTypeRef: Comparable {
+    public static func < (lhs: ComparableTypeRef, rhs: ComparableTypeRef) -> Bool {
+        __swift_bridge__$ComparableType$_lt(lhs.ptr, rhs.ptr)
     }
 }
 "#,
-        ])
+        )
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
-            r#"
-typedef struct __swift_bridge__$SomeType { uint8_t bytes[32]; } __swift_bridge__$SomeType;
-typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__$SomeType val; } __swift_bridge__$Option$SomeType;
-    "#,
+        ExpectedCHeader::ContainsAfterTrim(
             r#"
-#include <stdint.h>
-#include <stdbool.h>
+bool __swift_bridge__$ComparableType$_lt(void* lhs, void* rhs);
 "#,
-        ])
+        )
     }
 
     #[test]
-    fn extern_rust_copy_type() {
+    fn extern_rust_comparable_type() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
@@ -316,75 +269,57 @@ typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__
     }
 }
 
-/// Verify that we properly generate a method for a Copy opaque Rust type.
-mod extern_rust_copy_type_method {
+/// Test code generation for an extern "Rust" Copy type that implements Comparable.
+/// Copy types are passed by value instead of by pointer.
+mod extern_rust_copy_comparable_type {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Copy(32))]
-                    type SomeType;
-
-                    fn some_method(self);
-                    fn some_method_ref(&self);
+                    #[swift_bridge(Copy(4), Equatable, Comparable)]
+                    type ComparableType;
                 }
             }
         }
     }
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
-        ExpectedRustTokens::ContainsMany(vec![
-            quote! {
-                pub extern "C" fn __swift_bridge__SomeType_some_method (
-                    this: __swift_bridge__SomeType
-                ) {
-                    this.into_rust_repr().some_method()
-                }
-            },
-            quote! {
-                pub extern "C" fn __swift_bridge__SomeType_some_method_ref (
-                    this: __swift_bridge__SomeType
-                ) {
-                    this.into_rust_repr().some_method_ref()
-                }
-            },
-        ])
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$ComparableType$_lt"]
+        pub extern "C" fn __swift_bridge__ComparableType__lt (
+            lhs: __swift_bridge__ComparableType,
+            rhs: __swift_bridge__ComparableType
+        ) -> bool {
+            lhs.into_rust_repr() < rhs.into_rust_repr()
+        }
+        })
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
-        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
-            r#"
-extension SomeType {
-    public func some_method() {
-        __swift_bridge__$SomeType$some_method(self.bytes)
-    }
-}
-"#,
+        ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension SomeType {
-    public func some_method_ref() {
-        __swift_bridge__$SomeType$some_method_ref(self.bytes)
+extension ComparableType: Comparable {
+    public static func < (lhs: ComparableType, rhs: ComparableType) -> Bool {
+        __swift_bridge__$ComparableType$_lt(lhs.intoFfiRepr(), rhs.intoFfiRepr())
     }
 }
 "#,
-        ])
+        )
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
+        ExpectedCHeader::ContainsAfterTrim(
             r#"
-void __swift_bridge__$SomeType$some_method(struct __swift_bridge__$SomeType this);
-    "#,
-            r#"
-void __swift_bridge__$SomeType$some_method_ref(struct __swift_bridge__$SomeType this);
-    "#,
-        ])
+bool __swift_bridge__$ComparableType$_partial_eq(__swift_bridge__$ComparableType lhs, __swift_bridge__$ComparableType rhs);
+bool __swift_bridge__$ComparableType$_lt(__swift_bridge__$ComparableType lhs, __swift_bridge__$ComparableType rhs);
+"#,
+        )
     }
 
     #[test]
-    fn extern_rust_copy_type_method() {
+    fn extern_rust_copy_comparable_type() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
@@ -395,19 +330,16 @@ void __swift_bridge__$SomeType$some_method_ref(struct __swift_bridge__$SomeType
     }
 }
 
-/// Test code generation for freestanding Swift function that takes an opaque Rust type argument.
-mod extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg {
+/// Test code generation for an extern "Rust" type that implements Equatable.
+mod extern_rust_equatable_type {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
-            mod foo {
+            mod ffi {
                 extern "Rust" {
-                    type MyType;
-                }
-
-                extern "Swift" {
-                    fn some_function (arg: MyType);
+                    #[swift_bridge(Equatable)]
+                    type EquatableType;
                 }
             }
         }
@@ -415,60 +347,63 @@ mod extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-            pub fn some_function (arg: super::MyType) {
-                unsafe { __swift_bridge__some_function( Box::into_raw(Box::new({
-                    let val: super::MyType = arg;
-                    val
-                })) as *mut super::MyType ) }
-            }
-
-            #[allow(improper_ctypes)]
-            extern "C" {
-                #[link_name = "__swift_bridge__$some_function"]
-                fn __swift_bridge__some_function (arg: *mut super::MyType);
-            }
+        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
+        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
+            lhs: *const super::EquatableType,
+            rhs: *const super::EquatableType
+        ) -> bool {
+            (unsafe { &*lhs }) == (unsafe { &*rhs })
+        }
         })
     }
 
-    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
-        r#"
-@_cdecl("__swift_bridge__$some_function")
-func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
-    some_function(arg: MyType(ptr: arg))
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension EquatableTypeRef: Equatable {
+    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
+        __swift_bridge__$EquatableType$_partial_eq(lhs.ptr, rhs.ptr)
+    }
 }
 "#,
-    );
+        )
+    }
 
-    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
-        r#"
-typedef struct MyType MyType;
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
+    "#,
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
 "#,
-    );
+        ])
+    }
 
     #[test]
-    fn extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg() {
+    fn extern_rust_equatable_type() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
-            expected_swift_code: EXPECTED_SWIFT,
-            expected_c_header: EXPECTED_C_HEADER,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
         }
         .test();
     }
 }
 
-/// Verify that we generated a Swift class with a failable init method.
-mod extern_rust_class_with_failable_init {
+/// Test code generation for an extern "Rust" Copy type that implements Equatable.
+/// Copy types are passed by value instead of by pointer.
+mod extern_rust_copy_equatable_type {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
-            mod foo {
+            mod ffi {
                 extern "Rust" {
-                    type Foo;
-
-                    #[swift_bridge(init)]
-                    fn new() -> Option<Foo>;
+                    #[swift_bridge(Copy(4), Equatable)]
+                    type EquatableType;
                 }
             }
         }
@@ -476,53 +411,1853 @@ mod extern_rust_class_with_failable_init {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-            # [export_name = "__swift_bridge__$Foo$new"]
-            pub extern "C" fn __swift_bridge__Foo_new () -> * mut super :: Foo {
-                if let Some (val) = super :: Foo :: new () {
-                    Box :: into_raw (Box :: new (val))
-                } else {
-                    std :: ptr :: null_mut ()
-                }
-            }
+        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
+        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
+            lhs: __swift_bridge__EquatableType,
+            rhs: __swift_bridge__EquatableType
+        ) -> bool {
+            lhs.into_rust_repr() == rhs.into_rust_repr()
+        }
         })
     }
 
-    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
-        r#"
-public class Foo: FooRefMut {
-    var isOwned: Bool = true
-
-    public override init(ptr: UnsafeMutableRawPointer) {
-        super.init(ptr: ptr)
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension EquatableType: Equatable {
+    public static func == (lhs: EquatableType, rhs: EquatableType) -> Bool {
+        __swift_bridge__$EquatableType$_partial_eq(lhs.intoFfiRepr(), rhs.intoFfiRepr())
     }
-
-    deinit {
-        if isOwned {
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$EquatableType { uint8_t bytes[4]; } __swift_bridge__$EquatableType;
+typedef struct __swift_bridge__$Option$EquatableType { bool is_some; __swift_bridge__$EquatableType val; } __swift_bridge__$Option$EquatableType;
+bool __swift_bridge__$EquatableType$_partial_eq(__swift_bridge__$EquatableType lhs, __swift_bridge__$EquatableType rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_equatable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Iterator.
+mod extern_rust_iterator_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Iterator(Item = u32))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$next"]
+            pub extern "C" fn __swift_bridge__SomeType_next (this: *mut super::SomeType) -> swift_bridge::option::OptionU32 {
+                if let Some(val) = (unsafe { &mut *this }).next() {
+                    swift_bridge::option::OptionU32 { val, is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType: IteratorProtocol, Sequence {
+    public func makeIterator() -> SomeType {
+        self
+    }
+}
+"#,
+            r#"
+extension SomeTypeRefMut {
+    public func next() -> Optional<UInt32> {
+        __swift_bridge__$SomeType$next(ptr).intoSwiftRepr()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU32 __swift_bridge__$SomeType$next(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_iterator_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Stream.
+mod extern_rust_stream_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Stream(Item = u32))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$next"]
+            pub extern "C" fn __swift_bridge__SomeType_next (
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, swift_bridge::option::OptionU32) -> (),
+                this: *mut super::SomeType
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = swift_bridge::async_support::next_stream_item((unsafe { &mut *this }));
+                let task = async move {
+                    let val = if let Some(val) = fut.await {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    };
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType: AsyncIteratorProtocol, AsyncSequence {
+    public func makeAsyncIterator() -> SomeType {
+        self
+    }
+}
+"#,
+            r#"
+extension SomeTypeRefMut {
+    public func next() async -> Optional<UInt32> {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$next(void* callback_wrapper, void __swift_bridge__$SomeType$next$async(void* callback_wrapper, struct __private__OptionU32 ret), void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_stream_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Clone.
+mod extern_rust_clone_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_clone"]
+            pub extern "C" fn __swift_bridge__SomeType__clone (this: *const super::SomeType) -> *mut super::SomeType {
+                Box::into_raw(Box::new((unsafe { &*this }).clone()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    /// Create a new Rust value using the type's `Clone` implementation.
+    public func clone() -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$_clone(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_clone(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_clone_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that cloning a hardened type inserts the clone into the type's handle table.
+mod extern_rust_hardened_clone_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(hardened, Clone)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_clone"]
+            pub extern "C" fn __swift_bridge__SomeType__clone (this: *const super::SomeType) -> *mut super::SomeType {
+                <super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().insert(
+                    (&*<super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().resolve(this)).clone()
+                )
+            }
+        })
+    }
+
+    #[test]
+    fn extern_rust_hardened_clone_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements LocalizedError.
+/// The error description comes from the type's `Display` implementation.
+mod extern_rust_localized_error_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(LocalizedError)]
+                    type SomeError;
+
+                    fn fallible() -> Result<(), SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$SomeError$_to_string"]
+        pub extern "C" fn __swift_bridge__SomeError__to_string (
+            this: *const super::SomeError
+        ) -> *mut swift_bridge::string::RustString {
+            swift_bridge::string::RustString(
+                (unsafe { &*this }).to_string()
+            ).box_into_raw()
+        }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension SomeError: LocalizedError {
+    public var errorDescription: String? {
+        RustString(ptr: __swift_bridge__$SomeError$_to_string(ptr)).toString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeError$_to_string(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_localized_error_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate SwiftUI Bindings for getter/setter pairs on an extern "Rust" type with
+/// the `Bindings` attribute.
+mod extern_rust_type_bindings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Bindings)]
+                    type Model;
+
+                    fn volume(&self) -> f32;
+                    fn set_volume(&mut self, volume: f32);
+
+                    fn muted(&self) -> bool;
+                    #[swift_bridge(swift_name = "setMuted")]
+                    fn set_muted(&mut self, #[swift_bridge(label = "to")] muted: bool);
+
+                    fn name(&self) -> String;
+                    fn set_name(&mut self, name: String);
+
+                    fn set_unpaired(&mut self, value: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+#if canImport(SwiftUI)
+import SwiftUI
+
+extension ModelRefMut {
+    public var volume_binding: Binding<Float> {
+        Binding(get: { self.volume() }, set: { self.set_volume($0) })
+    }
+
+    public var muted_binding: Binding<Bool> {
+        Binding(get: { self.muted() }, set: { self.setMuted(to: $0) })
+    }
+}
+#endif
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_bindings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate SwiftUI Bindings for a type without the `Bindings` attribute.
+mod extern_rust_type_without_bindings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Model;
+
+                    fn volume(&self) -> f32;
+                    fn set_volume(&mut self, volume: f32);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extern_rust_type_without_bindings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("Binding"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Copy.
+mod extern_rust_copy_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(32))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    // We use a somewhat hacky approach to asserting that the Copy size is correct at compile time.
+    // In the future we'd prefer something like
+    //  `assert_eq!(std::mem::size_of::<super::SomeType>(), 32usize);`
+    // If compile time assertions are ever supported by Rust.
+    // https://github.com/rust-lang/rfcs/issues/2790
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![
+                quote! {
+                    const _: () = {
+                        let _: [u8; std::mem::size_of::<super::SomeType>()] = [0; 32usize];
+                        fn _assert_copy() {
+                            swift_bridge::copy_support::assert_copy::<super::SomeType>();
+                        }
+                    };
+                },
+                quote! {
+                    #[repr(C)]
+                    #[doc(hidden)]
+                    pub struct __swift_bridge__SomeType([u8; 32usize]);
+                    impl __swift_bridge__SomeType {
+                        #[inline(always)]
+                        fn into_rust_repr(self) -> super::SomeType {
+                            unsafe { std::mem::transmute(self) }
+                        }
+
+                        #[inline(always)]
+                        fn from_rust_repr(repr: super::SomeType) -> Self {
+                            unsafe { std::mem::transmute(repr) }
+                        }
+                    }
+
+                    #[repr(C)]
+                    #[doc(hidden)]
+                    pub struct __swift_bridge__Option_SomeType {
+                        is_some: bool,
+                        val: std::mem::MaybeUninit<__swift_bridge__SomeType>
+                    }
+                },
+            ],
+            // Copy types don't need a function for freeing memory.
+            does_not_contain: vec![quote! {
+                __swift_bridge__SomeType__free
+            }],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeType {
+    fileprivate var bytes: __swift_bridge__$SomeType
+
+    func intoFfiRepr() -> __swift_bridge__$SomeType {
+        bytes
+    }
+}
+"#,
+            r#"
+extension __swift_bridge__$SomeType {
+    func intoSwiftRepr() -> SomeType {
+        SomeType(bytes: self)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$SomeType { uint8_t bytes[32]; } __swift_bridge__$SomeType;
+typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__$SomeType val; } __swift_bridge__$Option$SomeType;
+    "#,
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_copy_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we properly generate a method for a Copy opaque Rust type.
+mod extern_rust_copy_type_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(32))]
+                    type SomeType;
+
+                    fn some_method(self);
+                    fn some_method_ref(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_some_method (
+                    this: __swift_bridge__SomeType
+                ) {
+                    this.into_rust_repr().some_method()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_some_method_ref (
+                    this: __swift_bridge__SomeType
+                ) {
+                    this.into_rust_repr().some_method_ref()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType {
+    public func some_method() {
+        __swift_bridge__$SomeType$some_method(self.bytes)
+    }
+}
+"#,
+            r#"
+extension SomeType {
+    public func some_method_ref() {
+        __swift_bridge__$SomeType$some_method_ref(self.bytes)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$SomeType$some_method(struct __swift_bridge__$SomeType this);
+    "#,
+            r#"
+void __swift_bridge__$SomeType$some_method_ref(struct __swift_bridge__$SomeType this);
+    "#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_copy_type_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for freestanding Swift function that takes an opaque Rust type argument.
+mod extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type MyType;
+                }
+
+                extern "Swift" {
+                    fn some_function (arg: MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function (arg: super::MyType) {
+                unsafe { __swift_bridge__some_function( Box::into_raw(Box::new({
+                    let val: super::MyType = arg;
+                    val
+                })) as *mut super::MyType ) }
+            }
+
+            #[allow(improper_ctypes)]
+            extern "C" {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function (arg: *mut super::MyType);
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: MyType(ptr: arg))
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+typedef struct MyType MyType;
+"#,
+    );
+
+    #[test]
+    fn extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift functions and methods that take `&` and `&mut` references to
+/// opaque Rust types.
+mod extern_swift_fn_with_ref_and_ref_mut_opaque_rust_type_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type MyType;
+                }
+
+                extern "Swift" {
+                    type SwiftType;
+
+                    fn some_function (arg: &MyType);
+                    fn some_function_mut (arg: &mut MyType);
+                    fn some_method (&self, arg: &mut MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (arg: &super::MyType) {
+                    unsafe { __swift_bridge__some_function(arg as *const super::MyType) }
+                }
+            },
+            quote! {
+                pub fn some_function_mut (arg: &mut super::MyType) {
+                    unsafe { __swift_bridge__some_function_mut(arg as *mut super::MyType) }
+                }
+            },
+            quote! {
+                pub fn some_method (&self, arg: &mut super::MyType) {
+                    unsafe {
+                        __swift_bridge__SwiftType_some_method(
+                            swift_bridge::PointerToSwiftType(self.0),
+                            arg as *mut super::MyType
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function_mut"]
+                fn __swift_bridge__some_function_mut (arg: *mut super::MyType);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: MyTypeRef(ptr: arg))
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$some_function_mut")
+func __swift_bridge__some_function_mut (_ arg: UnsafeMutableRawPointer) {
+    some_function_mut(arg: MyTypeRefMut(ptr: arg))
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SwiftType$some_method")
+func __swift_bridge__SwiftType_some_method (_ this: UnsafeMutableRawPointer, _ arg: UnsafeMutableRawPointer) {
+    Unmanaged<SwiftType>.fromOpaque(this).takeUnretainedValue().some_method(arg: MyTypeRefMut(ptr: arg))
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+typedef struct MyType MyType;
+"#,
+    );
+
+    #[test]
+    fn extern_swift_fn_with_ref_and_ref_mut_opaque_rust_type_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that `labeled_args` initializers use their argument names as labels, so that a type can
+/// have several initializers that take the same argument types.
+mod extern_rust_class_with_labeled_inits {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init, labeled_args)]
+                    fn new(name: &str) -> Foo;
+
+                    #[swift_bridge(init, labeled_args)]
+                    fn with_title(title: &str, #[swift_bridge(label = "_")] age: u8) -> Foo;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                # [export_name = "__swift_bridge__$Foo$new"]
+                pub extern "C" fn __swift_bridge__Foo_new (
+                    name: swift_bridge::string::RustStr
+                ) -> * mut super :: Foo {
+                    Box :: into_raw (Box :: new ({
+                        let val : super :: Foo = super :: Foo :: new (unsafe { name . to_str_unchecked () }) ;
+                        val
+                    })) as * mut super :: Foo
+                }
+            },
+            quote! {
+                # [export_name = "__swift_bridge__$Foo$with_title"]
+                pub extern "C" fn __swift_bridge__Foo_with_title (
+                    title: swift_bridge::string::RustStr,
+                    age: u8
+                ) -> * mut super :: Foo {
+                    Box :: into_raw (Box :: new ({
+                        let val : super :: Foo = super :: Foo :: with_title (unsafe { title . to_str_unchecked () }, age) ;
+                        val
+                    })) as * mut super :: Foo
+                }
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+extension Foo {
+    public convenience init<GenericToRustStr: ToRustStr>(name: GenericToRustStr) {
+        self.init(ptr: name.toRustStr({ nameAsRustStr in
+            __swift_bridge__$Foo$new(nameAsRustStr)
+        }))
+    }
+
+    public convenience init<GenericToRustStr: ToRustStr>(title: GenericToRustStr, _ age: UInt8) {
+        self.init(ptr: title.toRustStr({ titleAsRustStr in
+            __swift_bridge__$Foo$with_title(titleAsRustStr, age)
+        }))
+    }
+}
+"#,
+    );
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$Foo$new(struct RustStr name);",
+            "void* __swift_bridge__$Foo$with_title(struct RustStr title, uint8_t age);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_class_with_labeled_inits() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generated a Swift class with a failable init method.
+mod extern_rust_class_with_failable_init {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Option<Foo>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            # [export_name = "__swift_bridge__$Foo$new"]
+            pub extern "C" fn __swift_bridge__Foo_new () -> * mut super :: Foo {
+                if let Some (val) = super :: Foo :: new () {
+                    Box :: into_raw (Box :: new (val))
+                } else {
+                    std :: ptr :: null_mut ()
+                }
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+public class Foo: FooRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
             __swift_bridge__$Foo$_free(ptr)
         }
     }
 }
-extension Foo {
-    public convenience init?() {
-        guard let val = __swift_bridge__$Foo$new() else { return nil }; self.init(ptr: val)
+extension Foo {
+    public convenience init?() {
+        guard let val = __swift_bridge__$Foo$new() else { return nil }; self.init(ptr: val)
+    }
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void* __swift_bridge__$Foo$new(void);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_class_with_failable_init() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generated a Swift class with a throwing init method.
+mod extern_rust_class_with_throwing_init {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+                    type SomeErr;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Result<Foo, SomeErr>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            # [export_name = "__swift_bridge__$Foo$new"]
+            pub extern "C" fn __swift_bridge__Foo_new () -> swift_bridge::result::ResultPtrAndPtr {
+                match super :: Foo :: new () {
+                    Ok(ok) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: true,
+                            ok_or_err: Box::into_raw(Box::new({
+                                let val: super::Foo = ok;
+                                val
+                            })) as *mut super::Foo as *mut std::ffi::c_void
+                        }
+                    }
+                    Err(err) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: false,
+                            ok_or_err: Box::into_raw(Box::new({
+                                let val: super::SomeErr = err;
+                                val
+                            })) as *mut super::SomeErr as *mut std::ffi::c_void
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+extension Foo {
+    public convenience init() throws {
+        let val = __swift_bridge__$Foo$new(); if val.is_ok { self.init(ptr: val.ok_or_err!) } else { throw SomeErr(ptr: val.ok_or_err!) }
+    }
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$Foo$new(void);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_class_with_throwing_init() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a throwing initializer when the error type is passed by value, in which
+/// case the Result is passed using a custom FFI struct.
+mod extern_rust_class_with_throwing_init_custom_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                enum SomeErrEnum {
+                    Variant1,
+                    Variant2,
+                }
+
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Result<Foo, SomeErrEnum>;
+                }
+            }
+        }
+    }
+
+    const EXPECTED_SWIFT: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+extension Foo {
+    public convenience init() throws {
+        let val = __swift_bridge__$Foo$new(); switch val.tag { case __swift_bridge__$ResultFooAndSomeErrEnum$ResultOk: self.init(ptr: val.payload.ok) case __swift_bridge__$ResultFooAndSomeErrEnum$ResultErr: throw val.payload.err.intoSwiftRepr() default: fatalError() }
+    }
+}
+"#,
+    );
+
+    #[test]
+    fn extern_rust_class_with_throwing_init_custom_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: EXPECTED_SWIFT,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the free function of an extern "Rust" type with the `on_drop` attribute calls the
+/// on drop function before dropping the type.
+mod extern_rust_type_on_drop {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(on_drop = a::b::close)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_free"]
+            pub extern "C" fn __swift_bridge__SomeType__free (this: *mut super::SomeType) {
+                let mut this = unsafe { Box::from_raw(this) };
+                super::a::b::close(&mut this);
+                drop(this);
+            }
+        })
+    }
+
+    #[test]
+    fn extern_rust_type_on_drop() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" type with the `manual_release` attribute is freed by a `release()`
+/// method instead of when the Swift class is deinitialized.
+mod extern_rust_type_manual_release {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(manual_release)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeType: SomeTypeRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public func release() {
+        if isOwned {
+            isOwned = false
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_manual_release() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" type with the `trait_object` attribute is bridged as a
+/// `Box<dyn Trait>`.
+mod extern_rust_type_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(trait_object)]
+                    type SomeTrait;
+
+                    fn some_method(&self) -> u8;
+                    fn new_some_trait() -> SomeTrait;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeTrait$some_method"]
+                pub extern "C" fn __swift_bridge__SomeTrait_some_method(
+                    this: *mut Box<dyn super::SomeTrait>
+                ) -> u8 {
+                    (unsafe { &*this }).some_method()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_some_trait"]
+                pub extern "C" fn __swift_bridge__new_some_trait() -> *mut Box<dyn super::SomeTrait> {
+                    Box::into_raw(Box::new({
+                        let val: Box<dyn super::SomeTrait> = super::new_some_trait();
+                        val
+                    })) as *mut Box<dyn super::SomeTrait>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeTrait$_free"]
+                pub extern "C" fn __swift_bridge__SomeTrait__free(
+                    this: *mut Box<dyn super::SomeTrait>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    swift_bridge::scope::drop_or_defer(this);
+                }
+            },
+            quote! {
+                pub extern "C" fn _new() -> *mut Vec<Box<dyn super::SomeTrait> > {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::ContainsAfterTrim(
+                r#"
+public class SomeTrait: SomeTraitRefMut {
+"#,
+            ),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" type with the `hardened` attribute is passed to Swift as a handle
+/// that gets resolved through the type's handle table.
+mod extern_rust_type_hardened {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(hardened)]
+                    type SomeType;
+
+                    fn some_method(&mut self) -> u8;
+                    fn new_some_type() -> SomeType;
+                    fn take_some_type(arg: SomeType);
+                    fn borrow_some_type(arg: &SomeType, maybe: Option<&SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![
+                quote! {
+                    #[export_name = "__swift_bridge__$borrow_some_type"]
+                    pub extern "C" fn __swift_bridge__borrow_some_type(
+                        arg: *const super::SomeType,
+                        maybe: *const super::SomeType
+                    ) {
+                        super::borrow_some_type(
+                            &*<super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().resolve(arg),
+                            if maybe.is_null() {
+                                None
+                            } else {
+                                Some(<super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().resolve(maybe))
+                            }
+                            .as_deref()
+                        )
+                    }
+                },
+                quote! {
+                    #[export_name = "__swift_bridge__$SomeType$some_method"]
+                    pub extern "C" fn __swift_bridge__SomeType_some_method(
+                        this: *mut super::SomeType
+                    ) -> u8 {
+                        (&mut *<super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().resolve(this)).some_method()
+                    }
+                },
+                quote! {
+                    #[export_name = "__swift_bridge__$new_some_type"]
+                    pub extern "C" fn __swift_bridge__new_some_type() -> *mut super::SomeType {
+                        <super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().insert({
+                            let val: super::SomeType = super::new_some_type();
+                            val
+                        })
+                    }
+                },
+                quote! {
+                    #[export_name = "__swift_bridge__$take_some_type"]
+                    pub extern "C" fn __swift_bridge__take_some_type(arg: *mut super::SomeType) {
+                        super::take_some_type(<super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().remove(arg))
+                    }
+                },
+                quote! {
+                    #[export_name = "__swift_bridge__$SomeType$_free"]
+                    pub extern "C" fn __swift_bridge__SomeType__free(this: *mut super::SomeType) {
+                        let this = <super::SomeType as swift_bridge::handle_table::Hardened>::handle_table().remove(this);
+                        drop(this);
+                    }
+                },
+                quote! {
+                    impl swift_bridge::handle_table::Hardened for super::SomeType {
+                        fn handle_table() -> &'static swift_bridge::handle_table::HandleTable<Self> {
+                            static HANDLE_TABLE: swift_bridge::handle_table::HandleTable<super::SomeType> =
+                                swift_bridge::handle_table::HandleTable::new();
+                            &HANDLE_TABLE
+                        }
+                    }
+                },
+            ],
+            does_not_contain: vec![quote! { Box::from_raw }, quote! { Box::into_raw }],
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("intoRawPointer")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("Vec_SomeType")
+    }
+
+    #[test]
+    fn extern_rust_type_hardened() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate methods for converting an extern "Rust" type to and from a raw pointer.
+mod extern_rust_type_raw_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    /// Unsafe. Gives up ownership of the Rust value and returns a pointer to it.
+    ///
+    /// The pointer must be passed to `init(fromRawPointer:)` exactly once, or to
+    /// `swift_bridge::raw_pointer::from_raw_pointer` in Rust, or else the Rust value is leaked.
+    public func intoRawPointer() -> UnsafeMutableRawPointer {
+        isOwned = false
+        return ptr
+    }
+
+    /// Unsafe. Takes ownership of a pointer that was returned by `intoRawPointer()`, or by
+    /// `swift_bridge::raw_pointer::into_raw_pointer` in Rust.
+    public convenience init(fromRawPointer ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr)
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_raw_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate raw pointer methods for types that are declared elsewhere.
+mod extern_rust_already_declared_type_raw_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(already_declared)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extern_rust_already_declared_type_raw_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("intoRawPointer"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate scoped `with` accessors for methods that return references to opaque
+/// Rust types.
+mod extern_rust_type_scoped_accessors {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type Settings;
+
+                    fn settings(self: &SomeType) -> &Settings;
+                    fn audio_settings(self: &mut SomeType) -> &mut Settings;
+                    #[swift_bridge(swift_name = "renamedSettings")]
+                    fn settings_to_rename(self: &SomeType) -> &Settings;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRefMut {
+    public func withAudioSettings<R>(_ body: (SettingsRefMut) throws -> R) rethrows -> R {
+        try body(self.audio_settings())
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func withSettings<R>(_ body: (SettingsRef) throws -> R) rethrows -> R {
+        try body(self.settings())
+    }
+
+    public func withRenamedSettings<R>(_ body: (SettingsRef) throws -> R) rethrows -> R {
+        try body(self.renamedSettings())
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_type_scoped_accessors() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate scoped accessors for methods that take arguments or that return
+/// owned values.
+mod extern_rust_type_no_scoped_accessors {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type Settings;
+
+                    fn settings(self: &SomeType) -> Settings;
+                    fn settings_at(self: &SomeType, index: usize) -> &Settings;
+                    fn name(self: &SomeType) -> &str;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extern_rust_type_no_scoped_accessors() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("rethrows"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that methods on an `#[swift_bridge(interior_mut)]` type are called on the value that
+/// the type borrows, and that `&mut self` methods can be called through a shared Swift reference.
+mod extern_rust_type_interior_mut {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(interior_mut)]
+                    type SomeType;
+
+                    fn count(&self) -> u32;
+                    fn increment(&mut self);
+                    fn finish(self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$count"]
+                pub extern "C" fn __swift_bridge__SomeType_count(
+                    this: *mut super::SomeType
+                ) -> u32 {
+                    swift_bridge::interior_mut::InteriorMut::with_inner(unsafe { &*this }, |this| this.count())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$increment"]
+                pub extern "C" fn __swift_bridge__SomeType_increment(
+                    this: *mut super::SomeType
+                ) {
+                    swift_bridge::interior_mut::InteriorMut::with_inner_mut(unsafe { &*this }, |this| this.increment())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$finish"]
+                pub extern "C" fn __swift_bridge__SomeType_finish(
+                    this: *mut super::SomeType
+                ) -> u32 {
+                    swift_bridge::interior_mut::InteriorMut::into_inner(* unsafe { Box::from_raw(this) }).finish()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public func count() -> UInt32 {
+        __swift_bridge__$SomeType$count(ptr)
+    }
+
+    public func increment() {
+        __swift_bridge__$SomeType$increment(ptr)
     }
 }
 "#,
-    );
+        )
+    }
 
-    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
-        r#"
-void* __swift_bridge__$Foo$new(void);
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$increment(void* self);
 "#,
-    );
+        )
+    }
 
     #[test]
-    fn extern_rust_class_with_failable_init() {
+    fn extern_rust_type_interior_mut() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
-            expected_swift_code: EXPECTED_SWIFT,
-            expected_c_header: EXPECTED_C_HEADER,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a Swift struct for an extern "Rust" type that uses
+/// `#[swift_bridge(swift_repr = "struct")]`, and that Rust is given a clone whenever it takes
+/// ownership.
+mod extern_rust_type_swift_repr_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_repr = "struct", Equatable)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    fn a_ref_method(&self) -> u32;
+                    fn an_owned_method(self);
+
+                    fn take_owned(arg: SomeType);
+                    fn return_ref(arg: &SomeType) -> &SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_clone"]
+            pub extern "C" fn __swift_bridge__SomeType__clone (this: *const super::SomeType) -> *mut super::SomeType {
+                Box::into_raw(Box::new((unsafe { &*this }).clone()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeType {
+    fileprivate let storage: __private__SomeTypeStorage
+
+    var ptr: UnsafeMutableRawPointer {
+        storage.ptr
+    }
+
+    // Takes ownership of the Rust value, which is freed once the last copy of the struct is gone.
+    init(ptr: UnsafeMutableRawPointer) {
+        self.storage = __private__SomeTypeStorage(ptr: ptr)
+    }
+
+    func cloneForRust() -> UnsafeMutableRawPointer {
+        __swift_bridge__$SomeType$_clone(ptr)
+    }
+}
+final class __private__SomeTypeStorage {
+    let ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$SomeType$_free(ptr)
+    }
+}
+extension SomeType {
+    public init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+    }
+}
+extension SomeType {
+    public func an_owned_method() {
+        __swift_bridge__$SomeType$an_owned_method(cloneForRust())
+    }
+}
+extension SomeType {
+    public func a_ref_method() -> UInt32 {
+        __swift_bridge__$SomeType$a_ref_method(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeType: Equatable {
+    public static func == (lhs: SomeType, rhs: SomeType) -> Bool {
+        __swift_bridge__$SomeType$_partial_eq(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+            r#"
+public func take_owned(_ arg: SomeType) {
+    __swift_bridge__$take_owned(arg.cloneForRust())
+}
+"#,
+            r#"
+public func return_ref(_ arg: SomeType) -> SomeType {
+    SomeType(ptr: __swift_bridge__$SomeType$_clone(__swift_bridge__$return_ref(arg.ptr)))
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_clone(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_swift_repr_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(swift_name = "...")]` renames an extern "Rust" type on the Swift
+/// side while keeping the names of the symbols that Rust exports.
+mod extern_rust_type_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "User")]
+                    type RustUser;
+
+                    #[swift_bridge(swift_name = "userName")]
+                    fn get_user_name(&self) -> String;
+
+                    fn find_user(id: u32) -> Option<RustUser>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$RustUser$_free"]
+                pub extern "C" fn __swift_bridge__RustUser__free (this: *mut super::RustUser) {
+                    let this = unsafe { Box::from_raw(this) };
+                    swift_bridge::scope::drop_or_defer(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$RustUser$get_user_name"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func find_user(_ id: UInt32) -> Optional<User> {
+    { let val = __swift_bridge__$find_user(id); if val != nil { return User(ptr: val!) } else { return nil } }()
+}
+"#,
+            r#"
+public class User: UserRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$RustUser$_free(ptr)
+        }
+    }
+}
+"#,
+            r#"
+extension UserRef {
+    public func userName() -> RustString {
+        RustString(ptr: __swift_bridge__$RustUser$get_user_name(ptr))
+    }
+}
+"#,
+            r#"
+extension User: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_RustUser$new()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$RustUser$_free(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `self` methods give up ownership of the value through `__private__consume`, which
+/// traps if the value was already consumed.
+mod extern_rust_type_into_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn into_bytes(self) -> Vec<u8>;
+                    fn into_nothing(self);
+                    fn finish(self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$into_bytes"]
+            pub extern "C" fn __swift_bridge__SomeType_into_bytes(
+                this: *mut super::SomeType
+            ) -> *mut Vec<u8> {
+                Box::into_raw(Box::new((* unsafe { Box::from_raw(this) }).into_bytes()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public func into_bytes() -> RustVec<UInt8> {
+        RustVec(ptr: __swift_bridge__$SomeType$into_bytes({__private__consume("SomeType.into_bytes()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}()))
+    }
+
+    public func into_nothing() {
+        __swift_bridge__$SomeType$into_nothing({__private__consume("SomeType.into_nothing()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}())
+    }
+
+    public func finish() -> UInt32 {
+        __swift_bridge__$SomeType$finish({__private__consume("SomeType.finish()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$into_bytes(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_type_into_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
         }
         .test();
     }
@@ -24,7 +24,7 @@ mod extern_swift_freestanding_fn_with_owned_opaque_swift_type_arg {
             }
 
             #[repr(C)]
-            pub struct MyType(*mut std::ffi::c_void);
+            pub struct MyType(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for MyType {
                 fn drop (&mut self) {
@@ -183,3 +183,152 @@ func __swift_bridge__SomeType_some_method (_ this: UnsafeMutableRawPointer) -> U
         .test();
     }
 }
+
+/// Verify that `#[swift_bridge(swift_name = "...")]` lets an extern "Swift" type refer to a Swift
+/// class with a different name, while keeping the names of the symbols that Swift exports.
+mod test_extern_swift_type_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(swift_name = "Renderer")]
+                    type SwiftRenderer;
+
+                    fn draw(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[link_name = "__swift_bridge__$SwiftRenderer$draw"]
+            fn __swift_bridge__SwiftRenderer_draw(this: swift_bridge::PointerToSwiftType);
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$SwiftRenderer$draw")
+func __swift_bridge__SwiftRenderer_draw (_ this: UnsafeMutableRawPointer) {
+    Unmanaged<Renderer>.fromOpaque(this).takeUnretainedValue().draw()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SwiftRenderer$_free")
+func __swift_bridge__SwiftRenderer__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<Renderer>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn test_extern_swift_type_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(trait_object)]` extern "Swift" type generates a Swift protocol,
+/// and that Rust receives it as a `Box<dyn Trait>`.
+mod test_extern_swift_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(trait_object)]
+                    type DownloadDelegate;
+
+                    fn on_progress(&self, fraction: f64);
+                    fn should_continue(&mut self, url: &str) -> bool;
+                }
+
+                extern "Rust" {
+                    fn set_delegate(delegate: DownloadDelegate);
+                    fn maybe_set_delegate(delegate: Option<DownloadDelegate>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__set_delegate(delegate: DownloadDelegate) {
+                    super::set_delegate(Box::new(delegate) as Box<dyn super::DownloadDelegate>)
+                }
+            },
+            quote! {
+                Some(Box::new(DownloadDelegate(val as *mut std::ffi::c_void)) as Box<dyn super::DownloadDelegate>)
+            },
+            quote! {
+                impl super::DownloadDelegate for DownloadDelegate {
+                    fn on_progress(&self, fraction: f64) {
+                        DownloadDelegate::on_progress(self, fraction)
+                    }
+                    fn should_continue(&mut self, url: &str) -> bool {
+                        DownloadDelegate::should_continue(self, url)
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func set_delegate(_ delegate: DownloadDelegate) {
+    __swift_bridge__$set_delegate(Unmanaged.passRetained(delegate as AnyObject).toOpaque())
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$DownloadDelegate$on_progress")
+func __swift_bridge__DownloadDelegate_on_progress (_ this: UnsafeMutableRawPointer, _ fraction: Double) {
+    (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! DownloadDelegate).on_progress(fraction: fraction)
+}
+"#,
+            r#"
+public protocol DownloadDelegate: AnyObject {
+    func on_progress(fraction: Double)
+    func should_continue(url: RustStr) -> Bool
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$DownloadDelegate$_free")
+func __swift_bridge__DownloadDelegate__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<AnyObject>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim("void __swift_bridge__$set_delegate(void* delegate);")
+    }
+
+    #[test]
+    fn test_extern_swift_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
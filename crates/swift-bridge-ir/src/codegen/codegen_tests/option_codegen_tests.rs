@@ -685,7 +685,7 @@ mod extern_rust_fn_return_option_opaque_swift_type {
             },
             quote! {
                 #[repr(C)]
-                pub struct SomeSwiftType(*mut std::ffi::c_void);
+                pub struct SomeSwiftType(pub(crate) *mut std::ffi::c_void);
             },
         ])
     }
@@ -937,7 +937,7 @@ mod extern_rust_fn_with_option_opaque_swift_type_arg {
             },
             quote! {
                 #[repr(C)]
-                pub struct SomeSwiftType(*mut std::ffi::c_void);
+                pub struct SomeSwiftType(pub(crate) *mut std::ffi::c_void);
             },
         ])
     }
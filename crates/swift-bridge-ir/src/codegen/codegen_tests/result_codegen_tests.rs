@@ -781,3 +781,162 @@ typedef struct __swift_bridge__$ResultVoidAndSomeErrStruct{__swift_bridge__$Resu
         .test();
     }
 }
+
+/// Verify that an opaque Rust type with the `Error` attribute conforms to Swift's `Error`
+/// protocol, so that Swift can throw it.
+mod extern_rust_fn_return_result_opaque_rust_err_conforms_to_error {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeOkType;
+                    #[swift_bridge(Error)]
+                    type SomeErrType;
+                    #[swift_bridge(Error, swift_repr = "struct")]
+                    type AnotherErrType;
+
+                    fn some_function () -> Result<SomeOkType, SomeErrType>;
+                    fn another_function (self: &SomeOkType) -> Result<(), AnotherErrType>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeErrType: Error {}
+"#,
+            r#"
+extension AnotherErrType: Error {}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_opaque_rust_err_conforms_to_error() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we don't generate an `Error` conformance for an opaque Rust type without the
+/// `Error` attribute, since Swift code might already declare one.
+mod extern_rust_fn_result_opaque_rust_err_without_attribute_does_not_conform_to_error {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function () -> Result<(), SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+extension SomeType: Error {}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_result_opaque_rust_err_without_attribute_does_not_conform_to_error() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result<T, E> where T is an
+/// Option<String> and E is a String.
+mod extern_rust_fn_return_result_option_string_and_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<Option<String>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                pub enum ResultOptionStringAndString {
+                    #[allow(unused)]
+                    Ok(*mut swift_bridge::string::RustString),
+                    #[allow(unused)]
+                    Err(*mut swift_bridge::string::RustString),
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> ResultOptionStringAndString {
+                    match super::some_function() {
+                        Ok(ok) => ResultOptionStringAndString::Ok(
+                            if let Some(val) = ok {
+                                swift_bridge::string::RustString(val).box_into_raw()
+                            } else {
+                                std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                            }
+                        ),
+                        Err(err) => ResultOptionStringAndString::Err(swift_bridge::string::RustString(err).box_into_raw()),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> Optional<RustString> {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultOptionStringAndString$ResultOk: return { let val = val.payload.ok; if val != nil { return RustString(ptr: val!) } else { return nil } }() case __swift_bridge__$ResultOptionStringAndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+union __swift_bridge__$ResultOptionStringAndString$Fields {void* ok; void* err;};
+"#,
+            r#"
+struct __swift_bridge__$ResultOptionStringAndString __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_option_string_and_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
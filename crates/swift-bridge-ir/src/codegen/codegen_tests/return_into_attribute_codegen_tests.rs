@@ -47,3 +47,55 @@ mod return_into_already_declared_enum {
         .test();
     }
 }
+
+/// Verify that we call `.into()` on the value that an async function's future resolves to, not
+/// on the future itself.
+mod return_into_async_function {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(return_into)]
+                    async fn some_function() -> SomeStruct;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                let fut = super::some_function();
+            },
+            quote! {
+                let val = {let val: SomeStruct = fut.await.into(); val}.into_ffi_repr();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::SkipTest
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn return_into_async_function() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
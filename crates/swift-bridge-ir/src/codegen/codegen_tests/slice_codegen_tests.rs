@@ -0,0 +1,170 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes a &[T] argument.
+mod extern_rust_fn_with_ref_slice_argument {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: &[u8]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiSlice<u8>
+            ) {
+                super::some_function(arg.as_slice())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: UnsafeBufferPointer<UInt8>) {
+    __swift_bridge__$some_function(arg.toFfiSlice())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiSlice arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_with_ref_slice_argument() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes a &mut [T] argument.
+mod extern_rust_fn_with_mut_slice_argument {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: &mut [u8]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiSlice<u8>
+            ) {
+                super::some_function(arg.as_mut_slice())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: UnsafeMutableBufferPointer<UInt8>) {
+    __swift_bridge__$some_function(arg.toFfiSlice())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiSlice arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_with_mut_slice_argument() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust method that returns a &mut [T].
+mod extern_rust_method_returns_mut_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_method (&mut self) -> &mut [u16];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *mut super::SomeType
+            ) -> swift_bridge::FfiSlice<u16> {
+                swift_bridge::FfiSlice::from_slice((unsafe { &mut *this }).some_method())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_method() -> UnsafeMutableBufferPointer<UInt16> {
+        let slice = __swift_bridge__$SomeType$some_method(ptr); return UnsafeMutableBufferPointer(start: slice.start.assumingMemoryBound(to: UInt16.self), count: Int(slice.len));
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$SomeType$some_method(void* self);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_returns_mut_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
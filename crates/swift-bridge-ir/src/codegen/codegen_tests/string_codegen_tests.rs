@@ -77,7 +77,7 @@ mod extern_rust_fn_with_str_argument {
             pub extern "C" fn __swift_bridge__some_function(
                 arg: swift_bridge::string::RustStr
             ) {
-                super::some_function(arg.to_str())
+                super::some_function(unsafe { arg.to_str_unchecked() })
             }
         })
     }
@@ -847,3 +847,278 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
         .test();
     }
 }
+
+/// Verify that an enum with data-carrying variants that derives `Clone` can be used in a `Vec`.
+/// Related: crates/swift-integration-tests/src/push_parser.rs
+mod generates_vec_support_for_data_enum_that_derives_clone {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone)]
+                enum SomeEnum {
+                    Variant1(String),
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Clone)]
+                pub enum SomeEnum {
+                    Variant1(String),
+                    Variant2
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeEnum$get"]
+                pub extern "C" fn _get(vec: *const Vec<SomeEnum>, index: usize) -> __swift_bridge__Option_SomeEnum {
+                    let vec = unsafe { &*vec };
+                    let val = vec.get(index).cloned();
+                    __swift_bridge__Option_SomeEnum::from_rust_repr(val)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_SomeEnum$new()
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+__swift_bridge__$Option$SomeEnum __swift_bridge__$Vec_SomeEnum$get(void* vec_ptr, uintptr_t index);
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_vec_support_for_data_enum_that_derives_clone() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate `Vec` support for an enum with data-carrying variants that does
+/// not derive `Clone`.
+mod no_vec_support_for_data_enum_without_clone {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1(String),
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            Vec_SomeEnum
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Vectorizable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("Vec_SomeEnum")
+    }
+
+    #[test]
+    fn no_vec_support_for_data_enum_without_clone() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate exhaustive conversions between an enum and the handwritten Swift enum
+/// that it mirrors.
+mod generates_swift_mirror_conversions {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_mirror = "LegacyColor")]
+                enum Color {
+                    Red,
+                    Green,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension LegacyColor {
+    init(_ value: Color) {
+        switch value {
+        case .Red: self = .Red
+        case .Green: self = .Green
+        }
+    }
+}
+extension Color {
+    init(_ value: LegacyColor) {
+        switch value {
+        case .Red: self = .Red
+        case .Green: self = .Green
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_swift_mirror_conversions() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate methods, associated functions and initializers on an enum.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_enum.rs
+mod generates_enum_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    A,
+                    B(u8),
+                }
+
+                extern "Rust" {
+                    fn is_a(self: SomeEnum) -> bool;
+                    fn label(self: &SomeEnum) -> String;
+                    #[swift_bridge(associated_to = SomeEnum)]
+                    fn default_value() -> SomeEnum;
+                    #[swift_bridge(init)]
+                    fn new(value: u8) -> SomeEnum;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeEnum$is_a"]
+                pub extern "C" fn __swift_bridge__SomeEnum_is_a(
+                    this: __swift_bridge__SomeEnum
+                ) -> bool {
+                    (this.into_rust_repr()).is_a()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeEnum$label"]
+                pub extern "C" fn __swift_bridge__SomeEnum_label(
+                    this: __swift_bridge__SomeEnum
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString((this.into_rust_repr()).label()).box_into_raw()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeEnum$default_value"]
+                pub extern "C" fn __swift_bridge__SomeEnum_default_value() -> __swift_bridge__SomeEnum {
+                    SomeEnum::default_value().into_ffi_repr()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeEnum$new"]
+                pub extern "C" fn __swift_bridge__SomeEnum_new(value: u8) -> __swift_bridge__SomeEnum {
+                    SomeEnum::new(value).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum {
+    public func is_a() -> Bool {
+        __swift_bridge__$SomeEnum$is_a(self.intoFfiRepr())
+    }
+    public func label() -> RustString {
+        RustString(ptr: __swift_bridge__$SomeEnum$label(self.intoFfiRepr()))
+    }
+    static public func default_value() -> SomeEnum {
+        __swift_bridge__$SomeEnum$default_value().intoSwiftRepr()
+    }
+    public init(_ value: UInt8) {
+        self = __swift_bridge__$SomeEnum$new(value).intoSwiftRepr()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "bool __swift_bridge__$SomeEnum$is_a(struct __swift_bridge__$SomeEnum this);",
+            "void* __swift_bridge__$SomeEnum$label(struct __swift_bridge__$SomeEnum this);",
+            "struct __swift_bridge__$SomeEnum __swift_bridge__$SomeEnum$default_value(void);",
+            "struct __swift_bridge__$SomeEnum __swift_bridge__$SomeEnum$new(uint8_t value);",
+        ])
+    }
+
+    #[test]
+    fn generates_enum_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
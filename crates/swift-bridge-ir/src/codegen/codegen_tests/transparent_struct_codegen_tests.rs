@@ -702,3 +702,308 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that a struct with the `initialize` attribute generates an `initialize(config:)` Swift
+/// function that passes the struct to the user's Rust `initialize` function.
+mod shared_struct_initialize_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", initialize)]
+                struct AppConfig {
+                    log_level: String,
+                    verbose: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$initialize"]
+            pub extern "C" fn __swift_bridge__initialize(config: __swift_bridge__AppConfig) {
+                super::initialize(config.into_rust_repr())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func initialize(config: AppConfig) {
+    __swift_bridge__$initialize(config.intoFfiRepr())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$initialize(struct __swift_bridge__$AppConfig config);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_initialize_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct with the `patch` attribute generates a patch struct and the functions that
+/// apply it in Rust and in Swift.
+mod shared_struct_patch_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", patch)]
+                struct AppConfig {
+                    log_level: String,
+                    verbose: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                impl AppConfig {
+                    /// Overwrite the fields that are set in the patch.
+                    pub fn apply_patch(&mut self, patch: AppConfigPatch) {
+                        if let Some(val) = patch.log_level {
+                            self.log_level = val;
+                        }
+                        if let Some(val) = patch.verbose {
+                            self.verbose = val;
+                        }
+                    }
+                }
+            },
+            quote! {
+                pub struct AppConfigPatch {
+                    pub log_level: Option<String>,
+                    pub verbose: Option<bool>
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension AppConfig {
+    public mutating func apply(_ patch: AppConfigPatch) {
+        if let val = patch.log_level { self.log_level = val }
+        if let val = patch.verbose { self.verbose = val }
+    }
+}
+extension AppConfigPatch {
+    public init() {
+        self.init(log_level: nil, verbose: nil)
+    }
+}
+"#,
+            r#"
+public struct AppConfigPatch {
+    public var log_level: Optional<RustString>
+    public var verbose: Optional<Bool>
+"#,
+            r#"
+return __swift_bridge__$AppConfigPatch(log_level: { if let rustString = optionalStringIntoRustString(val.log_level) { rustString.isOwned = false; return rustString.ptr } else { return nil } }(), verbose: val.verbose.intoFfiRepr());
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$AppConfigPatch { void* log_level; struct __private__OptionBool verbose; } __swift_bridge__$AppConfigPatch;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_patch_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct with the `Identifiable` attribute conforms to Swift's `Identifiable`
+/// protocol using the named field.
+mod shared_struct_identifiable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable = "user_id")]
+                struct User {
+                    user_id: u32,
+                    name: String,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Identifiable = "id")]
+                struct Post {
+                    id: u64,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension User: Identifiable {
+    public var id: UInt32 {
+        self.user_id
+    }
+}"#,
+            r#"
+extension Post: Identifiable {}"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_identifiable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a newtype that derives `PartialEq`, `Eq` and `Hash` and uses the `Codable`
+/// attribute can be used as a Swift dictionary key, and is encoded as the value that it wraps.
+mod shared_struct_newtype_hashable_codable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+                struct UserId(u64);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+            pub struct UserId(pub u64);
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension UserId: Hashable {}
+extension UserId: Codable {
+    public init(from decoder: Decoder) throws {
+        self.init(_0: try decoder.singleValueContainer().decode(UInt64.self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self._0)
+    }
+}"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_newtype_hashable_codable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct with named fields gets Swift's synthesized `Equatable` and `Codable`
+/// conformances.
+mod shared_struct_equatable_codable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(PartialEq)]
+                struct Session {
+                    user_id: u64,
+                    active: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(PartialEq)]
+            pub struct Session {
+                pub user_id: u64,
+                pub active: bool
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Session: Equatable {}
+extension Session: Codable {}"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_equatable_codable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
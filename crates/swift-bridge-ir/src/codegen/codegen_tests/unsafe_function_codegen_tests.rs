@@ -0,0 +1,148 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we call an `unsafe` extern "Rust" function in an `unsafe` block and that the Swift
+/// function's name starts with `unsafe`.
+mod extern_rust_unsafe_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    unsafe fn read_byte(ptr: *const u8) -> u8;
+                    unsafe fn write_bytes(&mut self, bytes: *const u8, len: usize);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$read_byte"]
+                pub extern "C" fn __swift_bridge__read_byte(ptr: *const u8) -> u8 {
+                    unsafe { super::read_byte(ptr) }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$write_bytes"]
+                pub extern "C" fn __swift_bridge__SomeType_write_bytes(
+                    this: *mut super::SomeType,
+                    bytes: *const u8,
+                    len: usize
+                ) {
+                    unsafe { (unsafe { &mut *this }).write_bytes(bytes, len) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// Unsafe. Calls the Rust `unsafe fn read_byte`, so the caller must uphold its safety contract.
+public func unsafeReadByte(_ ptr: UnsafePointer<UInt8>) -> UInt8 {
+    __swift_bridge__$read_byte(ptr)
+}
+"#,
+            r#"
+    /// Unsafe. Calls the Rust `unsafe fn write_bytes`, so the caller must uphold its safety contract.
+    public func unsafeWriteBytes(_ bytes: UnsafePointer<UInt8>, _ len: UInt) {
+        __swift_bridge__$SomeType$write_bytes(ptr, bytes, len)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint8_t __swift_bridge__$read_byte(uint8_t const * ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_unsafe_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an explicit `swift_name` is used as is for an `unsafe` extern "Rust" function.
+mod extern_rust_unsafe_fn_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "readByteUnchecked")]
+                    unsafe fn read_byte(ptr: *const u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func readByteUnchecked(_ ptr: UnsafePointer<UInt8>) -> UInt8 {
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_unsafe_fn_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the Rust function that calls an `unsafe` extern "Swift" function is `unsafe`.
+mod extern_swift_unsafe_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    unsafe fn read_byte(ptr: *const u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub unsafe fn read_byte(ptr: *const u8) -> u8 {
+                unsafe { __swift_bridge__read_byte(ptr) }
+            }
+        })
+    }
+
+    #[test]
+    fn extern_swift_unsafe_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -13,7 +13,7 @@ const NOTICE: &'static str = "// File automatically generated by swift-bridge.";
 
 struct Bookkeeping {
     includes: BTreeSet<&'static str>,
-    slice_types: HashSet<String>,
+    slice_types: BTreeSet<String>,
 }
 
 /// Used to manage the structures declaration order in a C header file. In the C header file, it is necessary to declare fields of a structure before declaring the structure itself.
@@ -46,11 +46,13 @@ impl SwiftBridgeModule {
             return header;
         }
 
+        let arc_types = self.arc_opaque_types();
+
         let mut bookkeeping = Bookkeeping {
             includes: BTreeSet::new(),
             // TODO: Delete this.
             //  Don't think we're using it.
-            slice_types: HashSet::new(),
+            slice_types: BTreeSet::new(),
         };
 
         for ty in self.types.types() {
@@ -172,7 +174,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                             "".to_string()
                         };
 
-                        let maybe_vec_support = if ty_enum.has_one_or_more_variants_with_data() {
+                        let maybe_vec_support = if !ty_enum.supports_vec() {
                             "".to_string()
                         } else {
                             vec_transparent_enum_c_support(&ty_enum.swift_name_string())
@@ -272,13 +274,15 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                     if ty.attributes.declare_generic {
                         continue;
                     }
-                    if ty.attributes.hashable {
+                    if ty.attributes.hashable && ty.attributes.copy.is_none() {
                         let ty_name = ty.ty_name_ident();
                         let hash_ty =
                             format!("uint64_t __swift_bridge__${}$_hash(void* self);", ty_name);
+                        bookkeeping.includes.insert("stdint.h");
                         header += &hash_ty;
+                        header += "\n";
                     }
-                    if ty.attributes.equatable {
+                    if ty.attributes.equatable && ty.attributes.copy.is_none() {
                         let ty_name = ty.ty_name_ident();
                         let equal_ty = format!(
                             "bool __swift_bridge__${}$_partial_eq(void* lhs, void* rhs);",
@@ -289,6 +293,19 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.localized_error && ty.attributes.copy.is_none() {
+                        header += &format!(
+                            "void* __swift_bridge__${}$_to_string(void* self);\n",
+                            ty.ty_name_ident()
+                        );
+                    }
+                    if ty.attributes.comparable && ty.attributes.copy.is_none() {
+                        header += &format!(
+                            "bool __swift_bridge__${}$_lt(void* lhs, void* rhs);\n",
+                            ty.ty_name_ident()
+                        );
+                        bookkeeping.includes.insert("stdbool.h");
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -312,6 +329,29 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += "\n";
                         header += &option_ty_decl;
                         header += "\n";
+
+                        // Copy types are passed by value, so these need to come after the typedef.
+                        if ty.attributes.hashable {
+                            header += &format!(
+                                "uint64_t __swift_bridge__${ty_name}$_hash({copy_ffi_repr} self);\n",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name
+                            );
+                        }
+                        if ty.attributes.equatable {
+                            header += &format!(
+                                "bool __swift_bridge__${ty_name}$_partial_eq({copy_ffi_repr} lhs, {copy_ffi_repr} rhs);\n",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name
+                            );
+                        }
+                        if ty.attributes.comparable {
+                            header += &format!(
+                                "bool __swift_bridge__${ty_name}$_lt({copy_ffi_repr} lhs, {copy_ffi_repr} rhs);\n",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name
+                            );
+                        }
                     } else {
                         let ty_decl =
                             format!("typedef struct {ty_name} {ty_name};", ty_name = ty_name);
@@ -327,17 +367,36 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         header += &drop_ty;
                         header += "\n";
+
+                        if ty.has_clone_function() {
+                            let clone_ty = format!(
+                                "void* {}(void* self);",
+                                ty.clone_rust_opaque_type_ffi_name()
+                            );
+                            header += &clone_ty;
+                            header += "\n";
+                        }
                     }
 
                     // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                     //  make them pass.
                     // TODO: Support Vec<GenericOpaqueRustType
-                    if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
+                    // Vec<HardenedOpaqueRustType> is rejected with a parse error.
+                    if ty.attributes.copy.is_none()
+                        && !ty.attributes.hardened
+                        && ty.generics.len() == 0
+                    {
                         let vec_functions = vec_opaque_rust_type_c_support(&ty_name);
 
                         header += &vec_functions;
                         header += "\n";
                     }
+
+                    if arc_types.contains(&ty_name) {
+                        header += &format!(
+                            "void __swift_bridge__${ty_name}$_arc_free(void* self);\nvoid __swift_bridge__${ty_name}$_arc_clone(void* self);\n",
+                        );
+                    }
                 }
             }
         }
@@ -373,6 +432,27 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             )
         }
 
+        for custom_type_declaration in c_ffi_struct_bookkeeping.custom_type_declarations {
+            header += &custom_type_declaration;
+            header += "\n";
+        }
+
+        if let Some(cxx_header) = self.cxx_header {
+            header = format!(
+                r#"#ifdef __cplusplus
+extern "C" {{
+#endif
+{header}#ifdef __cplusplus
+}}
+#endif
+"#
+            );
+
+            if cxx_header.enum_class {
+                header += &self.cxx_enum_classes();
+            }
+        }
+
         let mut includes = bookkeeping.includes.iter().collect::<Vec<_>>();
         includes.sort();
         for include in includes {
@@ -382,12 +462,61 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                 include, header
             );
         }
-        for custom_type_declaration in c_ffi_struct_bookkeeping.custom_type_declarations {
-            header += &custom_type_declaration;
-            header += "\n";
-        }
         header
     }
+
+    /// Declare an `enum class` for every transparent enum without fields, inside of a namespace
+    /// named after the module, along with functions that convert to and from the FFI
+    /// representation.
+    ///
+    /// namespace ffi {
+    /// enum class SomeEnum { A = __swift_bridge__$SomeEnum$A };
+    /// inline __swift_bridge__$SomeEnum into_ffi(SomeEnum value) { ... }
+    /// inline SomeEnum from_ffi(__swift_bridge__$SomeEnum value) { ... }
+    /// }
+    fn cxx_enum_classes(&self) -> String {
+        let mut enums = "".to_string();
+
+        for ty in self.types.types() {
+            let ty_enum = match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(ty_enum)) => ty_enum,
+                _ => continue,
+            };
+            if ty_enum.already_declared || !ty_enum.all_variants_empty() {
+                continue;
+            }
+
+            let name = &ty_enum.name;
+            let ffi_name = ty_enum.ffi_name_string();
+            let ffi_tag_name = ty_enum.ffi_tag_name_string();
+            let variants: Vec<String> = ty_enum
+                .variants
+                .iter()
+                .map(|variant| format!("{} = {}${}", variant.name, ffi_name, variant.name))
+                .collect();
+
+            enums += &format!(
+                r#"enum class {name} {{ {variants} }};
+inline {ffi_name} into_ffi({name} value) {{ return {ffi_name} {{ static_cast<{ffi_tag_name}>(value) }}; }}
+inline {name} from_ffi({ffi_name} value) {{ return static_cast<{name}>(value.tag); }}
+"#,
+                variants = variants.join(", ")
+            );
+        }
+
+        if enums.is_empty() {
+            return enums;
+        }
+
+        format!(
+            r#"#ifdef __cplusplus
+namespace {module_name} {{
+{enums}}}
+#endif
+"#,
+            module_name = self.name
+        )
+    }
 }
 
 fn vec_opaque_rust_type_c_support(ty_name: &str) -> String {
@@ -466,6 +595,11 @@ fn declare_custom_c_ffi_types(
             }
         };
     }
+    if let Some(progress) = func.progress_closure(types) {
+        if let Some(declaration) = progress.generate_custom_c_ffi_types(types) {
+            push_custom_type_declarations(&declaration, c_ffi_struct_bookkeeping);
+        }
+    }
 }
 
 fn declare_func(
@@ -491,6 +625,14 @@ fn declare_func(
         }
     }
 
+    // Weakly link versioned functions so that the app still loads if an older Rust library that
+    // is missing the function is loaded at runtime.
+    let maybe_weak_import = if func.since.is_some() {
+        " __attribute__((weak_import))"
+    } else {
+        ""
+    };
+
     let declaration = if func.sig.asyncness.is_some() {
         let maybe_ret = BridgedType::new_with_return_type(&func.sig.output, types).unwrap();
         let maybe_ret = if maybe_ret.is_null() {
@@ -506,13 +648,13 @@ fn declare_func(
         };
 
         format!(
-            "void {name}(void* callback_wrapper, void {name}$async(void* callback_wrapper{maybe_ret}){maybe_params});\n",
+            "void {name}(void* callback_wrapper, void {name}$async(void* callback_wrapper{maybe_ret}){maybe_params}){maybe_weak_import};\n",
             name = name,
             maybe_ret = maybe_ret
         )
     } else {
         format!(
-            "{ret} {name}({params});\n",
+            "{ret} {name}({params}){maybe_weak_import};\n",
             ret = ret,
             name = name,
             params = params
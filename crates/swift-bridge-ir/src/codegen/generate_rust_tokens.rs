@@ -1,18 +1,22 @@
 //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
+use self::arc::generate_arc_of_opaque_rust_type_functions;
+use self::swift_mocks::generate_swift_mocks_module;
 use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functions;
 use crate::bridge_module_attributes::CfgAttr;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
 
+mod arc;
 mod shared_enum;
 mod shared_struct;
+mod swift_mocks;
 mod vec;
 
 impl ToTokens for SwiftBridgeModule {
@@ -27,11 +31,14 @@ impl ToTokens for SwiftBridgeModule {
 
         let mut shared_struct_definitions = vec![];
         let mut shared_enum_definitions = vec![];
-        let mut custom_type_definitions: HashMap<String, TokenStream> = HashMap::new();
+        let mut custom_type_definitions: BTreeMap<String, TokenStream> = BTreeMap::new();
+        let arc_types = self.arc_opaque_types();
         let mut impl_fn_tokens: HashMap<String, Vec<TokenStream>> = HashMap::new();
+        let mut trait_impl_fn_tokens: HashMap<String, Vec<TokenStream>> = HashMap::new();
         let mut callbacks_support = vec![];
         let mut freestanding_rust_call_swift_fn_tokens = vec![];
         let mut extern_swift_fn_tokens = vec![];
+        let mut swift_mocks = vec![];
 
         for func in &self.functions {
             match func.host_lang {
@@ -40,22 +47,46 @@ impl ToTokens for SwiftBridgeModule {
                         &self.swift_bridge_path,
                         &self.types,
                         &mut custom_type_definitions,
+                        self.record_breadcrumbs,
                     ));
                 }
                 HostLang::Swift => {
-                    let tokens = func
+                    let mut tokens = func
                         .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
+
+                    if self.generate_swift_mocks {
+                        if let Some(mock) = func.to_rust_swift_mock(&self.types) {
+                            let mock_fn = &mock.mock_fn;
+                            tokens = quote! {
+                                #[cfg(not(test))]
+                                #tokens
+
+                                #mock_fn
+                            };
+                            swift_mocks.push(mock);
+                        }
+                    }
                     callbacks_support
                         .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
 
                     if let Some(ty) = func.associated_type.as_ref() {
                         match ty {
                             TypeDeclaration::Shared(_) => {
-                                //
-
-                                todo!()
+                                unreachable!(
+                                    "Methods on shared types are rejected in extern \"Swift\" blocks while parsing."
+                                )
                             }
                             TypeDeclaration::Opaque(ty) => {
+                                if ty.attributes.trait_object && func.is_method() {
+                                    trait_impl_fn_tokens
+                                        .entry(ty.to_string())
+                                        .or_default()
+                                        .push(func.to_rust_trait_method_that_calls_a_swift_extern(
+                                            &self.swift_bridge_path,
+                                            &self.types,
+                                        ));
+                                }
+
                                 impl_fn_tokens
                                     .entry(ty.to_string())
                                     .or_default()
@@ -70,6 +101,7 @@ impl ToTokens for SwiftBridgeModule {
                         &self.swift_bridge_path,
                         &self.types,
                         &mut custom_type_definitions,
+                        self.record_breadcrumbs,
                     ));
                 }
             };
@@ -96,45 +128,139 @@ impl ToTokens for SwiftBridgeModule {
 
                     let link_name = ty.free_rust_opaque_type_ffi_name();
                     let free_mem_func_name = ty.free_rust_opaque_type_ident();
-                    let this = &ty.ty;
+                    let this = ty.rust_type_tokens();
                     let ty_name = &ty.ty;
 
+                    // `#[swift_bridge(hardened)]` types are passed to Rust as handles that get
+                    // resolved through the type's handle table.
+                    let handle_table = quote! {
+                        <#this as #swift_bridge_path::handle_table::Hardened>::handle_table()
+                    };
+                    let borrow = |ptr: TokenStream| {
+                        if ty.attributes.hardened {
+                            quote! { (&*#handle_table.resolve(#ptr)) }
+                        } else {
+                            quote! { (unsafe { &*#ptr }) }
+                        }
+                    };
+
                     match ty.host_lang {
                         HostLang::Rust => {
                             if ty.attributes.hashable {
+                                let this = borrow(quote! { this });
                                 let export_name = format!("__swift_bridge__${}$_hash", ty_name);
                                 let function_name = syn::Ident::new(
                                     &format!("__swift_bridge__{}__hash", ty_name),
                                     ty.ty.span(),
                                 );
+                                // Copy types are passed by value.
+                                let (this_param, this_val) = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    (
+                                        quote! { this: #copy_ty_name },
+                                        quote! { this.into_rust_repr() },
+                                    )
+                                } else {
+                                    (quote! { this: *const super::#ty_name }, this)
+                                };
                                 let tokens = quote! {
                                 #[export_name = #export_name]
                                 pub extern "C" fn #function_name (
-                                    this: *const super::#ty_name,
+                                    #this_param,
                                 ) -> u64 {
                                     use std::hash::{Hash, Hasher};
                                     use std::collections::hash_map::DefaultHasher;
                                     let mut s = DefaultHasher::new();
-                                    (unsafe {&*this}).hash(&mut s);
+                                    #this_val.hash(&mut s);
                                     s.finish()
                                 }
                                 };
                                 extern_rust_fn_tokens.push(tokens);
                             }
                             if ty.attributes.equatable {
+                                let lhs = borrow(quote! { lhs });
+                                let rhs = borrow(quote! { rhs });
                                 let export_name =
                                     format!("__swift_bridge__${}$_partial_eq", ty_name);
                                 let function_name = syn::Ident::new(
                                     &format!("__swift_bridge__{}__partial_eq", ty_name),
                                     ty.ty.span(),
                                 );
+                                // Copy types are passed by value.
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: #copy_ty_name,
+                                            rhs: #copy_ty_name
+                                        ) -> bool {
+                                            lhs.into_rust_repr() == rhs.into_rust_repr()
+                                        }
+                                    }
+                                } else {
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: *const super::#ty_name,
+                                            rhs: *const super::#ty_name
+                                        ) -> bool {
+                                            #lhs == #rhs
+                                        }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.comparable {
+                                let lhs = borrow(quote! { lhs });
+                                let rhs = borrow(quote! { rhs });
+                                let export_name = format!("__swift_bridge__${}$_lt", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__lt", ty_name),
+                                    ty.ty.span(),
+                                );
+                                // Copy types are passed by value.
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: #copy_ty_name,
+                                            rhs: #copy_ty_name
+                                        ) -> bool {
+                                            lhs.into_rust_repr() < rhs.into_rust_repr()
+                                        }
+                                    }
+                                } else {
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: *const super::#ty_name,
+                                            rhs: *const super::#ty_name
+                                        ) -> bool {
+                                            #lhs < #rhs
+                                        }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            // Copy types do not conform to Swift's `Error` protocol.
+                            if ty.attributes.localized_error && ty.attributes.copy.is_none() {
+                                let this = borrow(quote! { this });
+                                let export_name =
+                                    format!("__swift_bridge__${}$_to_string", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__to_string", ty_name),
+                                    ty.ty.span(),
+                                );
                                 let tokens = quote! {
                                     #[export_name = #export_name]
                                     pub extern "C" fn #function_name (
-                                        lhs: *const super::#ty_name,
-                                        rhs: *const super::#ty_name
-                                    ) -> bool {
-                                        unsafe { &*lhs == &*rhs }
+                                        this: *const super::#ty_name
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            #this.to_string()
+                                        ).box_into_raw()
                                     }
                                 };
                                 extern_rust_fn_tokens.push(tokens);
@@ -197,26 +323,96 @@ impl ToTokens for SwiftBridgeModule {
                                         .generics
                                         .angle_bracketed_concrete_generics_tokens(&self.types);
 
-                                    let free = quote! {
-                                        #[export_name = #link_name]
-                                        pub extern "C" fn #free_mem_func_name (this: *mut super::#this #generics) {
-                                            let this = unsafe { Box::from_raw(this) };
-                                            drop(this);
-                                        }
+                                    let free = match &ty.attributes.on_drop {
+                                        Some(on_drop) if ty.attributes.hardened => quote! {
+                                            #[export_name = #link_name]
+                                            pub extern "C" fn #free_mem_func_name (this: *mut #this #generics) {
+                                                let mut this = #handle_table.remove(this);
+                                                super::#on_drop(&mut this);
+                                                drop(this);
+                                            }
+                                        },
+                                        Some(on_drop) => quote! {
+                                            #[export_name = #link_name]
+                                            pub extern "C" fn #free_mem_func_name (this: *mut #this #generics) {
+                                                let mut this = unsafe { Box::from_raw(this) };
+                                                super::#on_drop(&mut this);
+                                                drop(this);
+                                            }
+                                        },
+                                        None if ty.attributes.hardened => quote! {
+                                            #[export_name = #link_name]
+                                            pub extern "C" fn #free_mem_func_name (this: *mut #this #generics) {
+                                                let this = #handle_table.remove(this);
+                                                drop(this);
+                                            }
+                                        },
+                                        None => quote! {
+                                            #[export_name = #link_name]
+                                            pub extern "C" fn #free_mem_func_name (this: *mut #this #generics) {
+                                                let this = unsafe { Box::from_raw(this) };
+                                                #swift_bridge_path::scope::drop_or_defer(this);
+                                            }
+                                        },
                                     };
 
                                     extern_rust_fn_tokens.push(free);
 
+                                    if ty.attributes.hardened {
+                                        let hardened = quote! {
+                                            impl #swift_bridge_path::handle_table::Hardened for #this #generics {
+                                                fn handle_table() -> &'static #swift_bridge_path::handle_table::HandleTable<Self> {
+                                                    static HANDLE_TABLE: #swift_bridge_path::handle_table::HandleTable<#this #generics> =
+                                                        #swift_bridge_path::handle_table::HandleTable::new();
+                                                    &HANDLE_TABLE
+                                                }
+                                            }
+                                        };
+                                        extern_rust_fn_tokens.push(hardened);
+                                    }
+
+                                    if ty.has_clone_function() {
+                                        let clone_link_name = ty.clone_rust_opaque_type_ffi_name();
+                                        let clone_func_name = ty.clone_rust_opaque_type_ident();
+
+                                        let clone = if ty.attributes.hardened {
+                                            quote_spanned! {ty.ty.span()=>
+                                                #[export_name = #clone_link_name]
+                                                pub extern "C" fn #clone_func_name (this: *const #this) -> *mut #this {
+                                                    #handle_table.insert((&*#handle_table.resolve(this)).clone())
+                                                }
+                                            }
+                                        } else {
+                                            quote_spanned! {ty.ty.span()=>
+                                                #[export_name = #clone_link_name]
+                                                pub extern "C" fn #clone_func_name (this: *const #this) -> *mut #this {
+                                                    Box::into_raw(Box::new((unsafe { &*this }).clone()))
+                                                }
+                                            }
+                                        };
+                                        extern_rust_fn_tokens.push(clone);
+                                    }
+
                                     // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                                     //  make them pass.
                                     // TODO: Support Vec<GenericOpaqueRustType
-                                    if ty.generics.len() == 0 {
+                                    // Vec<HardenedOpaqueRustType> is rejected with a parse error.
+                                    if ty.generics.len() == 0 && !ty.attributes.hardened {
                                         let vec_functions =
-                                            generate_vec_of_opaque_rust_type_functions(ty_name);
+                                            generate_vec_of_opaque_rust_type_functions(
+                                                ty_name, &this,
+                                            );
                                         extern_rust_fn_tokens.push(vec_functions);
                                     }
                                 }
                             }
+
+                            if !ty.attributes.already_declared
+                                && arc_types.contains(&ty_name.to_string())
+                            {
+                                extern_rust_fn_tokens
+                                    .push(generate_arc_of_opaque_rust_type_functions(ty_name));
+                            }
                         }
                         HostLang::Swift => {
                             let ty_name = &ty.ty;
@@ -234,9 +430,39 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             };
 
+                            // Rust receives a `#[swift_bridge(trait_object)]` type as a
+                            // `Box<dyn super::SomeTrait>`.
+                            let impls = if ty.attributes.trait_object {
+                                let trait_impls = trait_impl_fn_tokens
+                                    .get(&ty_name.to_string())
+                                    .cloned()
+                                    .unwrap_or_default();
+                                quote! {
+                                    #impls
+
+                                    impl super::#ty_name for #ty_name {
+                                        #(#trait_impls)*
+                                    }
+                                }
+                            } else {
+                                impls
+                            };
+
+                            // An already declared type's struct lives in the bridge module that
+                            // declared it, which the parent module must bring into scope.
+                            if ty.attributes.already_declared {
+                                let struct_tokens = quote! {
+                                    use super::#ty_name;
+
+                                    #impls
+                                };
+                                structs_for_swift_classes.push(struct_tokens);
+                                continue;
+                            }
+
                             let struct_tokens = quote! {
                                 #[repr(C)]
-                                pub struct #ty_name(*mut std::ffi::c_void);
+                                pub struct #ty_name(pub(crate) *mut std::ffi::c_void);
 
                                 #impls
 
@@ -246,6 +472,33 @@ impl ToTokens for SwiftBridgeModule {
                                     }
                                 }
                             };
+                            // Mocked instances are not backed by a Swift class, so there is
+                            // nothing to free.
+                            let struct_tokens = if self.generate_swift_mocks {
+                                quote! {
+                                    #[repr(C)]
+                                    pub struct #ty_name(pub(crate) *mut std::ffi::c_void);
+
+                                    #impls
+
+                                    #[cfg(test)]
+                                    impl #ty_name {
+                                        /// Create an instance to pass to and return from mocks.
+                                        pub fn new_mock() -> Self {
+                                            #ty_name(std::ptr::null_mut())
+                                        }
+                                    }
+
+                                    #[cfg(not(test))]
+                                    impl Drop for #ty_name {
+                                        fn drop (&mut self) {
+                                            unsafe { #free_mem_func_name(self.0) }
+                                        }
+                                    }
+                                }
+                            } else {
+                                struct_tokens
+                            };
                             structs_for_swift_classes.push(struct_tokens);
 
                             let free = quote! {
@@ -277,6 +530,8 @@ impl ToTokens for SwiftBridgeModule {
                 }
             };
         }
+        let swift_mocks = generate_swift_mocks_module(&swift_mocks);
+
         let custom_type_definitions = custom_type_definitions.into_values();
         let module_inner = quote! {
             #(#shared_struct_definitions)*
@@ -294,6 +549,8 @@ impl ToTokens for SwiftBridgeModule {
             #extern_swift_fn_tokens
 
             #(#callbacks_support)*
+
+            #swift_mocks
         };
 
         let t = quote! {
@@ -688,7 +945,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for Foo {
                 fn drop (&mut self) {
@@ -748,7 +1005,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn new () -> Foo {
@@ -861,7 +1118,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn notify (&self) {
@@ -1028,6 +1285,61 @@ mod tests {
         assert_to_extern_c_function_tokens(start, &expected);
     }
 
+    /// Verify that functions and methods in a `#[swift_bridge::bridge(breadcrumbs)]` module record
+    /// a breadcrumb before calling the Rust function.
+    #[test]
+    fn records_breadcrumbs() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function();
+                    fn some_method(&self);
+                }
+            }
+        };
+        let expected = quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function () {
+                swift_bridge::breadcrumbs::record("some_function");
+                super::some_function()
+            }
+
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method (
+                this: *mut super::SomeType
+            ) {
+                swift_bridge::breadcrumbs::record("SomeType::some_method");
+                (unsafe { &*this }).some_method()
+            }
+        };
+
+        let mut module = parse_ok(start);
+        module.set_record_breadcrumbs(true);
+
+        assert_tokens_contain(&module.to_token_stream(), &expected);
+    }
+
+    /// Verify that we do not record breadcrumbs unless the module opts in.
+    #[test]
+    fn does_not_record_breadcrumbs_by_default() {
+        let start = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        };
+        let expected = quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function () {
+                super::some_function()
+            }
+        };
+
+        assert_to_extern_c_function_tokens(start, &expected);
+    }
+
     fn parse_ok(tokens: TokenStream) -> SwiftBridgeModule {
         let module_and_errors: SwiftBridgeModuleAndErrors = syn::parse2(tokens).unwrap();
         module_and_errors.module
@@ -1041,7 +1353,8 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
+                module.record_breadcrumbs,
             ),
             &expected_fn,
         );
@@ -0,0 +1,28 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generate the functions that Swift's `MyRustTypeArc` class calls to release and clone its
+/// strong reference to an `Arc<MyRustType>`.
+pub(super) fn generate_arc_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+    // examples:
+    // "__swift_bridge__$MyRustType$_arc_free"
+    // "__swift_bridge__$MyRustType$_arc_clone"
+    let export_name_free = format!("__swift_bridge__${}$_arc_free", ty);
+    let export_name_clone = format!("__swift_bridge__${}$_arc_clone", ty);
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[export_name = #export_name_free]
+            pub extern "C" fn _arc_free(this: *const super::#ty) {
+                unsafe { std::sync::Arc::decrement_strong_count(this) }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_clone]
+            pub extern "C" fn _arc_clone(this: *const super::#ty) {
+                unsafe { std::sync::Arc::increment_strong_count(this) }
+            }
+        };
+    }
+}
@@ -163,11 +163,15 @@ impl SwiftBridgeModule {
             }
         }
 
-        let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
-            // Enums with variants that contain data are not yet supported.
-            quote! {}
-        } else {
+        // Fieldless enums are always `Copy + Clone`.
+        if shared_enum.derive.clone && shared_enum.has_one_or_more_variants_with_data() {
+            derives.push(quote! {Clone});
+        }
+
+        let vec_support = if shared_enum.supports_vec() {
             generate_vec_of_transparent_enum_functions(&shared_enum)
+        } else {
+            quote! {}
         };
 
         let definition = quote! {
@@ -98,11 +98,51 @@ impl SwiftBridgeModule {
         if shared_struct.derives.clone {
             derives.push(quote! {Clone});
         }
+        if shared_struct.derives.partial_eq {
+            derives.push(quote! {PartialEq});
+        }
+        if shared_struct.derives.eq {
+            derives.push(quote! {Eq});
+        }
+        if shared_struct.derives.hash {
+            derives.push(quote! {Hash});
+        }
+
+        let apply_patch = if shared_struct.patch {
+            let patch_name = shared_struct.patch_struct().name;
+            let set_fields = shared_struct
+                .fields
+                .normalized_fields()
+                .into_iter()
+                .map(|field| {
+                    let patch_field = field.append_field_accessor(&quote! { patch });
+                    let self_field = field.append_field_accessor(&quote! { self });
+
+                    quote! {
+                        if let Some(val) = #patch_field {
+                            #self_field = val;
+                        }
+                    }
+                });
+
+            quote! {
+                impl #struct_name {
+                    /// Overwrite the fields that are set in the patch.
+                    pub fn apply_patch(&mut self, patch: #patch_name) {
+                        #(#set_fields)*
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
 
         let definition = quote! {
             #[derive(#(#derives),*)]
             pub struct #struct_name #struct_fields
 
+            #apply_patch
+
             #struct_ffi_repr
 
             impl #swift_bridge_path::SharedStruct for #struct_name {
@@ -0,0 +1,70 @@
+use crate::parsed_extern_fn::SwiftMock;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::BTreeMap;
+
+/// Generate the mocks' thread locals along with the `swift_mocks` module that Rust tests use to
+/// set the mocks of the module's `extern "Swift"` functions.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// pub mod swift_mocks {
+///     pub use super::__swift_bridge__mock_some_function as some_function;
+///
+///     pub mod SomeType {
+///         pub use super::super::__swift_bridge__mock_SomeType_some_method as some_method;
+///     }
+/// }
+/// ```
+pub(super) fn generate_swift_mocks_module(mocks: &[SwiftMock]) -> TokenStream {
+    if mocks.is_empty() {
+        return quote! {};
+    }
+
+    let mut mock_support = vec![];
+    let mut freestanding = vec![];
+    let mut methods: BTreeMap<String, (&Ident, Vec<TokenStream>)> = BTreeMap::new();
+
+    for mock in mocks {
+        let prefixed = &mock.prefixed_set_mock_fn_name;
+        let name = &mock.set_mock_fn_name;
+
+        mock_support.push(&mock.mock_support);
+
+        match &mock.associated_type {
+            Some(ty) => {
+                methods
+                    .entry(ty.to_string())
+                    .or_insert_with(|| (ty, vec![]))
+                    .1
+                    .push(quote! {
+                        pub use super::super::#prefixed as #name;
+                    });
+            }
+            None => {
+                freestanding.push(quote! {
+                    pub use super::#prefixed as #name;
+                });
+            }
+        }
+    }
+
+    let methods = methods.values().map(|(ty, uses)| {
+        quote! {
+            pub mod #ty {
+                #(#uses)*
+            }
+        }
+    });
+
+    quote! {
+        #(#mock_support)*
+
+        #[cfg(test)]
+        pub mod swift_mocks {
+            #(#freestanding)*
+
+            #(#methods)*
+        }
+    }
+}
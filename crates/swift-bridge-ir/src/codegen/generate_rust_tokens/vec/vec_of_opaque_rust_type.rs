@@ -5,7 +5,10 @@ use quote::quote;
 /// Rust type's Vectorizable implementation.
 ///
 /// So inside of `extension MyRustType: Vectorizable {}` on the Swift side.
-pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(
+    ty: &Ident,
+    rust_ty: &TokenStream,
+) -> TokenStream {
     // examples:
     // "__swift_bridge__$Vec_MyRustType$new"
     // "__swift_bridge__$Vec_MyRustType$drop"
@@ -23,29 +26,29 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
         const _: () = {
             #[doc(hidden)]
             #[export_name = #export_name_new]
-            pub extern "C" fn _new() -> *mut Vec<super::#ty> {
+            pub extern "C" fn _new() -> *mut Vec<#rust_ty> {
                 Box::into_raw(Box::new(Vec::new()))
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_drop]
-            pub extern "C" fn _drop(vec: *mut Vec<super::#ty>) {
+            pub extern "C" fn _drop(vec: *mut Vec<#rust_ty>) {
                 let vec = unsafe { Box::from_raw(vec) };
                 drop(vec)
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_len]
-            pub extern "C" fn _len(vec: *const Vec<super::#ty>) -> usize {
+            pub extern "C" fn _len(vec: *const Vec<#rust_ty>) -> usize {
                 unsafe { &*vec }.len()
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_get]
-            pub extern "C" fn _get(vec: *const Vec<super::#ty>, index: usize) -> *const super::#ty {
+            pub extern "C" fn _get(vec: *const Vec<#rust_ty>, index: usize) -> *const #rust_ty {
                 let vec = unsafe { & *vec };
                 if let Some(val) = vec.get(index) {
-                    val as *const super::#ty
+                    val as *const #rust_ty
                 } else {
                     std::ptr::null()
                 }
@@ -53,35 +56,35 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
 
             #[doc(hidden)]
             #[export_name = #export_name_get_mut]
-            pub extern "C" fn _get_mut(vec: *mut Vec<super::#ty>, index: usize) -> *mut super::#ty {
+            pub extern "C" fn _get_mut(vec: *mut Vec<#rust_ty>, index: usize) -> *mut #rust_ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.get_mut(index) {
-                    val as *mut super::#ty
+                    val as *mut #rust_ty
                 } else {
-                    std::ptr::null::<super::#ty>() as *mut super::#ty
+                    std::ptr::null::<#rust_ty>() as *mut #rust_ty
                 }
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_push]
-            pub extern "C" fn _push(vec: *mut Vec<super::#ty>, val: *mut super::#ty) {
+            pub extern "C" fn _push(vec: *mut Vec<#rust_ty>, val: *mut #rust_ty) {
                 unsafe { &mut *vec }.push( unsafe { *Box::from_raw(val) } )
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_pop]
-            pub extern "C" fn _pop(vec: *mut Vec<super::#ty>) -> *mut super::#ty {
+            pub extern "C" fn _pop(vec: *mut Vec<#rust_ty>) -> *mut #rust_ty {
                 let vec = unsafe { &mut *vec };
                 if let Some(val) = vec.pop() {
                     Box::into_raw(Box::new(val))
                 } else {
-                    std::ptr::null::<super::#ty>() as *mut super::#ty
+                    std::ptr::null::<#rust_ty>() as *mut #rust_ty
                 }
             }
 
             #[doc(hidden)]
             #[export_name = #export_name_as_ptr]
-            pub extern "C" fn _as_ptr(vec: *const Vec<super::#ty>) -> *const super::#ty {
+            pub extern "C" fn _as_ptr(vec: *const Vec<#rust_ty>) -> *const #rust_ty {
                 unsafe { & *vec }.as_ptr()
             }
         };
@@ -168,10 +171,10 @@ mod tests {
         };
 
         assert_tokens_eq(
-            &generate_vec_of_opaque_rust_type_functions(&Ident::new(
-                "ARustType",
-                Span::call_site(),
-            )),
+            &generate_vec_of_opaque_rust_type_functions(
+                &Ident::new("ARustType", Span::call_site()),
+                &quote! { super::ARustType },
+            ),
             &expected,
         );
     }
@@ -30,6 +30,13 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
     let export_name_pop = make_export_name("pop");
     let export_name_as_ptr = make_export_name("as_ptr");
 
+    // Data-carrying enums are `Clone` but not `Copy`.
+    let copy_val = if shared_enum.has_one_or_more_variants_with_data() {
+        quote! { .cloned() }
+    } else {
+        quote! { .map(|v| *v) }
+    };
+
     let ffi_enum_repr = &shared_enum.ffi_name_tokens();
     let ffi_option_enum_repr = shared_enum.ffi_option_name_tokens();
 
@@ -58,7 +65,7 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
             #[export_name = #export_name_get]
             pub extern "C" fn _get(vec: *const Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
                 let vec = unsafe { &*vec };
-                let val = vec.get(index).map(|v| *v);
+                let val = vec.get(index)#copy_val;
                 #ffi_option_enum_repr::from_rust_repr(val)
             }
 
@@ -66,7 +73,7 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
             #[export_name = #export_name_get_mut]
             pub extern "C" fn _get_mut(vec: *mut Vec<#enum_name>, index: usize) -> #ffi_option_enum_repr {
                 let vec = unsafe { &mut *vec };
-                let val = vec.get_mut(index).map(|v| *v);
+                let val = vec.get_mut(index)#copy_val;
                 #ffi_option_enum_repr::from_rust_repr(val)
             }
 
@@ -168,6 +175,7 @@ mod tests {
             variants: vec![],
             already_declared: false,
             swift_name: None,
+            swift_mirror: None,
             derive: DeriveAttrs::default(),
         };
         assert_tokens_eq(
@@ -1,11 +1,16 @@
 use std::collections::HashMap;
 
-use syn::Path;
+use quote::ToTokens;
+use syn::{FnArg, Path};
 
-use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
+use crate::bridged_type::{swift_instance_from_opaque, BridgeableType, BridgedType, TypePosition};
+use crate::codegen::generate_swift::arc::generate_arc_class;
 use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
+use crate::codegen::generate_swift::namespace::namespace_swift_types;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
+use crate::codegen::generate_swift::opaque_value_type::generate_opaque_value_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
+use crate::codegen::generate_swift::swift_protocol::generate_swift_protocol;
 use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
 use crate::codegen::CodegenConfig;
 use crate::parse::{
@@ -17,11 +22,15 @@ use crate::SwiftBridgeModule;
 
 mod vec;
 
+mod arc;
 mod generate_function_swift_calls_rust;
+mod namespace;
 mod opaque_copy_type;
+mod opaque_value_type;
 mod shared_enum;
 mod shared_struct;
 mod swift_class;
+mod swift_protocol;
 
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code for the bridging module.
@@ -34,25 +43,33 @@ impl SwiftBridgeModule {
 
         let mut associated_funcs_and_methods: HashMap<String, Vec<&ParsedExternFn>> =
             HashMap::new();
+        let mut shared_type_methods: HashMap<String, Vec<&ParsedExternFn>> = HashMap::new();
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
+        let arc_types = self.arc_opaque_types();
+        let mut namespaced_types: Vec<String> = vec![];
 
         for function in &self.functions {
             if function.host_lang.is_rust() {
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
-                        TypeDeclaration::Shared(_) => {
-                            //
-                            todo!("Think about what to do here..")
+                        TypeDeclaration::Shared(shared_ty) => {
+                            shared_type_methods
+                                .entry(shared_ty.name().to_string())
+                                .or_default()
+                                .push(function);
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
                             associated_funcs_and_methods
-                                .entry(opaque_ty.to_string())
+                                .entry(opaque_ty.monomorphized_name_string())
                                 .or_default()
                                 .push(function);
 
                             if function.is_swift_identifiable {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: match &function.swift_name_override {
+                                        Some(swift_name) => swift_name.value(),
+                                        None => function.func.sig.ident.to_string(),
+                                    },
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -95,12 +112,35 @@ impl SwiftBridgeModule {
                         swift += &swift_struct;
                         swift += "\n";
                     }
+                    if let Some(methods) = shared_type_methods.get(&shared_struct.name.to_string())
+                    {
+                        swift += &generate_shared_type_extension(
+                            &shared_struct.swift_name_string(),
+                            methods,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
+                    }
+                    if !shared_struct.already_declared {
+                        namespaced_types.push(shared_struct.swift_name_string());
+                    }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
                     if let Some(swift_enum) = self.generate_shared_enum_string(shared_enum) {
                         swift += &swift_enum;
                         swift += "\n";
                     }
+                    if let Some(methods) = shared_type_methods.get(&shared_enum.name.to_string()) {
+                        swift += &generate_shared_type_extension(
+                            &shared_enum.swift_name_string(),
+                            methods,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
+                    }
+                    if !shared_enum.already_declared {
+                        namespaced_types.push(shared_enum.swift_name_string());
+                    }
                 }
                 TypeDeclaration::Opaque(ty) => match ty.host_lang {
                     HostLang::Rust => {
@@ -111,6 +151,18 @@ impl SwiftBridgeModule {
                                 &self.types,
                                 &self.swift_bridge_path,
                             );
+                        } else if ty.attributes.swift_repr_struct {
+                            let class_protocols = class_protocols.get(&ty.ty.to_string());
+                            let default_cp = ClassProtocols::default();
+                            let class_protocols = class_protocols.unwrap_or(&default_cp);
+
+                            swift += &generate_opaque_value_struct(
+                                ty,
+                                &associated_funcs_and_methods,
+                                class_protocols,
+                                &self.types,
+                                &self.swift_bridge_path,
+                            );
                         } else {
                             let class_protocols = class_protocols.get(&ty.ty.to_string());
                             let default_cp = ClassProtocols::default();
@@ -131,22 +183,154 @@ impl SwiftBridgeModule {
                             // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                             //  make them pass.
                             // TODO: Support Vec<GenericOpaqueRustType
-                            if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                                swift += &generate_vectorizable_extension(&ty);
+                            // TODO: Support Vec<T> where T uses `swift_repr = "struct"`.
+                            // Vec<HardenedOpaqueRustType> is rejected with a parse error.
+                            if ty.attributes.copy.is_none()
+                                && !ty.attributes.swift_repr_struct
+                                && !ty.attributes.hardened
+                                && ty.generics.len() == 0
+                            {
+                                swift +=
+                                    &generate_vectorizable_extension(&ty, &ty.swift_name_string());
                                 swift += "\n";
                             }
                         }
+
+                        if !ty.attributes.already_declared && arc_types.contains(&ty.ty.to_string())
+                        {
+                            swift += &generate_arc_class(&ty.ty, &ty.swift_name_string());
+                            swift += "\n";
+                            namespaced_types.push(format!("{}Arc", ty.swift_name_string()));
+                        }
+
+                        if !ty.attributes.already_declared {
+                            let swift_name = ty.swift_name_string();
+                            namespaced_types.push(swift_name.clone());
+                            if ty.attributes.copy.is_none() && !ty.attributes.swift_repr_struct {
+                                namespaced_types.push(format!("{}Ref", swift_name));
+                                namespaced_types.push(format!("{}RefMut", swift_name));
+                            }
+                        }
                     }
                     HostLang::Swift => {
-                        swift += &generate_drop_swift_instance_reference_count(ty);
-                        swift += "\n";
+                        if !ty.attributes.already_declared {
+                            if ty.attributes.trait_object {
+                                swift += &generate_swift_protocol(
+                                    ty,
+                                    &self.functions,
+                                    &self.types,
+                                    &self.swift_bridge_path,
+                                );
+                                swift += "\n";
+                            }
+
+                            swift += &generate_drop_swift_instance_reference_count(ty);
+                            swift += "\n";
+                        }
                     }
                 },
             };
         }
 
+        if !self.swift_namespace.is_empty() && !namespaced_types.is_empty() {
+            swift = namespace_swift_types(&swift, &self.swift_namespace, &namespaced_types);
+        }
+
+        swift += &self.generate_rust_api_availability();
+
+        // Swift imports are per file, so `Data`, `LocalizedError` and the `DispatchQueue` of
+        // throttled functions need their own import of Foundation.
+        let uses_localized_error = self.types.types().iter().any(|ty| match ty {
+            TypeDeclaration::Opaque(ty) => ty.attributes.localized_error,
+            _ => false,
+        });
+        if self
+            .functions
+            .iter()
+            .any(|f| f.bytes_as_swift_data || f.throttle_ms.is_some())
+            || uses_localized_error
+        {
+            swift = format!("import Foundation\n{}", swift);
+        }
+
         swift
     }
+
+    /// Generate a `RustApi` entry for every `#[swift_bridge(since = "...")]` function, so that Swift
+    /// can check whether the loaded Rust library has the function before calling it.
+    ///
+    /// Methods are grouped under an enum named after their type, such as `RustApi.SomeType.method`.
+    fn generate_rust_api_availability(&self) -> String {
+        let mut free_functions = vec![];
+        let mut methods: Vec<(String, Vec<String>)> = vec![];
+
+        for function in self.functions.iter().filter(|f| f.host_lang.is_rust()) {
+            let since = match function.since.as_ref() {
+                Some(since) => since.value(),
+                None => continue,
+            };
+            let name = match function.swift_name_override.as_ref() {
+                Some(swift_name) => swift_name.value(),
+                None => function.sig.ident.to_string(),
+            };
+
+            match function.associated_type.as_ref() {
+                Some(TypeDeclaration::Opaque(ty)) => {
+                    let ty = ty.to_string();
+                    let entry = format!(
+                        r#"public static let {name} = RustApiFunction(name: "{ty}.{name}", since: "{since}", symbol: "{symbol}")"#,
+                        symbol = function.link_name()
+                    );
+                    match methods.iter_mut().find(|(t, _)| t == &ty) {
+                        Some((_, entries)) => entries.push(entry),
+                        None => methods.push((ty, vec![entry])),
+                    };
+                }
+                _ => {
+                    free_functions.push(format!(
+                        r#"public static let {name} = RustApiFunction(name: "{name}", since: "{since}", symbol: "{symbol}")"#,
+                        symbol = function.link_name()
+                    ));
+                }
+            };
+        }
+
+        if free_functions.is_empty() && methods.is_empty() {
+            return "".to_string();
+        }
+
+        let mut entries = "".to_string();
+        for entry in free_functions {
+            entries += &format!(
+                "    {}
+",
+                entry
+            );
+        }
+        for (ty, methods) in methods {
+            entries += &format!(
+                "    public enum {} {{
+",
+                ty
+            );
+            for entry in methods {
+                entries += &format!(
+                    "        {}
+",
+                    entry
+                );
+            }
+            entries += "    }
+";
+        }
+
+        format!(
+            "extension RustApi {{
+{}}}
+",
+            entries
+        )
+    }
 }
 
 #[derive(Default)]
@@ -172,6 +356,12 @@ struct IdentifiableProtocol {
 fn generate_drop_swift_instance_reference_count(ty: &OpaqueForeignTypeDeclaration) -> String {
     let link_name = ty.free_swift_class_link_name();
     let fn_name = ty.free_swift_class_func_name();
+    // The protocol of a `#[swift_bridge(trait_object)]` type can only be released as an `AnyObject`.
+    let ty_name = if ty.attributes.trait_object {
+        "AnyObject".to_string()
+    } else {
+        ty.swift_name_string()
+    };
 
     format!(
         r##"
@@ -182,7 +372,7 @@ func {fn_name} (ptr: UnsafeMutableRawPointer) {{
 "##,
         link_name = link_name,
         fn_name = fn_name,
-        ty_name = ty.ty_name_ident()
+        ty_name = ty_name
     )
 }
 
@@ -199,6 +389,10 @@ fn gen_function_exposes_swift_to_rust(
         func.sig.ident.to_string()
     };
 
+    if func.sig.asyncness.is_some() {
+        return gen_async_function_exposes_swift_to_rust(func, &fn_name, types, swift_bridge_path);
+    }
+
     let params = func.to_swift_param_names_and_types(true, types, swift_bridge_path);
     let ret = func.to_swift_return_type(types, swift_bridge_path);
 
@@ -206,20 +400,23 @@ fn gen_function_exposes_swift_to_rust(
     let mut call_fn = format!("{}({})", fn_name, args);
     if let Some(built_in) = BridgedType::new_with_return_type(&func.sig.output, types) {
         if let Some(associated_type) = func.associated_type.as_ref() {
-            let ty_name = match associated_type {
+            let associated_type = match associated_type {
                 TypeDeclaration::Shared(_) => {
                     //
                     todo!()
                 }
-                TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
+                TypeDeclaration::Opaque(associated_type) => associated_type,
             };
+            let ty_name = associated_type.swift_name_string();
 
             if func.is_method() {
-                call_fn = format!(
-                    "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
-                    ty_name = ty_name,
-                    call_fn = call_fn
+                let this = swift_instance_from_opaque(
+                    &ty_name,
+                    associated_type.attributes.trait_object,
+                    "this",
+                    false,
                 );
+                call_fn = format!("{this}.{call_fn}");
                 call_fn = built_in.convert_swift_expression_to_ffi_type(
                     &call_fn,
                     types,
@@ -319,6 +516,16 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
         call_fn = format!("{{ {callback_initializers} {maybe_ret}{call_fn} }}()")
     }
 
+    if func.propagates_context {
+        call_fn = format!("__private__withContextFromRust({{ {call_fn} }})");
+    }
+
+    // Rust already coalesced the calls, so all that is left is to run the latest one on the main
+    // queue.
+    if func.throttle_ms.is_some() {
+        call_fn = format!("DispatchQueue.main.async {{ {call_fn} }}");
+    }
+
     let generated_func = format!(
         r#"@_cdecl("{link_name}")
 func {prefixed_fn_name} ({params}){ret} {{
@@ -335,11 +542,128 @@ func {prefixed_fn_name} ({params}){ret} {{
     generated_func
 }
 
+// Generate a function that runs an `async` Swift function in a `Task` and passes its output to
+// Rust's completion callback.
+//
+// The arguments are converted before the `Task` starts, since borrowed arguments such as `&str`
+// are only valid until the Rust function that called Swift returns.
+//
+// # Example
+//
+// ```
+// @_cdecl("__swift_bridge__$some_function")
+// func __swift_bridge__some_function (_ callbackWrapper: UnsafeMutableRawPointer?, _ callback: @convention(c) (UnsafeMutableRawPointer?, UInt32) -> (), _ arg: RustStr) {
+//     let arg = arg.toString()
+//     Task {
+//         let val = await some_function(arg: arg)
+//         callback(callbackWrapper, val)
+//     }
+// }
+// ```
+fn gen_async_function_exposes_swift_to_rust(
+    func: &ParsedExternFn,
+    fn_name: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let link_name = func.link_name();
+    let prefixed_fn_name = func.prefixed_fn_name();
+
+    let return_ty = BridgedType::new_with_return_type(&func.sig.output, types).unwrap();
+    let (maybe_ret, call_callback) = if return_ty.can_be_encoded_with_zero_bytes() {
+        ("".to_string(), "callback(callbackWrapper)".to_string())
+    } else {
+        let ret = return_ty.to_swift_type(
+            TypePosition::FnReturn(HostLang::Swift),
+            types,
+            swift_bridge_path,
+        );
+        let val = return_ty.convert_swift_expression_to_ffi_type(
+            "val",
+            types,
+            TypePosition::FnReturn(HostLang::Swift),
+        );
+        (
+            format!(", {}", ret),
+            format!("callback(callbackWrapper, {})", val),
+        )
+    };
+
+    let mut params = vec![
+        "_ callbackWrapper: UnsafeMutableRawPointer?".to_string(),
+        format!(
+            "_ callback: @convention(c) (UnsafeMutableRawPointer?{}) -> ()",
+            maybe_ret
+        ),
+    ];
+    let swift_params = func.to_swift_param_names_and_types(true, types, swift_bridge_path);
+    if !swift_params.is_empty() {
+        params.push(swift_params);
+    }
+
+    let mut convert_args = "".to_string();
+    let mut call_args = vec![];
+    for (arg_idx, arg) in func.sig.inputs.iter().enumerate() {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) => pat_ty,
+            FnArg::Receiver(_) => continue,
+        };
+        let arg_name = pat_ty.pat.to_token_stream().to_string();
+        let bridged_ty = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
+
+        let converted = if let Some(only) = bridged_ty.only_encoding() {
+            only.swift
+        } else {
+            bridged_ty.convert_ffi_value_to_swift_value(
+                &arg_name,
+                TypePosition::FnArg(HostLang::Swift, arg_idx),
+                types,
+                swift_bridge_path,
+            )
+        };
+        if converted != arg_name {
+            convert_args += &format!("    let {} = {}\n", arg_name, converted);
+        }
+        call_args.push(match func.swift_argument_label(&arg_name) {
+            Some(label) => format!("{}: {}", label, arg_name),
+            None => arg_name,
+        });
+    }
+
+    let call_fn = format!("{}({})", fn_name, call_args.join(", "));
+    let await_fn = if return_ty.can_be_encoded_with_zero_bytes() {
+        format!("await {}", call_fn)
+    } else {
+        format!("let val = await {}", call_fn)
+    };
+
+    format!(
+        r#"@_cdecl("{link_name}")
+func {prefixed_fn_name} ({params}) {{
+{convert_args}    Task {{
+        {await_fn}
+        {call_callback}
+    }}
+}}
+"#,
+        link_name = link_name,
+        prefixed_fn_name = prefixed_fn_name,
+        params = params.join(", "),
+        convert_args = convert_args,
+        await_fn = await_fn,
+        call_callback = call_callback,
+    )
+}
+
 struct ClassMethods {
     initializers: Vec<String>,
     owned_self_methods: Vec<String>,
     ref_self_methods: Vec<String>,
     ref_mut_self_methods: Vec<String>,
+    /// `#[swift_bridge(get)]` methods.
+    ref_self_properties: Vec<String>,
+    /// `#[swift_bridge(set)]` methods.
+    ref_mut_self_properties: Vec<String>,
 }
 
 fn generate_swift_class_methods(
@@ -352,20 +676,31 @@ fn generate_swift_class_methods(
     let mut owned_self_methods = vec![];
     let mut ref_self_methods = vec![];
     let mut ref_mut_self_methods = vec![];
+    let mut ref_self_properties = vec![];
+    let mut ref_mut_self_properties = vec![];
 
     if let Some(methods) = associated_funcs_and_methods.get(type_name) {
         for type_method in methods {
             let func_definition = gen_func_swift_calls_rust(type_method, types, swift_bridge_path);
 
             let is_class_func = type_method.func.sig.inputs.is_empty();
+            // `#[swift_bridge(interior_mut)]` types only need a shared reference to mutate.
+            let needs_ref_mut =
+                type_method.self_mutability().is_some() && !type_method.is_interior_mut_method();
 
             if type_method.is_swift_initializer {
                 initializers.push(func_definition);
+            } else if type_method.swift_property.is_some() {
+                if needs_ref_mut {
+                    ref_mut_self_properties.push(func_definition);
+                } else {
+                    ref_self_properties.push(func_definition);
+                }
             } else if is_class_func {
                 ref_self_methods.push(func_definition);
             } else {
                 if type_method.self_reference().is_some() {
-                    if type_method.self_mutability().is_some() {
+                    if needs_ref_mut {
                         ref_mut_self_methods.push(func_definition);
                     } else {
                         ref_self_methods.push(func_definition);
@@ -382,9 +717,32 @@ fn generate_swift_class_methods(
         owned_self_methods,
         ref_self_methods,
         ref_mut_self_methods,
+        ref_self_properties,
+        ref_mut_self_properties,
     }
 }
 
+// extension SomeEnum {
+//     public func is_a() -> Bool { ... }
+// }
+fn generate_shared_type_extension(
+    swift_name: &str,
+    methods: &[&ParsedExternFn],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let methods: Vec<String> = methods
+        .iter()
+        .map(|method| gen_func_swift_calls_rust(method, types, swift_bridge_path))
+        .collect();
+
+    format!(
+        "extension {swift_name} {{\n{methods}\n}}\n",
+        swift_name = swift_name,
+        methods = methods.join("\n"),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
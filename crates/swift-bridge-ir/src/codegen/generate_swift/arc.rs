@@ -0,0 +1,25 @@
+use proc_macro2::Ident;
+
+/// Generate the `MyRustTypeArc` class that holds a strong reference to an `Arc<MyRustType>`.
+///
+/// `swift_ty` is the name of the Swift class, which differs from `ty` for types that use
+/// `#[swift_bridge(swift_name = "...")]`.
+pub(super) fn generate_arc_class(ty: &Ident, swift_ty: &str) -> String {
+    format!(
+        r#"public class {swift_ty}Arc: {swift_ty}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    deinit {{
+        __swift_bridge__${ty}$_arc_free(ptr)
+    }}
+
+    /// Returns another strong reference to the same Rust value.
+    public func clone() -> {swift_ty}Arc {{
+        __swift_bridge__${ty}$_arc_clone(ptr)
+        return {swift_ty}Arc(ptr: ptr)
+    }}
+}}"#
+    )
+}
@@ -1,9 +1,10 @@
 use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType, StdLibType, TypePosition};
 use crate::parse::{HostLang, TypeDeclaration};
+use crate::parsed_extern_fn::{SwiftDataBytes, SwiftProperty};
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
-use quote::ToTokens;
+use quote::{format_ident, ToTokens};
 use std::ops::Deref;
-use syn::{Path, ReturnType, Type};
+use syn::{FnArg, Path, ReturnType, Type};
 
 pub(super) fn gen_func_swift_calls_rust(
     function: &ParsedExternFn,
@@ -27,12 +28,11 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
         match ty {
-            TypeDeclaration::Shared(_) => {
-                //
-                todo!()
+            TypeDeclaration::Shared(ty) => {
+                format!("${}", ty.name())
             }
             TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
+                format!("${}", ty.monomorphized_name_string())
             }
         }
     } else {
@@ -42,7 +42,10 @@ pub(super) fn gen_func_swift_calls_rust(
     let maybe_static_class_func = if function.associated_type.is_some()
         && (!function.is_method() && !function.is_swift_initializer)
     {
-        if function.is_copy_method_on_opaque_type() {
+        if function.is_copy_method_on_opaque_type()
+            || function.is_swift_repr_struct_method_on_opaque_type()
+            || function.associated_shared_type().is_some()
+        {
             "static "
         } else {
             "class "
@@ -52,8 +55,14 @@ pub(super) fn gen_func_swift_calls_rust(
     };
 
     let public_func_fn_name = if function.is_swift_initializer {
-        if function.is_copy_method_on_opaque_type() {
+        if function.is_copy_method_on_opaque_type() || function.associated_shared_type().is_some() {
             "public init".to_string()
+        } else if function.is_swift_repr_struct_method_on_opaque_type() {
+            if function.is_swift_failable_initializer {
+                "public init?".to_string()
+            } else {
+                "public init".to_string()
+            }
         } else {
             if function.is_swift_failable_initializer {
                 "public convenience init?".to_string()
@@ -81,10 +90,16 @@ pub(super) fn gen_func_swift_calls_rust(
         type_name_segment = maybe_type_name_segment,
         call_fn = call_fn
     );
+    let returns_swift_data = match &function.sig.output {
+        ReturnType::Type(_, ty) => function.swift_data_bytes(ty, types).is_some(),
+        ReturnType::Default => false,
+    };
     let mut call_rust = if function.sig.asyncness.is_some() {
         call_rust
     } else if function.is_swift_initializer {
         call_rust
+    } else if returns_swift_data {
+        format!("RustVec<UInt8>(ptr: {}).toData()", call_rust)
     } else if let Some(built_in) = function.return_ty_built_in(types) {
         built_in.convert_ffi_value_to_swift_value(
             &call_rust,
@@ -142,6 +157,18 @@ pub(super) fn gen_func_swift_calls_rust(
         "return "
     };
 
+    // Async functions are not supported yet since the Rust future may be polled on a different
+    // thread after the call returns.
+    if function.propagates_context && function.sig.asyncness.is_none() {
+        call_rust = format!(
+            r#"__private__withContextPropagatedToRust({{
+{indentation}        {call_rust}
+{indentation}    }})"#,
+            indentation = indentation,
+            call_rust = call_rust
+        );
+    }
+
     for arg in function.func.sig.inputs.iter() {
         let bridged_arg = BridgedType::new_with_fn_arg(arg, types);
         if bridged_arg.is_none() {
@@ -151,6 +178,21 @@ pub(super) fn gen_func_swift_calls_rust(
 
         let arg_name = fn_arg_name(arg).unwrap().to_string();
 
+        if let FnArg::Typed(pat_ty) = arg {
+            if function.swift_data_bytes(&pat_ty.ty, types) == Some(SwiftDataBytes::Slice) {
+                call_rust = format!(
+                    r#"{maybe_return}{arg}.__private__withUInt8Buffer({{ {arg}AsUInt8Buffer in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+                continue;
+            }
+        }
+
         // TODO: Refactor to make less duplicative
         match bridged_arg {
             BridgedType::StdLib(StdLibType::Str) => {
@@ -182,19 +224,43 @@ pub(super) fn gen_func_swift_calls_rust(
     if function.is_swift_initializer {
         if function.is_copy_method_on_opaque_type() {
             call_rust = format!("self.bytes = {}", call_rust)
+        } else if function.associated_shared_type().is_some() {
+            let shared_ty = function.return_ty_built_in(types).unwrap();
+            call_rust = format!(
+                "self = {}",
+                shared_ty.convert_ffi_value_to_swift_value(
+                    &call_rust,
+                    TypePosition::FnReturn(function.host_lang),
+                    types,
+                    swift_bridge_path,
+                )
+            )
         } else {
             if function.is_swift_failable_initializer {
                 call_rust = format!(
                     "guard let val = {} else {{ return nil }}; self.init(ptr: val)",
                     call_rust
                 )
+            } else if function.is_swift_throwing_initializer {
+                let func_ret_ty = function.return_ty_built_in(types).unwrap();
+                call_rust = func_ret_ty
+                    .as_result()
+                    .unwrap()
+                    .convert_ffi_value_to_swift_throwing_initializer(
+                        &call_rust,
+                        TypePosition::FnReturn(function.host_lang),
+                        types,
+                        swift_bridge_path,
+                    )
             } else {
                 call_rust = format!("self.init(ptr: {})", call_rust)
             }
         }
     }
 
-    let maybe_return = if function.is_swift_initializer {
+    let maybe_return = if function.is_swift_throwing_initializer {
+        " throws".to_string()
+    } else if function.is_swift_initializer {
         "".to_string()
     } else {
         function.to_swift_return_type(types, swift_bridge_path)
@@ -202,7 +268,7 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_generics = function.maybe_swift_generics(types);
 
-    let func_definition = if function.sig.asyncness.is_some() {
+    let mut func_definition = if function.sig.asyncness.is_some() {
         let func_ret_ty = function.return_ty_built_in(types).unwrap();
         let rust_fn_ret_ty = func_ret_ty.to_swift_type(
             TypePosition::FnReturn(HostLang::Rust),
@@ -313,6 +379,15 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             fn_body_indented = fn_body_indented,
             callback_wrapper = callback_wrapper
         )
+    } else if let Some(swift_property) = &function.swift_property {
+        gen_swift_property(
+            function,
+            swift_property,
+            &call_rust,
+            indentation,
+            types,
+            swift_bridge_path,
+        )
     } else {
         format!(
             r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
@@ -328,5 +403,181 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         )
     };
 
+    if function.sig.unsafety.is_some() {
+        func_definition = format!(
+            "{indentation}/// Unsafe. Calls the Rust `unsafe fn {fn_name}`, so the caller must uphold its safety contract.\n{func_definition}",
+            fn_name = function.sig.ident,
+        );
+    }
+
+    if function.has_timeout_variant {
+        func_definition += "\n";
+        func_definition += &gen_timeout_variant(
+            function,
+            indentation,
+            maybe_static_class_func,
+            &maybe_generics,
+            &maybe_return,
+            types,
+            swift_bridge_path,
+        );
+    }
+
     func_definition
 }
+
+/// Generate a computed property for a `#[swift_bridge(get)]` or `#[swift_bridge(set)]` method.
+///
+/// The getter is a read-only property on the `Ref` class, which the setter overrides with a
+/// read-write property on the `RefMut` class.
+///
+/// A constant is read once into a `static let`, while a `static` is read every time through a
+/// computed `static var`. Both are globals when they aren't associated to a type.
+fn gen_swift_property(
+    function: &ParsedExternFn,
+    swift_property: &SwiftProperty,
+    call_rust: &str,
+    indentation: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    match swift_property {
+        SwiftProperty::Getter => {
+            let name = match &function.swift_name_override {
+                Some(swift_name) => swift_name.value(),
+                None => function.sig.ident.to_string(),
+            };
+            let ty = function.to_swift_return_type(types, swift_bridge_path);
+            let ty = ty.trim_start_matches(" -> ");
+
+            format!(
+                r#"{indentation}public var {name}: {ty} {{
+{indentation}    {call_rust}
+{indentation}}}"#
+            )
+        }
+        SwiftProperty::Constant | SwiftProperty::Static => {
+            let name = match &function.swift_name_override {
+                Some(swift_name) => swift_name.value(),
+                None => function.sig.ident.to_string(),
+            };
+            let ty = function.to_swift_return_type(types, swift_bridge_path);
+            let ty = ty.trim_start_matches(" -> ");
+            let maybe_static = if function.associated_type.is_some() {
+                "static "
+            } else {
+                ""
+            };
+
+            if let SwiftProperty::Constant = swift_property {
+                format!(r#"{indentation}public {maybe_static}let {name}: {ty} = {call_rust}"#)
+            } else {
+                format!(
+                    r#"{indentation}public {maybe_static}var {name}: {ty} {{
+{indentation}    {call_rust}
+{indentation}}}"#
+                )
+            }
+        }
+        SwiftProperty::Setter { name, ty } => {
+            let ty = BridgedType::new_with_type(ty, types)
+                .unwrap()
+                .to_swift_type(
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                );
+            let new_value = function
+                .func
+                .sig
+                .inputs
+                .iter()
+                .filter_map(fn_arg_name)
+                .find(|arg_name| *arg_name != "self")
+                .unwrap();
+
+            format!(
+                r#"{indentation}public override var {name}: {ty} {{
+{indentation}    get {{
+{indentation}        super.{name}
+{indentation}    }}
+{indentation}    set({new_value}) {{
+{indentation}        {call_rust}
+{indentation}    }}
+{indentation}}}"#
+            )
+        }
+    }
+}
+
+/// Generate an async variant of a `#[swift_bridge(timeout)]` function that takes a `timeout` in
+/// seconds and calls the synchronous function on a background thread.
+fn gen_timeout_variant(
+    function: &ParsedExternFn,
+    indentation: &str,
+    maybe_static_class_func: &str,
+    maybe_generics: &str,
+    maybe_return: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let fn_name = match &function.swift_name_override {
+        Some(swift_name) => swift_name.value(),
+        None => function.sig.ident.to_string(),
+    };
+
+    // The variant creates its own cancellation token, which it cancels when the timeout expires.
+    let params = function.to_swift_param_names_and_types(false, types, swift_bridge_path);
+    let params = params
+        .replace(", cancellationToken: CancellationToken? = nil", "")
+        .replace("cancellationToken: CancellationToken? = nil, ", "")
+        .replace("cancellationToken: CancellationToken? = nil", "");
+    let params = if params.is_empty() {
+        "timeout: Double".to_string()
+    } else {
+        format!("{}, timeout: Double", params)
+    };
+
+    let mut forwarded_args = vec![];
+    for arg in function.func.sig.inputs.iter() {
+        let arg_name = match fn_arg_name(arg) {
+            Some(arg_name) if arg_name != "self" => arg_name.to_string(),
+            _ => continue,
+        };
+
+        match function.argument_labels.get(&format_ident!("{}", arg_name)) {
+            Some(label) => forwarded_args.push(format!("{}: {}", label.value(), arg_name)),
+            None => forwarded_args.push(arg_name),
+        }
+    }
+    let closure_param = if function.is_cancellable {
+        forwarded_args.push("cancellationToken: cancellationToken".to_string());
+        "cancellationToken"
+    } else {
+        "_"
+    };
+    if function.reports_progress {
+        forwarded_args.push("progress: progress".to_string());
+    }
+
+    let (maybe_try, ret_ty) = match maybe_return.strip_prefix(" throws") {
+        Some(ret_ty) => ("try ", ret_ty),
+        None => ("", maybe_return),
+    };
+    let maybe_return = if ret_ty.is_empty() { "" } else { "return " };
+    let maybe_self = if function.associated_type.is_some() {
+        "self."
+    } else {
+        ""
+    };
+
+    format!(
+        r#"{indentation}@available(macOS 10.15, iOS 13, tvOS 13, watchOS 6, *)
+{indentation}{maybe_static_class_func}public func {fn_name}{maybe_generics}({params}) async throws{ret_ty} {{
+{indentation}    {maybe_return}try await __private__withTimeout(timeout, {{ {closure_param} in
+{indentation}        {maybe_try}{maybe_self}{fn_name}({forwarded_args})
+{indentation}    }})
+{indentation}}}"#,
+        forwarded_args = forwarded_args.join(", ")
+    )
+}
@@ -0,0 +1,76 @@
+use crate::SWIFT_BRIDGE_PREFIX;
+
+/// Move the module's Swift types into a namespace such as `Engine.Audio`.
+///
+/// Swift extensions can only be declared at file scope, so the generated types keep being declared
+/// at file scope under a prefixed name such as `__swift_bridge__Engine_Audio__Mixer`, and the
+/// namespace exposes them through typealiases.
+///
+/// ```swift
+/// public class __swift_bridge__Engine_Audio__Mixer: __swift_bridge__Engine_Audio__MixerRefMut {
+///     ...
+/// }
+///
+/// extension Engine.Audio {
+///     public typealias Mixer = __swift_bridge__Engine_Audio__Mixer
+/// }
+/// ```
+///
+/// The caseless namespace enums themselves (`public enum Engine {}`) are declared once per crate by
+/// `swift-bridge-build`, since many bridge modules can share the same parent namespace.
+pub(super) fn namespace_swift_types(
+    swift: &str,
+    namespace: &[String],
+    type_names: &[String],
+) -> String {
+    let prefix = format!("{}{}__", SWIFT_BRIDGE_PREFIX, namespace.join("_"));
+
+    let mut namespaced = String::with_capacity(swift.len());
+    let mut identifier = String::new();
+    let mut identifier_starts_string_literal = false;
+    let mut previous_char = None;
+
+    // Identifiers are joined with `$` in our FFI symbol names, so we treat `$` as part of the
+    // identifier in order to leave names such as `__swift_bridge__$Mixer$new` untouched.
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+
+    for c in swift.chars().chain(std::iter::once('\n')) {
+        if is_identifier_char(c) {
+            if identifier.is_empty() {
+                // String literals such as `"Mixer.volume"` are kept as is.
+                identifier_starts_string_literal = previous_char == Some('"');
+            }
+            identifier.push(c);
+            continue;
+        }
+
+        if !identifier_starts_string_literal && type_names.contains(&identifier) {
+            namespaced += &prefix;
+        }
+        namespaced += &identifier;
+        identifier.clear();
+
+        namespaced.push(c);
+        previous_char = Some(c);
+    }
+    namespaced.pop();
+
+    let mut typealiases = "".to_string();
+    for type_name in type_names {
+        typealiases += &format!(
+            "    public typealias {type_name} = {prefix}{type_name}\n",
+            type_name = type_name,
+            prefix = prefix
+        );
+    }
+
+    format!(
+        r#"{namespaced}
+extension {namespace} {{
+{typealiases}}}
+"#,
+        namespaced = namespaced,
+        namespace = namespace.join("."),
+        typealiases = typealiases
+    )
+}
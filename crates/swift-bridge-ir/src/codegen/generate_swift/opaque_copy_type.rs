@@ -13,7 +13,7 @@ pub(super) fn generate_opaque_copy_struct(
     let type_name = &ty.ty.to_string();
 
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.monomorphized_name_string(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
@@ -31,6 +31,49 @@ pub(super) fn generate_opaque_copy_struct(
 
     if class_methods.owned_self_methods.len() > 0 {};
 
+    if ty.attributes.equatable && ty.generics.is_empty() {
+        extensions += &format!(
+            r#"
+extension {type_name}: Equatable {{
+    public static func == (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_partial_eq(lhs.intoFfiRepr(), rhs.intoFfiRepr())
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if ty.attributes.comparable && ty.generics.is_empty() {
+        extensions += &format!(
+            r#"
+extension {type_name}: Comparable {{
+    public static func < (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_lt(lhs.intoFfiRepr(), rhs.intoFfiRepr())
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if ty.attributes.hashable && ty.generics.is_empty() {
+        extensions += &format!(
+            r#"
+extension {type_name}: Hashable {{
+    public func hash(into hasher: inout Hasher) {{
+        hasher.combine({prefix}${type_name}$_hash(self.intoFfiRepr()))
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if ty.attributes.error && ty.generics.is_empty() {
+        extensions += &format!(
+            r#"
+extension {type_name}: Error {{}}"#
+        );
+    }
+
     let struct_definition = if !ty.attributes.already_declared {
         generate_struct_definition(ty, types, swift_bridge_path)
     } else {
@@ -120,7 +163,11 @@ extension {ffi_repr_name}: SwiftBridgeGenericCopyTypeFfiRepr {{}}"#,
     )
 }
 
-fn append_methods_extension(extensions: &mut String, type_name: &str, methods: &[String]) {
+pub(super) fn append_methods_extension(
+    extensions: &mut String,
+    type_name: &str,
+    methods: &[String],
+) {
     if methods.len() == 0 {
         return;
     }
@@ -0,0 +1,165 @@
+use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::codegen::generate_swift::opaque_copy_type::append_methods_extension;
+use crate::codegen::generate_swift::ClassProtocols;
+use crate::parse::OpaqueForeignTypeDeclaration;
+use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use std::collections::HashMap;
+use syn::Path;
+
+/// Generate the Swift struct for an opaque Rust type that uses
+/// `#[swift_bridge(swift_repr = "struct")]`.
+///
+/// Copies of the struct share the same Rust value, which is freed once the last copy is gone.
+/// Since the value cannot be mutated this is indistinguishable from each copy having its own
+/// value. Whenever Rust needs to take ownership we give it a clone.
+pub(super) fn generate_opaque_value_struct(
+    ty: &OpaqueForeignTypeDeclaration,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    class_protocols: &ClassProtocols,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let type_name = &ty.ty.to_string();
+
+    let class_methods = generate_swift_class_methods(
+        &ty.monomorphized_name_string(),
+        associated_funcs_and_methods,
+        types,
+        swift_bridge_path,
+    );
+
+    let mut extensions = "".to_string();
+
+    append_methods_extension(&mut extensions, type_name, &class_methods.initializers);
+    append_methods_extension(
+        &mut extensions,
+        type_name,
+        &class_methods.owned_self_methods,
+    );
+    append_methods_extension(&mut extensions, type_name, &class_methods.ref_self_methods);
+
+    if ty.attributes.equatable {
+        extensions += &format!(
+            r#"
+extension {type_name}: Equatable {{
+    public static func == (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_partial_eq(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if ty.attributes.comparable {
+        extensions += &format!(
+            r#"
+extension {type_name}: Comparable {{
+    public static func < (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_lt(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if ty.attributes.hashable {
+        extensions += &format!(
+            r#"
+extension {type_name}: Hashable {{
+    public func hash(into hasher: inout Hasher) {{
+        hasher.combine({prefix}${type_name}$_hash(self.ptr))
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    if let Some(identifiable) = class_protocols.identifiable.as_ref() {
+        let identifiable_var = if identifiable.func_name == "id" {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+    public var id: {return_ty} {{
+        return self.{func_name}()
+    }}
+"#,
+                return_ty = identifiable.return_ty,
+                func_name = identifiable.func_name,
+            )
+        };
+
+        extensions += &format!(
+            r#"
+extension {type_name}: Identifiable {{{identifiable_var}}}"#
+        );
+    }
+
+    if ty.attributes.error {
+        extensions += &format!(
+            r#"
+extension {type_name}: Error {{}}"#
+        );
+    }
+
+    if ty.attributes.localized_error {
+        extensions += &format!(
+            r#"
+extension {type_name}: LocalizedError {{
+    public var errorDescription: String? {{
+        RustString(ptr: {prefix}${type_name}$_to_string(self.ptr)).toString()
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
+    let struct_definition = if !ty.attributes.already_declared {
+        generate_struct_definition(ty)
+    } else {
+        "".to_string()
+    };
+
+    format!(
+        r#"{struct_definition}{extensions}"#,
+        struct_definition = struct_definition,
+        extensions = extensions
+    )
+}
+
+fn generate_struct_definition(ty: &OpaqueForeignTypeDeclaration) -> String {
+    let type_name = ty.ty.to_string();
+
+    format!(
+        r#"public struct {type_name} {{
+    fileprivate let storage: __private__{type_name}Storage
+
+    var ptr: UnsafeMutableRawPointer {{
+        storage.ptr
+    }}
+
+    // Takes ownership of the Rust value, which is freed once the last copy of the struct is gone.
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.storage = __private__{type_name}Storage(ptr: ptr)
+    }}
+
+    func cloneForRust() -> UnsafeMutableRawPointer {{
+        {clone_func_name}(ptr)
+    }}
+}}
+final class __private__{type_name}Storage {{
+    let ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        {free_func_name}(ptr)
+    }}
+}}"#,
+        type_name = type_name,
+        clone_func_name = ty.clone_rust_opaque_type_ffi_name(),
+        free_func_name = ty.free_rust_opaque_type_ffi_name(),
+    )
+}
@@ -101,7 +101,7 @@ impl SwiftBridgeModule {
             );
         }
 
-        let vectorizable_impl = if shared_enum.has_one_or_more_variants_with_data() {
+        let vectorizable_impl = if !shared_enum.supports_vec() {
             "".to_string()
         } else {
             format!(
@@ -159,6 +159,13 @@ extension {enum_name}: CustomDebugStringConvertible {{
                 "".to_string()
             };
 
+        let swift_mirror_impl = match shared_enum.swift_mirror.as_ref() {
+            Some(swift_mirror) => {
+                generate_swift_mirror_conversions(shared_enum, &enum_name, &swift_mirror.value())
+            }
+            None => "".to_string(),
+        };
+
         let swift_enum = format!(
             r#"public enum {enum_name} {{{variants}}}
 extension {enum_name} {{
@@ -188,7 +195,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{swift_mirror_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -201,3 +208,60 @@ extension {option_ffi_name} {{
         Some(swift_enum)
     }
 }
+
+/// Generate initializers that convert between a shared enum and the handwritten Swift enum that
+/// mirrors it.
+///
+/// Both switches are exhaustive and have no `default` case, so the Swift code stops compiling as
+/// soon as a case is added to, removed from or renamed in only one of the two enums.
+///
+/// The initializers are internal since the handwritten enum does not have to be public.
+///
+/// ```swift
+/// extension LegacyColor {
+///     init(_ value: Color) {
+///         switch value {
+///         case .Red: self = .Red
+///         }
+///     }
+/// }
+/// extension Color {
+///     init(_ value: LegacyColor) {
+///         switch value {
+///         case .Red: self = .Red
+///         }
+///     }
+/// }
+/// ```
+fn generate_swift_mirror_conversions(
+    shared_enum: &SharedEnum,
+    enum_name: &str,
+    swift_mirror: &str,
+) -> String {
+    let cases: String = shared_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "\n        case .{name}: self = .{name}",
+                name = variant.name
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+extension {swift_mirror} {{
+    init(_ value: {enum_name}) {{
+        switch value {{{cases}
+        }}
+    }}
+}}
+extension {enum_name} {{
+    init(_ value: {swift_mirror}) {{
+        switch value {{{cases}
+        }}
+    }}
+}}"#
+    )
+}
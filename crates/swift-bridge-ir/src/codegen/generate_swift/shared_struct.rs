@@ -96,11 +96,170 @@ extension {option_ffi_name} {{
                     convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
                 );
 
+                let swift_struct = if shared_struct.patch {
+                    swift_struct + &self.generate_apply_patch_method(shared_struct)
+                } else {
+                    swift_struct
+                };
+                let swift_struct =
+                    swift_struct + &self.generate_identifiable_conformance(shared_struct);
+                let swift_struct =
+                    swift_struct + &self.generate_derived_conformances(shared_struct);
+
                 Some(swift_struct)
             }
         }
     }
 
+    /// Generate the Swift method that overwrites the fields that are set in a
+    /// `#[swift_bridge(patch)]` struct's patch, along with an initializer for an empty patch.
+    fn generate_apply_patch_method(&self, shared_struct: &SharedStruct) -> String {
+        let patch_struct = shared_struct.patch_struct();
+
+        let fields: Vec<String> = match &shared_struct.fields {
+            StructFields::Named(named) => named.iter().map(|f| f.swift_name_string()).collect(),
+            StructFields::Unnamed(unnamed) => {
+                unnamed.iter().map(|f| f.swift_name_string()).collect()
+            }
+            StructFields::Unit => vec![],
+        };
+
+        let mut set_fields = "".to_string();
+        let mut empty_fields = vec![];
+        for field in fields.iter() {
+            set_fields += &format!(
+                "        if let val = patch.{field} {{ self.{field} = val }}\n",
+                field = field
+            );
+            empty_fields.push(format!("{}: nil", field));
+        }
+
+        // Structs without fields already have an `init()`.
+        let empty_initializer = if fields.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+extension {patch_name} {{
+    public init() {{
+        self.init({empty_fields})
+    }}
+}}"#,
+                patch_name = patch_struct.swift_name_string(),
+                empty_fields = empty_fields.join(", ")
+            )
+        };
+
+        format!(
+            r#"
+extension {struct_name} {{
+    public mutating func apply(_ patch: {patch_name}) {{
+{set_fields}    }}
+}}{empty_initializer}"#,
+            struct_name = shared_struct.swift_name_string(),
+            patch_name = patch_struct.swift_name_string(),
+            set_fields = set_fields,
+            empty_initializer = empty_initializer
+        )
+    }
+
+    /// Generate the Swift `Identifiable` conformance of a
+    /// `#[swift_bridge(Identifiable = "...")]` struct, which uses the named field as its `id`.
+    fn generate_identifiable_conformance(&self, shared_struct: &SharedStruct) -> String {
+        let field = match (&shared_struct.identifiable, &shared_struct.fields) {
+            (Some(id), StructFields::Named(named)) => {
+                named.iter().find(|field| &field.name == id).unwrap()
+            }
+            _ => return "".to_string(),
+        };
+        let struct_name = shared_struct.swift_name_string();
+        let field_name = field.swift_name_string();
+
+        // A field named `id` already satisfies the protocol's requirement.
+        if field_name == "id" {
+            return format!(
+                r#"
+extension {struct_name}: Identifiable {{}}"#
+            );
+        }
+
+        let id_ty = BridgedType::new_with_type(&field.ty, &self.types)
+            .unwrap()
+            .to_swift_type(
+                TypePosition::SharedStructField,
+                &self.types,
+                &self.swift_bridge_path,
+            );
+
+        format!(
+            r#"
+extension {struct_name}: Identifiable {{
+    public var id: {id_ty} {{
+        self.{field_name}
+    }}
+}}"#
+        )
+    }
+
+    /// Generate the Swift `Equatable`, `Hashable` and `Codable` conformances of a struct that
+    /// derives `PartialEq` or `Hash`, or that uses `#[swift_bridge(Codable)]`.
+    ///
+    /// Swift synthesizes the conformances from the struct's fields, except for the `Codable`
+    /// conformance of a newtype such as `struct UserId(u64)`, which is encoded as its field alone
+    /// so that it is interchangeable with the wrapped value in Codable payloads.
+    fn generate_derived_conformances(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+        let mut conformances = "".to_string();
+
+        // `Hashable` refines `Equatable`, so Swift synthesizes both from it.
+        if shared_struct.derives.hash {
+            conformances += &format!("\nextension {struct_name}: Hashable {{}}");
+        } else if shared_struct.derives.partial_eq {
+            conformances += &format!("\nextension {struct_name}: Equatable {{}}");
+        }
+
+        if !shared_struct.codable {
+            return conformances;
+        }
+
+        let newtype_field = match &shared_struct.fields {
+            StructFields::Unnamed(unnamed) if unnamed.len() == 1 => Some(&unnamed[0]),
+            _ => None,
+        };
+        let field = match newtype_field {
+            Some(field) => field,
+            None => {
+                conformances += &format!("\nextension {struct_name}: Codable {{}}");
+                return conformances;
+            }
+        };
+
+        let field_name = field.swift_name_string();
+        let field_ty = BridgedType::new_with_type(&field.ty, &self.types)
+            .unwrap()
+            .to_swift_type(
+                TypePosition::SharedStructField,
+                &self.types,
+                &self.swift_bridge_path,
+            );
+
+        conformances += &format!(
+            r#"
+extension {struct_name}: Codable {{
+    public init(from decoder: Decoder) throws {{
+        self.init({field_name}: try decoder.singleValueContainer().decode({field_ty}.self))
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.singleValueContainer()
+        try container.encode(self.{field_name})
+    }}
+}}"#
+        );
+
+        conformances
+    }
+
     fn convert_fields_to_initializer_params<'a, T>(
         &self,
         struct_fields: impl IntoIterator<Item = &'a T>,
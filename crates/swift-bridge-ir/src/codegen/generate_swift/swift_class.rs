@@ -1,8 +1,11 @@
-use crate::codegen::generate_swift::{generate_swift_class_methods, ClassProtocols};
-use crate::parse::OpaqueForeignTypeDeclaration;
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::codegen::generate_swift::{generate_swift_class_methods, ClassMethods, ClassProtocols};
+use crate::parse::{HostLang, OpaqueForeignTypeDeclaration, TypeDeclaration};
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use quote::{format_ident, ToTokens};
 use std::collections::HashMap;
-use syn::Path;
+use std::ops::Deref;
+use syn::{FnArg, Path, ReturnType, Type};
 
 pub(super) fn generate_swift_class(
     ty: &OpaqueForeignTypeDeclaration,
@@ -14,44 +17,306 @@ pub(super) fn generate_swift_class(
     let type_name = ty.to_string();
 
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.monomorphized_name_string(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
     );
 
-    create_class_declaration(
+    let mut class = create_class_declaration(
         ty,
         class_protocols,
-        &class_methods.initializers,
-        &class_methods.owned_self_methods,
-        &class_methods.ref_self_methods,
-        &class_methods.ref_mut_self_methods,
+        &class_methods,
         types,
         swift_bridge_path,
+    );
+
+    if let Some(methods) = associated_funcs_and_methods.get(&type_name) {
+        let swift_name = ty.swift_name_string();
+        class += &generate_scoped_accessors(&swift_name, methods, types, swift_bridge_path);
+
+        if ty.attributes.bindings {
+            class += &generate_swift_ui_bindings(&swift_name, methods, types, swift_bridge_path);
+        }
+    }
+
+    class
+}
+
+/// Generate a `with` method for every method that takes no arguments and returns a reference to
+/// an opaque Rust type, such as `fn settings(&self) -> &Settings`.
+///
+/// The reference is only passed to a closure, which makes it harder to accidentally keep it
+/// around after the value that it borrows from has been freed.
+///
+/// ```swift
+/// player.withSettings { settings in
+///     settings.volume()
+/// }
+/// ```
+fn generate_scoped_accessors(
+    type_name: &str,
+    methods: &[&ParsedExternFn],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let mut ref_accessors = vec![];
+    let mut ref_mut_accessors = vec![];
+
+    for method in methods {
+        if method.self_reference().is_none()
+            || method.func.sig.inputs.len() != 1
+            || method.func.sig.asyncness.is_some()
+            || method.swift_property.is_some()
+        {
+            continue;
+        }
+
+        let return_ty = match &method.func.sig.output {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => continue,
+        };
+        match return_ty.deref() {
+            Type::Reference(_) => {}
+            _ => continue,
+        };
+        match types.get_with_type(return_ty) {
+            Some(TypeDeclaration::Opaque(opaque))
+                if opaque.host_lang.is_rust()
+                    && opaque.attributes.copy.is_none()
+                    && !opaque.attributes.swift_repr_struct => {}
+            _ => continue,
+        };
+
+        let return_swift_ty = match BridgedType::new_with_type(return_ty, types) {
+            Some(return_ty) => return_ty.to_swift_type(
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+                swift_bridge_path,
+            ),
+            None => continue,
+        };
+
+        let method_name = swift_method_name(method);
+        let accessor_name = method_name
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => "".to_string(),
+                }
+            })
+            .collect::<String>();
+
+        let accessor = format!(
+            r#"    public func with{accessor_name}<R>(_ body: ({return_swift_ty}) throws -> R) rethrows -> R {{
+        try body(self.{method_name}())
+    }}"#
+        );
+
+        if method.self_mutability().is_some() {
+            ref_mut_accessors.push(accessor);
+        } else {
+            ref_accessors.push(accessor);
+        }
+    }
+
+    let mut accessors = "".to_string();
+    for (class_suffix, class_accessors) in [("RefMut", ref_mut_accessors), ("Ref", ref_accessors)] {
+        if class_accessors.is_empty() {
+            continue;
+        }
+
+        accessors += &format!(
+            r#"
+extension {type_name}{class_suffix} {{
+{class_accessors}
+}}"#,
+            class_accessors = class_accessors.join("\n\n")
+        );
+    }
+
+    accessors
+}
+
+/// The name of the method on the Swift side.
+fn swift_method_name(method: &ParsedExternFn) -> String {
+    match &method.swift_name_override {
+        Some(swift_name) => swift_name.value(),
+        None => method.func.sig.ident.to_string(),
+    }
+}
+
+/// Generate a SwiftUI `Binding` for every getter that has a corresponding `set_` method, such as
+/// `fn volume(&self) -> f32` and `fn set_volume(&mut self, volume: f32)`.
+///
+/// `#[swift_bridge(Bindings)]`
+fn generate_swift_ui_bindings(
+    type_name: &str,
+    methods: &[&ParsedExternFn],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let mut bindings = vec![];
+
+    for setter in methods {
+        if setter.self_mutability().is_none()
+            || setter.func.sig.asyncness.is_some()
+            || setter.swift_property.is_some()
+        {
+            continue;
+        }
+        let setter_name = setter.func.sig.ident.to_string();
+        let field = match setter_name.strip_prefix("set_") {
+            Some(field) => field,
+            None => continue,
+        };
+
+        let setter_args: Vec<_> = setter
+            .func
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => Some(pat_ty),
+                _ => None,
+            })
+            .collect();
+        if setter_args.len() != 1 {
+            continue;
+        }
+        let setter_arg = setter_args[0];
+
+        let getter = methods.iter().find(|method| {
+            method.func.sig.ident == field
+                && method.self_reference().is_some()
+                && method.self_mutability().is_none()
+                && method.func.sig.inputs.len() == 1
+                && method.func.sig.asyncness.is_none()
+                && method.swift_property.is_none()
+        });
+        let (getter, getter_ty) = match getter {
+            Some(getter) => match &getter.func.sig.output {
+                ReturnType::Type(_, ty) => (getter, ty),
+                ReturnType::Default => continue,
+            },
+            None => continue,
+        };
+
+        // Only pair up methods where the value that Swift gets can be passed back to the setter.
+        let (getter_ty, setter_ty) = match (
+            BridgedType::new_with_type(getter_ty, types),
+            BridgedType::new_with_type(&setter_arg.ty, types),
+        ) {
+            (Some(getter_ty), Some(setter_ty)) => (getter_ty, setter_ty),
+            _ => continue,
+        };
+        let getter_swift_ty = getter_ty.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+        let setter_swift_ty = setter_ty.to_swift_type(
+            TypePosition::FnArg(HostLang::Rust, 0),
+            types,
+            swift_bridge_path,
+        );
+        if getter_swift_ty != setter_swift_ty {
+            continue;
+        }
+
+        let setter_arg_name = setter_arg.pat.to_token_stream().to_string();
+        let maybe_label = match setter
+            .argument_labels
+            .get(&format_ident!("{}", setter_arg_name))
+        {
+            Some(label) => format!("{}: ", label.value()),
+            None => "".to_string(),
+        };
+
+        bindings.push(format!(
+            r#"    public var {field}_binding: Binding<{getter_swift_ty}> {{
+        Binding(get: {{ self.{getter}() }}, set: {{ self.{setter}({maybe_label}$0) }})
+    }}"#,
+            getter = swift_method_name(getter),
+            setter = swift_method_name(setter),
+        ));
+    }
+
+    if bindings.is_empty() {
+        return "".to_string();
+    }
+
+    format!(
+        r#"
+#if canImport(SwiftUI)
+import SwiftUI
+
+extension {type_name}RefMut {{
+{bindings}
+}}
+#endif"#,
+        bindings = bindings.join("\n\n")
     )
 }
 
+fn class_body_properties(properties: &[String]) -> String {
+    properties
+        .iter()
+        .map(|property| format!("\n\n{}", property))
+        .collect()
+}
+
 fn create_class_declaration(
     ty: &OpaqueForeignTypeDeclaration,
     class_protocols: &ClassProtocols,
-    initializers: &[String],
-    owned_self_methods: &[String],
-    ref_self_methods: &[String],
-    ref_mut_self_methods: &[String],
+    class_methods: &ClassMethods,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
-    let type_name = &ty.ty_name_ident().to_string();
+    let type_name = &ty.swift_name_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
+    let ClassMethods {
+        initializers,
+        owned_self_methods,
+        ref_self_methods,
+        ref_mut_self_methods,
+        ref_self_properties,
+        ref_mut_self_properties,
+    } = class_methods;
 
     let mut class_decl = {
         let free_func_call = if ty.generics.len() == 0 {
-            format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name)
+            format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, ty.ty_name_ident())
         } else {
             "(self as! SwiftBridgeGenericFreer).rust_free()".to_string()
         };
 
+        // With `#[swift_bridge(manual_release)]` the Rust value outlives the Swift class unless
+        // `release()` is called.
+        let free_on_deinit_or_release = if ty.attributes.manual_release {
+            format!(
+                r#"public func release() {{
+        if isOwned {{
+            isOwned = false
+            {free_func_call}
+        }}
+    }}"#,
+                free_func_call = free_func_call
+            )
+        } else {
+            format!(
+                r#"deinit {{
+        if isOwned {{
+            {free_func_call}
+        }}
+    }}"#,
+                free_func_call = free_func_call
+            )
+        };
+
         format!(
             r#"public class {type_name}{generics}: {type_name}RefMut{generics} {{
     var isOwned: Bool = true
@@ -60,28 +325,27 @@ fn create_class_declaration(
         super.init(ptr: ptr)
     }}
 
-    deinit {{
-        if isOwned {{
-            {free_func_call}
-        }}
-    }}
+    {free_on_deinit_or_release}
 }}"#,
             type_name = type_name,
             generics = generics,
-            free_func_call = free_func_call
+            free_on_deinit_or_release = free_on_deinit_or_release
         )
     };
 
+    // Properties are declared in the class bodies since Swift does not let a declaration in an
+    // extension override another one.
     let mut class_ref_mut_decl = {
         format!(
             r#"
 public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_mut_self_properties)
         )
     };
     let mut class_ref_decl = {
@@ -92,10 +356,11 @@ public class {type_name}Ref{generics} {{
 
     public init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_self_properties)
         )
     };
     if let Some(identifiable) = class_protocols.identifiable.as_ref() {
@@ -121,16 +386,81 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         );
     }
 
+    if ty.attributes.error {
+        class_decl += &format!(
+            r#"
+extension {type_name}: Error {{}}"#,
+            type_name = type_name
+        );
+    }
+
+    if ty.attributes.localized_error {
+        let ty_name = ty.ty_name_ident();
+        class_decl += &format!(
+            r#"
+extension {type_name}: LocalizedError {{
+    public var errorDescription: String? {{
+        RustString(ptr: __swift_bridge__${ty_name}$_to_string(ptr)).toString()
+    }}
+}}"#,
+            type_name = type_name
+        );
+    }
+
+    // `next()` comes from the `fn next(self: &mut T) -> Option<Item>` that the parser declares
+    // for `#[swift_bridge(Iterator(Item = ...))]` types.
+    if ty.attributes.iterator_item.is_some() {
+        class_decl += &format!(
+            r#"
+extension {type_name}: IteratorProtocol, Sequence {{
+    public func makeIterator() -> {type_name} {{
+        self
+    }}
+}}"#,
+            type_name = type_name
+        );
+    }
+
+    // `next()` comes from the `async fn next(self: &mut T) -> Option<Item>` that the parser
+    // declares for `#[swift_bridge(Stream(Item = ...))]` types. Breaking out of a `for await`
+    // loop releases the iterator, which drops the Rust stream.
+    if ty.attributes.stream_item.is_some() {
+        class_decl += &format!(
+            r#"
+extension {type_name}: AsyncIteratorProtocol, AsyncSequence {{
+    public func makeAsyncIterator() -> {type_name} {{
+        self
+    }}
+}}"#,
+            type_name = type_name
+        );
+    }
+
+    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+
+    // The methods of `type SomeType<u32>` are only available on `SomeType<UInt32>`.
+    // extension SomeTypeRef where A == UInt32 { ... }
+    let where_clause = if is_concrete_generic {
+        format!(
+            " where {}",
+            ty.generics
+                .rust_opaque_type_swift_generic_bounds(types, swift_bridge_path)
+        )
+    } else {
+        "".to_string()
+    };
+
     let initializers = if initializers.len() == 0 {
         "".to_string()
     } else {
         let initializers: String = initializers.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {initializers}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             initializers = initializers
         )
     };
@@ -141,10 +471,11 @@ extension {type_name} {{
         let owned_instance_methods: String = owned_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {owned_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             owned_instance_methods = owned_instance_methods
         )
     };
@@ -155,10 +486,11 @@ extension {type_name} {{
         let ref_instance_methods: String = ref_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}Ref {{
+extension {type_name}Ref{where_clause} {{
 {ref_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_instance_methods = ref_instance_methods
         )
     };
@@ -169,20 +501,46 @@ extension {type_name}Ref {{
         let ref_mut_instance_methods: String = ref_mut_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}RefMut {{
+extension {type_name}RefMut{where_clause} {{
 {ref_mut_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_mut_instance_methods = ref_mut_instance_methods
         )
     };
 
-    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+    let mut raw_pointer_methods = format!(
+        r#"
+extension {type_name} {{
+    /// Unsafe. Gives up ownership of the Rust value and returns a pointer to it.
+    ///
+    /// The pointer must be passed to `init(fromRawPointer:)` exactly once, or to
+    /// `swift_bridge::raw_pointer::from_raw_pointer` in Rust, or else the Rust value is leaked.
+    public func intoRawPointer() -> UnsafeMutableRawPointer {{
+        isOwned = false
+        return ptr
+    }}
+
+    /// Unsafe. Takes ownership of a pointer that was returned by `intoRawPointer()`, or by
+    /// `swift_bridge::raw_pointer::into_raw_pointer` in Rust.
+    public convenience init(fromRawPointer ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr)
+    }}
+}}"#,
+        type_name = type_name,
+    );
 
     if ty.attributes.already_declared || is_concrete_generic {
         class_decl = "".to_string();
         class_ref_decl = "".to_string();
         class_ref_mut_decl = "".to_string();
+        raw_pointer_methods = "".to_string();
+    }
+
+    // A hardened type's `ptr` is a handle, which `swift_bridge::raw_pointer` can't consume.
+    if ty.attributes.hardened {
+        raw_pointer_methods = "".to_string();
     }
 
     let mut generic_freer = "".to_string();
@@ -207,11 +565,42 @@ where {swift_generic_bounds} {{
             let ty_name = ty.ty_name_ident();
             format!(
                 r#"
-extension {ty_name}Ref: Equatable {{
-    public static func == (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
-        __swift_bridge__${ty_name}$_partial_eq(rhs.ptr, lhs.ptr)
+extension {type_name}Ref: Equatable {{
+    public static func == (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_partial_eq(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let comparable_method: String = {
+        if ty.attributes.comparable {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {type_name}Ref: Comparable {{
+    public static func < (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_lt(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let clone_method: String = {
+        if ty.has_clone_function() {
+            format!(
+                r#"
+extension {type_name}Ref {{
+    /// Create a new Rust value using the type's `Clone` implementation.
+    public func clone() -> {type_name} {{
+        {type_name}(ptr: {clone_func_name}(ptr))
     }}
 }}"#,
+                clone_func_name = ty.clone_rust_opaque_type_ffi_name(),
             )
         } else {
             "".to_string()
@@ -222,7 +611,7 @@ extension {ty_name}Ref: Equatable {{
             let ty_name = ty.ty_name_ident();
             format!(
                 r#"
-extension {ty_name}Ref: Hashable{{
+extension {type_name}Ref: Hashable{{
     public func hash(into hasher: inout Hasher){{
         hasher.combine(__swift_bridge__${ty_name}$_hash(self.ptr))
     }}
@@ -236,7 +625,7 @@ extension {ty_name}Ref: Hashable{{
 
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{comparable_method}{clone_method}{hashable_method}{raw_pointer_methods}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -245,7 +634,10 @@ extension {ty_name}Ref: Hashable{{
         ref_mut_instance_methods = ref_mut_instance_methods,
         ref_instance_methods = ref_instance_methods,
         equatable_method = equatable_method,
+        comparable_method = comparable_method,
+        clone_method = clone_method,
         hashable_method = hashable_method,
+        raw_pointer_methods = raw_pointer_methods,
     );
 
     return class;
@@ -0,0 +1,102 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::parse::{HostLang, OpaqueForeignTypeDeclaration, TypeDeclarations};
+use crate::parsed_extern_fn::ParsedExternFn;
+use quote::ToTokens;
+use syn::{FnArg, Path};
+
+/// Generate the protocol that Swift classes conform to in order to be passed to Rust as a
+/// `#[swift_bridge(trait_object)]` extern "Swift" type.
+///
+/// ```swift
+/// public protocol SomeDelegate: AnyObject {
+///     func on_event(count: UInt32) -> Bool
+/// }
+/// ```
+///
+/// The arguments have the types that Swift receives from Rust, such as `RustString` for `String`,
+/// since that is what the generated functions that Rust calls pass along.
+pub(super) fn generate_swift_protocol(
+    ty: &OpaqueForeignTypeDeclaration,
+    functions: &[ParsedExternFn],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let mut requirements = "".to_string();
+
+    for func in functions.iter().filter(|f| f.is_method()) {
+        let is_associated = match func.associated_type.as_ref() {
+            Some(associated_type) => associated_type
+                .as_opaque()
+                .map(|associated_type| associated_type.ty == ty.ty)
+                .unwrap_or(false),
+            None => false,
+        };
+        if !is_associated {
+            continue;
+        }
+
+        requirements += &format!(
+            "    func {}\n",
+            protocol_requirement(func, types, swift_bridge_path)
+        );
+    }
+
+    format!(
+        r#"public protocol {swift_name}: AnyObject {{
+{requirements}}}
+"#,
+        swift_name = ty.swift_name_string(),
+    )
+}
+
+// on_event(count: UInt32) -> Bool
+fn protocol_requirement(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let fn_name = match func.swift_name_override.as_ref() {
+        Some(swift_name) => swift_name.value(),
+        None => func.func.sig.ident.to_string(),
+    };
+
+    let mut params = vec![];
+    for arg in func.func.sig.inputs.iter() {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+            _ => continue,
+        };
+
+        let arg_name = pat_ty.pat.to_token_stream().to_string();
+        let ty = BridgedType::new_with_type(&pat_ty.ty, types)
+            .unwrap()
+            .to_swift_type(
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+                swift_bridge_path,
+            );
+
+        let param = match func.swift_argument_label(&arg_name) {
+            Some(label) if label == arg_name => format!("{}: {}", arg_name, ty),
+            Some(label) => format!("{} {}: {}", label, arg_name, ty),
+            None => format!("_ {}: {}", arg_name, ty),
+        };
+        params.push(param);
+    }
+
+    let ret = BridgedType::new_with_return_type(&func.func.sig.output, types).unwrap();
+    let ret = if ret.is_null() {
+        "".to_string()
+    } else {
+        format!(
+            " -> {}",
+            ret.to_swift_type(
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+                swift_bridge_path
+            )
+        )
+    };
+
+    format!("{}({}){}", fn_name, params.join(", "), ret)
+}
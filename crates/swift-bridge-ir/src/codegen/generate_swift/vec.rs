@@ -1,9 +1,12 @@
 use proc_macro2::Ident;
 
 /// Generate the `extension MyRustType: Vectorizable {}` for the Swift side.
-pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
+///
+/// `swift_ty` is the name of the Swift class, which differs from `ty` for types that use
+/// `#[swift_bridge(swift_name = "...")]`.
+pub(super) fn generate_vectorizable_extension(ty: &Ident, swift_ty: &str) -> String {
     format!(
-        r#"extension {ty}: Vectorizable {{
+        r#"extension {swift_ty}: Vectorizable {{
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
         __swift_bridge__$Vec_{ty}$new()
     }}
@@ -12,7 +15,7 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
         __swift_bridge__$Vec_{ty}$drop(vecPtr)
     }}
 
-    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {swift_ty}) {{
         __swift_bridge__$Vec_{ty}$push(vecPtr, {{value.isOwned = false; return value.ptr;}}())
     }}
 
@@ -21,30 +24,30 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
         if pointer == nil {{
             return nil
         }} else {{
-            return ({ty}(ptr: pointer!) as! Self)
+            return ({swift_ty}(ptr: pointer!) as! Self)
         }}
     }}
 
-    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}Ref> {{
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_ty}Ref> {{
         let pointer = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}Ref(ptr: pointer!)
+            return {swift_ty}Ref(ptr: pointer!)
         }}
     }}
 
-    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}RefMut> {{
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_ty}RefMut> {{
         let pointer = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}RefMut(ptr: pointer!)
+            return {swift_ty}RefMut(ptr: pointer!)
         }}
     }}
 
-    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{ty}Ref> {{
-        UnsafePointer<{ty}Ref>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{swift_ty}Ref> {{
+        UnsafePointer<{swift_ty}Ref>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
     }}
 
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
@@ -52,7 +55,8 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
     }}
 }}
 "#,
-        ty = ty.to_string()
+        ty = ty.to_string(),
+        swift_ty = swift_ty
     )
 }
 
@@ -118,7 +122,10 @@ extension ARustType: Vectorizable {
 "#;
 
         assert_trimmed_generated_equals_trimmed_expected(
-            &generate_vectorizable_extension(&Ident::new("ARustType", Span::call_site())),
+            &generate_vectorizable_extension(
+                &Ident::new("ARustType", Span::call_site()),
+                "ARustType",
+            ),
             &expected,
         );
     }
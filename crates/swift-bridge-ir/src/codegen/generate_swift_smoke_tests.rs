@@ -0,0 +1,279 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::codegen::CodegenConfig;
+use crate::parse::{HostLang, OpaqueForeignTypeDeclaration, TypeDeclaration};
+use crate::parsed_extern_fn::{ParsedExternFn, SwiftProperty};
+use crate::SwiftBridgeModule;
+use quote::{format_ident, ToTokens};
+use syn::FnArg;
+
+impl SwiftBridgeModule {
+    /// Generate an XCTest method for every opaque Rust class in the module that can be created
+    /// with an initializer whose arguments we can fill in.
+    ///
+    /// Each test creates the class, calls every method whose arguments we can fill in, reads and
+    /// writes every property and then lets the class go out of scope so that it gets freed. The
+    /// return values are ignored, so the tests only catch link errors, crashes and ownership
+    /// mistakes such as a double free.
+    ///
+    /// Arguments get default values such as `0`, `false`, `""` and `nil`. Functions that take
+    /// any other type of argument are skipped.
+    ///
+    /// ```swift
+    /// func testSomeType() throws {
+    ///     let val = SomeType(0)
+    ///     _ = val.some_method("")
+    /// }
+    /// ```
+    pub fn generate_swift_smoke_tests(&self, config: CodegenConfig) -> String {
+        if !self.module_will_be_compiled(&config) {
+            return "".to_string();
+        }
+
+        let mut tests = vec![];
+
+        for ty in self.types.types() {
+            let ty = match ty {
+                TypeDeclaration::Opaque(ty)
+                    if ty.host_lang.is_rust()
+                        && ty.attributes.copy.is_none()
+                        && !ty.attributes.swift_repr_struct
+                        && !ty.attributes.already_declared
+                        && ty.generics.is_empty() =>
+                {
+                    ty
+                }
+                _ => continue,
+            };
+
+            if let Some(test) = self.generate_smoke_test(ty) {
+                tests.push(test);
+            }
+        }
+
+        tests.join("\n\n")
+    }
+
+    fn generate_smoke_test(&self, ty: &OpaqueForeignTypeDeclaration) -> Option<String> {
+        let mut swift_name = self.swift_namespace.clone();
+        swift_name.push(ty.swift_name_string());
+
+        let methods: Vec<&ParsedExternFn> = self
+            .functions
+            .iter()
+            .filter(|function| match &function.associated_type {
+                Some(TypeDeclaration::Opaque(associated)) => associated.ty == ty.ty,
+                _ => false,
+            })
+            .filter(|function| can_be_smoke_tested(function))
+            .collect();
+
+        let (initializer, init_args) = methods.iter().find_map(|function| {
+            if !function.is_swift_initializer || function.is_swift_failable_initializer {
+                return None;
+            }
+            Some((*function, self.smoke_test_call_args(function)?))
+        })?;
+        let maybe_try = if initializer.is_swift_throwing_initializer {
+            "try "
+        } else {
+            ""
+        };
+
+        let init = format!("{}{}({})", maybe_try, swift_name.join("."), init_args);
+
+        let mut body = vec![];
+
+        for method in methods {
+            if method.is_swift_initializer || method.self_reference().is_none() {
+                continue;
+            }
+
+            let method_name = match &method.swift_name_override {
+                Some(swift_name) => swift_name.value(),
+                None => method.func.sig.ident.to_string(),
+            };
+
+            match &method.swift_property {
+                Some(SwiftProperty::Getter) => {
+                    body.push(format!("_ = val.{}", method_name));
+                }
+                Some(SwiftProperty::Setter { name, .. }) => {
+                    body.push(format!("val.{name} = val.{name}", name = name));
+                }
+                // Constants don't take `self`, so they were skipped above.
+                Some(SwiftProperty::Constant) | Some(SwiftProperty::Static) => {}
+                None => {
+                    let call_args = match self.smoke_test_call_args(method) {
+                        Some(call_args) => call_args,
+                        None => continue,
+                    };
+                    let returns_null =
+                        BridgedType::new_with_return_type(&method.func.sig.output, &self.types)
+                            .map(|ty| ty.is_null())
+                            .unwrap_or(false);
+                    let maybe_ignore_return = if returns_null { "" } else { "_ = " };
+
+                    body.push(format!(
+                        "{}val.{}({})",
+                        maybe_ignore_return, method_name, call_args
+                    ));
+                }
+            }
+        }
+
+        if ty.attributes.manual_release {
+            body.push("val.release()".to_string());
+        }
+
+        // Swift warns about variables that are never used.
+        if body.is_empty() {
+            body.push(format!("_ = {}", init));
+        } else {
+            body.insert(0, format!("let val = {}", init));
+        }
+
+        let body: Vec<String> = body
+            .iter()
+            .map(|line| format!("        {}", line))
+            .collect();
+
+        Some(format!(
+            r#"    func test{test_name}() throws {{
+{body}
+    }}"#,
+            test_name = swift_name.join(""),
+            body = body.join("\n")
+        ))
+    }
+
+    /// The arguments to call a function with, such as `0, label: ""`, or `None` if one of the
+    /// arguments does not have a default value.
+    fn smoke_test_call_args(&self, function: &ParsedExternFn) -> Option<String> {
+        let mut call_args = vec![];
+
+        for (arg_idx, arg) in function.func.sig.inputs.iter().enumerate() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => continue,
+            };
+
+            let value = if function.swift_data_bytes(&pat_ty.ty, &self.types).is_some() {
+                "Data()"
+            } else {
+                let swift_ty = BridgedType::new_with_type(&pat_ty.ty, &self.types)?.to_swift_type(
+                    TypePosition::FnArg(HostLang::Rust, arg_idx),
+                    &self.types,
+                    &self.swift_bridge_path,
+                );
+                default_swift_value(&swift_ty)?
+            };
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            match function.argument_labels.get(&format_ident!("{}", arg_name)) {
+                Some(label) => call_args.push(format!("{}: {}", label.value(), value)),
+                None => call_args.push(value.to_string()),
+            }
+        }
+
+        Some(call_args.join(", "))
+    }
+}
+
+/// Whether or not we can call the function from a smoke test.
+///
+/// Versioned functions might not be in the Rust library that the tests link against, and the
+/// extra arguments of the other skipped functions have no sensible default.
+fn can_be_smoke_tested(function: &ParsedExternFn) -> bool {
+    function.host_lang.is_rust()
+        && function.sig.asyncness.is_none()
+        && function.since.is_none()
+        && !function.reports_progress
+        && !function.is_cancellable
+        && function.sig.unsafety.is_none()
+}
+
+fn default_swift_value(swift_ty: &str) -> Option<&'static str> {
+    let value = match swift_ty {
+        "UInt8" | "UInt16" | "UInt32" | "UInt64" | "UInt" | "Int8" | "Int16" | "Int32"
+        | "Int64" | "Int" | "Float" | "Double" => "0",
+        "Bool" => "false",
+        "GenericToRustStr" | "GenericIntoRustString" => "\"\"",
+        _ if swift_ty.starts_with("Optional<") => "nil",
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::CodegenConfig;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we create the class, call its methods with default arguments and skip the
+    /// methods whose arguments do not have a default value.
+    #[test]
+    fn calls_methods_with_default_arguments() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type OtherType;
+
+                    #[swift_bridge(init)]
+                    fn new(count: u32, name: &str) -> SomeType;
+
+                    fn len(self: &SomeType) -> usize;
+                    #[swift_bridge(swift_name = "setName")]
+                    fn set_name(self: &mut SomeType, #[swift_bridge(label = "to")] name: String);
+                    fn maybe(self: &SomeType, flag: bool, value: Option<u8>);
+                    fn skipped(self: &SomeType, other: &OtherType);
+                    fn consume(self: SomeType);
+                    async fn skipped_async(self: &SomeType);
+
+                    #[swift_bridge(get)]
+                    fn volume(self: &SomeType) -> u8;
+                    #[swift_bridge(set)]
+                    fn set_volume(self: &mut SomeType, volume: u8);
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let tests = module.generate_swift_smoke_tests(CodegenConfig::no_features_enabled());
+
+        let expected = r#"    func testSomeType() throws {
+        let val = SomeType(0, "")
+        _ = val.len()
+        val.setName(to: "")
+        val.maybe(false, nil)
+        _ = val.volume
+        val.volume = val.volume
+    }"#;
+        assert_eq!(tests, expected);
+    }
+
+    /// Verify that we do not generate a test for a class that we cannot create.
+    #[test]
+    fn skips_types_without_usable_initializer() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type OtherType;
+
+                    #[swift_bridge(init)]
+                    fn new(other: OtherType) -> SomeType;
+
+                    fn len(self: &SomeType) -> usize;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let tests = module.generate_swift_smoke_tests(CodegenConfig::no_features_enabled());
+
+        assert_eq!(tests, "");
+    }
+}
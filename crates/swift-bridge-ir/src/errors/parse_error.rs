@@ -1,8 +1,8 @@
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Error, FnArg, Item, Receiver};
-use syn::{ForeignItemFn, ForeignItemType, LitStr};
-use syn::{Token, Type};
+use syn::{Error, FnArg, GenericParam, Item, Receiver, WherePredicate};
+use syn::{ForeignItemFn, ForeignItemType, LitInt, LitStr};
+use syn::{Token, Type, TypeReference};
 
 pub(crate) enum ParseError {
     ArgsIntoArgNotFound {
@@ -38,8 +38,16 @@ pub(crate) enum ParseError {
     StructInvalidSwiftRepr { swift_repr_attr_value: LitStr },
     /// A struct was declared with an unrecognized attribute.
     StructUnrecognizedAttribute { attribute: Ident },
+    /// The `Identifiable = "..."` attribute of a struct does not name one of its named fields.
+    StructIdentifiableFieldNotFound { field: LitStr },
     /// An enum was declared with an unrecognized attribute.
     EnumUnrecognizedAttribute { attribute: Ident },
+    /// An enum with the `swift_mirror` attribute has a variant with fields.
+    /// Only enums whose variants have no fields can be mirrored.
+    EnumSwiftMirrorVariantHasFields {
+        swift_mirror: LitStr,
+        variant: Ident,
+    },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
@@ -52,15 +60,151 @@ pub(crate) enum ParseError {
     /// We do not currently support passing mutable references to Copy opaque types across FFI.
     // Would need to Box the copy type and pass a pointer between languages.
     ArgCopyAndRefMut { arg: FnArg },
+    /// The function argument is a mutable reference to an opaque type that uses
+    /// `#[swift_bridge(swift_repr = "struct")]`, which is immutable in Swift.
+    ArgSwiftReprStructAndRefMut { arg: FnArg },
     /// There was an unsupported item in the module, such as a `use` statement.
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// Only freestanding functions in `extern "Swift"` blocks can be async, since the Swift
+    /// object could be freed before the method's `Task` completes.
+    AsyncExternSwiftMethod { fn_ident: Ident },
+    /// Async `extern "Swift"` functions cannot take references, since Swift may still be using
+    /// the borrowed value after the Rust future was dropped.
+    AsyncExternSwiftReferenceArg { arg: FnArg },
+    /// Swift only holds handles to `#[swift_bridge(hardened)]` types, so Rust can't pass them to
+    /// Swift by reference.
+    HardenedTypeReferencePassedToSwift { reference: TypeReference },
+    /// `fn foo(arg: Vec<SomeType>);`
+    /// where `SomeType` is a `#[swift_bridge(hardened)]` type. Swift only holds handles to hardened
+    /// values, while a `Vec` stores the values themselves.
+    VecOfHardenedType { ty: Type },
+    /// `async fn foo(&self);` or `async fn foo(arg: &SomeType);`
+    /// where `SomeType` is a `#[swift_bridge(hardened)]` type. The borrowed value is only kept
+    /// alive until the function returns, which is before its future completes.
+    AsyncFnBorrowsHardenedType { arg: FnArg },
+    /// `extern "Rust" { fn foo() -> SomeDelegate; }`
+    /// where `SomeDelegate` is a `#[swift_bridge(trait_object)]` extern "Swift" type.
+    /// Rust holds these as `Box<dyn Trait>`, so they can only be passed from Swift to Rust.
+    SwiftTraitObjectPassedToSwift { ty: Type },
+    /// `fn foo<T>(arg: T)`
+    ///         -
+    /// Only lifetime parameters can be declared on a bridged function.
+    FunctionGenericParam { param: GenericParam },
+    /// `fn foo(arg: &str) where String: Clone`
+    /// Only lifetime bounds can be used in the where-clause of a bridged function.
+    FunctionWherePredicate { predicate: WherePredicate },
+    /// `extern "Rust" { static mut COUNTER: u32; }`
+    /// Swift reads a static without any synchronization, so it can't be mutable.
+    MutableStatic { ident: Ident },
+    /// `extern "Swift" { static MAX_USERS: u32; }`
+    /// Only Rust constants and statics can be bridged.
+    ExternSwiftConstant { ident: Ident },
+    /// `extern "Swift" { fn is_a(self: SomeEnum) -> bool; }`
+    /// where `SomeEnum` is a shared type. Only Rust methods can be declared on shared types.
+    ExternSwiftSharedTypeMethod { fn_ident: Ident },
+    /// `fn toggle(self: &mut SomeEnum);`
+    /// Swift passes a copy of a shared type to Rust, so changes would be lost.
+    MutableSharedTypeSelf { fn_ident: Ident },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Progress(ProgressParseError),
+    Cancellable(CancellableParseError),
+    Timeout(TimeoutParseError),
+    SwiftData(SwiftDataParseError),
+    NonReentrant(NonReentrantParseError),
+    Property(PropertyParseError),
+    Since(SinceParseError),
+    Throttle(ThrottleParseError),
+}
+
+/// An error while parsing a function's `progress` attribute.
+pub(crate) enum ProgressParseError {
+    /// Only functions in `extern "Rust"` blocks can report progress to Swift.
+    MustBeExternRust { fn_ident: Ident },
+    /// Async functions cannot report progress.
+    MustNotBeAsync { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `cancellable` attribute.
+pub(crate) enum CancellableParseError {
+    /// Only functions in `extern "Rust"` blocks can be cancelled from Swift.
+    MustBeExternRust { fn_ident: Ident },
+    /// Async functions cannot take a cancellation token.
+    MustNotBeAsync { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `timeout` attribute.
+pub(crate) enum TimeoutParseError {
+    /// Only functions in `extern "Rust"` blocks can have a timeout variant.
+    MustBeExternRust { fn_ident: Ident },
+    /// Async functions cannot have a timeout variant.
+    MustNotBeAsync { fn_ident: Ident },
+    /// Initializers cannot have a timeout variant.
+    InitializerNotSupported { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `swift_data` attribute.
+pub(crate) enum SwiftDataParseError {
+    /// Only functions in `extern "Rust"` blocks can expose their bytes as `Data`.
+    MustBeExternRust { fn_ident: Ident },
+    /// Async functions cannot expose their bytes as `Data`.
+    MustNotBeAsync { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `non_reentrant` attribute.
+pub(crate) enum NonReentrantParseError {
+    /// Only methods in `extern "Rust"` blocks can be guarded against reentrancy.
+    MustBeExternRust { fn_ident: Ident },
+    /// Only methods that take `&self` or `&mut self` on an opaque Rust type that is not `Copy`
+    /// have an object to guard.
+    NotAReferenceMethod { fn_ident: Ident },
+    /// Async methods cannot be guarded against reentrancy.
+    AsyncNotSupported { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `get` or `set` attribute.
+pub(crate) enum PropertyParseError {
+    /// Only methods in `extern "Rust"` blocks can become Swift properties.
+    MustBeExternRust { fn_ident: Ident },
+    /// Swift properties cannot be async.
+    AsyncNotSupported { fn_ident: Ident },
+    /// Properties can only be added to non generic opaque Rust types that are declared in the
+    /// same bridge module and are not `Copy`.
+    UnsupportedType { fn_ident: Ident },
+    /// A getter must take `&self`, take no other arguments and return a value.
+    InvalidGetter { fn_ident: Ident },
+    /// A setter must take `&mut self`, take one other argument and return nothing.
+    InvalidSetter { fn_ident: Ident },
+    /// A setter named `set_<property>` must have a `get` method named `<property>` that returns
+    /// the type that the setter takes.
+    GetterNotFound { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `since` attribute.
+pub(crate) enum SinceParseError {
+    /// Only functions in `extern "Rust"` blocks can be missing from an older Rust library.
+    MustBeExternRust { fn_ident: Ident },
+    /// A version must be made of numbers separated by dots, such as "1.2".
+    InvalidVersion { version: LitStr },
+}
+
+/// An error while parsing a function's `throttle_ms` attribute.
+pub(crate) enum ThrottleParseError {
+    /// Only calls from Rust to Swift functions can be throttled.
+    MustBeExternSwift { fn_ident: Ident },
+    /// Only freestanding functions that are not async and do not return a value can be
+    /// throttled, since a call that gets coalesced never runs.
+    UnsupportedFunction { fn_ident: Ident },
+    /// A throttled call can run after the Rust function returns, so it can not borrow its
+    /// arguments.
+    ReferenceArg { arg: FnArg },
+    /// The interval must be a whole number of milliseconds that is greater than 0.
+    InvalidInterval { interval: LitInt },
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -130,8 +274,8 @@ self: &mut SomeType
             ParseError::StructMissingSwiftRepr { struct_ident } => {
                 let message = format!(
                     r#"Shared structs with one or more fields must specify their swift
-representation. 
- 
+representation.
+
 ```
 // Valid values are "struct" and "class"
 #[swift_bridge(swift_repr = "struct")]
@@ -140,7 +284,8 @@ struct {struct_name} {{
 }}
 ```
 
-TODO: Link to documntation on how to decide on the swift representation.
+See the `swift_repr` section of the "Transparent Structs" chapter of the swift-bridge book
+to decide between the two.
 "#,
                     struct_name = struct_ident
                 );
@@ -149,7 +294,7 @@ TODO: Link to documntation on how to decide on the swift representation.
             ParseError::StructInvalidSwiftRepr {
                 swift_repr_attr_value,
             } => {
-                let message = r#"Invalid value. Must be either "class" or "struct"#;
+                let message = r#"Invalid value. Must be either "class" or "struct"."#;
                 Error::new_spanned(swift_repr_attr_value, message)
             }
             ParseError::EmptyStructHasSwiftReprClass {
@@ -173,10 +318,28 @@ struct {struct_name};
                 let message = format!(r#"Did not recognize struct attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::StructIdentifiableFieldNotFound { field } => {
+                let message = format!(
+                    r#"Identifiable field "{}" is not a named field of the struct."#,
+                    field.value()
+                );
+                Error::new_spanned(field, message)
+            }
             ParseError::EnumUnrecognizedAttribute { attribute } => {
                 let message = format!(r#"Did not recognize enum attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::EnumSwiftMirrorVariantHasFields {
+                swift_mirror,
+                variant,
+            } => {
+                let message = format!(
+                    r#"Variant "{}" has fields. Only enums whose variants have no fields can use swift_mirror = "{}"."#,
+                    variant,
+                    swift_mirror.value()
+                );
+                Error::new_spanned(variant, message)
+            }
             ParseError::FunctionAttribute(fn_attrib) => match fn_attrib {
                 FunctionAttributeParseError::Identifiable(identifiable) => match identifiable {
                     IdentifiableParseError::MustBeRefSelf { fn_ident } => {
@@ -194,12 +357,180 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Progress(progress) => match progress {
+                    ProgressParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"Progress function {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ProgressParseError::MustNotBeAsync { fn_ident } => {
+                        let message = format!(r#"Progress function {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Cancellable(cancellable) => match cancellable {
+                    CancellableParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"Cancellable function {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    CancellableParseError::MustNotBeAsync { fn_ident } => {
+                        let message =
+                            format!(r#"Cancellable function {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Timeout(timeout) => match timeout {
+                    TimeoutParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"Timeout function {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    TimeoutParseError::MustNotBeAsync { fn_ident } => {
+                        let message = format!(r#"Timeout function {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    TimeoutParseError::InitializerNotSupported { fn_ident } => {
+                        let message =
+                            format!(r#"Timeout function {} cannot be an initializer."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::SwiftData(swift_data) => match swift_data {
+                    SwiftDataParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"swift_data function {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SwiftDataParseError::MustNotBeAsync { fn_ident } => {
+                        let message =
+                            format!(r#"swift_data function {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::NonReentrant(non_reentrant) => match non_reentrant {
+                    NonReentrantParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"non_reentrant method {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    NonReentrantParseError::NotAReferenceMethod { fn_ident } => {
+                        let message = format!(
+                            r#"non_reentrant method {} must take &self or &mut self on an opaque Rust type that is not Copy."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    NonReentrantParseError::AsyncNotSupported { fn_ident } => {
+                        let message =
+                            format!(r#"non_reentrant method {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Property(property) => match property {
+                    PropertyParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"Property method {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::AsyncNotSupported { fn_ident } => {
+                        let message = format!(r#"Property method {} cannot be async."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::UnsupportedType { fn_ident } => {
+                        let message = format!(
+                            r#"Property method {} must be on a non generic opaque Rust type that is declared in this module and is not Copy."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::InvalidGetter { fn_ident } => {
+                        let message = format!(
+                            r#"get method {} must take only &self and return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::InvalidSetter { fn_ident } => {
+                        let message = format!(
+                            r#"set method {} must take &mut self and one other argument and return nothing."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::GetterNotFound { fn_ident } => {
+                        let message = format!(
+                            r#"set method {} must be named set_<property> and have a get method named <property> that returns the type that {} takes."#,
+                            fn_ident, fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Since(since) => match since {
+                    SinceParseError::MustBeExternRust { fn_ident } => {
+                        let message = format!(
+                            r#"Versioned function {} must be declared in an extern "Rust" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SinceParseError::InvalidVersion { version } => {
+                        let message = format!(
+                            r#"Version "{}" must be numbers separated by dots, such as "1.2"."#,
+                            version.value()
+                        );
+                        Error::new_spanned(version, message)
+                    }
+                },
+                FunctionAttributeParseError::Throttle(throttle) => match throttle {
+                    ThrottleParseError::MustBeExternSwift { fn_ident } => {
+                        let message = format!(
+                            r#"Throttled function {} must be declared in an extern "Swift" block."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ThrottleParseError::UnsupportedFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Throttled function {} must be a freestanding function that is not async and does not return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ThrottleParseError::ReferenceArg { arg } => {
+                        let message = "Throttled functions can not take references, since the call can happen after the Rust function returns.";
+                        Error::new_spanned(arg, message)
+                    }
+                    ThrottleParseError::InvalidInterval { interval } => {
+                        let message = format!(
+                            r#"Throttle interval {} must be a number of milliseconds that is greater than 0."#,
+                            interval
+                        );
+                        Error::new_spanned(interval, message)
+                    }
+                },
             },
             ParseError::ArgCopyAndRefMut { arg } => {
                 let message =
                     format!(r#"Mutable references to opaque Copy types are not yet supported."#);
                 Error::new_spanned(arg, message)
             }
+            ParseError::ArgSwiftReprStructAndRefMut { arg } => {
+                let message = r#"Opaque types with `swift_repr = "struct"` are immutable, so they cannot be mutably borrowed."#;
+                Error::new_spanned(arg, message)
+            }
             ParseError::InvalidModuleItem { item } => {
                 let message = format!(r#"Only `extern` blocks, structs and enums are supported."#);
                 Error::new_spanned(item, message)
@@ -209,6 +540,63 @@ struct {struct_name};
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::AsyncExternSwiftMethod { fn_ident } => {
+                let message = format!(
+                    r#"Swift method {} cannot be async. Only freestanding extern "Swift" functions can be async."#,
+                    fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            ParseError::AsyncExternSwiftReferenceArg { arg } => {
+                let message = r#"Async extern "Swift" functions cannot take references. Pass an owned value instead."#;
+                Error::new_spanned(arg, message)
+            }
+            ParseError::HardenedTypeReferencePassedToSwift { reference } => {
+                let message = "Hardened types cannot be passed to Swift by reference, since Swift only holds handles to owned values.";
+                Error::new_spanned(reference, message)
+            }
+            ParseError::VecOfHardenedType { ty } => {
+                let message = "Vecs of hardened types are not supported, since Swift only holds handles to hardened values.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::AsyncFnBorrowsHardenedType { arg } => {
+                let message = "Async functions cannot borrow hardened types, since the value could be freed before the future completes. Pass an owned value instead.";
+                Error::new_spanned(arg, message)
+            }
+            ParseError::SwiftTraitObjectPassedToSwift { ty } => {
+                let message = "trait_object Swift types can only be passed from Swift to Rust, since Rust holds them as a `Box<dyn Trait>`.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::FunctionGenericParam { param } => {
+                let message = "Bridged functions can only have lifetime parameters.";
+                Error::new_spanned(param, message)
+            }
+            ParseError::FunctionWherePredicate { predicate } => {
+                let message = "Bridged functions can only have lifetime bounds in their where-clause, such as `'b: 'a`.";
+                Error::new_spanned(predicate, message)
+            }
+            ParseError::MutableStatic { ident } => {
+                let message = "Mutable statics cannot be bridged. Use a static with interior mutability and bridge a function that reads it instead.";
+                Error::new_spanned(ident, message)
+            }
+            ParseError::ExternSwiftConstant { ident } => {
+                let message = r#"Statics can only be declared in extern "Rust" blocks."#;
+                Error::new_spanned(ident, message)
+            }
+            ParseError::ExternSwiftSharedTypeMethod { fn_ident } => {
+                let message = format!(
+                    r#"Method {} cannot be declared on a shared type in an extern "Swift" block. Declare it in an extern "Rust" block instead."#,
+                    fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            ParseError::MutableSharedTypeSelf { fn_ident } => {
+                let message = format!(
+                    r#"Method {} cannot take `self: &mut` a shared type. Take `self` by value and return the new value instead."#,
+                    fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
         }
     }
 }
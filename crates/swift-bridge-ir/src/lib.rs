@@ -8,7 +8,7 @@
 #![deny(missing_docs)]
 
 use proc_macro2::Ident;
-use syn::{Path, Visibility};
+use syn::{LitStr, Path, Visibility};
 
 use crate::bridge_module_attributes::CfgAttr;
 use crate::parse::TypeDeclarations;
@@ -17,6 +17,13 @@ use crate::parsed_extern_fn::ParsedExternFn;
 pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
 pub use self::codegen::CodegenConfig;
 
+/// The version of the conventions that the generated Rust, Swift and C code use to pass values
+/// across the FFI boundary.
+///
+/// This changes whenever code generated by an older version of swift-bridge can no longer call code
+/// generated by a newer one.
+pub const BRIDGE_ABI_VERSION: u32 = 1;
+
 mod errors;
 mod parse;
 
@@ -63,6 +70,17 @@ pub struct SwiftBridgeModule {
     functions: Vec<ParsedExternFn>,
     swift_bridge_path: Path,
     cfg_attrs: Vec<CfgAttr>,
+    record_breadcrumbs: bool,
+    swift_namespace: Vec<String>,
+    generate_swift_mocks: bool,
+    cxx_header: Option<CxxHeader>,
+}
+
+/// Options for a C header that is also usable from C++.
+#[derive(Copy, Clone)]
+pub struct CxxHeader {
+    /// Declare an `enum class` for every transparent enum that has no fields.
+    pub enum_class: bool,
 }
 
 impl SwiftBridgeModule {
@@ -71,6 +89,57 @@ impl SwiftBridgeModule {
     pub fn set_swift_bridge_path(&mut self, path: Path) {
         self.swift_bridge_path = path;
     }
+
+    /// Whether or not the generated Rust functions should record a breadcrumb with
+    /// `swift_bridge::breadcrumbs` every time that Swift calls them.
+    pub fn set_record_breadcrumbs(&mut self, record_breadcrumbs: bool) {
+        self.record_breadcrumbs = record_breadcrumbs;
+    }
+
+    /// Whether or not every call between Swift and the module's functions should propagate the
+    /// context that was registered with `swift_bridge::context`.
+    pub fn set_propagate_context(&mut self, propagate_context: bool) {
+        for function in self.functions.iter_mut() {
+            function.propagates_context = propagate_context;
+        }
+    }
+
+    /// Whether or not Rust tests should call mocks instead of the module's `extern "Swift"`
+    /// functions, so that they can run without linking any Swift.
+    pub fn set_generate_swift_mocks(&mut self, generate_swift_mocks: bool) {
+        self.generate_swift_mocks = generate_swift_mocks;
+    }
+
+    /// Whether or not the module's part of the generated C header should also be usable from C++.
+    pub fn set_cxx_header(&mut self, cxx_header: Option<CxxHeader>) {
+        self.cxx_header = cxx_header;
+    }
+
+    /// Nest the module's Swift types inside of caseless enums that mirror the given Rust module
+    /// path, so that `engine::audio` types are accessed as `Engine.Audio.SomeType`.
+    pub fn set_swift_namespace(&mut self, rust_module_path: &LitStr) {
+        self.swift_namespace = rust_module_path
+            .value()
+            .split("::")
+            .map(|segment| {
+                segment
+                    .split('_')
+                    .filter(|word| !word.is_empty())
+                    .map(|word| {
+                        let mut chars = word.chars();
+                        let first = chars.next().unwrap();
+                        first.to_ascii_uppercase().to_string() + chars.as_str()
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    /// The Swift namespace that the module's types are nested inside of, such as
+    /// `["Engine", "Audio"]`. Empty if the module does not use a Swift namespace.
+    pub fn swift_namespace(&self) -> &[String] {
+        &self.swift_namespace
+    }
 }
 
 #[cfg(test)]
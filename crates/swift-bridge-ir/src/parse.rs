@@ -1,18 +1,24 @@
 use crate::bridge_module_attributes::CfgAttr;
-use crate::bridged_type::BridgedType;
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType};
 use crate::errors::{ParseError, ParseErrors};
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
-use crate::SwiftBridgeModule;
-use proc_macro2::TokenTree;
+use crate::parsed_extern_fn::ParsedExternFn;
+use crate::{CxxHeader, SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+use proc_macro2::{Ident, TokenTree};
 use quote::{quote, ToTokens};
+use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{
+    FnArg, GenericArgument, Item, ItemForeignMod, ItemMod, PathArguments, ReturnType, Token, Type,
+    TypeReference,
+};
 
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
+mod type_registry;
 
 mod type_declarations;
 pub(crate) use self::type_declarations::*;
@@ -56,6 +62,7 @@ impl Parse for SwiftBridgeModuleAndErrors {
         let mut errors = ParseErrors::new();
 
         if let Ok(item_mod) = input.parse::<ItemMod>() {
+            let original_item_mod = item_mod.clone();
             let module_name = item_mod.ident;
             let vis = item_mod.vis;
 
@@ -63,6 +70,8 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
             let mut cfg_attrs = vec![];
+            let mut bridge_attrs = vec![];
+            let mut initialize_configs = vec![];
 
             for attr in item_mod.attrs {
                 match attr.path.to_token_stream().to_string().as_str() {
@@ -70,6 +79,14 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         let cfg: CfgAttr = syn::parse2(attr.tokens)?;
                         cfg_attrs.push(cfg);
                     }
+                    // When parsing a module from a source file, such as in `swift-bridge-build`,
+                    // the `#[swift_bridge::bridge(...)]` attribute is still on the module.
+                    "swift_bridge :: bridge" | "swift_bridge_macro :: bridge"
+                        if !attr.tokens.is_empty() =>
+                    {
+                        let attrs: SwiftBridgeModuleAttrs = attr.parse_args()?;
+                        bridge_attrs.extend(attrs.attributes);
+                    }
                     _ => {}
                 };
             }
@@ -91,10 +108,26 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             errors: &mut errors,
                         }
                         .parse()?;
+                        if shared_struct.initialize {
+                            initialize_configs.push(shared_struct.name.clone());
+                        }
+                        let patch_struct = if shared_struct.patch {
+                            Some(shared_struct.patch_struct())
+                        } else {
+                            None
+                        };
                         type_declarations.insert(
                             shared_struct.name.to_string(),
                             TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)),
                         );
+                        if let Some(patch_struct) = patch_struct {
+                            type_declarations.insert(
+                                patch_struct.name.to_string(),
+                                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(
+                                    patch_struct,
+                                )),
+                            );
+                        }
                     }
                     Item::Enum(item_enum) => {
                         let shared_enum = SharedEnumDeclarationParser {
@@ -114,24 +147,123 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 };
             }
 
-            for unresolved_type in unresolved_types.into_iter() {
-                if BridgedType::new_with_type(&unresolved_type, &type_declarations).is_some() {
-                    continue;
+            for config in initialize_configs {
+                ForeignModParser {
+                    errors: &mut errors,
+                    type_declarations: &mut type_declarations,
+                    functions: &mut functions,
+                    unresolved_types: &mut unresolved_types,
+                }
+                .parse(initialize_function(&config))?;
+            }
+
+            let iterators: Vec<_> = type_declarations
+                .types()
+                .into_iter()
+                .filter_map(|ty| ty.as_opaque())
+                .filter(|ty| ty.host_lang.is_rust() && !ty.attributes.already_declared)
+                .filter_map(|ty| Some((ty.ty.clone(), ty.attributes.iterator_item.clone()?)))
+                .collect();
+            for (ty, item) in iterators {
+                ForeignModParser {
+                    errors: &mut errors,
+                    type_declarations: &mut type_declarations,
+                    functions: &mut functions,
+                    unresolved_types: &mut unresolved_types,
+                }
+                .parse(iterator_next_function(&ty, &item))?;
+            }
+
+            let streams: Vec<_> = type_declarations
+                .types()
+                .into_iter()
+                .filter_map(|ty| ty.as_opaque())
+                .filter(|ty| ty.host_lang.is_rust() && !ty.attributes.already_declared)
+                .filter_map(|ty| Some((ty.ty.clone(), ty.attributes.stream_item.clone()?)))
+                .collect();
+            for (ty, item) in streams {
+                ForeignModParser {
+                    errors: &mut errors,
+                    type_declarations: &mut type_declarations,
+                    functions: &mut functions,
+                    unresolved_types: &mut unresolved_types,
+                }
+                .parse(stream_next_function(&ty, &item))?;
+            }
+
+            type_registry::register_declared_types(&type_declarations);
+
+            for func in functions.iter() {
+                for reference in hardened_references_passed_to_swift(func, &type_declarations) {
+                    errors.push(ParseError::HardenedTypeReferencePassedToSwift { reference });
+                }
+                for ty in swift_trait_objects_passed_to_swift(func, &type_declarations) {
+                    errors.push(ParseError::SwiftTraitObjectPassedToSwift { ty });
+                }
+                for ty in vecs_of_hardened_types(func, &type_declarations) {
+                    errors.push(ParseError::VecOfHardenedType { ty });
+                }
+                for arg in hardened_types_borrowed_by_async_fn(func, &type_declarations) {
+                    errors.push(ParseError::AsyncFnBorrowsHardenedType { arg });
+                }
+            }
+
+            let unresolved_types: Vec<_> = unresolved_types
+                .into_iter()
+                .filter(|ty| BridgedType::new_with_type(ty, &type_declarations).is_none())
+                .collect();
+
+            // Types that other bridge modules in the crate declared get declared as
+            // `already_declared`, then we parse the module again.
+            if !unresolved_types.is_empty() {
+                let mut item_mod = original_item_mod;
+                if type_registry::declare_types_from_other_modules(&mut item_mod, &unresolved_types)
+                {
+                    return syn::parse2(item_mod.to_token_stream());
                 }
+            }
 
+            for unresolved_type in unresolved_types.into_iter() {
                 errors.push(ParseError::UndeclaredType {
                     ty: unresolved_type.clone(),
                 });
             }
 
-            let module = SwiftBridgeModule {
+            let mut module = SwiftBridgeModule {
                 name: module_name,
                 vis,
                 types: type_declarations,
                 functions,
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
                 cfg_attrs,
+                record_breadcrumbs: false,
+                swift_namespace: vec![],
+                generate_swift_mocks: false,
+                cxx_header: None,
             };
+            for attr in bridge_attrs {
+                match attr {
+                    SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
+                        module.set_swift_bridge_path(path);
+                    }
+                    SwiftBridgeModuleAttr::Breadcrumbs => {
+                        module.set_record_breadcrumbs(true);
+                    }
+                    SwiftBridgeModuleAttr::PropagateContext => {
+                        module.set_propagate_context(true);
+                    }
+                    SwiftBridgeModuleAttr::SwiftNamespace(path) => {
+                        module.set_swift_namespace(&path);
+                    }
+                    SwiftBridgeModuleAttr::SwiftMocks => {
+                        module.set_generate_swift_mocks(true);
+                    }
+                    SwiftBridgeModuleAttr::CxxHeader { enum_class } => {
+                        module.set_cxx_header(Some(CxxHeader { enum_class }));
+                    }
+                }
+            }
+
             Ok(SwiftBridgeModuleAndErrors { module, errors })
         } else {
             return Err(syn::Error::new_spanned(
@@ -142,6 +274,252 @@ impl Parse for SwiftBridgeModuleAndErrors {
     }
 }
 
+// A `#[swift_bridge(initialize)]` struct is passed from Swift to the `initialize` function that the
+// user defines, as if the module declared this extern "Rust" block.
+fn initialize_function(config: &Ident) -> ItemForeignMod {
+    syn::parse_quote! {
+        extern "Rust" {
+            fn initialize(#[swift_bridge(label = "config")] config: #config);
+        }
+    }
+}
+
+// A `#[swift_bridge(Iterator(Item = T))]` type's `Iterator::next` is exposed to Swift as if the
+// module declared this extern "Rust" block.
+fn iterator_next_function(ty: &Ident, item: &Type) -> ItemForeignMod {
+    syn::parse_quote! {
+        extern "Rust" {
+            fn next(self: &mut #ty) -> Option<#item>;
+        }
+    }
+}
+
+// A `#[swift_bridge(Stream(Item = T))]` type's next item is exposed to Swift as if the module
+// declared this extern "Rust" block. The call is routed through
+// `swift_bridge::async_support::next_stream_item`, since the type only implements `Stream`.
+fn stream_next_function(ty: &Ident, item: &Type) -> ItemForeignMod {
+    syn::parse_quote! {
+        extern "Rust" {
+            async fn next(self: &mut #ty) -> Option<#item>;
+        }
+    }
+}
+
+// Swift only holds handles to the values that a `#[swift_bridge(hardened)]` type's handle table
+// owns, so Rust can't give Swift a reference to a hardened type.
+fn hardened_references_passed_to_swift(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> Vec<TypeReference> {
+    let mut references = vec![];
+
+    match func.host_lang {
+        HostLang::Rust => {
+            if let ReturnType::Type(_, ty) = &func.func.sig.output {
+                find_hardened_references(ty, types, &mut references);
+            }
+        }
+        HostLang::Swift => {
+            for arg in func.func.sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    find_hardened_references(&pat_ty.ty, types, &mut references);
+                }
+            }
+        }
+    }
+
+    references
+}
+
+fn find_hardened_references(
+    ty: &Type,
+    types: &TypeDeclarations,
+    references: &mut Vec<TypeReference>,
+) {
+    match ty {
+        Type::Reference(reference) => {
+            let declaration = match reference.elem.deref() {
+                Type::Path(path) => path
+                    .path
+                    .get_ident()
+                    .and_then(|ty| types.get(&ty.to_string())),
+                _ => None,
+            };
+
+            if let Some(TypeDeclaration::Opaque(opaque)) = declaration {
+                if opaque.attributes.hardened {
+                    references.push(reference.clone());
+                }
+            }
+        }
+        Type::Path(path) => {
+            for segment in path.path.segments.iter() {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in args.args.iter() {
+                        if let GenericArgument::Type(ty) = arg {
+                            find_hardened_references(ty, types, references);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for ty in tuple.elems.iter() {
+                find_hardened_references(ty, types, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Rust only ever sees a `#[swift_bridge(trait_object)]` Swift type as a `Box<dyn Trait>`, which
+// might not be backed by a Swift instance, so it can only be passed from Swift to Rust.
+fn swift_trait_objects_passed_to_swift(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> Vec<Type> {
+    let mut trait_objects = vec![];
+
+    match func.host_lang {
+        HostLang::Rust => {
+            if let ReturnType::Type(_, ty) = &func.func.sig.output {
+                find_swift_trait_objects(ty, types, &mut trait_objects);
+            }
+        }
+        HostLang::Swift => {
+            for arg in func.func.sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    if !pat_type_pat_is_self(pat_ty) {
+                        find_swift_trait_objects(&pat_ty.ty, types, &mut trait_objects);
+                    }
+                }
+            }
+        }
+    }
+
+    trait_objects
+}
+
+fn find_swift_trait_objects(ty: &Type, types: &TypeDeclarations, trait_objects: &mut Vec<Type>) {
+    match ty {
+        Type::Path(path) => {
+            if let Some(TypeDeclaration::Opaque(opaque)) = path
+                .path
+                .get_ident()
+                .and_then(|ty| types.get(&ty.to_string()))
+            {
+                if opaque.host_lang.is_swift() && opaque.attributes.trait_object {
+                    trait_objects.push(ty.clone());
+                }
+            }
+
+            for segment in path.path.segments.iter() {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in args.args.iter() {
+                        if let GenericArgument::Type(ty) = arg {
+                            find_swift_trait_objects(ty, types, trait_objects);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for ty in tuple.elems.iter() {
+                find_swift_trait_objects(ty, types, trait_objects);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Swift only holds handles to `#[swift_bridge(hardened)]` types, while a `Vec` stores the values
+// themselves.
+fn vecs_of_hardened_types(func: &ParsedExternFn, types: &TypeDeclarations) -> Vec<Type> {
+    let mut vecs = vec![];
+
+    if let ReturnType::Type(_, ty) = &func.func.sig.output {
+        find_vecs_of_hardened_types(ty, types, &mut vecs);
+    }
+    for arg in func.func.sig.inputs.iter() {
+        if let FnArg::Typed(pat_ty) = arg {
+            find_vecs_of_hardened_types(&pat_ty.ty, types, &mut vecs);
+        }
+    }
+
+    vecs
+}
+
+fn find_vecs_of_hardened_types(ty: &Type, types: &TypeDeclarations, vecs: &mut Vec<Type>) {
+    match ty {
+        Type::Path(path) => {
+            for segment in path.path.segments.iter() {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in args.args.iter() {
+                        if let GenericArgument::Type(inner) = arg {
+                            if segment.ident == "Vec" && is_hardened_type(inner, types) {
+                                vecs.push(ty.clone());
+                            }
+                            find_vecs_of_hardened_types(inner, types, vecs);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(reference) => {
+            find_vecs_of_hardened_types(&reference.elem, types, vecs);
+        }
+        Type::Tuple(tuple) => {
+            for ty in tuple.elems.iter() {
+                find_vecs_of_hardened_types(ty, types, vecs);
+            }
+        }
+        _ => {}
+    }
+}
+
+// A borrowed hardened value is only kept alive until the Rust function returns, which is before
+// an async function's future completes.
+fn hardened_types_borrowed_by_async_fn(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> Vec<FnArg> {
+    if func.func.sig.asyncness.is_none() || !func.host_lang.is_rust() {
+        return vec![];
+    }
+
+    let self_is_hardened = match func.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(opaque)) => opaque.attributes.hardened,
+        _ => false,
+    };
+
+    func.func
+        .sig
+        .inputs
+        .iter()
+        .filter(|arg| match arg {
+            FnArg::Receiver(receiver) => self_is_hardened && receiver.reference.is_some(),
+            FnArg::Typed(pat_ty) => {
+                let mut references = vec![];
+                find_hardened_references(&pat_ty.ty, types, &mut references);
+                !references.is_empty()
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+fn is_hardened_type(ty: &Type, types: &TypeDeclarations) -> bool {
+    let declaration = match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .and_then(|ty| types.get(&ty.to_string())),
+        _ => None,
+    };
+
+    matches!(declaration, Some(TypeDeclaration::Opaque(opaque)) if opaque.attributes.hardened)
+}
+
 // Used to fast-forward our attribute parsing to the next attribute when we've run into an
 // issue parsing the current attribute.
 fn move_input_cursor_to_next_comma(input: ParseStream) {
@@ -209,4 +587,134 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    /// Verify that we get an error when Rust passes a `#[swift_bridge(trait_object)]` Swift type to
+    /// Swift.
+    #[test]
+    fn error_if_swift_trait_object_passed_to_swift() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(trait_object)]
+                    type SomeDelegate;
+
+                    fn on_event(&self);
+                    fn swift_takes(arg: Option<SomeDelegate>);
+                }
+
+                extern "Rust" {
+                    fn rust_takes(arg: SomeDelegate);
+                    fn rust_returns() -> SomeDelegate;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::SwiftTraitObjectPassedToSwift { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "SomeDelegate");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we get an error when Rust passes a reference to a hardened type to Swift.
+    #[test]
+    fn error_if_hardened_type_reference_passed_to_swift() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(hardened)]
+                    type SomeType;
+
+                    fn borrow(&self) -> &SomeType;
+                    fn maybe_borrow(&self) -> Option<&SomeType>;
+                    fn take(&self, arg: &SomeType);
+                }
+
+                extern "Swift" {
+                    fn swift_borrow(arg: &SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            match error {
+                ParseError::HardenedTypeReferencePassedToSwift { reference } => {
+                    assert_eq!(reference.to_token_stream().to_string(), "& SomeType");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we get an error when a function takes or returns a Vec of a hardened type.
+    #[test]
+    fn error_if_vec_of_hardened_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(hardened)]
+                    type SomeType;
+
+                    fn take(arg: Vec<SomeType>);
+                    fn give() -> Option<Vec<SomeType>>;
+                }
+
+                extern "Swift" {
+                    fn swift_take(arg: Vec<SomeType>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            match error {
+                ParseError::VecOfHardenedType { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Vec < SomeType >");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we get an error when an async function borrows a hardened type.
+    #[test]
+    fn error_if_async_fn_borrows_hardened_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(hardened)]
+                    type SomeType;
+
+                    async fn borrow_self(&self);
+                    async fn borrow_arg(arg: &SomeType);
+                    async fn take_self(self);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::AsyncFnBorrowsHardenedType { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
+use crate::errors::{ParseError, ParseErrors};
 use syn::ItemEnum;
 
 use self::enum_attributes::SharedEnumAllAttributes;
@@ -28,11 +28,22 @@ impl<'a> SharedEnumDeclarationParser<'a> {
             variants.push(variant);
         }
 
+        if let Some(swift_mirror) = attribs.swift_bridge.swift_mirror.as_ref() {
+            for variant in variants.iter().filter(|v| !v.fields.is_empty()) {
+                self.errors
+                    .push(ParseError::EnumSwiftMirrorVariantHasFields {
+                        swift_mirror: swift_mirror.clone(),
+                        variant: variant.name.clone(),
+                    });
+            }
+        }
+
         let shared_enum = SharedEnum {
             name: item_enum.ident,
             variants,
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
+            swift_mirror: attribs.swift_bridge.swift_mirror,
             derive: attribs.derive,
         };
 
@@ -184,6 +195,50 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse the `#[swift_bridge(swift_mirror = "...")`] attribute.
+    #[test]
+    fn swift_mirror_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_mirror = "LegacyFoo")]
+                enum Foo {
+                    Variant1
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert_eq!(ty.swift_mirror.as_ref().unwrap().value(), "LegacyFoo");
+    }
+
+    /// Verify that we return an error if a mirrored enum has a variant with fields.
+    #[test]
+    fn error_if_swift_mirror_variant_has_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_mirror = "LegacyFoo")]
+                enum Foo {
+                    Variant1,
+                    Variant2(u8)
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::EnumSwiftMirrorVariantHasFields { variant, .. } => {
+                assert_eq!(variant, "Variant2");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -202,4 +257,24 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse #[derive(Clone)] on enums
+    #[test]
+    fn derive_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone, Debug)]
+                enum Foo {
+                    Variant1(String)
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.derive.clone);
+        assert!(ty.derive.debug);
+    }
 }
@@ -28,6 +28,7 @@ impl SharedEnumAllAttributes {
                     for arg in args.into_iter() {
                         match arg.get_ident().unwrap().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
+                            "Clone" => attributes.derive.clone = true,
                             _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
                         }
                     }
@@ -50,6 +51,7 @@ pub(super) enum EnumAttr {
     AlreadyDeclared,
     Error(ParseError),
     SwiftName(LitStr),
+    SwiftMirror(LitStr),
 }
 
 #[derive(Default)]
@@ -57,6 +59,9 @@ pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
+    /// `#[swift_bridge(swift_mirror = "...")]`
+    /// A handwritten Swift enum that must have the same cases as the bridged enum.
+    pub swift_mirror: Option<LitStr>,
 }
 
 impl SharedEnumSwiftBridgeAttributes {
@@ -65,6 +70,7 @@ impl SharedEnumSwiftBridgeAttributes {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
             EnumAttr::Error(error) => self.errors.push(error),
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
+            EnumAttr::SwiftMirror(name) => self.swift_mirror = Some(name),
         };
         Ok(())
     }
@@ -97,6 +103,12 @@ impl Parse for EnumAttr {
                 let name = input.parse()?;
                 EnumAttr::SwiftName(name)
             }
+            "swift_mirror" => {
+                input.parse::<Token![=]>()?;
+
+                let name = input.parse()?;
+                EnumAttr::SwiftMirror(name)
+            }
             _ => {
                 move_input_cursor_to_next_comma(input);
                 EnumAttr::Error(ParseError::EnumUnrecognizedAttribute { attribute: key })
@@ -1,27 +1,36 @@
 use self::argument_attributes::ArgumentAttributes;
 pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
+use crate::bridged_type::bridgeable_result::result_ok_and_err_types;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::errors::{
+    CancellableParseError, FunctionAttributeParseError, IdentifiableParseError,
+    NonReentrantParseError, ParseError, ParseErrors, ProgressParseError, PropertyParseError,
+    SinceParseError, SwiftDataParseError, ThrottleParseError, TimeoutParseError,
+};
+use crate::parse::parse_extern_mod::associated_constant::constant_accessor;
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
 use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
+use crate::parsed_extern_fn::{fn_arg_is_mutable_reference, SwiftProperty};
 use crate::ParsedExternFn;
 use proc_macro2::Ident;
 use quote::{format_ident, ToTokens};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
+use syn::spanned::Spanned;
 use syn::{
     FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemForeignMod, LitStr, Pat, ReturnType, Type,
+    TypeParamBound, WherePredicate,
 };
 
 mod argument_attributes;
+mod associated_constant;
 mod function_attributes;
 mod generics;
 mod opaque_type_attributes;
@@ -63,8 +72,73 @@ impl<'a> ForeignModParser<'a> {
             }
         });
 
+        // `static` declarations are read through functions that Swift calls, so we turn them into
+        // those functions before parsing the block's functions.
+        let block_types: Vec<Ident> = foreign_mod
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ForeignItem::Type(ty) => Some(ty.ident.clone()),
+                _ => None,
+            })
+            .collect();
+        let block_type = if block_types.len() == 1 {
+            block_types.first()
+        } else {
+            None
+        };
+        let mut constants = HashMap::new();
+        let mut items = Vec::with_capacity(foreign_mod.items.len());
+        for item in foreign_mod.items {
+            let item_static = match item {
+                ForeignItem::Static(item_static) => item_static,
+                item => {
+                    items.push(item);
+                    continue;
+                }
+            };
+
+            if item_static.mutability.is_some() {
+                self.errors.push(ParseError::MutableStatic {
+                    ident: item_static.ident,
+                });
+                continue;
+            }
+            if host_lang.is_swift() {
+                self.errors.push(ParseError::ExternSwiftConstant {
+                    ident: item_static.ident,
+                });
+                continue;
+            }
+
+            let mut attributes = FunctionAttributes::default();
+            for attr in item_static.attrs.iter() {
+                attributes = attr.parse_args()?;
+            }
+
+            let swift_property = if attributes.is_constant {
+                SwiftProperty::Constant
+            } else {
+                SwiftProperty::Static
+            };
+            constants.insert(item_static.ident.to_string(), swift_property);
+
+            let associate_to = if attributes.associated_to.is_none() {
+                block_type
+            } else {
+                None
+            };
+            items.push(ForeignItem::Fn(constant_accessor(
+                item_static.attrs,
+                item_static.ident,
+                *item_static.ty,
+                associate_to,
+            )));
+        }
+
         let mut local_type_declarations = HashMap::new();
-        for foreign_mod_item in foreign_mod.items {
+        let mut property_setters = vec![];
+        for foreign_mod_item in items {
             match foreign_mod_item {
                 ForeignItem::Type(foreign_ty) => {
                     // TODO: Normalize with the code used to parse generic foreign item types
@@ -95,6 +169,7 @@ impl<'a> ForeignModParser<'a> {
                     local_type_declarations.insert(ty_name, foreign_type);
                 }
                 ForeignItem::Fn(func) => {
+                    let constant = constants.remove(&func.sig.ident.to_string());
                     let mut attributes = FunctionAttributes::default();
 
                     for attr in func.attrs.iter() {
@@ -112,6 +187,7 @@ impl<'a> ForeignModParser<'a> {
 
                     let return_type = &func.sig.output;
                     let mut is_swift_failable_initializer = false;
+                    let mut is_swift_throwing_initializer = false;
                     if let ReturnType::Type(_, return_ty) = return_type {
                         let bridged_return_type =
                             BridgedType::new_with_type(return_ty.deref(), &self.type_declarations);
@@ -119,6 +195,9 @@ impl<'a> ForeignModParser<'a> {
                             if ty.as_option().is_some() && attributes.is_swift_initializer {
                                 is_swift_failable_initializer = true;
                             }
+                            if ty.as_result().is_some() && attributes.is_swift_initializer {
+                                is_swift_throwing_initializer = true;
+                            }
                         }
                         if bridged_return_type.is_none() {
                             self.unresolved_types.push(return_ty.deref().clone());
@@ -132,6 +211,7 @@ impl<'a> ForeignModParser<'a> {
                         &attributes,
                         &mut local_type_declarations,
                         is_swift_failable_initializer,
+                        is_swift_throwing_initializer,
                     )?;
 
                     if attributes.is_swift_identifiable {
@@ -171,6 +251,276 @@ impl<'a> ForeignModParser<'a> {
                             ));
                         }
                     }
+                    if attributes.reports_progress {
+                        if host_lang.is_swift() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Progress(
+                                    ProgressParseError::MustBeExternRust {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                        if func.sig.asyncness.is_some() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Progress(
+                                    ProgressParseError::MustNotBeAsync {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+                    if attributes.is_cancellable {
+                        if host_lang.is_swift() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Cancellable(
+                                    CancellableParseError::MustBeExternRust {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                        if func.sig.asyncness.is_some() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Cancellable(
+                                    CancellableParseError::MustNotBeAsync {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+                    if attributes.has_timeout_variant {
+                        let fn_ident = func.sig.ident.clone();
+                        let error = if host_lang.is_swift() {
+                            Some(TimeoutParseError::MustBeExternRust { fn_ident })
+                        } else if func.sig.asyncness.is_some() {
+                            Some(TimeoutParseError::MustNotBeAsync { fn_ident })
+                        } else if attributes.is_swift_initializer {
+                            Some(TimeoutParseError::InitializerNotSupported { fn_ident })
+                        } else {
+                            None
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Timeout(error),
+                            ));
+                        }
+                    }
+                    if attributes.bytes_as_swift_data {
+                        let fn_ident = func.sig.ident.clone();
+                        let error = if host_lang.is_swift() {
+                            Some(SwiftDataParseError::MustBeExternRust { fn_ident })
+                        } else if func.sig.asyncness.is_some() {
+                            Some(SwiftDataParseError::MustNotBeAsync { fn_ident })
+                        } else {
+                            None
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::SwiftData(error),
+                            ));
+                        }
+                    }
+                    if attributes.is_non_reentrant {
+                        let fn_ident = func.sig.ident.clone();
+                        let takes_self_by_reference = match func.sig.inputs.first() {
+                            Some(FnArg::Receiver(receiver)) => receiver.reference.is_some(),
+                            Some(FnArg::Typed(pat_ty)) => {
+                                pat_type_pat_is_self(pat_ty)
+                                    && matches!(pat_ty.ty.deref(), Type::Reference(_))
+                            }
+                            None => false,
+                        };
+                        let is_non_copy_opaque_type = matches!(
+                            associated_type.as_ref(),
+                            Some(TypeDeclaration::Opaque(o)) if o.attributes.copy.is_none()
+                        );
+                        let error = if host_lang.is_swift() {
+                            Some(NonReentrantParseError::MustBeExternRust { fn_ident })
+                        } else if !takes_self_by_reference || !is_non_copy_opaque_type {
+                            Some(NonReentrantParseError::NotAReferenceMethod { fn_ident })
+                        } else if func.sig.asyncness.is_some() {
+                            Some(NonReentrantParseError::AsyncNotSupported { fn_ident })
+                        } else {
+                            None
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::NonReentrant(error),
+                            ));
+                        }
+                    }
+                    let mut is_valid_property_setter = false;
+                    if attributes.is_swift_property_getter || attributes.is_swift_property_setter {
+                        let fn_ident = func.sig.ident.clone();
+                        let self_reference = match func.sig.inputs.first() {
+                            Some(FnArg::Receiver(receiver)) => receiver
+                                .reference
+                                .as_ref()
+                                .map(|_| receiver.mutability.is_some()),
+                            Some(FnArg::Typed(pat_ty)) if pat_type_pat_is_self(pat_ty) => {
+                                match pat_ty.ty.deref() {
+                                    Type::Reference(type_ref) => {
+                                        Some(type_ref.mutability.is_some())
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        };
+                        // Properties are declared in the Swift class bodies, which are
+                        // generated once for a type in the bridge module that declares it.
+                        let is_supported_type = matches!(
+                            associated_type.as_ref(),
+                            Some(TypeDeclaration::Opaque(o))
+                                if o.attributes.copy.is_none()
+                                    && !o.attributes.swift_repr_struct
+                                    && !o.attributes.already_declared
+                                    && o.generics.is_empty()
+                        );
+                        let returns_value = matches!(func.sig.output, ReturnType::Type(_, _));
+                        let error = if host_lang.is_swift() {
+                            Some(PropertyParseError::MustBeExternRust { fn_ident })
+                        } else if func.sig.asyncness.is_some() {
+                            Some(PropertyParseError::AsyncNotSupported { fn_ident })
+                        } else if self_reference.is_some() && !is_supported_type {
+                            Some(PropertyParseError::UnsupportedType { fn_ident })
+                        } else if attributes.is_swift_property_getter {
+                            let is_valid_getter = self_reference == Some(false)
+                                && func.sig.inputs.len() == 1
+                                && returns_value;
+                            (!is_valid_getter)
+                                .then_some(PropertyParseError::InvalidGetter { fn_ident })
+                        } else {
+                            let is_valid_setter = self_reference == Some(true)
+                                && func.sig.inputs.len() == 2
+                                && !returns_value;
+                            is_valid_property_setter = is_valid_setter;
+                            (!is_valid_setter)
+                                .then_some(PropertyParseError::InvalidSetter { fn_ident })
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Property(error),
+                            ));
+                        }
+                    }
+                    if let Some(version) = attributes.since.as_ref() {
+                        let fn_ident = func.sig.ident.clone();
+                        let is_valid_version = version.value().split('.').all(|part| {
+                            !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())
+                        });
+                        let error = if host_lang.is_swift() {
+                            Some(SinceParseError::MustBeExternRust { fn_ident })
+                        } else if !is_valid_version {
+                            Some(SinceParseError::InvalidVersion {
+                                version: version.clone(),
+                            })
+                        } else {
+                            None
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Since(error),
+                            ));
+                        }
+                    }
+                    let mut throttle_ms = None;
+                    if let Some(interval) = attributes.throttle_ms.as_ref() {
+                        let fn_ident = func.sig.ident.clone();
+                        let reference_arg = func.sig.inputs.iter().find(|arg| {
+                            matches!(arg, FnArg::Typed(pat_ty) if matches!(pat_ty.ty.deref(), Type::Reference(_)))
+                        });
+                        let error = if host_lang.is_rust() {
+                            Some(ThrottleParseError::MustBeExternSwift { fn_ident })
+                        } else if func.sig.asyncness.is_some()
+                            || associated_type.is_some()
+                            || matches!(func.sig.output, ReturnType::Type(_, _))
+                        {
+                            Some(ThrottleParseError::UnsupportedFunction { fn_ident })
+                        } else if let Some(arg) = reference_arg {
+                            Some(ThrottleParseError::ReferenceArg { arg: arg.clone() })
+                        } else {
+                            match interval.base10_parse::<u64>() {
+                                Ok(interval) if interval > 0 => {
+                                    throttle_ms = Some(interval);
+                                    None
+                                }
+                                _ => Some(ThrottleParseError::InvalidInterval {
+                                    interval: interval.clone(),
+                                }),
+                            }
+                        };
+                        if let Some(error) = error {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Throttle(error),
+                            ));
+                        }
+                    }
+                    if host_lang.is_swift()
+                        && func.sig.asyncness.is_some()
+                        && associated_type.is_some()
+                    {
+                        self.errors.push(ParseError::AsyncExternSwiftMethod {
+                            fn_ident: func.sig.ident.clone(),
+                        });
+                    }
+                    if let Some(TypeDeclaration::Shared(_)) = associated_type.as_ref() {
+                        if host_lang.is_swift() {
+                            self.errors.push(ParseError::ExternSwiftSharedTypeMethod {
+                                fn_ident: func.sig.ident.clone(),
+                            });
+                        } else if func
+                            .sig
+                            .receiver()
+                            .map(fn_arg_is_mutable_reference)
+                            .unwrap_or(false)
+                        {
+                            self.errors.push(ParseError::MutableSharedTypeSelf {
+                                fn_ident: func.sig.ident.clone(),
+                            });
+                        }
+                    }
+                    if host_lang.is_swift() && func.sig.asyncness.is_some() {
+                        for arg in func.sig.inputs.iter() {
+                            if let FnArg::Typed(pat_ty) = arg {
+                                if !pat_type_pat_is_self(pat_ty)
+                                    && matches!(pat_ty.ty.deref(), Type::Reference(_))
+                                {
+                                    self.errors.push(ParseError::AsyncExternSwiftReferenceArg {
+                                        arg: arg.clone(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    // Lifetimes only exist at compile time, so they don't change the FFI signature.
+                    for param in func.sig.generics.params.iter() {
+                        if !matches!(param, GenericParam::Lifetime(_)) {
+                            self.errors.push(ParseError::FunctionGenericParam {
+                                param: param.clone(),
+                            });
+                        }
+                    }
+                    if let Some(where_clause) = func.sig.generics.where_clause.as_ref() {
+                        for predicate in where_clause.predicates.iter() {
+                            let is_lifetime_bound = match predicate {
+                                WherePredicate::Lifetime(_) => true,
+                                WherePredicate::Type(predicate) => predicate
+                                    .bounds
+                                    .iter()
+                                    .all(|bound| matches!(bound, TypeParamBound::Lifetime(_))),
+                                _ => false,
+                            };
+                            if !is_lifetime_bound {
+                                self.errors.push(ParseError::FunctionWherePredicate {
+                                    predicate: predicate.clone(),
+                                });
+                            }
+                        }
+                    }
                     let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
                     for arg in func.sig.inputs.iter() {
                         let is_mutable_ref = fn_arg_is_mutable_reference(arg);
@@ -190,6 +540,22 @@ impl<'a> ForeignModParser<'a> {
                             self.errors
                                 .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
                         }
+
+                        let is_swift_repr_struct_opaque_type = is_mutable_ref
+                            && match arg {
+                                syn::FnArg::Receiver(_) => matches!(
+                                    associated_type.as_ref(),
+                                    Some(TypeDeclaration::Opaque(o)) if o.attributes.swift_repr_struct
+                                ),
+                                syn::FnArg::Typed(pat_ty) => matches!(
+                                    self.type_declarations.get_with_type(&pat_ty.ty),
+                                    Some(TypeDeclaration::Opaque(o)) if o.attributes.swift_repr_struct
+                                ),
+                            };
+                        if is_swift_repr_struct_opaque_type {
+                            self.errors
+                                .push(ParseError::ArgSwiftReprStructAndRefMut { arg: arg.clone() });
+                        }
                         match arg {
                             syn::FnArg::Typed(ty) => {
                                 for attr in ty.attrs.iter() {
@@ -208,6 +574,20 @@ impl<'a> ForeignModParser<'a> {
                             _ => {}
                         }
                     }
+                    if attributes.labeled_args {
+                        for arg in func.sig.inputs.iter() {
+                            if let FnArg::Typed(pat_ty) = arg {
+                                if pat_type_pat_is_self(pat_ty) {
+                                    continue;
+                                }
+
+                                let arg_name = pat_ty.pat.to_token_stream().to_string();
+                                argument_labels
+                                    .entry(format_ident!("{}", arg_name))
+                                    .or_insert_with(|| LitStr::new(&arg_name, pat_ty.pat.span()));
+                            }
+                        }
+                    }
                     if let Some(ref args) = attributes.args_into {
                         let mut func_sig_args = HashSet::with_capacity(args.len());
                         for fn_arg in func.sig.inputs.iter() {
@@ -232,21 +612,51 @@ impl<'a> ForeignModParser<'a> {
                         }
                     }
 
+                    // Swift doesn't have unsafe functions, so the name warns the caller instead.
+                    let swift_name = match attributes.swift_name {
+                        None if host_lang.is_rust() && func.sig.unsafety.is_some() => {
+                            Some(LitStr::new(
+                                &unsafe_swift_fn_name(&func.sig.ident),
+                                func.sig.ident.span(),
+                            ))
+                        }
+                        swift_name => swift_name,
+                    };
+
                     let func = ParsedExternFn {
                         func,
                         associated_type,
                         is_swift_initializer: attributes.is_swift_initializer,
                         is_swift_failable_initializer: is_swift_failable_initializer,
+                        is_swift_throwing_initializer,
                         is_swift_identifiable: attributes.is_swift_identifiable,
+                        reports_progress: attributes.reports_progress,
+                        is_cancellable: attributes.is_cancellable,
+                        has_timeout_variant: attributes.has_timeout_variant,
+                        bytes_as_swift_data: attributes.bytes_as_swift_data,
+                        is_non_reentrant: attributes.is_non_reentrant,
+                        swift_property: if let Some(constant) = constant {
+                            Some(constant)
+                        } else if attributes.is_swift_property_getter {
+                            Some(SwiftProperty::Getter)
+                        } else {
+                            None
+                        },
+                        since: attributes.since,
+                        throttle_ms,
+                        propagates_context: false,
                         host_lang,
                         rust_name_override: attributes.rust_name,
-                        swift_name_override: attributes.swift_name,
+                        swift_name_override: swift_name,
                         return_into: attributes.return_into,
                         return_with: attributes.return_with,
                         args_into: attributes.args_into,
                         get_field: attributes.get_field,
                         argument_labels: argument_labels,
                     };
+                    if is_valid_property_setter {
+                        property_setters.push(self.functions.len());
+                    }
                     self.functions.push(func);
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
@@ -293,9 +703,68 @@ impl<'a> ForeignModParser<'a> {
             }
         }
 
+        for setter_idx in property_setters {
+            self.pair_property_setter_with_getter(setter_idx);
+        }
+
         Ok(())
     }
 
+    /// Find the `get` method for a `set_<property>` method, and turn the setter into the
+    /// property's setter.
+    fn pair_property_setter_with_getter(&mut self, setter_idx: usize) {
+        let setter = &self.functions[setter_idx];
+        let setter_arg_ty = match setter.func.sig.inputs.iter().nth(1) {
+            Some(FnArg::Typed(pat_ty)) => pat_ty.ty.to_token_stream().to_string(),
+            _ => unreachable!("Setters were checked to take one argument"),
+        };
+        let setter_ident = setter.func.sig.ident.to_string();
+
+        let getter = setter_ident.strip_prefix("set_").and_then(|property| {
+            self.functions.iter().find(|getter| {
+                let getter_ty = match &getter.func.sig.output {
+                    ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+                    ReturnType::Default => return false,
+                };
+
+                matches!(getter.swift_property, Some(SwiftProperty::Getter))
+                    && getter.func.sig.ident == property
+                    && getter_ty == setter_arg_ty
+                    && match (&getter.associated_type, &setter.associated_type) {
+                        (
+                            Some(TypeDeclaration::Opaque(getter_ty)),
+                            Some(TypeDeclaration::Opaque(setter_ty)),
+                        ) => getter_ty.ty == setter_ty.ty,
+                        _ => false,
+                    }
+            })
+        });
+
+        let swift_property = match getter {
+            Some(getter) => {
+                let name = match &getter.swift_name_override {
+                    Some(swift_name) => swift_name.value(),
+                    None => getter.func.sig.ident.to_string(),
+                };
+                let ty = match &getter.func.sig.output {
+                    ReturnType::Type(_, ty) => ty.clone(),
+                    ReturnType::Default => unreachable!(),
+                };
+                SwiftProperty::Setter { name, ty }
+            }
+            None => {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Property(PropertyParseError::GetterNotFound {
+                        fn_ident: setter.func.sig.ident.clone(),
+                    }),
+                ));
+                return;
+            }
+        };
+
+        self.functions[setter_idx].swift_property = Some(swift_property);
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
@@ -303,6 +772,7 @@ impl<'a> ForeignModParser<'a> {
         attributes: &FunctionAttributes,
         local_type_declarations: &mut HashMap<String, OpaqueForeignTypeDeclaration>,
         is_swift_failable_initializer: bool,
+        is_swift_throwing_initializer: bool,
     ) -> syn::Result<Option<TypeDeclaration>> {
         let associated_type = match first {
             Some(FnArg::Receiver(recv)) => {
@@ -347,6 +817,7 @@ impl<'a> ForeignModParser<'a> {
                             attributes,
                             local_type_declarations,
                             is_swift_failable_initializer,
+                            is_swift_throwing_initializer,
                         )?;
                         associated_type
                     }
@@ -380,7 +851,15 @@ Otherwise we use a more general error that says that your argument is invalid.
                         }
                         ReturnType::Type(_, ty) => {
                             let ty_string = ty.deref().to_token_stream().to_string();
-                            ty_string
+                            // Handles generics. i.e. "SomeType < u32 >" -> "SomeType<u32>"
+                            if ty_string.contains('<')
+                                && !is_swift_failable_initializer
+                                && !is_swift_throwing_initializer
+                            {
+                                ty_string.replace(" ", "")
+                            } else {
+                                ty_string
+                            }
                         }
                     };
                     if is_swift_failable_initializer {
@@ -391,6 +870,17 @@ Otherwise we use a more general error that says that your argument is invalid.
                         let inner = inner.trim_start_matches("Option < ").trim_end_matches(" ");
                         let ty = self.type_declarations.get(inner);
                         ty.map(|ty| ty.clone())
+                    } else if is_swift_throwing_initializer {
+                        let ok = match &func.sig.output {
+                            ReturnType::Type(_, ty) => {
+                                result_ok_and_err_types(ty).map(|(ok, _)| ok)
+                            }
+                            ReturnType::Default => None,
+                        };
+                        ok.and_then(|ok| {
+                            let ok = ok.to_token_stream().to_string();
+                            self.type_declarations.get(&ok).cloned()
+                        })
                     } else {
                         let ty = self.type_declarations.get(&ty_string);
 
@@ -408,6 +898,21 @@ Otherwise we use a more general error that says that your argument is invalid.
     }
 }
 
+/// `raw_write` -> `unsafeRawWrite`
+fn unsafe_swift_fn_name(fn_ident: &Ident) -> String {
+    let mut swift_name = "unsafe".to_string();
+
+    for word in fn_ident.to_string().split('_') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            swift_name.push(first.to_ascii_uppercase());
+            swift_name += chars.as_str();
+        }
+    }
+
+    swift_name
+}
+
 #[cfg(test)]
 mod tests {
     use crate::errors::ParseError;
@@ -726,6 +1231,36 @@ mod tests {
         }
     }
 
+    /// Verify that a throwing initializer gets associated with its Ok type when the error type
+    /// contains a comma.
+    #[test]
+    fn throwing_init_error_type_with_comma() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Result<SomeType, (u8, u16)>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let functions = &module.functions;
+        assert_eq!(functions.len(), 1);
+        assert_eq!(
+            functions[0]
+                .associated_type
+                .as_ref()
+                .unwrap()
+                .unwrap_opaque()
+                .ty,
+            "SomeType"
+        );
+    }
+
     /// Verify that if we have multiple externs types can be inferred within each.
     #[test]
     fn infer_type_with_multiple_externs() {
@@ -834,6 +1369,31 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse the `Error` attribute.
+    #[test]
+    fn parse_error_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Error)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .error
+        );
+    }
+
     /// Verify that we can parse the `equatable` attribute.
     #[test]
     fn parse_equatable_attribute() {
@@ -860,13 +1420,13 @@ mod tests {
         );
     }
 
-    /// Verify that we can parse the `copy` attribute.
+    /// Verify that we can parse the `Comparable` attribute.
     #[test]
-    fn parse_copy_attribute() {
+    fn parse_comparable_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Copy(4))]
+                    #[swift_bridge(Comparable)]
                     type SomeType;
                 }
             }
@@ -881,20 +1441,18 @@ mod tests {
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .copy
-                .unwrap()
-                .size_bytes,
-            4
+                .comparable,
+            true
         );
     }
 
-    /// Verify that we can parse multiple atributes from an opaque type.
+    /// Verify that we can parse the `Iterator` attribute and that it declares a `next` method.
     #[test]
-    fn parse_multiple_attributes() {
+    fn parse_iterator_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(already_declared, Copy(4))]
+                    #[swift_bridge(Iterator(Item = u32))]
                     type SomeType;
                 }
             }
@@ -902,75 +1460,440 @@ mod tests {
 
         let module = parse_ok(tokens);
 
-        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
-        assert!(ty.attributes.copy.is_some());
-        assert!(ty.attributes.already_declared)
+        let item = module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes
+            .iterator_item
+            .as_ref()
+            .unwrap();
+        assert_eq!(item.to_token_stream().to_string(), "u32");
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].func.sig.ident.to_string(), "next");
     }
 
-    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    /// Verify that we can parse the `Stream` attribute and that it declares an async `next`
+    /// method.
     #[test]
-    fn parse_opaque_rust_type_doc_comment() {
+    fn parse_stream_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    /// Some comment
-                    type AnotherType;
+                    #[swift_bridge(Stream(Item = String))]
+                    type SomeType;
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        assert_eq!(
-            module
-                .types
-                .get("AnotherType")
-                .unwrap()
-                .unwrap_opaque()
-                .attributes
-                .doc_comment
-                .as_ref()
-                .unwrap(),
-            " Some comment"
-        );
+        let item = module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes
+            .stream_item
+            .as_ref()
+            .unwrap();
+        assert_eq!(item.to_token_stream().to_string(), "String");
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].func.sig.ident.to_string(), "next");
+        assert!(module.functions[0].func.sig.asyncness.is_some());
     }
 
-    /// Verify that we push errors for unknown arguments in a function
+    /// Verify that we can parse the `Clone` attribute.
     #[test]
-    fn error_args_into_arg_not_found_in_function() {
+    fn parse_clone_attribute() {
         let tokens = quote! {
-            #[swift_bridge::bridge]
-            mod ffi {
+            mod foo {
                 extern "Rust" {
-                    #[swift_bridge(args_into = (foo, bar))]
-                    fn some_function(foo: u8);
+                    #[swift_bridge(Clone)]
+                    type SomeType;
                 }
             }
         };
 
-        let errors = parse_errors(tokens);
+        let module = parse_ok(tokens);
 
-        // Only "bar" should be missing argument.
-        assert_eq!(errors.len(), 1);
-        match &errors[0] {
-            ParseError::ArgsIntoArgNotFound {
-                func: _,
-                missing_arg,
-            } => {
-                assert_eq!(missing_arg, "bar")
-            }
-            _ => panic!(),
-        }
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .clone,
+            true
+        );
     }
 
-    /// Verify that we push errors for function arguments that are both mutable and opaque Copy.
+    /// Verify that we can parse the `LocalizedError` attribute.
     #[test]
-    fn error_if_mutable_opaque_copy_type() {
+    fn parse_localized_error_attribute() {
         let tokens = quote! {
-            #[swift_bridge:bridge]
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Copy(2))]
+                    #[swift_bridge(LocalizedError)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .localized_error,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `Bindings` attribute.
+    #[test]
+    fn parse_bindings_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Bindings)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .bindings,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `on_drop` attribute.
+    #[test]
+    fn parse_on_drop_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(on_drop = a::b::c)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let on_drop = module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes
+            .on_drop
+            .clone()
+            .unwrap();
+        assert_eq!(on_drop.to_token_stream().to_string(), "a :: b :: c");
+    }
+
+    /// Verify that we can parse the `manual_release` attribute.
+    #[test]
+    fn parse_manual_release_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(manual_release)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .manual_release,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `trait_object` attribute.
+    #[test]
+    fn parse_trait_object_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(trait_object)]
+                    type SomeTrait;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeTrait")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .trait_object,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `hardened` attribute.
+    #[test]
+    fn parse_hardened_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(hardened)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .hardened,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `swift_name` attribute.
+    #[test]
+    fn parse_swift_name_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "User")]
+                    type RustUser;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("RustUser").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.swift_name.as_ref().unwrap().value(), "User");
+        assert_eq!(ty.swift_name_string(), "User");
+    }
+
+    /// Verify that we reject the `swift_name` attribute on types whose Swift name can't be changed.
+    #[test]
+    fn error_if_swift_name_on_copy_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), swift_name = "User")]
+                    type RustUser;
+                }
+            }
+        };
+
+        let err = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            r#"swift_name is not supported on Copy, generic or swift_repr = "struct" types."#
+        );
+    }
+
+    /// Verify that we can parse the `interior_mut` attribute.
+    #[test]
+    fn parse_interior_mut_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(interior_mut)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .interior_mut,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `swift_repr` attribute.
+    #[test]
+    fn parse_swift_repr_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_repr = "struct")]
+                    type SomeType;
+
+                    #[swift_bridge(swift_repr = "class")]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let swift_repr_struct = |name: &str| {
+            module
+                .types
+                .get(name)
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .swift_repr_struct
+        };
+        assert_eq!(swift_repr_struct("SomeType"), true);
+        assert_eq!(swift_repr_struct("AnotherType"), false);
+    }
+
+    /// Verify that we can parse the `copy` attribute.
+    #[test]
+    fn parse_copy_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .copy
+                .unwrap()
+                .size_bytes,
+            4
+        );
+    }
+
+    /// Verify that we can parse multiple atributes from an opaque type.
+    #[test]
+    fn parse_multiple_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(already_declared, Copy(4))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.copy.is_some());
+        assert!(ty.attributes.already_declared)
+    }
+
+    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("AnotherType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .doc_comment
+                .as_ref()
+                .unwrap(),
+            " Some comment"
+        );
+    }
+
+    /// Verify that we push errors for unknown arguments in a function
+    #[test]
+    fn error_args_into_arg_not_found_in_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(args_into = (foo, bar))]
+                    fn some_function(foo: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        // Only "bar" should be missing argument.
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ArgsIntoArgNotFound {
+                func: _,
+                missing_arg,
+            } => {
+                assert_eq!(missing_arg, "bar")
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push errors for function arguments that are both mutable and opaque Copy.
+    #[test]
+    fn error_if_mutable_opaque_copy_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(2))]
                     type SomeType;
 
                     fn a(&mut self);
@@ -991,4 +1914,176 @@ mod tests {
             }
         }
     }
+
+    /// Verify that we push errors for mutable references to types that use
+    /// `#[swift_bridge(swift_repr = "struct")]`, since they are immutable in Swift.
+    #[test]
+    fn error_if_mutable_swift_repr_struct_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_repr = "struct")]
+                    type SomeType;
+
+                    fn a(&mut self);
+                    fn b(self: &mut SomeType);
+                    fn c(arg: &mut SomeType);
+                    fn d(&self, arg: &SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ArgSwiftReprStructAndRefMut { arg: _ } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if an async extern "Swift" function is a method or takes a
+    /// reference.
+    #[test]
+    fn error_if_async_extern_swift_method_or_reference_arg() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    type Foo;
+
+                    async fn a(&self);
+                    async fn b(arg: &str);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::AsyncExternSwiftMethod { fn_ident } => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::AsyncExternSwiftReferenceArg { arg } => {
+                assert_eq!(arg.to_token_stream().to_string(), "arg : & str");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error for methods on shared types that we can't bridge.
+    #[test]
+    fn error_if_unsupported_shared_type_method() {
+        let tokens = quote! {
+            mod foo {
+                enum SomeEnum {
+                    A,
+                    B,
+                }
+
+                extern "Rust" {
+                    fn toggle(self: &mut SomeEnum);
+                    fn is_a(self: &SomeEnum) -> bool;
+                }
+
+                extern "Swift" {
+                    fn is_b(self: SomeEnum) -> bool;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::MutableSharedTypeSelf { fn_ident } => {
+                assert_eq!(fn_ident, "toggle");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::ExternSwiftSharedTypeMethod { fn_ident } => {
+                assert_eq!(fn_ident, "is_b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we accept lifetime parameters and lifetime bounds on functions.
+    #[test]
+    fn parse_function_with_lifetimes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a<'a>(&'a self) -> &'a str;
+                    fn b<'a, 'b: 'a>(self: &'a Foo, arg: &'b str) -> &'a str where 'b: 'a, Foo: 'a;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert_eq!(module.functions.len(), 2);
+    }
+
+    /// Verify that we push an error if a function has a type parameter or a where-clause with a
+    /// trait bound.
+    #[test]
+    fn error_if_function_generic_param_or_where_predicate() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a<'a, T>(arg: &'a str);
+                    fn b(arg: u8) where String: Clone;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionGenericParam { param } => {
+                assert_eq!(param.to_token_stream().to_string(), "T");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionWherePredicate { predicate } => {
+                assert_eq!(predicate.to_token_stream().to_string(), "String : Clone");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that the Swift name of an `unsafe` extern "Rust" function starts with `unsafe`.
+    #[test]
+    fn unsafe_function_swift_name() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    unsafe fn read_byte(ptr: *const u8) -> u8;
+                    #[swift_bridge(swift_name = "readByteUnchecked")]
+                    unsafe fn read_byte_unchecked(ptr: *const u8) -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let names: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.swift_name_override.as_ref().unwrap().value())
+            .collect();
+        assert_eq!(names, vec!["unsafeReadByte", "readByteUnchecked"]);
+    }
 }
@@ -0,0 +1,146 @@
+use proc_macro2::{Ident, TokenStream};
+use syn::{Attribute, ForeignItemFn, Type};
+
+/// The function that Swift calls to read the value of a `static` declaration.
+///
+/// `static MAX_USERS: u32;` becomes `fn MAX_USERS() -> u32;`, which is associated to the block's
+/// type when the block declares exactly one type and the declaration is not already
+/// `associated_to` a type.
+pub(super) fn constant_accessor(
+    attributes: Vec<Attribute>,
+    ident: Ident,
+    ty: Type,
+    associate_to: Option<&Ident>,
+) -> ForeignItemFn {
+    let (swift_bridge_attrs, other_attrs): (Vec<_>, Vec<_>) = attributes
+        .into_iter()
+        .partition(|attr| attr.path.is_ident("swift_bridge"));
+
+    // Only the last `#[swift_bridge(...)]` attribute is used, so we add `associated_to` to it
+    // instead of adding another attribute.
+    let args = swift_bridge_attrs
+        .last()
+        .map(|attr| attr.parse_args::<TokenStream>().unwrap());
+    let swift_bridge_attr = match (associate_to, args) {
+        (Some(ty), Some(args)) => quote::quote! { #[swift_bridge(associated_to = #ty, #args)] },
+        (Some(ty), None) => quote::quote! { #[swift_bridge(associated_to = #ty)] },
+        (None, Some(args)) => quote::quote! { #[swift_bridge(#args)] },
+        (None, None) => quote::quote! {},
+    };
+
+    syn::parse_quote! {
+        #(#other_attrs)*
+        #swift_bridge_attr
+        fn #ident() -> #ty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::parsed_extern_fn::SwiftProperty;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+
+    /// Verify that we parse statics into functions that are associated to the block's type.
+    #[test]
+    fn parses_associated_constants() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Server;
+
+                    #[swift_bridge(constant)]
+                    static MAX_USERS: u32;
+                    static VERSION: &'static str;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let max_users = &module.functions[0];
+        assert_eq!(max_users.func.sig.ident, "MAX_USERS");
+        assert!(matches!(
+            max_users.swift_property,
+            Some(SwiftProperty::Constant)
+        ));
+        assert_eq!(
+            max_users
+                .associated_type
+                .as_ref()
+                .unwrap()
+                .unwrap_opaque()
+                .ty,
+            "Server"
+        );
+
+        let version = &module.functions[1];
+        assert!(matches!(
+            version.swift_property,
+            Some(SwiftProperty::Static)
+        ));
+        assert!(version.associated_type.is_some());
+    }
+
+    /// Verify that a static in a block with more than one type is freestanding unless it uses
+    /// the `associated_to` attribute.
+    #[test]
+    fn parses_freestanding_and_associated_to_constants() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Server;
+                    type Client;
+
+                    #[swift_bridge(constant)]
+                    static MAX_USERS: u32;
+                    #[swift_bridge(associated_to = Client, constant)]
+                    static TIMEOUT_MS: u64;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].associated_type.is_none());
+        assert_eq!(
+            module.functions[1]
+                .associated_type
+                .as_ref()
+                .unwrap()
+                .unwrap_opaque()
+                .ty,
+            "Client"
+        );
+        assert!(matches!(
+            module.functions[1].swift_property,
+            Some(SwiftProperty::Constant)
+        ));
+    }
+
+    /// Verify that we push errors for statics that we can't bridge.
+    #[test]
+    fn error_if_unsupported_constant() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    static mut COUNTER: u32;
+                }
+
+                extern "Swift" {
+                    static SWIFT_CONSTANT: u32;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        match &errors[0] {
+            ParseError::MutableStatic { ident } => assert_eq!(ident, "COUNTER"),
+            _ => panic!(),
+        }
+        match &errors[1] {
+            ParseError::ExternSwiftConstant { ident } => assert_eq!(ident, "SWIFT_CONSTANT"),
+            _ => panic!(),
+        }
+    }
+}
@@ -1,13 +1,24 @@
 use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{LitStr, Path, Token};
+use syn::{LitInt, LitStr, Path, Token};
 
 #[derive(Default)]
 pub(super) struct FunctionAttributes {
     pub associated_to: Option<Ident>,
     pub is_swift_initializer: bool,
+    pub labeled_args: bool,
     pub is_swift_identifiable: bool,
+    pub reports_progress: bool,
+    pub is_cancellable: bool,
+    pub has_timeout_variant: bool,
+    pub bytes_as_swift_data: bool,
+    pub is_non_reentrant: bool,
+    pub is_swift_property_getter: bool,
+    pub is_swift_property_setter: bool,
+    pub is_constant: bool,
+    pub since: Option<LitStr>,
+    pub throttle_ms: Option<LitInt>,
     pub rust_name: Option<LitStr>,
     pub swift_name: Option<LitStr>,
     pub return_into: bool,
@@ -23,6 +34,7 @@ impl FunctionAttributes {
                 self.associated_to = Some(ident);
             }
             FunctionAttr::Init => self.is_swift_initializer = true,
+            FunctionAttr::LabeledArgs => self.labeled_args = true,
             FunctionAttr::RustName(name) => {
                 self.rust_name = Some(name);
             }
@@ -39,6 +51,36 @@ impl FunctionAttributes {
             FunctionAttr::Identifiable => {
                 self.is_swift_identifiable = true;
             }
+            FunctionAttr::Progress => {
+                self.reports_progress = true;
+            }
+            FunctionAttr::Cancellable => {
+                self.is_cancellable = true;
+            }
+            FunctionAttr::Timeout => {
+                self.has_timeout_variant = true;
+            }
+            FunctionAttr::SwiftData => {
+                self.bytes_as_swift_data = true;
+            }
+            FunctionAttr::NonReentrant => {
+                self.is_non_reentrant = true;
+            }
+            FunctionAttr::PropertyGetter => {
+                self.is_swift_property_getter = true;
+            }
+            FunctionAttr::PropertySetter => {
+                self.is_swift_property_setter = true;
+            }
+            FunctionAttr::Constant => {
+                self.is_constant = true;
+            }
+            FunctionAttr::Since(version) => {
+                self.since = Some(version);
+            }
+            FunctionAttr::ThrottleMs(interval) => {
+                self.throttle_ms = Some(interval);
+            }
             FunctionAttr::GetField(get_field) => self.get_field = Some(GetField::Direct(get_field)),
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
@@ -52,7 +94,18 @@ pub(super) enum FunctionAttr {
     SwiftName(LitStr),
     RustName(LitStr),
     Init,
+    LabeledArgs,
     Identifiable,
+    Progress,
+    Cancellable,
+    Timeout,
+    SwiftData,
+    NonReentrant,
+    PropertyGetter,
+    PropertySetter,
+    Constant,
+    Since(LitStr),
+    ThrottleMs(LitInt),
     ReturnInto,
     ReturnWith(Path),
     ArgsInto(Vec<Ident>),
@@ -94,7 +147,25 @@ impl Parse for FunctionAttr {
                 FunctionAttr::SwiftName(value)
             }
             "init" => FunctionAttr::Init,
+            "labeled_args" => FunctionAttr::LabeledArgs,
             "Identifiable" => FunctionAttr::Identifiable,
+            "progress" => FunctionAttr::Progress,
+            "cancellable" => FunctionAttr::Cancellable,
+            "timeout" => FunctionAttr::Timeout,
+            "swift_data" => FunctionAttr::SwiftData,
+            "non_reentrant" => FunctionAttr::NonReentrant,
+            "since" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::Since(value)
+            }
+            "throttle_ms" => {
+                input.parse::<Token![=]>()?;
+                let value: LitInt = input.parse()?;
+
+                FunctionAttr::ThrottleMs(value)
+            }
             // TODO: Right before we release 0.2.0 we should remove this
             //  "into_return_type" variant since it is deprecated.
             //
@@ -118,6 +189,9 @@ impl Parse for FunctionAttr {
                 let args = syn::punctuated::Punctuated::<_, Token![,]>::parse_terminated(&content)?;
                 FunctionAttr::ArgsInto(args.into_iter().collect())
             }
+            "get" if !input.peek(syn::token::Paren) => FunctionAttr::PropertyGetter,
+            "set" => FunctionAttr::PropertySetter,
+            "constant" => FunctionAttr::Constant,
             "get" => {
                 let content;
                 syn::parenthesized!(content in input);
@@ -164,9 +238,14 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        CancellableParseError, FunctionAttributeParseError, IdentifiableParseError,
+        NonReentrantParseError, ParseError, ProgressParseError, PropertyParseError,
+        SinceParseError, SwiftDataParseError, ThrottleParseError, TimeoutParseError,
+    };
+    use crate::parsed_extern_fn::SwiftProperty;
     use crate::test_utils::{parse_errors, parse_ok};
-    use quote::{quote, ToTokens};
+    use quote::{format_ident, quote, ToTokens};
 
     /// Verify that we can parse the return_into attribute from extern "Rust" blocks.
     #[test]
@@ -298,6 +377,38 @@ mod tests {
         assert!(func.is_swift_failable_initializer);
     }
 
+    /// Verify that we can parse a throwing init function.
+    #[test]
+    fn throwing_initializer() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+                    type SomeErr;
+
+                    #[swift_bridge(init)]
+                    fn bar () -> Result<Foo, SomeErr>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+        assert!(func.is_swift_initializer);
+        assert!(func.is_swift_throwing_initializer);
+        assert!(!func.is_swift_failable_initializer);
+        assert_eq!(
+            func.associated_type
+                .as_ref()
+                .unwrap()
+                .as_opaque()
+                .unwrap()
+                .to_string(),
+            "Foo"
+        );
+    }
+
     /// Verify that we can parse an init function that takes inputs.
     #[test]
     fn initializer_with_inputs() {
@@ -318,6 +429,30 @@ mod tests {
         assert!(func.is_swift_initializer);
     }
 
+    /// Verify that the `labeled_args` attribute labels every argument with its name, unless the
+    /// argument has its own label.
+    #[test]
+    fn parses_labeled_args_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init, labeled_args)]
+                    fn new(name: &str, #[swift_bridge(label = "_")] age: u8) -> Foo;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+        assert!(func.is_swift_initializer);
+        assert_eq!(func.argument_labels.len(), 2);
+        assert_eq!(func.argument_labels[&format_ident!("name")].value(), "name");
+        assert_eq!(func.argument_labels[&format_ident!("age")].value(), "_");
+    }
+
     /// Verify that we push an error if the initialize type is not defined.
     #[test]
     fn error_if_initialized_type_not_defined() {
@@ -469,6 +604,602 @@ mod tests {
         assert!(func.is_swift_identifiable);
     }
 
+    /// Verify that we can parse the `progress` attribute.
+    #[test]
+    fn parses_progress_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(progress)]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].reports_progress);
+    }
+
+    /// Verify that we push a parse error if we put a progress attribute on an extern "Swift"
+    /// function or on an async function.
+    #[test]
+    fn error_if_progress_attribute_on_swift_or_async_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(progress)]
+                    fn a();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(progress)]
+                    async fn b();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Progress(
+                ProgressParseError::MustBeExternRust { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Progress(
+                ProgressParseError::MustNotBeAsync { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `cancellable` attribute.
+    #[test]
+    fn parses_cancellable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(cancellable)]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_cancellable);
+    }
+
+    /// Verify that we push a parse error if we put a cancellable attribute on an extern "Swift"
+    /// function or on an async function.
+    #[test]
+    fn error_if_cancellable_attribute_on_swift_or_async_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(cancellable)]
+                    fn a();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(cancellable)]
+                    async fn b();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Cancellable(
+                CancellableParseError::MustBeExternRust { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Cancellable(
+                CancellableParseError::MustNotBeAsync { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `timeout` attribute.
+    #[test]
+    fn parses_timeout_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(timeout)]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].has_timeout_variant);
+    }
+
+    /// Verify that we push a parse error if we put a timeout attribute on an extern "Swift"
+    /// function, an async function or an initializer.
+    #[test]
+    fn error_if_timeout_attribute_on_swift_async_or_init_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(timeout)]
+                    fn a();
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(timeout)]
+                    async fn b();
+
+                    #[swift_bridge(init, timeout)]
+                    fn c() -> SomeType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Timeout(
+                TimeoutParseError::MustBeExternRust { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Timeout(
+                TimeoutParseError::MustNotBeAsync { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+        match &errors[2] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Timeout(
+                TimeoutParseError::InitializerNotSupported { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "c");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `swift_data` attribute.
+    #[test]
+    fn parses_swift_data_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_data)]
+                    fn some_function(bytes: &[u8]) -> Vec<u8>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].bytes_as_swift_data);
+    }
+
+    /// Verify that we push a parse error if we put a swift_data attribute on an extern "Swift"
+    /// function or an async function.
+    #[test]
+    fn error_if_swift_data_attribute_on_swift_or_async_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(swift_data)]
+                    fn a() -> Vec<u8>;
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(swift_data)]
+                    async fn b() -> Vec<u8>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::SwiftData(
+                SwiftDataParseError::MustBeExternRust { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::SwiftData(
+                SwiftDataParseError::MustNotBeAsync { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `non_reentrant` attribute.
+    #[test]
+    fn parses_non_reentrant_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(non_reentrant)]
+                    fn some_method(&mut self);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_non_reentrant);
+    }
+
+    /// Verify that we push a parse error if we put a non_reentrant attribute on an extern "Swift"
+    /// function, a function that does not take self by reference or an async method.
+    #[test]
+    fn error_if_non_reentrant_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    type SwiftType;
+
+                    #[swift_bridge(non_reentrant)]
+                    fn a(&self);
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+
+                    #[swift_bridge(non_reentrant)]
+                    fn b();
+
+                    #[swift_bridge(non_reentrant)]
+                    fn c(self: SomeType);
+
+                    #[swift_bridge(non_reentrant)]
+                    fn d(self: &SomeType);
+
+                    #[swift_bridge(non_reentrant)]
+                    fn e(self: &SomeCopyType);
+
+                    #[swift_bridge(non_reentrant)]
+                    async fn f(self: &SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        let expected = [
+            ("a", "extern Swift"),
+            ("b", "by value"),
+            ("c", "by value"),
+            ("e", "by value"),
+            ("f", "async"),
+        ];
+        for (error, (expected_fn, expected_kind)) in errors.iter().zip(expected.iter()) {
+            let (fn_ident, kind) = match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::NonReentrant(
+                    NonReentrantParseError::MustBeExternRust { fn_ident },
+                )) => (fn_ident, "extern Swift"),
+                ParseError::FunctionAttribute(FunctionAttributeParseError::NonReentrant(
+                    NonReentrantParseError::NotAReferenceMethod { fn_ident },
+                )) => (fn_ident, "by value"),
+                ParseError::FunctionAttribute(FunctionAttributeParseError::NonReentrant(
+                    NonReentrantParseError::AsyncNotSupported { fn_ident },
+                )) => (fn_ident, "async"),
+                _ => panic!(),
+            };
+            assert_eq!(fn_ident, expected_fn);
+            assert_eq!(&kind, expected_kind);
+        }
+    }
+
+    /// Verify that we can parse the `get` and `set` attributes, and that we pair a setter with
+    /// its getter.
+    #[test]
+    fn parses_property_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get, swift_name = "someValue")]
+                    fn some_value(&self) -> u8;
+
+                    #[swift_bridge(set)]
+                    fn set_some_value(&mut self, value: u8);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(matches!(
+            module.functions[0].swift_property,
+            Some(SwiftProperty::Getter)
+        ));
+        match module.functions[1].swift_property.as_ref().unwrap() {
+            SwiftProperty::Setter { name, ty } => {
+                assert_eq!(name, "someValue");
+                assert_eq!(ty.to_token_stream().to_string(), "u8");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push a parse error if we put a get or set attribute on a function that
+    /// cannot be part of a Swift property, or on a setter that does not have a matching getter.
+    #[test]
+    fn error_if_property_attributes_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    type SwiftType;
+
+                    #[swift_bridge(get)]
+                    fn a(&self) -> u8;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+
+                    #[swift_bridge(get)]
+                    async fn b(self: &SomeType) -> u8;
+
+                    #[swift_bridge(get)]
+                    fn c(self: &SomeCopyType) -> u8;
+
+                    #[swift_bridge(get)]
+                    fn d(self: &mut SomeType) -> u8;
+
+                    #[swift_bridge(get)]
+                    fn e(self: &SomeType);
+
+                    #[swift_bridge(set)]
+                    fn set_f(self: &mut SomeType);
+
+                    #[swift_bridge(get)]
+                    fn g(self: &SomeType) -> u8;
+
+                    #[swift_bridge(set)]
+                    fn set_g(self: &mut SomeType, g: u16);
+
+                    #[swift_bridge(set)]
+                    fn h(self: &mut SomeType, h: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 8);
+
+        let expected = [
+            ("a", "extern Swift"),
+            ("b", "async"),
+            ("c", "unsupported type"),
+            ("d", "invalid getter"),
+            ("e", "invalid getter"),
+            ("set_f", "invalid setter"),
+            ("set_g", "getter not found"),
+            ("h", "getter not found"),
+        ];
+        for (error, (expected_fn, expected_kind)) in errors.iter().zip(expected.iter()) {
+            let error = match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Property(error)) => {
+                    error
+                }
+                _ => panic!(),
+            };
+            let (fn_ident, kind) = match error {
+                PropertyParseError::MustBeExternRust { fn_ident } => (fn_ident, "extern Swift"),
+                PropertyParseError::AsyncNotSupported { fn_ident } => (fn_ident, "async"),
+                PropertyParseError::UnsupportedType { fn_ident } => (fn_ident, "unsupported type"),
+                PropertyParseError::InvalidGetter { fn_ident } => (fn_ident, "invalid getter"),
+                PropertyParseError::InvalidSetter { fn_ident } => (fn_ident, "invalid setter"),
+                PropertyParseError::GetterNotFound { fn_ident } => (fn_ident, "getter not found"),
+            };
+            assert_eq!(fn_ident, expected_fn);
+            assert_eq!(&kind, expected_kind);
+        }
+    }
+
+    /// Verify that we can parse the `since` attribute.
+    #[test]
+    fn parses_since_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(since = "1.2.3")]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].since.as_ref().unwrap().value(), "1.2.3");
+    }
+
+    /// Verify that we push a parse error if we put a since attribute on an extern "Swift"
+    /// function or use a version that is not numbers separated by dots.
+    #[test]
+    fn error_if_since_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(since = "1.0")]
+                    fn a();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(since = "1.x")]
+                    fn b();
+
+                    #[swift_bridge(since = "1..2")]
+                    fn c();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Since(
+                SinceParseError::MustBeExternRust { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        for (error, expected) in errors[1..].iter().zip(["1.x", "1..2"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Since(
+                    SinceParseError::InvalidVersion { version },
+                )) => {
+                    assert_eq!(version.value(), expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `throttle_ms` attribute.
+    #[test]
+    fn parses_throttle_ms_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(throttle_ms = 250)]
+                    fn some_function(value: u32);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].throttle_ms, Some(250));
+    }
+
+    /// Verify that we push a parse error if we put a throttle_ms attribute on an extern "Rust"
+    /// function, on a Swift function that can not be coalesced, or use an interval of 0.
+    #[test]
+    fn error_if_throttle_ms_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(throttle_ms = 100)]
+                    fn a();
+                }
+
+                extern "Swift" {
+                    type SomeType;
+
+                    #[swift_bridge(throttle_ms = 100)]
+                    async fn b();
+
+                    #[swift_bridge(throttle_ms = 100)]
+                    fn c() -> u32;
+
+                    #[swift_bridge(throttle_ms = 100)]
+                    fn d(&self);
+
+                    #[swift_bridge(throttle_ms = 100)]
+                    fn e(label: &str);
+
+                    #[swift_bridge(throttle_ms = 0)]
+                    fn f();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 6);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Throttle(
+                ThrottleParseError::MustBeExternSwift { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        for (error, expected) in errors[1..4].iter().zip(["b", "c", "d"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Throttle(
+                    ThrottleParseError::UnsupportedFunction { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+        match &errors[4] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Throttle(
+                ThrottleParseError::ReferenceArg { arg },
+            )) => {
+                assert_eq!(arg.to_token_stream().to_string(), "label : & str");
+            }
+            _ => panic!(),
+        };
+        match &errors[5] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Throttle(
+                ThrottleParseError::InvalidInterval { interval },
+            )) => {
+                assert_eq!(interval.to_string(), "0");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse the `get` attribute.
     #[test]
     fn parses_get_attribute() {
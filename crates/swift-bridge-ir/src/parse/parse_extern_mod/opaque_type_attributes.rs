@@ -3,7 +3,7 @@ use proc_macro2::Ident;
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, LitInt, Meta};
+use syn::{Attribute, LitInt, LitStr, Meta, Path, Token, Type};
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeAllAttributes {
@@ -32,10 +32,54 @@ pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
     pub hashable: bool,
+    /// `#[swift_bridge(Comparable)]`
+    /// Used to determine if Comparable needs to be implemented.
+    pub comparable: bool,
+    /// `#[swift_bridge(Clone)]`
+    /// Used to determine if a Swift `clone()` method that calls the Rust `Clone` should be generated.
+    pub clone: bool,
+    /// `#[swift_bridge(Error)]`
+    /// Used to determine if Error needs to be implemented, so that the type can be thrown.
+    pub error: bool,
+    /// `#[swift_bridge(LocalizedError)]`
+    /// Used to determine if LocalizedError needs to be implemented using the type's `Display`.
+    pub localized_error: bool,
+    /// `#[swift_bridge(Iterator(Item = T))]`
+    /// The type implements `Iterator<Item = T>` and is exposed to Swift as a `Sequence`.
+    pub iterator_item: Option<Box<Type>>,
+    /// `#[swift_bridge(Stream(Item = T))]`
+    /// The type implements `futures::Stream<Item = T>` and is exposed to Swift as an
+    /// `AsyncSequence`.
+    pub stream_item: Option<Box<Type>>,
+    /// `#[swift_bridge(Bindings)]`
+    /// Used to determine if SwiftUI `Binding`s should be generated for getter/setter pairs.
+    pub bindings: bool,
+    /// `#[swift_bridge(on_drop = path::to::function)]`
+    /// A function that is called with `&mut` the type right before Swift frees it.
+    pub on_drop: Option<Path>,
+    /// `#[swift_bridge(manual_release)]`
+    /// Swift frees the type when `release()` is called instead of when the class is deinitialized.
+    pub manual_release: bool,
+    /// `#[swift_bridge(interior_mut)]`
+    /// The type implements `swift_bridge::interior_mut::InteriorMut`, and its methods are called
+    /// on the inner value that it borrows.
+    pub interior_mut: bool,
+    /// `#[swift_bridge(swift_repr = "struct")]`
+    /// Whether the type is exposed to Swift as an immutable struct instead of a class.
+    pub swift_repr_struct: bool,
+    /// `#[swift_bridge(trait_object)]`
+    /// The type is a trait, and the Rust value that gets bridged is a `Box<dyn Trait>`.
+    pub trait_object: bool,
+    /// `#[swift_bridge(hardened)]`
+    /// Swift holds a generation-checked handle to the type instead of a pointer to it.
+    pub hardened: bool,
+    /// `#[swift_bridge(swift_name = "...")]`
+    /// The name of the type on the Swift side.
+    pub swift_name: Option<LitStr>,
 }
 
 impl OpaqueTypeAllAttributes {
-    pub(super) fn from_attributes(attribs: &[Attribute]) -> Result<Self, syn::Error> {
+    pub(crate) fn from_attributes(attribs: &[Attribute]) -> Result<Self, syn::Error> {
         let mut attributes = OpaqueTypeAllAttributes::default();
 
         for attr in attribs.iter() {
@@ -77,6 +121,22 @@ impl OpaqueTypeSwiftBridgeAttributes {
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
             OpaqueTypeAttr::Equatable => self.equatable = true,
             OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::Comparable => self.comparable = true,
+            OpaqueTypeAttr::Clone => self.clone = true,
+            OpaqueTypeAttr::Error => self.error = true,
+            OpaqueTypeAttr::LocalizedError => self.localized_error = true,
+            OpaqueTypeAttr::Iterator { item } => self.iterator_item = Some(item),
+            OpaqueTypeAttr::Stream { item } => self.stream_item = Some(item),
+            OpaqueTypeAttr::Bindings => self.bindings = true,
+            OpaqueTypeAttr::OnDrop(path) => self.on_drop = Some(path),
+            OpaqueTypeAttr::ManualRelease => self.manual_release = true,
+            OpaqueTypeAttr::InteriorMut => self.interior_mut = true,
+            OpaqueTypeAttr::TraitObject => self.trait_object = true,
+            OpaqueTypeAttr::Hardened => self.hardened = true,
+            OpaqueTypeAttr::SwiftName(name) => self.swift_name = Some(name),
+            OpaqueTypeAttr::SwiftReprStruct(swift_repr_struct) => {
+                self.swift_repr_struct = swift_repr_struct
+            }
         }
     }
 }
@@ -87,6 +147,20 @@ pub(crate) enum OpaqueTypeAttr {
     DeclareGeneric,
     Equatable,
     Hashable,
+    Comparable,
+    Clone,
+    Error,
+    LocalizedError,
+    Iterator { item: Box<Type> },
+    Stream { item: Box<Type> },
+    Bindings,
+    OnDrop(Path),
+    ManualRelease,
+    InteriorMut,
+    SwiftReprStruct(bool),
+    TraitObject,
+    Hardened,
+    SwiftName(LitStr),
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
@@ -100,6 +174,42 @@ impl Parse for OpaqueTypeSwiftBridgeAttributes {
             attributes.store_attrib(attr);
         }
 
+        if let Some(swift_name) = attributes.swift_name.as_ref() {
+            if attributes.copy.is_some()
+                || attributes.swift_repr_struct
+                || attributes.declare_generic
+            {
+                Err(syn::Error::new_spanned(
+                    swift_name,
+                    "swift_name is not supported on Copy, generic or swift_repr = \"struct\" types.",
+                ))?;
+            }
+        }
+
+        if let Some(item) = attributes.iterator_item.as_ref() {
+            if attributes.copy.is_some() || attributes.swift_repr_struct {
+                Err(syn::Error::new_spanned(
+                    item,
+                    "Iterator is not supported on Copy or swift_repr = \"struct\" types.",
+                ))?;
+            }
+        }
+
+        if let Some(item) = attributes.stream_item.as_ref() {
+            if attributes.copy.is_some() || attributes.swift_repr_struct {
+                Err(syn::Error::new_spanned(
+                    item,
+                    "Stream is not supported on Copy or swift_repr = \"struct\" types.",
+                ))?;
+            }
+            if attributes.iterator_item.is_some() {
+                Err(syn::Error::new_spanned(
+                    item,
+                    "A type can not have both the Iterator and the Stream attribute.",
+                ))?;
+            }
+        }
+
         Ok(attributes)
     }
 }
@@ -124,6 +234,47 @@ impl Parse for OpaqueTypeAttr {
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
             "Equatable" => OpaqueTypeAttr::Equatable,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            "Comparable" => OpaqueTypeAttr::Comparable,
+            "Clone" => OpaqueTypeAttr::Clone,
+            "Error" => OpaqueTypeAttr::Error,
+            "LocalizedError" => OpaqueTypeAttr::LocalizedError,
+            // Iterator(Item = u32)
+            "Iterator" => OpaqueTypeAttr::Iterator {
+                item: parse_item_type(input)?,
+            },
+            // Stream(Item = String)
+            "Stream" => OpaqueTypeAttr::Stream {
+                item: parse_item_type(input)?,
+            },
+            "Bindings" => OpaqueTypeAttr::Bindings,
+            // on_drop = path::to::function
+            "on_drop" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::OnDrop(input.parse()?)
+            }
+            "manual_release" => OpaqueTypeAttr::ManualRelease,
+            "interior_mut" => OpaqueTypeAttr::InteriorMut,
+            "trait_object" => OpaqueTypeAttr::TraitObject,
+            "hardened" => OpaqueTypeAttr::Hardened,
+            // swift_name = "SomeName"
+            "swift_name" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::SwiftName(input.parse()?)
+            }
+            // swift_repr = "struct"
+            "swift_repr" => {
+                input.parse::<Token![=]>()?;
+                let swift_repr: LitStr = input.parse()?;
+
+                match swift_repr.value().as_str() {
+                    "class" => OpaqueTypeAttr::SwiftReprStruct(false),
+                    "struct" => OpaqueTypeAttr::SwiftReprStruct(true),
+                    _ => Err(syn::Error::new_spanned(
+                        swift_repr,
+                        r#"Invalid swift_repr. Must be either "class" or "struct"."#,
+                    ))?,
+                }
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -137,6 +288,20 @@ impl Parse for OpaqueTypeAttr {
     }
 }
 
+// The `(Item = T)` of `Iterator(Item = T)` and `Stream(Item = T)`.
+fn parse_item_type(input: ParseStream) -> syn::Result<Box<Type>> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let item: Ident = content.parse()?;
+    if item != "Item" {
+        Err(syn::Error::new_spanned(item, "Expected `Item = ...`."))?;
+    }
+    content.parse::<Token![=]>()?;
+
+    Ok(Box::new(content.parse()?))
+}
+
 impl Deref for OpaqueTypeAllAttributes {
     type Target = OpaqueTypeSwiftBridgeAttributes;
 
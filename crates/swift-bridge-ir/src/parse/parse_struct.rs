@@ -18,6 +18,10 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Initialize,
+    Patch,
+    Identifiable(LitStr),
+    Codable,
 }
 
 enum StructAttrParseError {
@@ -30,6 +34,10 @@ struct StructAttribs {
     swift_repr: Option<(StructSwiftRepr, LitStr)>,
     swift_name: Option<LitStr>,
     already_declared: bool,
+    initialize: bool,
+    patch: bool,
+    identifiable: Option<LitStr>,
+    codable: bool,
     derives: StructDerives,
 }
 
@@ -38,6 +46,9 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            partial_eq: false,
+            eq: false,
+            hash: false,
         }
     }
 }
@@ -77,6 +88,15 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "initialize" => StructAttr::Initialize,
+            "patch" => StructAttr::Patch,
+            "Identifiable" => {
+                input.parse::<Token![=]>()?;
+
+                let field = input.parse()?;
+                StructAttr::Identifiable(field)
+            }
+            "Codable" => StructAttr::Codable,
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -124,6 +144,18 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::Initialize => {
+                                attribs.initialize = true;
+                            }
+                            StructAttr::Patch => {
+                                attribs.patch = true;
+                            }
+                            StructAttr::Identifiable(field) => {
+                                attribs.identifiable = Some(field);
+                            }
+                            StructAttr::Codable => {
+                                attribs.codable = true;
+                            }
                         };
                     }
                 }
@@ -133,6 +165,9 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             match derive.to_token_stream().to_string().as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "PartialEq" => attribs.derives.partial_eq = true,
+                                "Eq" => attribs.derives.eq = true,
+                                "Hash" => attribs.derives.hash = true,
                                 _ => {}
                             }
                         }
@@ -170,13 +205,36 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        let fields = StructFields::from_syn_fields(item_struct.fields);
+
+        let identifiable = match attribs.identifiable {
+            Some(field) => {
+                let field_exists = match &fields {
+                    StructFields::Named(named) => named.iter().any(|f| f.name == field.value()),
+                    _ => false,
+                };
+                if field_exists {
+                    Some(Ident::new(&field.value(), field.span()))
+                } else {
+                    self.errors
+                        .push(ParseError::StructIdentifiableFieldNotFound { field });
+                    None
+                }
+            }
+            None => None,
+        };
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
             derives: attribs.derives,
+            initialize: attribs.initialize,
+            patch: attribs.patch,
+            identifiable,
+            codable: attribs.codable,
         };
 
         Ok(shared_struct)
@@ -369,6 +427,36 @@ mod tests {
         assert_eq!(ty2.derives.clone, true);
     }
 
+    /// Verify that we parse the derives and the attribute that give a struct Swift's `Equatable`,
+    /// `Hashable` and `Codable` conformances.
+    #[test]
+    fn parse_equatable_hashable_codable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(PartialEq, Eq, Hash)]
+                struct UserId(u64);
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SessionId(u64);
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.derives.partial_eq);
+        assert!(ty.derives.eq);
+        assert!(ty.derives.hash);
+        assert!(ty.codable);
+
+        let ty2 = module.types.types()[1].unwrap_shared_struct();
+        assert!(!ty2.derives.partial_eq);
+        assert!(!ty2.derives.hash);
+        assert!(!ty2.codable);
+    }
+
     /// Verify that we properly parse multiple comma separated struct attributes.
     #[test]
     fn parses_multiple_struct_attributes() {
@@ -429,6 +517,113 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse an `initialize` attribute, and that it declares an extern "Rust"
+    /// `initialize` function that accepts the struct.
+    #[test]
+    fn parses_struct_initialize_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", initialize)]
+                struct AppConfig {
+                    verbose: bool,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.initialize);
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].sig.ident.to_string(), "initialize");
+    }
+
+    /// Verify that we can parse a `patch` attribute, and that it declares a patch struct with an
+    /// `Option` of each of the struct's fields.
+    #[test]
+    fn parses_struct_patch_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", patch)]
+                struct AppConfig {
+                    verbose: bool,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module
+            .types
+            .get("AppConfig")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert!(ty.patch);
+
+        let patch = module
+            .types
+            .get("AppConfigPatch")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert!(!patch.patch);
+        match &patch.fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(
+                    fields[0].ty.to_token_stream().to_string(),
+                    "Option < bool >"
+                );
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse an `Identifiable = "..."` attribute.
+    #[test]
+    fn parses_struct_identifiable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable = "user_id")]
+                struct User {
+                    user_id: u32,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("User").unwrap().unwrap_shared_struct();
+        assert_eq!(ty.identifiable.as_ref().unwrap(), "user_id");
+    }
+
+    /// Verify that we return an error if the `Identifiable` attribute does not name a field.
+    #[test]
+    fn error_if_identifiable_field_not_found() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable = "missing")]
+                struct User {
+                    user_id: u32,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::StructIdentifiableFieldNotFound { field } => {
+                assert_eq!(field.value(), "missing");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
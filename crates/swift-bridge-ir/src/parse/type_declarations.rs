@@ -58,6 +58,10 @@ impl TypeDeclaration {
                 reference,
                 mutable,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
+                swift_repr_struct: opaque.attributes.swift_repr_struct,
+                trait_object: opaque.attributes.trait_object,
+                hardened: opaque.attributes.hardened,
+                swift_name: opaque.swift_name_string(),
                 generics: opaque.generics.clone(),
             }),
             _ => None,
@@ -78,13 +82,57 @@ impl OpaqueForeignTypeDeclaration {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
         } else {
-            let ty_name = &self.ty;
+            let rust_ty = self.rust_type_tokens();
             quote::quote! {
-                *mut super::#ty_name
+                *mut #rust_ty
             }
         }
     }
 
+    /// Like [`Self::ffi_repr_type_tokens`], but with the concrete generics of a monomorphized
+    /// generic type.
+    /// *mut super::SomeType<u32>
+    pub(crate) fn ffi_repr_type_tokens_with_generics(
+        &self,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.attributes.copy.is_some() {
+            self.ffi_copy_repr_ident().to_token_stream()
+        } else {
+            let rust_ty = self.rust_type_tokens();
+            let generics = self
+                .generics
+                .angle_bracketed_concrete_generics_tokens(types);
+            quote::quote! {
+                *mut #rust_ty #generics
+            }
+        }
+    }
+
+    /// The Rust type that backs an opaque Rust type, without its generics.
+    /// super::SomeType, or Box<dyn super::SomeTrait> for `#[swift_bridge(trait_object)]` types.
+    pub(crate) fn rust_type_tokens(&self) -> TokenStream {
+        let ty_name = &self.ty;
+
+        if self.attributes.trait_object {
+            quote::quote! { Box<dyn super::#ty_name> }
+        } else {
+            quote::quote! { super::#ty_name }
+        }
+    }
+
+    /// The name of the type followed by its concrete generics, which tells apart the
+    /// monomorphizations of a generic type in link names.
+    ///
+    /// "SomeType$u32" for `type SomeType<u32>`, "SomeType" for `type SomeType`.
+    pub(crate) fn monomorphized_name_string(&self) -> String {
+        format!(
+            "{}{}",
+            self.ty,
+            self.generics.dollar_prefixed_generics_string()
+        )
+    }
+
     /// The name of the FFI representation for an opaque Rust type.
     /// __swift_bridge__$SomeType
     pub(crate) fn ffi_repr_name_string(&self) -> String {
@@ -125,6 +173,33 @@ impl OpaqueForeignTypeDeclaration {
         )
     }
 
+    /// The C FFI link name of the function used to clone a `#[swift_bridge(swift_repr = "struct")]`
+    /// or `#[swift_bridge(Clone)]` opaque Rust type.
+    ///
+    /// "__swift_bridge__$SomeType$_clone"
+    pub(crate) fn clone_rust_opaque_type_ffi_name(&self) -> String {
+        format!("{}${}$_clone", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    /// The Rust function used to clone a `#[swift_bridge(swift_repr = "struct")]` or
+    /// `#[swift_bridge(Clone)]` opaque Rust type.
+    ///
+    /// "__swift_bridge__SomeType__clone"
+    pub(crate) fn clone_rust_opaque_type_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}__clone", SWIFT_BRIDGE_PREFIX, self.ty),
+            self.ty.span(),
+        )
+    }
+
+    /// Whether or not Rust exports a function that Swift uses to clone the type.
+    ///
+    /// `#[swift_bridge(Clone)]` is not supported on generic types, since every concrete generic
+    /// type would need its own function.
+    pub(crate) fn has_clone_function(&self) -> bool {
+        self.attributes.swift_repr_struct || (self.attributes.clone && self.generics.is_empty())
+    }
+
     /// The identifier for the `#[repr(C)] __swift_bridge__SomeStruct([u8; 123usize])`
     /// type that is generated to pass a Copy type over FFI.
     pub(crate) fn ffi_copy_repr_ident(&self) -> Ident {
@@ -205,6 +280,15 @@ impl OpaqueForeignTypeDeclaration {
     pub fn ty_name_ident(&self) -> &Ident {
         &self.ty
     }
+
+    /// The name of the type on the Swift side, which is the Rust name unless the type uses
+    /// `#[swift_bridge(swift_name = "...")]`.
+    pub fn swift_name_string(&self) -> String {
+        match self.attributes.swift_name.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.ty.to_string(),
+        }
+    }
 }
 
 impl TypeDeclarations {
@@ -235,6 +319,14 @@ impl TypeDeclarations {
             Type::Path(path) => path.to_token_stream().to_string(),
             _ => todo!("Handle other cases"),
         };
+
+        // Handles generics. i.e. "SomeType < u32, u64 >" -> SomeType<u32,u64>
+        let ty = if ty.contains('<') {
+            ty.replace(" ", "")
+        } else {
+            ty
+        };
+
         self.get(&ty)
     }
 
@@ -258,6 +350,23 @@ impl TypeDeclaration {
             _ => None,
         }
     }
+
+    pub(crate) fn as_shared(&self) -> Option<&SharedTypeDeclaration> {
+        match self {
+            TypeDeclaration::Shared(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl SharedTypeDeclaration {
+    /// SomeType
+    pub(crate) fn name(&self) -> &Ident {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => &shared_struct.name,
+            SharedTypeDeclaration::Enum(shared_enum) => &shared_enum.name,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,405 @@
+//! The opaque types that the bridge modules of a crate declare, so that a bridge module can use a
+//! type that another one declared.
+//!
+//! ```ignore
+//! // scene.rs
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         type Scene;
+//!     }
+//! }
+//!
+//! // renderer.rs
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         fn render(scene: &Scene);
+//!     }
+//! }
+//! ```
+//!
+//! Every bridge module that gets parsed registers the opaque types that it declares. When a
+//! bridge module uses a type that it does not declare, we look the type up in the registry, and
+//! then in the bridge modules in the crate's source files, since the Rust compiler does not
+//! expand the bridge modules in any particular order. The module gets an
+//! `#[swift_bridge(already_declared)]` declaration of the type, so the type's Swift class and C
+//! declarations are only generated by the module that declared it.
+
+use crate::parse::parse_extern_mod::OpaqueTypeAllAttributes;
+use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use syn::{ForeignItem, Item, ItemForeignMod, ItemMod, Type};
+
+// We store strings instead of tokens, since tokens can not outlive the macro invocation that
+// they came from.
+#[derive(Clone)]
+struct RegisteredType {
+    host_lang: HostLang,
+    /// The `#[swift_bridge(...)]` arguments that change how the type is passed across the bridge.
+    attributes: Vec<String>,
+}
+
+thread_local! {
+    /// Registered types by crate, then by name.
+    static REGISTRY: RefCell<HashMap<String, BTreeMap<String, RegisteredType>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Register the opaque types that a bridge module declared.
+pub(super) fn register_declared_types(type_declarations: &TypeDeclarations) {
+    let types: BTreeMap<_, _> = type_declarations
+        .types()
+        .into_iter()
+        .filter_map(TypeDeclaration::as_opaque)
+        .filter(|ty| ty.generics.is_empty())
+        .filter_map(|ty| {
+            let registered = RegisteredType::new(ty.host_lang, &ty.attributes)?;
+            Some((ty.ty.to_string(), registered))
+        })
+        .collect();
+
+    if types.is_empty() {
+        return;
+    }
+
+    REGISTRY.with(|registry| {
+        registry
+            .borrow_mut()
+            .entry(crate_key())
+            .or_default()
+            .extend(types)
+    });
+}
+
+/// Add `already_declared` declarations of the types that other bridge modules in the crate
+/// declared to a module that could not resolve them.
+///
+/// Returns `false` if none of the unresolved types were declared elsewhere.
+pub(super) fn declare_types_from_other_modules(
+    item_mod: &mut ItemMod,
+    unresolved_types: &[Type],
+) -> bool {
+    let mut names = BTreeSet::new();
+    for ty in unresolved_types {
+        collect_idents(ty.to_token_stream(), &mut names);
+    }
+
+    let mut found = REGISTRY.with(|registry| {
+        let registry = registry.borrow();
+        let registered = registry.get(&crate_key());
+
+        names
+            .iter()
+            .filter_map(|name| Some((name.clone(), registered?.get(name)?.clone())))
+            .collect::<BTreeMap<_, _>>()
+    });
+
+    let missing: Vec<_> = names.iter().filter(|n| !found.contains_key(*n)).collect();
+    if !missing.is_empty() {
+        if let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") {
+            found.extend(declared_in_source_files(
+                &Path::new(&dir).join("src"),
+                &missing,
+            ));
+        }
+    }
+
+    if found.is_empty() {
+        return false;
+    }
+
+    let declarations = found.into_iter().map(|(name, ty)| {
+        let abi = match ty.host_lang {
+            HostLang::Rust => "Rust",
+            HostLang::Swift => "Swift",
+        };
+        let mut args = vec!["already_declared".to_string()];
+        args.extend(ty.attributes);
+
+        let declaration = format!(
+            r#"extern "{}" {{ #[swift_bridge({})] type {}; }}"#,
+            abi,
+            args.join(", "),
+            name
+        );
+        Item::ForeignMod(syn::parse_str::<ItemForeignMod>(&declaration).unwrap())
+    });
+
+    let items = &mut item_mod.content.as_mut().unwrap().1;
+    items.splice(0..0, declarations);
+
+    true
+}
+
+impl RegisteredType {
+    /// Types that were declared elsewhere or that are generic are not registered.
+    fn new(host_lang: HostLang, attributes: &OpaqueTypeAllAttributes) -> Option<Self> {
+        let attributes = &attributes.swift_bridge;
+        if attributes.already_declared || attributes.declare_generic {
+            return None;
+        }
+
+        let mut args = vec![];
+        if let Some(copy) = attributes.copy {
+            args.push(format!("Copy({})", copy.size_bytes));
+        }
+        if attributes.swift_repr_struct {
+            args.push(r#"swift_repr = "struct""#.to_string());
+        }
+        if attributes.trait_object {
+            args.push("trait_object".to_string());
+        }
+        if attributes.hardened {
+            args.push("hardened".to_string());
+        }
+        if attributes.interior_mut {
+            args.push("interior_mut".to_string());
+        }
+        if let Some(swift_name) = &attributes.swift_name {
+            args.push(format!("swift_name = {:?}", swift_name.value()));
+        }
+
+        Some(RegisteredType {
+            host_lang,
+            attributes: args,
+        })
+    }
+}
+
+// Proc macro servers, such as the one that rust-analyzer uses, can expand the macros of many
+// crates on the same thread.
+fn crate_key() -> String {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    format!("{}:{}", var("CARGO_MANIFEST_DIR"), var("CARGO_CRATE_NAME"))
+}
+
+/// The types with the given names that the bridge modules in the source files in the directory
+/// declare.
+///
+/// We don't cache what we find, since rust-analyzer keeps expanding macros while files change.
+fn declared_in_source_files(dir: &Path, names: &[&String]) -> BTreeMap<String, RegisteredType> {
+    let mut files = vec![];
+    read_rust_files_recursive(dir, &mut files);
+
+    let mut found = BTreeMap::new();
+    for file in files {
+        let contents = match std::fs::read_to_string(&file) {
+            Ok(contents) => contents,
+            Err(_) => continue,
+        };
+        // Most files don't declare any of the types, so we avoid parsing them.
+        if !names
+            .iter()
+            .any(|name| contents.contains(&format!("type {}", name)))
+        {
+            continue;
+        }
+        let file = match syn::parse_file(&contents) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+
+        for item in file.items {
+            let module = match item {
+                Item::Mod(module) if is_bridge_module(&module) => module,
+                _ => continue,
+            };
+
+            for item in module.content.map(|(_, items)| items).unwrap_or_default() {
+                let foreign_mod = match item {
+                    Item::ForeignMod(foreign_mod) => foreign_mod,
+                    _ => continue,
+                };
+                let host_lang = match foreign_mod.abi.name.as_ref().map(|name| name.value()) {
+                    Some(name) if name == "Rust" => HostLang::Rust,
+                    Some(name) if name == "Swift" => HostLang::Swift,
+                    _ => continue,
+                };
+
+                for foreign_item in foreign_mod.items {
+                    let ty = match foreign_item {
+                        ForeignItem::Type(ty) => ty,
+                        _ => continue,
+                    };
+                    let name = ty.ident.to_string();
+                    if !names.contains(&&name) {
+                        continue;
+                    }
+
+                    let swift_bridge_attrs: Vec<_> = ty
+                        .attrs
+                        .into_iter()
+                        .filter(|attr| attr.path.is_ident("swift_bridge"))
+                        .collect();
+                    let registered = OpaqueTypeAllAttributes::from_attributes(&swift_bridge_attrs)
+                        .ok()
+                        .and_then(|attributes| RegisteredType::new(host_lang, &attributes));
+                    if let Some(registered) = registered {
+                        found.insert(name, registered);
+                    }
+                }
+            }
+        }
+    }
+
+    found
+}
+
+fn is_bridge_module(module: &ItemMod) -> bool {
+    module.attrs.iter().any(|attr| {
+        let path = attr.path.to_token_stream().to_string();
+        path == "swift_bridge :: bridge" || path == "swift_bridge_macro :: bridge"
+    })
+}
+
+fn read_rust_files_recursive(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            read_rust_files_recursive(&path, files);
+        } else if path.extension().and_then(|extension| extension.to_str()) == Some("rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+
+    /// Verify that a bridge module can use an opaque type that another bridge module declared.
+    #[test]
+    fn uses_type_declared_by_another_module() {
+        parse_ok(quote! {
+            mod scene {
+                extern "Rust" {
+                    #[swift_bridge(Copy(8))]
+                    type Scene;
+                }
+                extern "Swift" {
+                    type Canvas;
+                }
+            }
+        });
+
+        let module = parse_ok(quote! {
+            mod renderer {
+                extern "Rust" {
+                    fn render(scene: &Scene, canvas: Canvas);
+                }
+            }
+        });
+
+        let scene = module.types.get("Scene").unwrap().unwrap_opaque();
+        assert!(scene.attributes.already_declared);
+        assert!(scene.host_lang.is_rust());
+        assert_eq!(scene.attributes.copy.unwrap().size_bytes, 8);
+
+        let canvas = module.types.get("Canvas").unwrap().unwrap_opaque();
+        assert!(canvas.attributes.already_declared);
+        assert!(canvas.host_lang.is_swift());
+    }
+
+    /// Verify that a type that a module declares itself is not treated as already declared.
+    #[test]
+    fn does_not_redeclare_local_types() {
+        parse_ok(quote! {
+            mod scene {
+                extern "Rust" {
+                    type Scene;
+                    type Camera;
+                }
+            }
+        });
+
+        let module = parse_ok(quote! {
+            mod renderer {
+                extern "Rust" {
+                    type Scene;
+
+                    fn render(scene: &Scene);
+                }
+            }
+        });
+
+        assert!(
+            !module
+                .types
+                .get("Scene")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .already_declared
+        );
+        assert!(module.types.get("Camera").is_none());
+    }
+
+    /// Verify that types that no bridge module declared are still undeclared.
+    #[test]
+    fn error_if_type_was_not_declared_elsewhere() {
+        let errors = parse_errors(quote! {
+            mod renderer {
+                extern "Rust" {
+                    fn render(scene: &Scene);
+                }
+            }
+        });
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0], ParseError::UndeclaredType { .. }));
+    }
+
+    /// Verify that we find types that the bridge modules in source files declare.
+    #[test]
+    fn finds_types_declared_in_source_files() {
+        let dir =
+            std::env::temp_dir().join(format!("swift-bridge-type-registry-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("scene")).unwrap();
+        std::fs::write(
+            dir.join("scene/mod.rs"),
+            r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Copy(4))]
+        type Scene;
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let scene = String::from("Scene");
+        let camera = String::from("Camera");
+        let found = declared_in_source_files(&dir, &[&scene, &camera]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found["Scene"].attributes, vec!["Copy(4)"]);
+    }
+}
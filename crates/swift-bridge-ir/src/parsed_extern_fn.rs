@@ -4,7 +4,7 @@ use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclara
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Deref;
 use syn::spanned::Spanned;
 use syn::{FnArg, ForeignItemFn, Lifetime, LitStr, Path, ReturnType, Token, Type};
@@ -12,8 +12,13 @@ use syn::{FnArg, ForeignItemFn, Lifetime, LitStr, Path, ReturnType, Token, Type}
 mod to_extern_c_fn;
 mod to_extern_c_param_names_and_types;
 mod to_rust_impl_call_swift;
+mod to_rust_swift_mock;
 mod to_swift_func;
 
+pub(crate) use self::to_rust_swift_mock::SwiftMock;
+
+pub(crate) use self::to_swift_func::SwiftDataBytes;
+
 #[derive(Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub(crate) enum SwiftFuncGenerics {
     String,
@@ -63,9 +68,60 @@ pub(crate) struct ParsedExternFn {
     /// For more details, see:
     /// [Swift Documentation - Failable Initializers](https://docs.swift.org/swift-book/documentation/the-swift-programming-language/initialization/#Failable-Initializers)
     pub is_swift_failable_initializer: bool,
+    /// Whether or not this function is a Swift throwing initializer.
+    /// This is the case when an initializer returns a `Result<Self, E>`.
+    pub is_swift_throwing_initializer: bool,
     /// Whether or not this function should be used for the associated type's Swift
     /// `Identifiable` protocol implementation.
     pub is_swift_identifiable: bool,
+    /// Whether or not this function takes a `&swift_bridge::progress::Progress` as its last
+    /// argument, which Swift can observe by passing in an optional progress closure.
+    ///
+    /// `#[swift_bridge(progress)]`
+    pub reports_progress: bool,
+    /// Whether or not this function takes a `&swift_bridge::cancellation::CancellationToken` as
+    /// an argument, which Swift can use to cancel the function by passing in an optional
+    /// `CancellationToken`.
+    ///
+    /// `#[swift_bridge(cancellable)]`
+    pub is_cancellable: bool,
+    /// Whether or not we generate an async Swift variant of this function that takes a
+    /// `timeout` in seconds and throws a `RustTimeoutError` if the function does not return in
+    /// time.
+    ///
+    /// `#[swift_bridge(timeout)]`
+    pub has_timeout_variant: bool,
+    /// Whether or not the `Vec<u8>` and `&[u8]` arguments and `Vec<u8>` return value of this
+    /// function appear in Swift as `Data`.
+    ///
+    /// `#[swift_bridge(swift_data)]`
+    pub bytes_as_swift_data: bool,
+    /// Whether or not this method panics with a clear message when Swift calls it while another
+    /// `non_reentrant` method is still running on the same object, such as from inside a
+    /// callback.
+    ///
+    /// `#[swift_bridge(non_reentrant)]`
+    pub is_non_reentrant: bool,
+    /// Whether or not this method is the getter or setter of a Swift computed property.
+    ///
+    /// `#[swift_bridge(get)]` and `#[swift_bridge(set)]`
+    pub swift_property: Option<SwiftProperty>,
+    /// The version of the Rust library that added this function. Swift weakly links versioned
+    /// functions so that an app can check for them at runtime when it loads an older Rust library.
+    ///
+    /// `#[swift_bridge(since = "1.2")]`
+    pub since: Option<syn::LitStr>,
+    /// The number of milliseconds that must pass between two calls from Rust to this Swift
+    /// function. Calls in between are coalesced into the latest one, which runs on the main
+    /// queue.
+    ///
+    /// `#[swift_bridge(throttle_ms = 100)]`
+    pub throttle_ms: Option<u64>,
+    /// Whether or not calls to this function propagate the context that was registered with
+    /// `swift_bridge::context` from the caller's language to the callee's language.
+    ///
+    /// `#[swift_bridge::bridge(propagate_context)]`
+    pub propagates_context: bool,
     pub rust_name_override: Option<syn::LitStr>,
     pub swift_name_override: Option<syn::LitStr>,
     /// If true, we call `.into()` on the expression that the function returns before returning it.
@@ -99,6 +155,26 @@ pub(crate) struct ParsedExternFn {
     pub argument_labels: HashMap<Ident, LitStr>,
 }
 
+/// One half of a Swift computed property, or a Rust constant or `static` that Swift reads as a
+/// property.
+pub(crate) enum SwiftProperty {
+    /// A `&self` method that returns the property's value.
+    Getter,
+    /// A `&mut self` method named `set_<getter>` that takes the property's new value.
+    Setter {
+        /// The name of the property in Swift.
+        name: String,
+        /// The type that the getter returns.
+        ty: Box<Type>,
+    },
+    /// A `#[swift_bridge(constant)] static MAX_USERS: u32;` declaration, which Swift reads once
+    /// into a `static let`.
+    Constant,
+    /// A `static VERSION: &'static str;` declaration, which Swift reads into a computed
+    /// `static var` every time.
+    Static,
+}
+
 pub(crate) enum GetField {
     Direct(GetFieldDirect),
     With(GetFieldWith),
@@ -139,6 +215,14 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// The shared struct or enum that this function is a method or an associated function of.
+    pub(crate) fn associated_shared_type(&self) -> Option<BridgedType> {
+        let associated_type = self.associated_type.as_ref()?;
+        associated_type.as_shared()?;
+
+        Some(associated_type.to_bridged_type(false, false))
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
@@ -163,7 +247,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let sig = &self.func.sig;
 
@@ -241,6 +325,16 @@ impl ParsedExternFn {
             }
         }
     }
+
+    /// The `Box<dyn Fn(f64)>` that Swift passes in to observe the progress of a
+    /// `#[swift_bridge(progress)]` function.
+    pub(crate) fn progress_closure(&self, types: &TypeDeclarations) -> Option<BridgedType> {
+        if !(self.reports_progress && self.host_lang.is_rust()) {
+            return None;
+        }
+
+        BridgedType::new_with_type(&syn::parse_quote!(Box<dyn Fn(f64)>), types)
+    }
 }
 
 impl ParsedExternFn {
@@ -284,6 +378,12 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let BridgedType::StdLib(StdLibType::Str) = built_in {
+                                // The generated Swift code only passes the bytes of Swift
+                                // Strings, which are always valid UTF-8.
+                                quote_spanned! {pat_ty.ty.span()=>
+                                    unsafe { #arg.to_str_unchecked() }
+                                }
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -320,6 +420,27 @@ impl ParsedExternFn {
             };
         }
 
+        if self.is_cancellable && self.host_lang.is_rust() {
+            args.push(quote! {
+                &unsafe {
+                    #swift_bridge_path::cancellation::CancellationToken::from_ffi_ptr(
+                        cancellation_token
+                    )
+                }
+            });
+        }
+        if let Some(progress) = self.progress_closure(types) {
+            let progress = progress.convert_ffi_expression_to_rust_type(
+                &quote! { progress },
+                self.func.sig.span(),
+                swift_bridge_path,
+                types,
+            );
+            args.push(quote! {
+                &#swift_bridge_path::progress::Progress::new(#progress, progress_observed)
+            });
+        }
+
         quote! {
             #(#args),*
         }
@@ -334,13 +455,13 @@ impl ParsedExternFn {
         for arg in inputs {
             match arg {
                 FnArg::Receiver(_receiver) => {
-                    self.push_self_param(&mut params);
+                    self.push_self_param(&mut params, types);
                 }
                 FnArg::Typed(pat_ty) => {
                     let pat = &pat_ty.pat;
 
                     if pat_type_pat_is_self(pat_ty) {
-                        self.push_self_param(&mut params);
+                        self.push_self_param(&mut params, types);
                     } else {
                         let built_in = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
 
@@ -357,6 +478,14 @@ impl ParsedExternFn {
             };
         }
 
+        if self.is_cancellable {
+            params.push("void* cancellation_token".to_string());
+        }
+        if let Some(progress) = self.progress_closure(types) {
+            params.push(format!("{} progress", progress.to_c(types)));
+            params.push("bool progress_observed".to_string());
+        }
+
         if params.len() == 0 {
             "void".to_string()
         } else {
@@ -430,8 +559,10 @@ impl ParsedExternFn {
         }
     }
 
-    fn push_self_param(&self, params: &mut Vec<String>) {
-        let param = if self.is_copy_method_on_opaque_type() {
+    fn push_self_param(&self, params: &mut Vec<String>, types: &TypeDeclarations) {
+        let param = if let Some(shared_ty) = self.associated_shared_type() {
+            format!("{} this", shared_ty.to_c(types))
+        } else if self.is_copy_method_on_opaque_type() {
             format!(
                 "struct {}${} this",
                 SWIFT_BRIDGE_PREFIX,
@@ -456,15 +587,12 @@ impl ParsedExternFn {
         let host_type = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
-                    }
+            .map(|h| match h {
+                TypeDeclaration::Shared(h) => {
+                    format!("${}", h.name())
+                }
+                TypeDeclaration::Opaque(h) => {
+                    format!("${}", h.monomorphized_name_string())
                 }
             })
             .unwrap_or("".to_string());
@@ -519,15 +647,16 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         let host_type_prefix = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
-                    }
+            .map(|h| match h {
+                TypeDeclaration::Shared(h) => {
+                    format!("{}_", h.name())
+                }
+                TypeDeclaration::Opaque(h) => {
+                    format!(
+                        "{}{}_",
+                        h.to_token_stream().to_string(),
+                        h.generics.underscore_prefixed_generics_string()
+                    )
                 }
             })
             .unwrap_or_default();
@@ -773,7 +902,7 @@ mod tests {
                 arg: swift_bridge::string::RustStr
             ) -> swift_bridge::string::RustStr {
                 swift_bridge::string::RustStr::from_str(
-                    super::some_function(arg.to_str())
+                    super::some_function(unsafe { arg.to_str_unchecked() })
                 )
             }
         };
@@ -1,9 +1,13 @@
 use crate::bridged_type::BridgedType;
-use crate::parse::{HostLang, OpaqueCopy, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, ParsedExternFn};
+use crate::parse::{
+    HostLang, OpaqueCopy, OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
+};
+use crate::parsed_extern_fn::{
+    GetField, GetFieldDirect, GetFieldWith, ParsedExternFn, SwiftProperty,
+};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use syn::spanned::Spanned;
 use syn::Path;
 
@@ -28,7 +32,8 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
+        record_breadcrumbs: bool,
     ) -> TokenStream {
         let link_name = self.link_name();
 
@@ -50,18 +55,49 @@ impl ParsedExternFn {
 
                 let is_async = self.sig.asyncness.is_some();
 
+                let maybe_record_breadcrumb = if record_breadcrumbs {
+                    let breadcrumb = self.breadcrumb_name();
+                    quote! {
+                        #swift_bridge_path::breadcrumbs::record(#breadcrumb);
+                    }
+                } else {
+                    quote! {}
+                };
+
                 if !is_async {
+                    let maybe_restore_context = if self.propagates_context {
+                        quote! {
+                            let _context = #swift_bridge_path::context::restore_from_swift();
+                        }
+                    } else {
+                        quote! {}
+                    };
+
+                    let maybe_reentrancy_guard = if self.is_non_reentrant {
+                        let method = self.breadcrumb_name();
+                        quote! {
+                            let _reentrancy_guard = #swift_bridge_path::reentrancy::enter(this, #method);
+                        }
+                    } else {
+                        quote! {}
+                    };
+
                     quote! {
                         #[export_name = #link_name]
                         pub extern "C" fn #prefixed_fn_name ( #params ) #ret {
+                            #maybe_record_breadcrumb
+                            #maybe_restore_context
+                            #maybe_reentrancy_guard
                             #call_fn
                         }
                     }
                 } else {
                     let (await_fut, call_callback) = if maybe_return_ty.is_some() {
                         let return_ty = self.return_ty_built_in(types).unwrap();
+                        let awaited_val =
+                            self.convert_returned_value(&quote! {fut.await}, &return_ty);
                         let awaited_val = return_ty.convert_rust_expression_to_ffi_type(
-                            &quote! {fut.await},
+                            &awaited_val,
                             swift_bridge_path,
                             types,
                             // TODO: Add a UI test and then add a better span.
@@ -94,6 +130,7 @@ impl ParsedExternFn {
                             callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
                             #params
                         ) {
+                            #maybe_record_breadcrumb
                             let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                             let fut = #call_fn;
                             let task = async move {
@@ -110,14 +147,40 @@ impl ParsedExternFn {
                 }
             }
             HostLang::Swift => {
-                quote! {
-                    #[link_name = #link_name]
-                    fn #prefixed_fn_name ( #params ) #ret;
+                if self.sig.asyncness.is_some() {
+                    let maybe_return_ty =
+                        self.maybe_async_rust_fn_return_ty(swift_bridge_path, types);
+
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name (
+                            callback_wrapper: *mut std::ffi::c_void,
+                            callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
+                            #params
+                        );
+                    }
+                } else {
+                    quote! {
+                        #[link_name = #link_name]
+                        fn #prefixed_fn_name ( #params ) #ret;
+                    }
                 }
             }
         }
     }
 
+    /// The name that we record in `swift_bridge::breadcrumbs` when Swift calls this function.
+    ///
+    /// `some_function` for freestanding functions and `SomeType::some_method` for methods.
+    fn breadcrumb_name(&self) -> String {
+        let fn_name = self.func.sig.ident.to_string();
+
+        match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => format!("{}::{}", ty.ty, fn_name),
+            _ => fn_name,
+        }
+    }
+
     fn call_fn_tokens(&self, swift_bridge_path: &Path, types: &TypeDeclarations) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = if let Some(fn_name) = self.rust_name_override.as_ref() {
@@ -134,30 +197,36 @@ impl ParsedExternFn {
 
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
 
-        let call_fn = quote! {
-            #fn_name ( #call_args )
+        // Constants and statics are read instead of called.
+        let call_fn = if matches!(
+            self.swift_property,
+            Some(SwiftProperty::Constant) | Some(SwiftProperty::Static)
+        ) {
+            quote! { #fn_name }
+        } else {
+            quote! {
+                #fn_name ( #call_args )
+            }
         };
 
         let mut call_fn = if self.is_method() {
-            self.call_method_tokens(&call_fn)
+            self.call_method_tokens(&call_fn, swift_bridge_path, types)
         } else {
             self.call_function_tokens(&call_fn)
         };
 
-        let return_ty = self.return_ty_built_in(types).unwrap();
-
-        if self.return_into {
-            call_fn = return_ty.rust_expression_into(&call_fn);
+        // The bridge module's `unsafe fn` declaration is the caller's promise to uphold the
+        // function's safety contract.
+        if sig.unsafety.is_some() {
+            call_fn = quote! { unsafe { #call_fn } };
         }
 
-        if let Some(return_with) = self.return_with.as_ref() {
-            call_fn = quote! {
-                super:: #return_with ( #call_fn )
-            }
-        }
+        let return_ty = self.return_ty_built_in(types).unwrap();
 
-        // Async functions get this conversion done after awaiting the returned future.
+        // Async functions get these conversions done after awaiting the returned future.
         if self.sig.asyncness.is_none() {
+            call_fn = self.convert_returned_value(&call_fn, &return_ty);
+
             let fn_span = self.func.span();
             call_fn = return_ty.convert_rust_expression_to_ffi_type(
                 &call_fn,
@@ -170,12 +239,100 @@ impl ParsedExternFn {
         call_fn
     }
 
+    /// Apply the `return_into` and `return_with` attributes to the value that the Rust function
+    /// returned.
+    fn convert_returned_value(&self, value: &TokenStream, return_ty: &BridgedType) -> TokenStream {
+        let mut value = value.clone();
+
+        if self.return_into {
+            value = return_ty.rust_expression_into(&value);
+        }
+
+        if let Some(return_with) = self.return_with.as_ref() {
+            value = quote! {
+                super:: #return_with ( #value )
+            }
+        }
+
+        value
+    }
+
     /// Generate tokens for calling a method.
-    fn call_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
-        let this = if self.is_copy_method_on_opaque_type() {
+    fn call_method_tokens(
+        &self,
+        call_fn: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        // `#[swift_bridge(interior_mut)]` methods are called inside of a closure that borrows the
+        // inner value.
+        if self.is_interior_mut_method() {
+            let interior_mut = quote! { #swift_bridge_path::interior_mut::InteriorMut };
+            let handle_table = self.hardened_associated_type().map(|ty| {
+                let rust_ty = ty.rust_type_tokens();
+                quote! {
+                    <#rust_ty as #swift_bridge_path::handle_table::Hardened>::handle_table()
+                }
+            });
+
+            return match (self.self_reference(), self.self_mutability()) {
+                (Some(_), maybe_mut) => {
+                    let this = match &handle_table {
+                        Some(handle_table) => quote! { &*#handle_table.resolve(this) },
+                        None => quote! { unsafe { &*this } },
+                    };
+                    let with_inner = if maybe_mut.is_some() {
+                        quote! { with_inner_mut }
+                    } else {
+                        quote! { with_inner }
+                    };
+                    let call = self.call_on_this(&quote! { this }, call_fn);
+
+                    quote! {
+                        #interior_mut::#with_inner(#this, |this| #call)
+                    }
+                }
+                (None, _) => {
+                    let owned = match &handle_table {
+                        Some(handle_table) => quote! { #handle_table.remove(this) },
+                        None => quote! { * unsafe { Box::from_raw(this) } },
+                    };
+                    self.call_on_this(&quote! { #interior_mut::into_inner(#owned) }, call_fn)
+                }
+            };
+        }
+
+        let this = if let Some(shared_ty) = self.associated_shared_type() {
+            // Swift passes shared types by value, so `&self` methods borrow the received value.
+            let this = shared_ty.convert_ffi_expression_to_rust_type(
+                &quote! { this },
+                self.func.span(),
+                swift_bridge_path,
+                types,
+            );
+            quote! { (#this) }
+        } else if self.is_copy_method_on_opaque_type() {
             quote! {
                 this.into_rust_repr()
             }
+        } else if let Some(ty) = self.hardened_associated_type() {
+            let rust_ty = ty.rust_type_tokens();
+            let handle_table = quote! {
+                <#rust_ty as #swift_bridge_path::handle_table::Hardened>::handle_table()
+            };
+
+            if let Some(reference) = self.self_reference() {
+                let maybe_ref = reference.0;
+                let maybe_mut = self.self_mutability();
+
+                quote! {
+                    (#maybe_ref #maybe_mut *#handle_table.resolve(this))
+                }
+            } else {
+                quote! {
+                    #handle_table.remove(this)
+                }
+            }
         } else {
             if let Some(reference) = self.self_reference() {
                 let maybe_ref = reference.0;
@@ -191,6 +348,17 @@ impl ParsedExternFn {
             }
         };
 
+        if self.is_stream_next_method() {
+            return quote! {
+                #swift_bridge_path::async_support::next_stream_item(#this)
+            };
+        }
+
+        self.call_on_this(&this, call_fn)
+    }
+
+    /// Call the method, or get the field of a `#[swift_bridge(get(...))]` method, on `this`.
+    fn call_on_this(&self, this: &TokenStream, call_fn: &TokenStream) -> TokenStream {
         match &self.get_field {
             Some(GetField::Direct(get_direct)) => {
                 let GetFieldDirect {
@@ -225,19 +393,25 @@ impl ParsedExternFn {
     fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
         let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
             match ty {
-                TypeDeclaration::Shared(_) => {
-                    //
-                    todo!()
+                // Shared types are declared inside of the bridge module.
+                TypeDeclaration::Shared(ty) => {
+                    let ty = ty.name();
+                    quote! {#ty::}
                 }
                 TypeDeclaration::Opaque(ty) => {
                     let ty = &ty.ty;
-                    quote! {#ty::}
+                    quote! {super:: #ty::}
                 }
             }
         });
 
-        quote! {
-            super:: #maybe_associated_type #call_fn
+        match maybe_associated_type {
+            Some(associated_type) => quote! {
+                #associated_type #call_fn
+            },
+            None => quote! {
+                super:: #call_fn
+            },
         }
     }
 
@@ -251,6 +425,44 @@ impl ParsedExternFn {
         self.maybe_copy_descriptor().is_some()
     }
 
+    /// Whether or not this is a method on a type that is using
+    /// `#[swift_bridge(swift_repr = "struct")]`
+    pub(crate) fn is_swift_repr_struct_method_on_opaque_type(&self) -> bool {
+        match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => ty.attributes.swift_repr_struct,
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is the `next` method that the parser declares for a
+    /// `#[swift_bridge(Stream(Item = ...))]` type.
+    fn is_stream_next_method(&self) -> bool {
+        match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => {
+                ty.attributes.stream_item.is_some()
+                    && self.sig.asyncness.is_some()
+                    && self.sig.ident == "next"
+            }
+            _ => false,
+        }
+    }
+
+    /// The type that this method is on, if it uses `#[swift_bridge(hardened)]`.
+    fn hardened_associated_type(&self) -> Option<&OpaqueForeignTypeDeclaration> {
+        match self.associated_type.as_ref()? {
+            TypeDeclaration::Opaque(ty) if ty.attributes.hardened => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Whether or not this is a method on a type that is using `#[swift_bridge(interior_mut)]`.
+    pub(crate) fn is_interior_mut_method(&self) -> bool {
+        match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => ty.attributes.interior_mut,
+            _ => false,
+        }
+    }
+
     /// Describes the "..." in a `#[swift_bridge(Copy(...))]`
     pub(crate) fn maybe_copy_descriptor(&self) -> Option<OpaqueCopy> {
         match self.associated_type.as_ref()? {
@@ -335,7 +547,8 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
+                module.record_breadcrumbs,
             ),
             &expected_fn,
         );
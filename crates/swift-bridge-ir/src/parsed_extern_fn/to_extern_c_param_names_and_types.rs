@@ -2,8 +2,8 @@ use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
-use std::collections::HashMap;
+use quote::quote;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 use syn::spanned::Spanned;
 use syn::{FnArg, Path, Type};
@@ -13,7 +13,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
@@ -85,14 +85,13 @@ impl ParsedExternFn {
                         bridged_type = &ty_ref.elem;
                     };
 
-                    let arg_ty = match types
-                        .get(&bridged_type.to_token_stream().to_string())
-                        .unwrap()
-                    {
-                        TypeDeclaration::Shared(_) => {
-                            todo!("Support methods on shared types.")
+                    let arg_ty = match types.get_with_type(bridged_type).unwrap() {
+                        shared @ TypeDeclaration::Shared(_) => shared
+                            .to_bridged_type(false, false)
+                            .to_ffi_compatible_rust_type(swift_bridge_path, types),
+                        TypeDeclaration::Opaque(opaque) => {
+                            opaque.ffi_repr_type_tokens_with_generics(types)
                         }
-                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
                     };
 
                     params.push(quote! {
@@ -102,6 +101,28 @@ impl ParsedExternFn {
             };
         }
 
+        if self.is_cancellable && self.host_lang.is_rust() {
+            params.push(quote! {
+                cancellation_token: *const #swift_bridge_path::cancellation::CancellationToken
+            });
+        }
+        if let Some(progress) = self.progress_closure(types) {
+            if let Some(tokens) = progress.generate_custom_rust_ffi_types(swift_bridge_path, types)
+            {
+                for token in tokens.into_iter() {
+                    custom_type_definitions.insert(token.to_string(), token);
+                }
+            }
+
+            let ty = progress.to_ffi_compatible_rust_type(swift_bridge_path, types);
+            params.push(quote! {
+                progress: #ty
+            });
+            params.push(quote! {
+                progress_observed: bool
+            });
+        }
+
         quote! {
             #(#params),*
         }
@@ -139,7 +160,7 @@ mod tests {
                 &method.to_extern_c_param_names_and_types(
                     &module.swift_bridge_path,
                     &module.types,
-                    &mut HashMap::new(),
+                    &mut BTreeMap::new(),
                 ),
                 &quote! { this },
             );
@@ -169,7 +190,7 @@ mod tests {
             &funcs[0].to_extern_c_param_names_and_types(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             expected_params,
         );
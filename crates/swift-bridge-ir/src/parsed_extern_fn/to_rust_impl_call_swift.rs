@@ -5,7 +5,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{FnArg, PatType, Path, ReturnType, Type, TypeReference};
+use syn::{FnArg, ImplItemMethod, PatType, Path, ReturnType, Type, TypeReference};
 
 /// Generates the
 ///
@@ -37,9 +37,110 @@ impl ParsedExternFn {
         let sig = &self.func.sig;
         let fn_name = &sig.ident;
 
+        let ret = self.rust_fn_that_calls_a_swift_extern_return_tokens(types);
+
+        let params = self.params_with_explicit_self_types_removed(types);
+        let call_args = self.to_call_rust_args(swift_bridge_path, types);
+        let linked_fn_name = self.extern_swift_linked_fn_new();
+
+        if sig.asyncness.is_some() {
+            return self.to_rust_async_fn_that_calls_a_swift_extern(
+                &params,
+                &call_args,
+                &linked_fn_name,
+                swift_bridge_path,
+                types,
+            );
+        }
+
+        let mut inner = quote! {
+            unsafe { #linked_fn_name(#call_args) }
+        };
+
+        if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
+            inner = built_in.convert_ffi_expression_to_rust_type(
+                &inner,
+                sig.output.span(),
+                swift_bridge_path,
+                types,
+            );
+        } else {
+            todo!("Push to ParsedErrors")
+        }
+
+        // Every call hands its arguments to the throttle, which only calls Swift with the latest
+        // ones once the interval has passed.
+        if let Some(throttle_ms) = self.throttle_ms {
+            inner = quote! {
+                static THROTTLE: #swift_bridge_path::throttle::Throttle =
+                    #swift_bridge_path::throttle::Throttle::new(#throttle_ms);
+                THROTTLE.call(move || #inner);
+            };
+        }
+
+        let maybe_propagate_context = if self.propagates_context {
+            quote! {
+                let _context = #swift_bridge_path::context::propagate_to_swift();
+            }
+        } else {
+            quote! {}
+        };
+
+        let unsafety = &sig.unsafety;
+
+        quote! {
+            pub #unsafety fn #fn_name(#params) #ret {
+                #maybe_propagate_context
+                #inner
+            }
+        }
+    }
+
+    /// Generates a method of the `impl super::SomeTrait for SomeType` of a
+    /// `#[swift_bridge(trait_object)]` Swift type, which calls the inherent method that calls Swift.
+    ///
+    /// fn some_method(&self, arg: u8) -> u16 {
+    ///     SomeType::some_method(self, arg)
+    /// }
+    pub fn to_rust_trait_method_that_calls_a_swift_extern(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let method: ImplItemMethod =
+            syn::parse2(self.to_rust_fn_that_calls_a_swift_extern(swift_bridge_path, types))
+                .unwrap();
+        let sig = &method.sig;
+        let fn_name = &sig.ident;
+        let ty = &self
+            .associated_type
+            .as_ref()
+            .unwrap()
+            .as_opaque()
+            .unwrap()
+            .ty;
+
+        let args = sig.inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => Some(&pat_ty.pat),
+            FnArg::Receiver(_) => None,
+        });
+
+        quote! {
+            #sig {
+                #ty::#fn_name(self, #(#args),*)
+            }
+        }
+    }
+
+    /// The `-> SomeType` of the Rust function that calls the Swift function.
+    pub(crate) fn rust_fn_that_calls_a_swift_extern_return_tokens(
+        &self,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
         let ret = &sig.output;
 
-        let ret = match &ret {
+        match &ret {
             ReturnType::Default => {
                 quote! {#ret}
             }
@@ -53,30 +154,71 @@ impl ParsedExternFn {
                     quote! { #ret }
                 }
             }
-        };
+        }
+    }
 
-        let params = self.params_with_explicit_self_types_removed(types);
-        let call_args = self.to_call_rust_args(swift_bridge_path, types);
-        let linked_fn_name = self.extern_swift_linked_fn_new();
+    /// Generates a function that starts an `async` Swift function and returns a future that
+    /// completes once Swift passes the function's output to our completion callback.
+    ///
+    /// pub fn some_function() -> impl std::future::Future<Output = u32> {
+    ///     let (future, callback_wrapper) = SwiftFuture::<u32>::new();
+    ///     extern "C" fn callback(callback_wrapper: *mut c_void, val: u32) { ... }
+    ///     unsafe { __swift_bridge__some_function(callback_wrapper, callback) };
+    ///     future
+    /// }
+    fn to_rust_async_fn_that_calls_a_swift_extern(
+        &self,
+        params: &TokenStream,
+        call_args: &TokenStream,
+        linked_fn_name: &Ident,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
+        let fn_name = &sig.ident;
 
-        let mut inner = quote! {
-            unsafe { #linked_fn_name(#call_args) }
+        let return_ty = BridgedType::new_with_return_type(&sig.output, types).unwrap();
+        let output_ty = match &sig.output {
+            ReturnType::Default => quote! { () },
+            ReturnType::Type(_, _) => return_ty.maybe_convert_pointer_to_super_pointer(types),
         };
 
-        if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
-            inner = built_in.convert_ffi_expression_to_rust_type(
-                &inner,
+        let ffi_return_ty = return_ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let (maybe_val_param, output) = if ffi_return_ty.to_string() == "()" {
+            (quote! {}, quote! { () })
+        } else {
+            let output = return_ty.convert_ffi_expression_to_rust_type(
+                &quote! { val },
                 sig.output.span(),
                 swift_bridge_path,
                 types,
             );
+            (quote! { , val: #ffi_return_ty }, output)
+        };
+
+        let maybe_call_args = if call_args.is_empty() {
+            quote! {}
         } else {
-            todo!("Push to ParsedErrors")
-        }
+            quote! { , #call_args }
+        };
+
+        let unsafety = &sig.unsafety;
 
         quote! {
-            pub fn #fn_name(#params) #ret {
-                #inner
+            pub #unsafety fn #fn_name(#params) -> impl std::future::Future<Output = #output_ty> {
+                let (future, callback_wrapper) =
+                    #swift_bridge_path::async_support::SwiftFuture::<#output_ty>::new();
+
+                extern "C" fn callback(callback_wrapper: *mut std::ffi::c_void #maybe_val_param) {
+                    let completer = unsafe {
+                        #swift_bridge_path::async_support::SwiftFutureCompleter::<#output_ty>::from_raw(callback_wrapper)
+                    };
+                    completer.complete(#output);
+                }
+
+                unsafe { #linked_fn_name(callback_wrapper, callback #maybe_call_args) };
+
+                future
             }
         }
     }
@@ -183,8 +325,19 @@ impl ParsedExternFn {
     // `self: &Foo` -> `&self`,
     // `self: &mut Foo` -> `&mut self`
     fn params_with_explicit_self_types_removed(&self, types: &TypeDeclarations) -> TokenStream {
-        let params = self
-            .sig
+        let params = self.params_with_explicit_self_types_removed_list(types);
+
+        quote! {
+            #(#params),*
+        }
+    }
+
+    /// Same as `params_with_explicit_self_types_removed`, but with each param kept separate.
+    pub(crate) fn params_with_explicit_self_types_removed_list(
+        &self,
+        types: &TypeDeclarations,
+    ) -> Vec<TokenStream> {
+        self.sig
             .inputs
             .iter()
             .map(|fn_arg| {
@@ -197,14 +350,31 @@ impl ParsedExternFn {
                     }
                 } else {
                     match fn_arg {
-                        FnArg::Receiver(_) => {
-                            quote! { #fn_arg }
-                        }
+                        // Lifetimes are elided, since the other arguments are
+                        // regenerated without them.
+                        FnArg::Receiver(receiver) => match &receiver.reference {
+                            Some((ref_token, _lifetime)) => {
+                                let maybe_mut = receiver.mutability;
+                                quote! { #ref_token #maybe_mut self }
+                            }
+                            None => quote! { #fn_arg },
+                        },
                         FnArg::Typed(pat_ty) => {
                             let pat = &pat_ty.pat;
 
                             if let Some(built_in) = BridgedType::new_with_fn_arg(fn_arg, types) {
-                                let ty = built_in.maybe_convert_pointer_to_super_pointer(types);
+                                let mut ty = built_in.maybe_convert_pointer_to_super_pointer(types);
+
+                                // `&SomeType` and `&mut SomeType` arguments are lowered to
+                                // pointers, so the wrapper needs to keep taking a reference.
+                                if let Type::Reference(reference) = pat_ty.ty.deref() {
+                                    if built_in.is_custom_type() && built_in.is_passed_via_pointer()
+                                    {
+                                        let ref_token = reference.and_token;
+                                        let maybe_mut = reference.mutability;
+                                        ty = quote! { #ref_token #maybe_mut #ty };
+                                    }
+                                }
 
                                 let maybe_unused = if built_in.can_be_encoded_with_zero_bytes() {
                                     "_"
@@ -244,11 +414,7 @@ impl ParsedExternFn {
                     }
                 }
             })
-            .collect::<Vec<_>>();
-
-        quote! {
-            #(#params),*
-        }
+            .collect::<Vec<_>>()
     }
 }
 
@@ -0,0 +1,151 @@
+use crate::parse::{TypeDeclaration, TypeDeclarations};
+use crate::parsed_extern_fn::ParsedExternFn;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{FnArg, ReturnType};
+
+/// The code that lets Rust tests replace an `extern "Swift"` function with a closure.
+pub(crate) struct SwiftMock {
+    /// A `#[cfg(test)]` version of the Rust function that calls the Swift function, which calls
+    /// the mock instead.
+    pub mock_fn: TokenStream,
+    /// The thread local that holds the mock and the function that sets it.
+    pub mock_support: TokenStream,
+    /// The name of the type that the function is associated with, if any.
+    pub associated_type: Option<Ident>,
+    /// The name that the function that sets the mock is re-exported as in the `swift_mocks`
+    /// module.
+    pub set_mock_fn_name: Ident,
+    /// The generated name of the function that sets the mock.
+    pub prefixed_set_mock_fn_name: Ident,
+}
+
+impl ParsedExternFn {
+    /// Generates:
+    ///
+    /// ```ignore
+    /// #[cfg(test)]
+    /// pub fn add(a: u8, b: u8) -> u8 {
+    ///     __swift_bridge__mock_storage_add.with(|mock| {
+    ///         let mut mock = mock.borrow_mut();
+    ///         let mock = mock.as_mut().expect("...");
+    ///         mock(a, b)
+    ///     })
+    /// }
+    ///
+    /// #[cfg(test)]
+    /// thread_local! {
+    ///     static __swift_bridge__mock_storage_add: RefCell<Option<Box<dyn FnMut(u8, u8) -> u8>>> =
+    ///         RefCell::new(None);
+    /// }
+    ///
+    /// #[cfg(test)]
+    /// pub fn __swift_bridge__mock_add(mock: impl FnMut(u8, u8) -> u8 + 'static) {
+    ///     __swift_bridge__mock_storage_add.with(|m| *m.borrow_mut() = Some(Box::new(mock)));
+    /// }
+    /// ```
+    ///
+    /// Returns `None` for functions that cannot be mocked. `async` functions return a future that
+    /// Swift completes, and a returned reference could not outlive the mock.
+    pub(crate) fn to_rust_swift_mock(&self, types: &TypeDeclarations) -> Option<SwiftMock> {
+        let sig = &self.func.sig;
+        let fn_name = &sig.ident;
+
+        if sig.asyncness.is_some() {
+            return None;
+        }
+        if let ReturnType::Type(_, ty) = &sig.output {
+            if ty.to_token_stream().to_string().contains('&') {
+                return None;
+            }
+        }
+
+        let associated_type = match &self.associated_type {
+            Some(TypeDeclaration::Opaque(ty)) => Some(ty.ty.clone()),
+            _ => None,
+        };
+        let mocked_fn_name = match &associated_type {
+            Some(ty) => format!("{}::{}", ty, fn_name),
+            None => fn_name.to_string(),
+        };
+        let ident_suffix = match &associated_type {
+            Some(ty) => format!("{}_{}", ty, fn_name),
+            None => fn_name.to_string(),
+        };
+
+        let params = self.params_with_explicit_self_types_removed_list(types);
+        let ret = self.rust_fn_that_calls_a_swift_extern_return_tokens(types);
+
+        let mut mock_args = vec![];
+        let mut mock_params = vec![];
+        for param in params.iter() {
+            match syn::parse2::<FnArg>(param.clone()).unwrap() {
+                FnArg::Receiver(receiver) => {
+                    let ty = associated_type.as_ref().unwrap();
+                    let maybe_mut = &receiver.mutability;
+                    let param = match &receiver.reference {
+                        Some(_) => quote! { & #maybe_mut #ty },
+                        None => quote! { #ty },
+                    };
+
+                    mock_args.push(quote! { self });
+                    mock_params.push(param);
+                }
+                FnArg::Typed(pat_ty) => {
+                    let pat = &pat_ty.pat;
+                    let ty = &pat_ty.ty;
+
+                    mock_args.push(quote! { #pat });
+                    mock_params.push(quote! { #ty });
+                }
+            }
+        }
+
+        let storage = format_ident!("__swift_bridge__mock_storage_{}", ident_suffix);
+        let prefixed_set_mock_fn_name = format_ident!("__swift_bridge__mock_{}", ident_suffix);
+        let mock_fn_ty = quote! { FnMut(#(#mock_params),*) #ret };
+
+        let set_mock_path = match &associated_type {
+            Some(ty) => format!("swift_mocks::{}::{}", ty, fn_name),
+            None => format!("swift_mocks::{}", fn_name),
+        };
+        let missing_mock = format!(
+            "The Swift function `{}` was called without a mock. Set one with `{}`.",
+            mocked_fn_name, set_mock_path
+        );
+
+        let unsafety = &sig.unsafety;
+        let mock_fn = quote! {
+            #[cfg(test)]
+            pub #unsafety fn #fn_name(#(#params),*) #ret {
+                #storage.with(|mock| {
+                    let mut mock = mock.borrow_mut();
+                    let mock = mock.as_mut().expect(#missing_mock);
+                    mock(#(#mock_args),*)
+                })
+            }
+        };
+
+        let mock_support = quote! {
+            #[cfg(test)]
+            thread_local! {
+                #[allow(non_upper_case_globals)]
+                static #storage: std::cell::RefCell<Option<Box<dyn #mock_fn_ty>>> =
+                    std::cell::RefCell::new(None);
+            }
+
+            #[cfg(test)]
+            pub fn #prefixed_set_mock_fn_name(mock: impl #mock_fn_ty + 'static) {
+                #storage.with(|m| *m.borrow_mut() = Some(Box::new(mock)));
+            }
+        };
+
+        Some(SwiftMock {
+            mock_fn,
+            mock_support,
+            associated_type,
+            set_mock_fn_name: fn_name.clone(),
+            prefixed_set_mock_fn_name,
+        })
+    }
+}
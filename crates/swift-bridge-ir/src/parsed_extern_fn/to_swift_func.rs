@@ -1,11 +1,60 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType, TypePosition,
+};
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use quote::{format_ident, ToTokens};
 use std::ops::Deref;
 use syn::{FnArg, Path, ReturnType, Type};
 
+/// A byte buffer that a `#[swift_bridge(swift_data)]` function exposes to Swift as `Data`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SwiftDataBytes {
+    /// A `Vec<u8>`. Returned vectors are wrapped in a `Data` without copying, and `Data`
+    /// arguments are copied into a new vector.
+    Vec,
+    /// A `&[u8]`, which borrows the bytes of a `Data` argument without copying.
+    Slice,
+}
+
 impl ParsedExternFn {
+    /// Whether the given argument or return type is a byte buffer that appears in Swift as
+    /// `Data`.
+    pub(crate) fn swift_data_bytes(
+        &self,
+        ty: &Type,
+        types: &TypeDeclarations,
+    ) -> Option<SwiftDataBytes> {
+        if !self.bytes_as_swift_data || !self.host_lang.is_rust() {
+            return None;
+        }
+
+        match BridgedType::new_with_type(ty, types)? {
+            BridgedType::StdLib(StdLibType::Vec(vec))
+                if matches!(vec.ty.deref(), BridgedType::StdLib(StdLibType::U8)) =>
+            {
+                Some(SwiftDataBytes::Vec)
+            }
+            BridgedType::StdLib(StdLibType::RefSlice(slice))
+                if !slice.mutable
+                    && matches!(slice.ty.deref(), BridgedType::StdLib(StdLibType::U8)) =>
+            {
+                Some(SwiftDataBytes::Slice)
+            }
+            _ => None,
+        }
+    }
+
+    /// The label that an argument is passed with when calling the function in Swift, or `None`
+    /// for an argument that uses `#[swift_bridge(label = "_")]`.
+    pub(crate) fn swift_argument_label(&self, arg_name: &str) -> Option<String> {
+        match self.argument_labels.get(&format_ident!("{}", arg_name)) {
+            Some(label) if label.value() == "_" => None,
+            Some(label) => Some(label.value()),
+            None => Some(arg_name.to_string()),
+        }
+    }
+
     pub fn to_swift_param_names_and_types(
         &self,
         include_receiver_if_present: bool,
@@ -34,7 +83,9 @@ impl ParsedExternFn {
 
                     let arg_name = pat_ty.pat.to_token_stream().to_string();
 
-                    let ty = if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
+                    let ty = if self.swift_data_bytes(&pat_ty.ty, types).is_some() {
+                        "Data".to_string()
+                    } else if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
                         if self.host_lang.is_swift() {
                             if built_in.can_be_encoded_with_zero_bytes() {
                                 continue;
@@ -53,7 +104,11 @@ impl ParsedExternFn {
                     if let Some(argument_label) =
                         self.argument_labels.get(&format_ident!("{}", arg_name))
                     {
-                        format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        if argument_label.value() == arg_name {
+                            format!("{}: {}", arg_name, ty)
+                        } else {
+                            format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        }
                     } else {
                         format!("_ {}: {}", arg_name, ty)
                     }
@@ -62,6 +117,13 @@ impl ParsedExternFn {
             params.push(param)
         }
 
+        if self.is_cancellable && self.host_lang.is_rust() {
+            params.push("cancellationToken: CancellationToken? = nil".to_string());
+        }
+        if self.reports_progress && self.host_lang.is_rust() {
+            params.push("progress: ((Double) -> ())? = nil".to_string());
+        }
+
         params.join(", ")
     }
 
@@ -84,7 +146,7 @@ impl ParsedExternFn {
             match arg {
                 FnArg::Receiver(receiver) => {
                     if include_receiver_if_present {
-                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some());
+                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some(), types);
                     }
                 }
                 FnArg::Typed(pat_ty) => {
@@ -95,7 +157,7 @@ impl ParsedExternFn {
 
                     if pat_type_pat_is_self(pat_ty) {
                         if include_receiver_if_present {
-                            self.push_receiver_as_arg(&mut args, is_reference);
+                            self.push_receiver_as_arg(&mut args, is_reference, types);
                         }
 
                         continue;
@@ -105,43 +167,64 @@ impl ParsedExternFn {
                     let arg = pat.to_token_stream().to_string();
                     let arg_name = arg.clone();
 
-                    let arg =
-                        if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                            if self.host_lang.is_rust() {
-                                if bridged_ty.can_be_encoded_with_zero_bytes() {
-                                    continue;
-                                }
+                    let swift_data_bytes = self.swift_data_bytes(&pat_ty.ty, types);
 
-                                bridged_ty.convert_swift_expression_to_ffi_type(
+                    let arg = if let Some(swift_data_bytes) = swift_data_bytes {
+                        match swift_data_bytes {
+                            SwiftDataBytes::Vec => format!(
+                                "{{ let val = {}.toRustVec(); val.isOwned = false; return val.ptr }}()",
+                                arg
+                            ),
+                            SwiftDataBytes::Slice => format!("{}AsUInt8Buffer.toFfiSlice()", arg),
+                        }
+                    } else if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
+                        if self.host_lang.is_rust() {
+                            if bridged_ty.can_be_encoded_with_zero_bytes() {
+                                continue;
+                            }
+
+                            bridged_ty.convert_swift_expression_to_ffi_type(
+                                &arg,
+                                types,
+                                TypePosition::FnArg(self.host_lang, arg_idx),
+                            )
+                        } else {
+                            if let Some(only) = bridged_ty.only_encoding() {
+                                only.swift
+                            } else {
+                                bridged_ty.convert_ffi_value_to_swift_value(
                                     &arg,
-                                    types,
                                     TypePosition::FnArg(self.host_lang, arg_idx),
+                                    types,
+                                    swift_bridge_path,
                                 )
-                            } else {
-                                if let Some(only) = bridged_ty.only_encoding() {
-                                    only.swift
-                                } else {
-                                    bridged_ty.convert_ffi_value_to_swift_value(
-                                        &arg,
-                                        TypePosition::FnArg(self.host_lang, arg_idx),
-                                        types,
-                                        swift_bridge_path,
-                                    )
-                                }
                             }
-                        } else {
-                            todo!("Push to ParsedErrors")
-                        };
-                    let arg = if include_var_name {
-                        format!("{}: {}", arg_name, arg)
+                        }
                     } else {
-                        arg
+                        todo!("Push to ParsedErrors")
+                    };
+                    let arg = match self.swift_argument_label(&arg_name) {
+                        Some(label) if include_var_name => format!("{}: {}", label, arg),
+                        _ => arg,
                     };
 
                     args.push(arg);
                 }
             };
         }
+
+        if self.is_cancellable && self.host_lang.is_rust() {
+            args.push("cancellationToken?.ptr".to_string());
+        }
+        if let Some(progress) = self.progress_closure(types) {
+            args.push(progress.convert_swift_expression_to_ffi_type(
+                "progress ?? { _ in }",
+                types,
+                TypePosition::FnArg(self.host_lang, args.len()),
+            ));
+            args.push("progress != nil".to_string());
+        }
+
         args.join(", ")
     }
 
@@ -153,6 +236,10 @@ impl ParsedExternFn {
         match &self.func.sig.output {
             ReturnType::Default => "".to_string(),
             ReturnType::Type(_, ty) => {
+                if self.swift_data_bytes(ty, types).is_some() {
+                    return " -> Data".to_string();
+                }
+
                 if let Some(built_in) = BridgedType::new_with_type(&ty, types) {
                     if self.host_lang.is_swift() {
                         if built_in.can_be_encoded_with_zero_bytes() {
@@ -178,9 +265,34 @@ impl ParsedExternFn {
         }
     }
 
-    fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
+    fn push_receiver_as_arg(
+        &self,
+        args: &mut Vec<String>,
+        is_reference: bool,
+        types: &TypeDeclarations,
+    ) {
+        if let Some(shared_ty) = self.associated_shared_type() {
+            args.push(shared_ty.convert_swift_expression_to_ffi_type(
+                "self",
+                types,
+                TypePosition::FnArg(self.host_lang, 0),
+            ));
+            return;
+        }
+
+        if let Some(ty) = self.consumed_opaque_type() {
+            args.push(format!(
+                "{{__private__consume(\"{}.{}()\") {{ let wasOwned = isOwned; isOwned = false; return wasOwned }}; return ptr}}()",
+                ty.swift_name_string(),
+                self.func.sig.ident
+            ));
+            return;
+        }
+
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
+        } else if self.is_swift_repr_struct_method_on_opaque_type() && !is_reference {
+            "cloneForRust()"
         } else {
             if is_reference {
                 "ptr"
@@ -190,6 +302,24 @@ impl ParsedExternFn {
         };
         args.push(arg.to_string());
     }
+
+    /// The opaque Rust type that a `self` method consumes.
+    ///
+    /// Swift gives up ownership of the value under a lock, so consuming it twice traps instead of
+    /// freeing it twice.
+    pub(crate) fn consumed_opaque_type(&self) -> Option<&OpaqueForeignTypeDeclaration> {
+        let ty = match self.associated_type.as_ref()? {
+            TypeDeclaration::Opaque(ty) => ty,
+            _ => return None,
+        };
+
+        let is_consuming_method = self.host_lang.is_rust()
+            && self.func.sig.receiver().is_some()
+            && self.self_reference().is_none();
+        let is_class = ty.attributes.copy.is_none() && !ty.attributes.swift_repr_struct;
+
+        (is_consuming_method && is_class).then_some(ty)
+    }
 }
 
 #[cfg(test)]
@@ -344,12 +474,12 @@ mod tests {
 
         assert_eq!(
             functions[0].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            r#"{__private__consume("Foo.make1()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}()"#
         );
 
         assert_eq!(
             functions[1].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            r#"{__private__consume("Foo.make2()") { let wasOwned = isOwned; isOwned = false; return wasOwned }; return ptr}()"#
         );
 
         assert_eq!(
@@ -1,5 +1,7 @@
 use quote::quote;
-use swift_bridge_ir::{SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+use swift_bridge_ir::{
+    CxxHeader, SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs,
+};
 use syn::parse_macro_input;
 
 #[proc_macro_attribute]
@@ -15,6 +17,21 @@ pub fn bridge(
             SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
                 module.set_swift_bridge_path(path);
             }
+            SwiftBridgeModuleAttr::Breadcrumbs => {
+                module.set_record_breadcrumbs(true);
+            }
+            SwiftBridgeModuleAttr::PropagateContext => {
+                module.set_propagate_context(true);
+            }
+            SwiftBridgeModuleAttr::SwiftNamespace(path) => {
+                module.set_swift_namespace(&path);
+            }
+            SwiftBridgeModuleAttr::SwiftMocks => {
+                module.set_generate_swift_mocks(true);
+            }
+            SwiftBridgeModuleAttr::CxxHeader { enum_class } => {
+                module.set_cxx_header(Some(CxxHeader { enum_class }));
+            }
         }
     }
 
@@ -0,0 +1,18 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=ambiguous-self.rs
+
+// When an extern block declares more than one type, `self` could refer to any of them.
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+        type AnotherType;
+
+        fn some_method(&self);
+    }
+}
+
+pub struct SomeType;
+pub struct AnotherType;
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=async-extern-swift-method.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        type SomeSwiftType;
+
+        async fn some_method(&self);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=function-generic-param.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function<'a, T>(arg: &'a str);
+        fn another_function(arg: &str)
+        where
+            String: Clone;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=invalid-property-attributes.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        // A getter must take `&self` and return a value.
+        #[swift_bridge(get)]
+        fn no_return_value(&self);
+
+        // A setter needs a getter with the same name.
+        #[swift_bridge(set)]
+        fn set_volume(&mut self, volume: u8);
+    }
+}
+
+pub struct SomeType;
+
+fn main() {}
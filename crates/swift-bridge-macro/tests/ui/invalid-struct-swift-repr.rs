@@ -0,0 +1,12 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=invalid-struct-swift-repr.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "enum")]
+    struct SomeStruct {
+        field: u8,
+    }
+}
+
+fn main() {}
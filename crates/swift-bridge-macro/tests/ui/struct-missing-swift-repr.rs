@@ -0,0 +1,11 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=struct-missing-swift-repr.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    struct SomeStruct {
+        field: u8,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=undeclared-type.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function(arg: SomeUndeclaredType);
+    }
+}
+
+fn main() {}
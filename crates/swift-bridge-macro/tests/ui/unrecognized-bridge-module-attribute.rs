@@ -0,0 +1,7 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unrecognized-bridge-module-attribute.rs
+
+#[swift_bridge::bridge(invalid_attribute)]
+mod ffi {}
+
+fn main() {}
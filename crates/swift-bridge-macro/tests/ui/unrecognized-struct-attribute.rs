@@ -0,0 +1,10 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unrecognized-struct-attribute.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(InvalidAttribute)]
+    struct SomeStruct;
+}
+
+fn main() {}
@@ -12,6 +12,8 @@ fn main() {
     }
 
     swift_bridge_build::parse_bridges(bridges)
+        .expose_cargo_features(["this_is_enabled", "this_is_not_enabled"])
+        .expose_bridge_info()
         .write_all_concatenated(out_dir, env!("CARGO_PKG_NAME"));
 }
 
@@ -0,0 +1,44 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ArcCounter;
+
+        fn increment(&self) -> u32;
+
+        fn rust_arc_new_counter() -> Arc<ArcCounter>;
+        fn rust_arc_swift_strong_count(counter: Arc<ArcCounter>) -> usize;
+        fn rust_arc_keep_counter(counter: Arc<ArcCounter>);
+        fn rust_arc_increment_kept_counter() -> u32;
+    }
+}
+
+struct ArcCounter(AtomicU32);
+
+impl ArcCounter {
+    fn increment(&self) -> u32 {
+        self.0.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+static KEPT_COUNTER: Mutex<Option<Arc<ArcCounter>>> = Mutex::new(None);
+
+fn rust_arc_new_counter() -> Arc<ArcCounter> {
+    Arc::new(ArcCounter(AtomicU32::new(0)))
+}
+
+/// The number of strong references that Swift holds, not counting the one that was just passed
+/// to this function.
+fn rust_arc_swift_strong_count(counter: Arc<ArcCounter>) -> usize {
+    Arc::strong_count(&counter) - 1
+}
+
+fn rust_arc_keep_counter(counter: Arc<ArcCounter>) {
+    *KEPT_COUNTER.lock().unwrap() = Some(counter);
+}
+
+fn rust_arc_increment_kept_counter() -> u32 {
+    KEPT_COUNTER.lock().unwrap().as_ref().unwrap().increment()
+}
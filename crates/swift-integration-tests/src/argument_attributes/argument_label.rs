@@ -5,9 +5,41 @@ mod ffi {
             #[swift_bridge(label = "someArg")] some_arg: i32,
             another_arg: i32,
         ) -> i32;
+
+        type ArgumentLabelDirectory;
+
+        #[swift_bridge(init)]
+        fn new() -> ArgumentLabelDirectory;
+
+        fn lookup(&self, #[swift_bridge(label = "for")] id: u64) -> u64;
+
+        fn rust_calls_swift_with_argument_labels() -> i32;
+    }
+
+    extern "Swift" {
+        fn swift_subtract_with_argument_labels(
+            #[swift_bridge(label = "_")] lhs: i32,
+            #[swift_bridge(label = "minus")] rhs: i32,
+        ) -> i32;
     }
 }
 
 fn test_argument_label(some_arg: i32, another_arg: i32) -> i32 {
     some_arg + another_arg
 }
+
+pub struct ArgumentLabelDirectory;
+
+impl ArgumentLabelDirectory {
+    fn new() -> Self {
+        ArgumentLabelDirectory
+    }
+
+    fn lookup(&self, id: u64) -> u64 {
+        id * 10
+    }
+}
+
+fn rust_calls_swift_with_argument_labels() -> i32 {
+    ffi::swift_subtract_with_argument_labels(10, 3)
+}
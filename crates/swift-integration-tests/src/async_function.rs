@@ -14,6 +14,7 @@ mod ffi {
         async fn rust_async_return_null();
         async fn rust_async_reflect_u8(arg: u8) -> u8;
         async fn rust_async_reflect_string(string: String) -> String;
+        async fn rust_async_reflect_option_string(arg: Option<String>) -> Option<String>;
         async fn rust_async_return_struct() -> AsyncRustFnReturnStruct;
         async fn rust_async_func_reflect_result_opaque_rust(
             arg: Result<AsyncResultOpaqueRustType1, AsyncResultOpaqueRustType2>,
@@ -23,6 +24,16 @@ mod ffi {
         ) -> Result<(), AsyncResultOpaqueRustType2>;
     }
 
+    extern "Rust" {
+        async fn rust_async_calls_swift_async_reflect_u32(arg: u32) -> u32;
+        async fn rust_async_calls_swift_async_reflect_string(arg: String) -> String;
+    }
+
+    extern "Swift" {
+        async fn swift_async_reflect_u32(arg: u32) -> u32;
+        async fn swift_async_reflect_string(arg: String) -> String;
+    }
+
     extern "Rust" {
         type TestRustAsyncSelf;
 
@@ -84,6 +95,18 @@ async fn rust_async_reflect_string(string: String) -> String {
     string
 }
 
+async fn rust_async_reflect_option_string(arg: Option<String>) -> Option<String> {
+    arg
+}
+
+async fn rust_async_calls_swift_async_reflect_u32(arg: u32) -> u32 {
+    ffi::swift_async_reflect_u32(arg).await
+}
+
+async fn rust_async_calls_swift_async_reflect_string(arg: String) -> String {
+    ffi::swift_async_reflect_string(arg).await
+}
+
 async fn rust_async_return_struct() -> ffi::AsyncRustFnReturnStruct {
     ffi::AsyncRustFnReturnStruct { field: 123 }
 }
@@ -0,0 +1,27 @@
+use swift_bridge::atomic::AtomicCell;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_atomic_cell_new(value: u32) -> AtomicCell<u32>;
+        fn rust_atomic_cell_increment(counter: AtomicCell<u32>);
+        fn rust_atomic_cell_load_f64(cell: AtomicCell<f64>) -> f64;
+        fn rust_atomic_cell_set_from_other_thread(flag: AtomicCell<bool>);
+    }
+}
+
+fn rust_atomic_cell_new(value: u32) -> AtomicCell<u32> {
+    AtomicCell::new(value)
+}
+
+fn rust_atomic_cell_increment(counter: AtomicCell<u32>) {
+    counter.store(counter.load() + 1);
+}
+
+fn rust_atomic_cell_load_f64(cell: AtomicCell<f64>) -> f64 {
+    cell.load()
+}
+
+fn rust_atomic_cell_set_from_other_thread(flag: AtomicCell<bool>) {
+    std::thread::spawn(move || flag.store(true)).join().unwrap();
+}
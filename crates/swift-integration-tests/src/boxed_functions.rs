@@ -19,6 +19,11 @@ mod ffi {
         fn swift_func_takes_callback_with_result_arg(
             arg: Box<dyn FnOnce(Result<CallbackTestOpaqueRustType, String>)>,
         );
+
+        fn swift_calls_fn_callback_three_times(arg: Box<dyn Fn(u32) -> u32>) -> u32;
+        fn swift_stores_fn_callback(arg: Box<dyn Fn()>);
+        fn swift_calls_stored_fn_callback();
+        fn swift_drops_stored_fn_callback();
     }
 
     extern "Swift" {
@@ -63,6 +68,15 @@ mod ffi {
 
     extern "Rust" {
         fn test_callbacks_rust_calls_swift();
+        fn test_rust_passes_fn_closures_to_swift();
+    }
+
+    extern "Rust" {
+        type SwiftClosureHolder;
+
+        fn rust_calls_swift_closure_for_each(upto: u32, callback: Box<dyn Fn(u32)>);
+        fn rust_holds_swift_closure(callback: Box<dyn Fn(u32) -> u32>) -> SwiftClosureHolder;
+        fn call(&self, arg: u32) -> u32;
     }
 }
 
@@ -91,6 +105,39 @@ mod ffi {
 //     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
 // }
 
+fn test_rust_passes_fn_closures_to_swift() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let sum = ffi::swift_calls_fn_callback_three_times(Box::new(|num| num * 10));
+    assert_eq!(sum, 60);
+
+    let calls = Rc::new(Cell::new(0));
+    let dropped = Rc::new(Cell::new(false));
+
+    let drop_flag = DropFlag(dropped.clone());
+    let calls_clone = calls.clone();
+    ffi::swift_stores_fn_callback(Box::new(move || {
+        let _ = &drop_flag;
+        calls_clone.set(calls_clone.get() + 1);
+    }));
+
+    ffi::swift_calls_stored_fn_callback();
+    ffi::swift_calls_stored_fn_callback();
+    assert_eq!(calls.get(), 2);
+    assert!(!dropped.get());
+
+    ffi::swift_drops_stored_fn_callback();
+    assert!(dropped.get());
+}
+
+struct DropFlag(std::rc::Rc<std::cell::Cell<bool>>);
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
 pub struct CallbackTestOpaqueRustType {
     val: u32,
 }
@@ -137,3 +184,22 @@ fn test_callbacks_rust_calls_swift() {
         assert_eq!(result.unwrap().val(), 555)
     }));
 }
+
+fn rust_calls_swift_closure_for_each(upto: u32, callback: Box<dyn Fn(u32)>) {
+    for num in 0..upto {
+        callback(num);
+    }
+}
+
+fn rust_holds_swift_closure(callback: Box<dyn Fn(u32) -> u32>) -> SwiftClosureHolder {
+    SwiftClosureHolder { callback }
+}
+
+pub struct SwiftClosureHolder {
+    callback: Box<dyn Fn(u32) -> u32>,
+}
+impl SwiftClosureHolder {
+    fn call(&self, arg: u32) -> u32 {
+        (self.callback)(arg)
+    }
+}
@@ -0,0 +1,24 @@
+#[swift_bridge::bridge(breadcrumbs)]
+mod ffi {
+    extern "Rust" {
+        type BreadcrumbsTestType;
+
+        fn breadcrumbs_test_function();
+
+        #[swift_bridge(init)]
+        fn new() -> BreadcrumbsTestType;
+        fn some_method(&self);
+    }
+}
+
+fn breadcrumbs_test_function() {}
+
+pub struct BreadcrumbsTestType;
+
+impl BreadcrumbsTestType {
+    fn new() -> Self {
+        BreadcrumbsTestType
+    }
+
+    fn some_method(&self) {}
+}
@@ -0,0 +1,43 @@
+use swift_bridge::byte_stream::ByteStream;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type ByteStream;
+
+        fn byte_stream_test_chunks(chunk_count: u8, chunk_len: usize) -> ByteStream;
+        fn byte_stream_test_empty() -> ByteStream;
+        fn byte_stream_test_never_ends() -> ByteStream;
+    }
+}
+
+/// Sends `chunk_count` chunks where every byte of the nth chunk is `n`.
+///
+/// The stream only buffers one chunk, so the sending thread has to wait for Swift to consume
+/// each chunk before it can send the next one.
+fn byte_stream_test_chunks(chunk_count: u8, chunk_len: usize) -> ByteStream {
+    let (sender, stream) = ByteStream::channel(1);
+
+    std::thread::spawn(move || {
+        for n in 0..chunk_count {
+            if sender.blocking_send(vec![n; chunk_len]).is_err() {
+                break;
+            }
+        }
+    });
+
+    stream
+}
+
+fn byte_stream_test_empty() -> ByteStream {
+    let (_sender, stream) = ByteStream::channel(1);
+    stream
+}
+
+/// A stream that never sends a chunk and never ends, since its sender is never dropped.
+fn byte_stream_test_never_ends() -> ByteStream {
+    let (sender, stream) = ByteStream::channel(1);
+    std::mem::forget(sender);
+    stream
+}
@@ -0,0 +1,69 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_channel_of_numbers(count: u32) -> Receiver<u32>;
+        fn rust_channel_doubler(numbers: Receiver<i64>) -> Receiver<i64>;
+        fn rust_channel_make_pair() -> ChannelPair;
+    }
+
+    extern "Rust" {
+        type ChannelPair;
+
+        fn sender(&mut self) -> Sender<i64>;
+        fn receiver(&mut self) -> Receiver<i64>;
+    }
+}
+
+/// Sends `0..count` from another thread, then drops the sender so that the channel closes.
+fn rust_channel_of_numbers(count: u32) -> Receiver<u32> {
+    let (sender, receiver) = channel();
+
+    std::thread::spawn(move || {
+        for n in 0..count {
+            sender.send(n).unwrap();
+        }
+    });
+
+    receiver
+}
+
+/// Doubles every number that Swift sends until Swift drops its sender.
+fn rust_channel_doubler(numbers: Receiver<i64>) -> Receiver<i64> {
+    let (sender, receiver) = channel();
+
+    std::thread::spawn(move || {
+        for n in numbers {
+            if sender.send(n * 2).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+struct ChannelPair {
+    sender: Option<Sender<i64>>,
+    receiver: Option<Receiver<i64>>,
+}
+
+fn rust_channel_make_pair() -> ChannelPair {
+    let (sender, receiver) = channel();
+
+    ChannelPair {
+        sender: Some(sender),
+        receiver: Some(receiver),
+    }
+}
+
+impl ChannelPair {
+    fn sender(&mut self) -> Sender<i64> {
+        self.sender.take().unwrap()
+    }
+
+    fn receiver(&mut self) -> Receiver<i64> {
+        self.receiver.take().unwrap()
+    }
+}
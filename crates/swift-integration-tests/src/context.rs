@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+use std::sync::Once;
+use swift_bridge::context::ContextPropagator;
+
+#[swift_bridge::bridge(propagate_context)]
+mod ffi {
+    extern "Rust" {
+        fn register_context_test_propagator();
+        fn context_test_rust_request_id() -> String;
+        fn context_test_call_swift_with_request_id(request_id: &str) -> String;
+    }
+
+    extern "Swift" {
+        fn context_test_swift_request_id() -> String;
+    }
+}
+
+thread_local! {
+    static REQUEST_ID: RefCell<Option<String>> = RefCell::new(None);
+}
+
+struct RequestIdPropagator;
+
+impl ContextPropagator for RequestIdPropagator {
+    fn capture(&self) -> Option<String> {
+        REQUEST_ID.with(|id| id.borrow().clone())
+    }
+
+    fn restore(&self, value: Option<String>) {
+        REQUEST_ID.with(|id| *id.borrow_mut() = value);
+    }
+}
+
+fn register_context_test_propagator() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        swift_bridge::context::register_context_propagator("request_id", RequestIdPropagator);
+    });
+}
+
+fn context_test_rust_request_id() -> String {
+    RequestIdPropagator.capture().unwrap_or_default()
+}
+
+fn context_test_call_swift_with_request_id(request_id: &str) -> String {
+    let previous = RequestIdPropagator.capture();
+
+    RequestIdPropagator.restore(Some(request_id.to_string()));
+    let seen_by_swift = ffi::context_test_swift_request_id();
+    RequestIdPropagator.restore(previous);
+
+    seen_by_swift
+}
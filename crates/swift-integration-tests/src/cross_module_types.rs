@@ -0,0 +1,4 @@
+//! A bridge module that uses an opaque type that another bridge module declared.
+
+mod renderer;
+mod scene;
@@ -0,0 +1,12 @@
+use super::scene::CrossModuleScene;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn cross_module_render(scene: &CrossModuleScene) -> u32;
+    }
+}
+
+fn cross_module_render(scene: &CrossModuleScene) -> u32 {
+    scene.shapes
+}
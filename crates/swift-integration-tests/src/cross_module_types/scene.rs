@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type CrossModuleScene;
+
+        #[swift_bridge(init)]
+        fn new() -> CrossModuleScene;
+
+        fn add_shape(&mut self);
+    }
+}
+
+pub struct CrossModuleScene {
+    pub shapes: u32,
+}
+
+impl CrossModuleScene {
+    fn new() -> Self {
+        CrossModuleScene { shapes: 0 }
+    }
+
+    fn add_shape(&mut self) {
+        self.shapes += 1;
+    }
+}
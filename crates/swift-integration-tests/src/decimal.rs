@@ -0,0 +1,47 @@
+use rust_decimal::Decimal;
+
+#[swift_bridge::bridge]
+mod ffi {
+    // Struct fields keep the type that they are declared with, so they use the full path.
+    #[swift_bridge(swift_repr = "struct")]
+    struct DecimalTestInvoice {
+        subtotal: rust_decimal::Decimal,
+        tax: rust_decimal::Decimal,
+    }
+
+    extern "Rust" {
+        fn rust_decimal_test_identity(value: Decimal) -> Decimal;
+        fn rust_decimal_test_add(lhs: Decimal, rhs: Decimal) -> Decimal;
+        fn rust_decimal_test_to_string(value: Decimal) -> String;
+        fn rust_decimal_test_invoice(subtotal: Decimal) -> DecimalTestInvoice;
+        fn rust_calls_swift_decimal_multiply(lhs: Decimal, rhs: Decimal) -> Decimal;
+    }
+
+    extern "Swift" {
+        fn swift_decimal_multiply(lhs: Decimal, rhs: Decimal) -> Decimal;
+    }
+}
+
+fn rust_decimal_test_identity(value: Decimal) -> Decimal {
+    value
+}
+
+fn rust_decimal_test_add(lhs: Decimal, rhs: Decimal) -> Decimal {
+    lhs + rhs
+}
+
+fn rust_decimal_test_to_string(value: Decimal) -> String {
+    value.to_string()
+}
+
+/// Adds an 8% tax, rounded to cents.
+fn rust_decimal_test_invoice(subtotal: Decimal) -> ffi::DecimalTestInvoice {
+    ffi::DecimalTestInvoice {
+        subtotal,
+        tax: (subtotal * Decimal::new(8, 2)).round_dp(2),
+    }
+}
+
+fn rust_calls_swift_decimal_multiply(lhs: Decimal, rhs: Decimal) -> Decimal {
+    ffi::swift_decimal_multiply(lhs, rhs)
+}
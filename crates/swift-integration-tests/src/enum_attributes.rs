@@ -1,3 +1,4 @@
 mod already_declared;
 mod derive;
+mod swift_mirror;
 mod swift_name;
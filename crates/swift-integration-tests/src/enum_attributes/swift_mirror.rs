@@ -0,0 +1,28 @@
+/// We declare an enum that mirrors a handwritten Swift enum using the `swift_mirror` attribute.
+/// The generated conversions stop compiling if the two enums' cases drift apart.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_enum_codegen_tests.rs
+///   - generates_swift_mirror_conversions
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_mirror = "HandwrittenTrafficLight")]
+    enum TrafficLight {
+        Red,
+        Yellow,
+        Green,
+    }
+
+    extern "Rust" {
+        fn rust_next_traffic_light(light: TrafficLight) -> TrafficLight;
+    }
+}
+
+use ffi::TrafficLight;
+
+fn rust_next_traffic_light(light: TrafficLight) -> TrafficLight {
+    match light {
+        TrafficLight::Red => TrafficLight::Green,
+        TrafficLight::Yellow => TrafficLight::Red,
+        TrafficLight::Green => TrafficLight::Yellow,
+    }
+}
@@ -13,6 +13,8 @@ mod ffi {
         fn len(&self) -> usize;
 
         fn as_slice(&self) -> &[u8];
+
+        fn into_vec(self) -> Vec<u8>;
     }
 
     extern "Rust" {
@@ -65,4 +67,8 @@ impl ARustStack {
     pub fn as_slice(&self) -> &[u8] {
         self.stack.as_slice()
     }
+
+    fn into_vec(self) -> Vec<u8> {
+        self.stack
+    }
 }
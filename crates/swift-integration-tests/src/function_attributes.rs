@@ -1,8 +1,18 @@
 mod args_into;
+mod cancellable;
+mod constant;
 mod get;
 mod get_with;
 mod identifiable;
+mod labeled_args;
+mod non_reentrant;
+mod progress;
+mod property;
 mod return_into;
 mod return_with;
 mod rust_name;
+mod since;
+mod swift_data;
 mod swift_name;
+mod throttle;
+mod timeout;
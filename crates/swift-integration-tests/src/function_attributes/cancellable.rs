@@ -0,0 +1,33 @@
+use swift_bridge::cancellation::CancellationToken;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(cancellable)]
+        fn count_until_cancelled(max: u32) -> u32;
+
+        #[swift_bridge(cancellable)]
+        fn cancel_after(count: u32, max: u32) -> u32;
+    }
+}
+
+fn count_until_cancelled(max: u32, cancellation_token: &CancellationToken) -> u32 {
+    let mut count = 0;
+    while count < max && !cancellation_token.is_cancelled() {
+        count += 1;
+    }
+    count
+}
+
+/// Cancels the token from Rust after `count` iterations so that we can test cancellation without
+/// needing a second thread.
+fn cancel_after(count: u32, max: u32, cancellation_token: &CancellationToken) -> u32 {
+    let mut iterations = 0;
+    while iterations < max && !cancellation_token.is_cancelled() {
+        iterations += 1;
+        if iterations == count {
+            cancellation_token.cancel();
+        }
+    }
+    iterations
+}
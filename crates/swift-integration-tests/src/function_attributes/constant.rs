@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ConstantServer;
+
+        #[swift_bridge(constant)]
+        static MAX_USERS: u32;
+
+        #[swift_bridge(constant, swift_name = "defaultPort")]
+        static DEFAULT_PORT: u16;
+    }
+
+    extern "Rust" {
+        static SERVER_NAME: &'static str;
+    }
+}
+
+pub struct ConstantServer;
+
+impl ConstantServer {
+    const MAX_USERS: u32 = 500;
+    const DEFAULT_PORT: u16 = 8080;
+}
+
+static SERVER_NAME: &str = "swift-bridge";
@@ -0,0 +1,45 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type LabeledGreeter;
+
+        #[swift_bridge(init, labeled_args)]
+        fn new(name: &str) -> LabeledGreeter;
+
+        #[swift_bridge(init, labeled_args)]
+        fn with_title(title: &str, name: &str) -> LabeledGreeter;
+
+        #[swift_bridge(init, labeled_args)]
+        fn with_nickname(nickname: &str) -> LabeledGreeter;
+
+        fn greeting(&self) -> String;
+    }
+}
+
+pub struct LabeledGreeter {
+    name: String,
+}
+
+impl LabeledGreeter {
+    fn new(name: &str) -> Self {
+        LabeledGreeter {
+            name: name.to_string(),
+        }
+    }
+
+    fn with_title(title: &str, name: &str) -> Self {
+        LabeledGreeter {
+            name: format!("{} {}", title, name),
+        }
+    }
+
+    fn with_nickname(nickname: &str) -> Self {
+        LabeledGreeter {
+            name: format!("\"{}\"", nickname),
+        }
+    }
+
+    fn greeting(&self) -> String {
+        format!("Hello, {}!", self.name)
+    }
+}
@@ -0,0 +1,44 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type NonReentrantCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> NonReentrantCounter;
+
+        #[swift_bridge(non_reentrant)]
+        fn increment(&mut self) -> u32;
+
+        #[swift_bridge(non_reentrant)]
+        fn increment_and_notify(&mut self, listener: NonReentrantListener) -> u32;
+    }
+
+    extern "Swift" {
+        type NonReentrantListener;
+
+        fn on_increment(&self, count: u32);
+    }
+}
+
+pub struct NonReentrantCounter {
+    count: u32,
+}
+
+impl NonReentrantCounter {
+    fn new() -> Self {
+        NonReentrantCounter { count: 0 }
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+
+    /// Swift's listener calls back into Rust, which is allowed as long as it does not call back
+    /// into this same counter.
+    fn increment_and_notify(&mut self, listener: ffi::NonReentrantListener) -> u32 {
+        let count = self.increment();
+        listener.on_increment(count);
+        count
+    }
+}
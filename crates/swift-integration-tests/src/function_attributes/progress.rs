@@ -0,0 +1,25 @@
+use swift_bridge::progress::Progress;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(progress)]
+        fn count_to_with_progress(total: u32) -> u32;
+
+        #[swift_bridge(progress)]
+        fn progress_is_observed() -> bool;
+    }
+}
+
+fn count_to_with_progress(total: u32, progress: &Progress) -> u32 {
+    let mut count = 0;
+    for _ in 0..total {
+        count += 1;
+        progress.report(count as f64 / total as f64);
+    }
+    count
+}
+
+fn progress_is_observed(progress: &Progress) -> bool {
+    progress.is_observed()
+}
@@ -0,0 +1,58 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PropertySpeaker;
+
+        #[swift_bridge(init)]
+        fn new() -> PropertySpeaker;
+
+        #[swift_bridge(get)]
+        fn volume(&self) -> u8;
+
+        #[swift_bridge(set)]
+        fn set_volume(&mut self, volume: u8);
+
+        #[swift_bridge(get, swift_name = "displayName")]
+        fn name(&self) -> String;
+
+        #[swift_bridge(set)]
+        fn set_name(&mut self, name: String);
+
+        #[swift_bridge(get)]
+        fn is_muted(&self) -> bool;
+    }
+}
+
+pub struct PropertySpeaker {
+    volume: u8,
+    name: String,
+}
+
+impl PropertySpeaker {
+    fn new() -> Self {
+        PropertySpeaker {
+            volume: 50,
+            name: "Speaker".to_string(),
+        }
+    }
+
+    fn volume(&self) -> u8 {
+        self.volume
+    }
+
+    fn set_volume(&mut self, volume: u8) {
+        self.volume = volume.min(100);
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    fn is_muted(&self) -> bool {
+        self.volume == 0
+    }
+}
@@ -5,6 +5,11 @@ use ffi2::AlreadyDeclaredStruct;
 mod ffi {
     struct ReturnIntoSomeStruct;
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct ReturnIntoAsyncStruct {
+        field: u8,
+    }
+
     #[swift_bridge(already_declared, swift_repr = "struct")]
     struct AlreadyDeclaredStruct;
 
@@ -42,6 +47,10 @@ mod ffi {
         // transparent enum.
         #[swift_bridge(return_into)]
         fn get_already_declared_enum() -> AlreadyDeclaredEnum;
+
+        // Verify that our code compiles when we use `return_into` on an async function.
+        #[swift_bridge(return_into)]
+        async fn get_struct_async() -> ReturnIntoAsyncStruct;
     }
 }
 #[swift_bridge::bridge]
@@ -73,6 +82,10 @@ fn get_already_declared_struct() -> SomeType {
     SomeType
 }
 
+async fn get_struct_async() -> SomeType {
+    SomeType
+}
+
 fn get_transparent_enum() -> u32 {
     123
 }
@@ -96,6 +109,11 @@ impl Into<ffi::ReturnIntoSomeStruct> for SomeType {
         ffi::ReturnIntoSomeStruct
     }
 }
+impl From<SomeType> for ffi::ReturnIntoAsyncStruct {
+    fn from(_: SomeType) -> Self {
+        ffi::ReturnIntoAsyncStruct { field: 1 }
+    }
+}
 impl Into<ffi2::AlreadyDeclaredStruct> for SomeType {
     fn into(self) -> ffi2::AlreadyDeclaredStruct {
         ffi2::AlreadyDeclaredStruct
@@ -0,0 +1,32 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type VersionedCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> VersionedCounter;
+
+        #[swift_bridge(since = "1.1")]
+        fn increment(&mut self) -> u32;
+
+        #[swift_bridge(since = "1.2.3")]
+        fn versioned_double(value: u32) -> u32;
+    }
+}
+
+struct VersionedCounter(u32);
+
+impl VersionedCounter {
+    fn new() -> Self {
+        VersionedCounter(0)
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn versioned_double(value: u32) -> u32 {
+    value * 2
+}
@@ -0,0 +1,32 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_data)]
+        fn swift_data_make_bytes(len: u8) -> Vec<u8>;
+
+        #[swift_bridge(swift_data)]
+        fn swift_data_sum_vec(bytes: Vec<u8>) -> u32;
+
+        #[swift_bridge(swift_data)]
+        fn swift_data_sum_slice(bytes: &[u8]) -> u32;
+
+        #[swift_bridge(swift_data)]
+        fn swift_data_reverse(bytes: &[u8]) -> Vec<u8>;
+    }
+}
+
+fn swift_data_make_bytes(len: u8) -> Vec<u8> {
+    (0..len).collect()
+}
+
+fn swift_data_sum_vec(bytes: Vec<u8>) -> u32 {
+    swift_data_sum_slice(&bytes)
+}
+
+fn swift_data_sum_slice(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|byte| *byte as u32).sum()
+}
+
+fn swift_data_reverse(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().rev().copied().collect()
+}
@@ -0,0 +1,18 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn throttle_report_progress(count: u32);
+    }
+
+    extern "Swift" {
+        #[swift_bridge(throttle_ms = 50)]
+        fn swift_throttled_progress(completed: u32, label: String);
+    }
+}
+
+/// Reports progress far more often than Swift should be called.
+fn throttle_report_progress(count: u32) {
+    for completed in 1..=count {
+        ffi::swift_throttled_progress(completed, format!("{} of {}", completed, count));
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use swift_bridge::cancellation::CancellationToken;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(timeout)]
+        fn timeout_add_one(value: u32) -> u32;
+
+        #[swift_bridge(timeout, cancellable)]
+        fn timeout_loop_until_cancelled() -> u32;
+
+        fn timeout_loop_saw_cancellation() -> bool;
+    }
+}
+
+static SAW_CANCELLATION: AtomicBool = AtomicBool::new(false);
+
+fn timeout_add_one(value: u32) -> u32 {
+    value + 1
+}
+
+/// Runs until the Swift timeout cancels the token.
+fn timeout_loop_until_cancelled(cancellation_token: &CancellationToken) -> u32 {
+    SAW_CANCELLATION.store(false, Ordering::SeqCst);
+
+    let mut iterations = 0;
+    while !cancellation_token.is_cancelled() {
+        iterations += 1;
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    SAW_CANCELLATION.store(true, Ordering::SeqCst);
+    iterations
+}
+
+fn timeout_loop_saw_cancellation() -> bool {
+    SAW_CANCELLATION.load(Ordering::SeqCst)
+}
@@ -8,6 +8,16 @@ mod ffi {
 
         fn new_some_generic_type_u32() -> SomeGenericType<u32>;
         fn reflect_generic_u32(arg: SomeGenericType<u32>) -> SomeGenericType<u32>;
+
+        #[swift_bridge(init)]
+        fn new(field: u32) -> SomeGenericType<u32>;
+        fn field(self: &SomeGenericType<u32>) -> u32;
+        fn set_field(self: &mut SomeGenericType<u32>, field: u32);
+
+        type SomeGenericType<i16>;
+
+        fn new_some_generic_type_i16() -> SomeGenericType<i16>;
+        fn field(self: &SomeGenericType<i16>) -> i16;
     }
 
     extern "Rust" {
@@ -32,10 +42,23 @@ mod ffi {
 }
 
 pub struct SomeGenericType<T> {
-    #[allow(unused)]
     field: T,
 }
 
+impl<T: Copy> SomeGenericType<T> {
+    fn new(field: T) -> Self {
+        SomeGenericType { field }
+    }
+
+    fn field(&self) -> T {
+        self.field
+    }
+
+    fn set_field(&mut self, field: T) {
+        self.field = field;
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct SomeGenericCopyType<T> {
     #[allow(unused)]
@@ -56,6 +79,10 @@ fn reflect_generic_u32(arg: SomeGenericType<u32>) -> SomeGenericType<u32> {
     arg
 }
 
+fn new_some_generic_type_i16() -> SomeGenericType<i16> {
+    SomeGenericType { field: -5 }
+}
+
 fn new_some_generic_copy_type_u32() -> SomeGenericCopyType<u32> {
     SomeGenericCopyType { field: 123 }
 }
@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_hash_map_word_counts(text: &str) -> HashMap<String, u32>;
+        fn rust_hash_map_sum_values(map: HashMap<u8, f64>) -> f64;
+        fn rust_hash_map_invert(map: HashMap<String, i64>) -> HashMap<i64, String>;
+    }
+}
+
+fn rust_hash_map_word_counts(text: &str) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn rust_hash_map_sum_values(map: HashMap<u8, f64>) -> f64 {
+    map.values().sum()
+}
+
+fn rust_hash_map_invert(map: HashMap<String, i64>) -> HashMap<i64, String> {
+    map.into_iter().map(|(key, value)| (value, key)).collect()
+}
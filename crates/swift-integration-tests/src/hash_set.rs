@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_hash_set_unique_words(text: &str) -> HashSet<String>;
+        fn rust_hash_set_sum(set: HashSet<u16>) -> u32;
+        fn rust_hash_set_lengths(set: HashSet<String>) -> HashSet<usize>;
+    }
+}
+
+fn rust_hash_set_unique_words(text: &str) -> HashSet<String> {
+    text.split_whitespace()
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn rust_hash_set_sum(set: HashSet<u16>) -> u32 {
+    set.into_iter().map(u32::from).sum()
+}
+
+fn rust_hash_set_lengths(set: HashSet<String>) -> HashSet<usize> {
+    set.iter().map(|s| s.len()).collect()
+}
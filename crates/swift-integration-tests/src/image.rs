@@ -0,0 +1,31 @@
+use swift_bridge::image::ImageBuffer;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type ImageBuffer;
+
+        fn image_test_invert(image: &mut ImageBuffer);
+        fn image_test_describe(image: &ImageBuffer) -> String;
+    }
+}
+
+/// Invert every pixel byte, leaving the padding at the end of each row untouched.
+fn image_test_invert(image: &mut ImageBuffer) {
+    for row in image.rows_mut() {
+        for byte in row {
+            *byte = !*byte;
+        }
+    }
+}
+
+fn image_test_describe(image: &ImageBuffer) -> String {
+    format!(
+        "{}x{} {:?} {}",
+        image.width(),
+        image.height(),
+        image.pixel_format(),
+        image.bytes_per_row()
+    )
+}
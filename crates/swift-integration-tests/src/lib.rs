@@ -1,21 +1,43 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod arc;
 mod async_function;
+mod atomic_cell;
 mod boxed_functions;
+mod breadcrumbs;
+mod byte_stream;
+mod channel;
 mod conditional_compilation;
+mod context;
+mod cross_module_types;
+mod decimal;
 mod generics;
+mod hash_map;
+mod hash_set;
+mod image;
+mod lifecycle;
+mod locale;
+mod memory_pressure;
 mod option;
+mod os_log;
 mod pointer;
 mod primitive;
+mod push_parser;
+mod reader;
 mod result;
 mod rust_function_uses_opaque_swift_type;
+mod rust_io;
+mod scope;
 mod shared_types;
 mod single_representation_type_elision;
 mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod swift_mocks;
+mod swift_namespace;
+mod text;
 mod tuple;
 mod vec;
 
@@ -0,0 +1,25 @@
+use std::sync::Mutex;
+use swift_bridge::lifecycle::LifecycleEvent;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn register_lifecycle_test_handler();
+        fn lifecycle_test_last_event() -> Option<String>;
+    }
+}
+
+static LAST_EVENT: Mutex<Option<LifecycleEvent>> = Mutex::new(None);
+
+fn register_lifecycle_test_handler() {
+    swift_bridge::lifecycle::register_lifecycle_handler(|event| {
+        *LAST_EVENT.lock().unwrap() = Some(event);
+    });
+}
+
+fn lifecycle_test_last_event() -> Option<String> {
+    LAST_EVENT
+        .lock()
+        .unwrap()
+        .map(|event| format!("{:?}", event))
+}
@@ -0,0 +1,38 @@
+use swift_bridge::locale::SwiftLocale;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type SwiftLocale;
+
+        fn locale_test_format_decimal(value: f64, locale: &SwiftLocale) -> String;
+        fn locale_test_describe(locale: &SwiftLocale) -> String;
+        fn locale_test_german() -> SwiftLocale;
+        fn locale_test_roundtrip(locale: SwiftLocale) -> SwiftLocale;
+    }
+}
+
+fn locale_test_format_decimal(value: f64, locale: &SwiftLocale) -> String {
+    locale.format_decimal(value, 2)
+}
+
+fn locale_test_describe(locale: &SwiftLocale) -> String {
+    format!(
+        "{} {} {}",
+        locale.identifier(),
+        locale.calendar(),
+        locale.currency_code().unwrap_or("none")
+    )
+}
+
+fn locale_test_german() -> SwiftLocale {
+    SwiftLocale::new("de_DE")
+        .with_decimal_separator(",")
+        .with_grouping_separator(".")
+        .with_currency_code("EUR")
+}
+
+fn locale_test_roundtrip(locale: SwiftLocale) -> SwiftLocale {
+    locale
+}
@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn register_memory_pressure_test_cache();
+        fn memory_pressure_test_cache_purge_count() -> u32;
+    }
+}
+
+static PURGE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+fn register_memory_pressure_test_cache() {
+    swift_bridge::memory_pressure::register_purge_callback(|| {
+        PURGE_COUNT.fetch_add(1, Ordering::SeqCst);
+    });
+}
+
+fn memory_pressure_test_cache_purge_count() -> u32 {
+    PURGE_COUNT.load(Ordering::SeqCst)
+}
@@ -1,4 +1,18 @@
 mod already_declared;
+mod bindings;
+mod clone;
+mod comparable;
 mod copy;
 mod equatable;
+mod hardened;
 mod hashable;
+mod interior_mut;
+mod iterator;
+mod localized_error;
+mod manual_release;
+mod on_drop;
+mod stream;
+mod swift_name;
+mod swift_repr_struct;
+mod swift_trait_object;
+mod trait_object;
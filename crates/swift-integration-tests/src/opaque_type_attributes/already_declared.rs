@@ -11,8 +11,14 @@ mod ffi1 {
         #[swift_bridge(Copy(4))]
         type AlreadyDeclaredCopyTypeTest;
     }
+
+    extern "Swift" {
+        type AlreadyDeclaredSwiftTypeTest;
+    }
 }
 
+use ffi1::AlreadyDeclaredSwiftTypeTest;
+
 #[swift_bridge::bridge]
 mod ffi2 {
     extern "Rust" {
@@ -43,6 +49,23 @@ mod ffi2 {
         #[swift_bridge(associated_to = AlreadyDeclaredCopyTypeTest)]
         fn an_associated_function() -> bool;
     }
+
+    extern "Swift" {
+        #[swift_bridge(already_declared)]
+        type AlreadyDeclaredSwiftTypeTest;
+
+        fn already_declared_swift_type_test_new() -> AlreadyDeclaredSwiftTypeTest;
+
+        fn a_ref_method(&self) -> bool;
+    }
+
+    extern "Rust" {
+        fn rust_calls_already_declared_swift_type_method() -> bool;
+    }
+}
+
+fn rust_calls_already_declared_swift_type_method() -> bool {
+    ffi2::already_declared_swift_type_test_new().a_ref_method()
 }
 
 pub struct AlreadyDeclaredTypeTest;
@@ -0,0 +1,31 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Bindings)]
+        type RustBindingsType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustBindingsType;
+
+        fn volume(&self) -> f32;
+        fn set_volume(&mut self, volume: f32);
+    }
+}
+
+pub struct RustBindingsType {
+    volume: f32,
+}
+
+impl RustBindingsType {
+    fn new() -> Self {
+        RustBindingsType { volume: 0.5 }
+    }
+
+    fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+    }
+}
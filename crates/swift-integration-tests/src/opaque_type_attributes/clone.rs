@@ -0,0 +1,31 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Clone)]
+        type RustCloneType;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> RustCloneType;
+        fn value(&self) -> u32;
+        fn set_value(&mut self, value: u32);
+    }
+}
+
+#[derive(Clone)]
+pub struct RustCloneType {
+    value: u32,
+}
+
+impl RustCloneType {
+    fn new(value: u32) -> Self {
+        RustCloneType { value }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: u32) {
+        self.value = value;
+    }
+}
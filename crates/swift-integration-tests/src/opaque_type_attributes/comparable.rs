@@ -0,0 +1,42 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Equatable, Comparable)]
+        type RustComparableType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustComparableType;
+
+        fn value(&self) -> i32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Copy(4), Equatable, Comparable)]
+        type RustCopyComparableType;
+
+        #[swift_bridge(associated_to = RustCopyComparableType)]
+        fn new_copy_comparable(value: i32) -> RustCopyComparableType;
+    }
+}
+
+#[derive(PartialEq, PartialOrd)]
+pub struct RustComparableType(i32);
+
+impl RustComparableType {
+    fn new(value: i32) -> Self {
+        RustComparableType(value)
+    }
+
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct RustCopyComparableType(i32);
+
+impl RustCopyComparableType {
+    fn new_copy_comparable(value: i32) -> Self {
+        RustCopyComparableType(value)
+    }
+}
@@ -9,6 +9,14 @@ mod ffi {
 
         fn set_value(&mut self, value: i32);
     }
+
+    extern "Rust" {
+        #[swift_bridge(Copy(4), Equatable)]
+        type RustCopyEquatableType;
+
+        #[swift_bridge(associated_to = RustCopyEquatableType)]
+        fn new_copy_equatable(value: i32) -> RustCopyEquatableType;
+    }
 }
 
 #[derive(PartialEq)]
@@ -23,3 +31,12 @@ impl RustEquatableType {
         self.0 = value;
     }
 }
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct RustCopyEquatableType(i32);
+
+impl RustCopyEquatableType {
+    fn new_copy_equatable(value: i32) -> Self {
+        RustCopyEquatableType(value)
+    }
+}
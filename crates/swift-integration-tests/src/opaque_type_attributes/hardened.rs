@@ -0,0 +1,54 @@
+use swift_bridge::handle_table::Hardened;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(hardened)]
+        type RustHardenedType;
+
+        #[swift_bridge(init)]
+        fn new(secret: u32) -> RustHardenedType;
+
+        fn secret(&self) -> u32;
+        fn increment(&mut self);
+        fn into_secret(self) -> u32;
+
+        fn rust_hardened_type_reflect(val: RustHardenedType) -> RustHardenedType;
+        fn rust_hardened_type_sum(val: &RustHardenedType, other: Option<&RustHardenedType>) -> u32;
+        fn rust_hardened_type_live_count() -> usize;
+    }
+}
+
+pub struct RustHardenedType {
+    secret: u32,
+}
+
+impl RustHardenedType {
+    fn new(secret: u32) -> Self {
+        RustHardenedType { secret }
+    }
+
+    fn secret(&self) -> u32 {
+        self.secret
+    }
+
+    fn increment(&mut self) {
+        self.secret += 1;
+    }
+
+    fn into_secret(self) -> u32 {
+        self.secret
+    }
+}
+
+fn rust_hardened_type_reflect(val: RustHardenedType) -> RustHardenedType {
+    val
+}
+
+fn rust_hardened_type_sum(val: &RustHardenedType, other: Option<&RustHardenedType>) -> u32 {
+    val.secret + other.map(|other| other.secret).unwrap_or(0)
+}
+
+fn rust_hardened_type_live_count() -> usize {
+    RustHardenedType::handle_table().len()
+}
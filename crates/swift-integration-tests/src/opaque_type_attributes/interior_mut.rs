@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use swift_bridge::interior_mut::InteriorMut;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(interior_mut)]
+        type RustInteriorMutType;
+
+        #[swift_bridge(init)]
+        fn new(count: u32) -> RustInteriorMutType;
+
+        fn count(&self) -> u32;
+        fn increment(&mut self);
+        fn into_count(self) -> u32;
+    }
+}
+
+pub struct RustInteriorMutType(RefCell<Counter>);
+
+pub struct Counter {
+    count: u32,
+}
+
+impl RustInteriorMutType {
+    fn new(count: u32) -> Self {
+        RustInteriorMutType(RefCell::new(Counter { count }))
+    }
+}
+
+impl InteriorMut for RustInteriorMutType {
+    type Inner = Counter;
+
+    fn with_inner<R>(&self, f: impl FnOnce(&Counter) -> R) -> R {
+        self.0.with_inner(f)
+    }
+
+    fn with_inner_mut<R>(&self, f: impl FnOnce(&mut Counter) -> R) -> R {
+        self.0.with_inner_mut(f)
+    }
+
+    fn into_inner(self) -> Counter {
+        self.0.into_inner()
+    }
+}
+
+impl Counter {
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    fn into_count(self) -> u32 {
+        self.count
+    }
+}
@@ -0,0 +1,52 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Iterator(Item = u32))]
+        type RustEvenNumbers;
+
+        #[swift_bridge(Iterator(Item = String))]
+        type RustWords;
+
+        fn rust_even_numbers(below: u32) -> RustEvenNumbers;
+        fn rust_words(text: &str) -> RustWords;
+    }
+}
+
+pub struct RustEvenNumbers {
+    next: u32,
+    below: u32,
+}
+
+impl Iterator for RustEvenNumbers {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.next >= self.below {
+            return None;
+        }
+
+        let next = self.next;
+        self.next += 2;
+        Some(next)
+    }
+}
+
+/// Wraps an `impl Iterator` so that it can be returned to Swift.
+pub struct RustWords(Box<dyn Iterator<Item = String>>);
+
+impl Iterator for RustWords {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.0.next()
+    }
+}
+
+fn rust_even_numbers(below: u32) -> RustEvenNumbers {
+    RustEvenNumbers { next: 0, below }
+}
+
+fn rust_words(text: &str) -> RustWords {
+    let words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+    RustWords(Box::new(words.into_iter()))
+}
@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(LocalizedError)]
+        type RustLocalizedError;
+
+        fn rust_parse_port(port: &str) -> Result<u16, RustLocalizedError>;
+    }
+}
+
+pub struct RustLocalizedError {
+    input: String,
+}
+
+impl Display for RustLocalizedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" is not a valid port", self.input)
+    }
+}
+
+fn rust_parse_port(port: &str) -> Result<u16, RustLocalizedError> {
+    port.parse().map_err(|_| RustLocalizedError {
+        input: port.to_string(),
+    })
+}
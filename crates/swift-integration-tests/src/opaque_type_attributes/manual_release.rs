@@ -0,0 +1,34 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(manual_release)]
+        type RustManualReleaseType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustManualReleaseType;
+
+        fn rust_manual_release_type_drop_count() -> u32;
+    }
+}
+
+static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+pub struct RustManualReleaseType;
+
+impl RustManualReleaseType {
+    fn new() -> Self {
+        RustManualReleaseType
+    }
+}
+
+impl Drop for RustManualReleaseType {
+    fn drop(&mut self) {
+        DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn rust_manual_release_type_drop_count() -> u32 {
+    DROP_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,41 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(on_drop = close_rust_on_drop_type)]
+        type RustOnDropType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustOnDropType;
+
+        fn rust_on_drop_type_close_count() -> u32;
+    }
+}
+
+static CLOSE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+pub struct RustOnDropType {
+    closed: bool,
+}
+
+impl RustOnDropType {
+    fn new() -> Self {
+        RustOnDropType { closed: false }
+    }
+}
+
+impl Drop for RustOnDropType {
+    fn drop(&mut self) {
+        assert!(self.closed, "on_drop should run before the type is dropped");
+    }
+}
+
+fn close_rust_on_drop_type(val: &mut RustOnDropType) {
+    val.closed = true;
+    CLOSE_COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+fn rust_on_drop_type_close_count() -> u32 {
+    CLOSE_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,82 @@
+use futures_core::Stream;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Stream(Item = u32))]
+        type RustCountdown;
+
+        #[swift_bridge(Stream(Item = String))]
+        type RustMessages;
+
+        fn rust_countdown(from: u32) -> RustCountdown;
+        fn rust_messages() -> RustMessages;
+        fn rust_messages_were_dropped() -> bool;
+    }
+}
+
+/// Counts down to 1, waiting once before every number so that Swift has to suspend.
+pub struct RustCountdown {
+    remaining: u32,
+    waited: bool,
+}
+
+impl Stream for RustCountdown {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        if !self.waited {
+            self.waited = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        self.waited = false;
+        let next = self.remaining;
+        self.remaining -= 1;
+        Poll::Ready(Some(next))
+    }
+}
+
+static MESSAGES_DROPPED: AtomicBool = AtomicBool::new(false);
+
+/// An endless stream of messages that records when it gets dropped.
+pub struct RustMessages(u32);
+
+impl Stream for RustMessages {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<String>> {
+        self.0 += 1;
+        Poll::Ready(Some(format!("message {}", self.0)))
+    }
+}
+
+impl Drop for RustMessages {
+    fn drop(&mut self) {
+        MESSAGES_DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+fn rust_countdown(from: u32) -> RustCountdown {
+    RustCountdown {
+        remaining: from,
+        waited: false,
+    }
+}
+
+fn rust_messages() -> RustMessages {
+    MESSAGES_DROPPED.store(false, Ordering::SeqCst);
+    RustMessages(0)
+}
+
+fn rust_messages_were_dropped() -> bool {
+    MESSAGES_DROPPED.load(Ordering::SeqCst)
+}
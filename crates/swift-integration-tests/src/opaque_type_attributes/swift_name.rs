@@ -0,0 +1,34 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_name = "RenamedRustUser")]
+        type RustUser;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> RustUser;
+
+        #[swift_bridge(swift_name = "userName")]
+        fn get_user_name(&self) -> String;
+
+        fn rust_reverse_users(users: Vec<RustUser>) -> Vec<RustUser>;
+    }
+}
+
+pub struct RustUser {
+    name: String,
+}
+
+impl RustUser {
+    fn new(name: String) -> Self {
+        RustUser { name }
+    }
+
+    fn get_user_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+fn rust_reverse_users(mut users: Vec<RustUser>) -> Vec<RustUser> {
+    users.reverse();
+    users
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_repr = "struct", Equatable)]
+        type RustValueType;
+
+        #[swift_bridge(init)]
+        fn new(val: u32) -> RustValueType;
+
+        fn val(&self) -> u32;
+        fn into_val(self) -> u32;
+
+        fn rust_value_type_echo_ref(arg: &RustValueType) -> &RustValueType;
+        fn rust_value_type_live_count() -> u32;
+    }
+}
+
+static LIVE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[derive(PartialEq)]
+pub struct RustValueType {
+    val: u32,
+}
+
+impl RustValueType {
+    fn new(val: u32) -> Self {
+        LIVE_COUNT.fetch_add(1, Ordering::SeqCst);
+        RustValueType { val }
+    }
+
+    fn val(&self) -> u32 {
+        self.val
+    }
+
+    fn into_val(self) -> u32 {
+        self.val
+    }
+}
+
+impl Clone for RustValueType {
+    fn clone(&self) -> Self {
+        RustValueType::new(self.val)
+    }
+}
+
+impl Drop for RustValueType {
+    fn drop(&mut self) {
+        LIVE_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn rust_value_type_echo_ref(arg: &RustValueType) -> &RustValueType {
+    arg
+}
+
+fn rust_value_type_live_count() -> u32 {
+    LIVE_COUNT.load(Ordering::SeqCst)
+}
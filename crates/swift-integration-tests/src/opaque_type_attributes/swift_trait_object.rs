@@ -0,0 +1,74 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Swift" {
+        #[swift_bridge(trait_object)]
+        type DownloadObserver;
+
+        fn on_progress(&self, completed: u32, total: u32);
+        fn should_continue(&mut self, completed: u32) -> bool;
+        fn on_finished(&self, summary: String);
+    }
+
+    extern "Rust" {
+        type RustDownloader;
+
+        #[swift_bridge(init)]
+        fn new(chunks: u32) -> RustDownloader;
+        fn set_observer(&mut self, observer: DownloadObserver);
+        fn clear_observer(&mut self);
+        fn run(&mut self) -> u32;
+    }
+}
+
+pub trait DownloadObserver {
+    fn on_progress(&self, completed: u32, total: u32);
+    fn should_continue(&mut self, completed: u32) -> bool;
+    fn on_finished(&self, summary: String);
+}
+
+pub struct RustDownloader {
+    chunks: u32,
+    observer: Option<Box<dyn DownloadObserver>>,
+}
+
+impl RustDownloader {
+    fn new(chunks: u32) -> Self {
+        RustDownloader {
+            chunks,
+            observer: None,
+        }
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn DownloadObserver>) {
+        self.observer = Some(observer);
+    }
+
+    fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    /// Downloads chunks until they are all done or the observer asks to stop, and returns the
+    /// number of chunks that were downloaded.
+    fn run(&mut self) -> u32 {
+        let mut completed = 0;
+
+        while completed < self.chunks {
+            if let Some(observer) = self.observer.as_mut() {
+                if !observer.should_continue(completed) {
+                    break;
+                }
+            }
+
+            completed += 1;
+            if let Some(observer) = self.observer.as_ref() {
+                observer.on_progress(completed, self.chunks);
+            }
+        }
+
+        if let Some(observer) = self.observer.as_ref() {
+            observer.on_finished(format!("{} of {} chunks", completed, self.chunks));
+        }
+
+        completed
+    }
+}
@@ -0,0 +1,74 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(trait_object)]
+        type Shape;
+
+        fn name(&self) -> String;
+        fn area(&self) -> f64;
+        fn scale(&mut self, factor: f64);
+
+        fn rust_new_square(side: f64) -> Shape;
+        fn rust_new_circle(radius: f64) -> Shape;
+        fn rust_all_shapes() -> Vec<Shape>;
+        fn rust_total_area(shapes: Vec<Shape>) -> f64;
+    }
+}
+
+pub trait Shape {
+    fn name(&self) -> String;
+    fn area(&self) -> f64;
+    fn scale(&mut self, factor: f64);
+}
+
+struct Square {
+    side: f64,
+}
+
+impl Shape for Square {
+    fn name(&self) -> String {
+        "square".to_string()
+    }
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.side *= factor;
+    }
+}
+
+struct Circle {
+    radius: f64,
+}
+
+impl Shape for Circle {
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.radius *= factor;
+    }
+}
+
+fn rust_new_square(side: f64) -> Box<dyn Shape> {
+    Box::new(Square { side })
+}
+
+fn rust_new_circle(radius: f64) -> Box<dyn Shape> {
+    Box::new(Circle { radius })
+}
+
+fn rust_all_shapes() -> Vec<Box<dyn Shape>> {
+    vec![rust_new_square(2.), rust_new_circle(1.)]
+}
+
+fn rust_total_area(shapes: Vec<Box<dyn Shape>>) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
@@ -85,6 +85,9 @@ mod ffi {
         fn rust_reflect_option_string(arg: Option<String>) -> Option<String>;
         fn rust_create_option_static_str() -> Option<&'static str>;
         fn rust_reflect_option_str(arg: Option<&str>) -> Option<&str>;
+        fn rust_find_option_string(name: &str) -> Option<String>;
+        fn rust_find_option_string_result(name: &str) -> Result<Option<String>, String>;
+        fn rust_find_option_string_tuple(name: &str) -> (Option<String>, u8);
 
         fn rust_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16>>;
 
@@ -287,6 +290,25 @@ fn rust_reflect_option_string(arg: Option<String>) -> Option<String> {
     arg
 }
 
+fn rust_find_option_string(name: &str) -> Option<String> {
+    match name {
+        "hello" => Some("world".to_string()),
+        _ => None,
+    }
+}
+
+fn rust_find_option_string_result(name: &str) -> Result<Option<String>, String> {
+    if name.is_empty() {
+        return Err("empty name".to_string());
+    }
+
+    Ok(rust_find_option_string(name))
+}
+
+fn rust_find_option_string_tuple(name: &str) -> (Option<String>, u8) {
+    (rust_find_option_string(name), name.len() as u8)
+}
+
 fn rust_create_option_static_str() -> Option<&'static str> {
     Some("hello")
 }
@@ -0,0 +1,18 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn init_os_log_test_logger();
+        fn os_log_test_log_info(message: &str);
+    }
+}
+
+const OS_LOG_TEST_SUBSYSTEM: &str = "dev.swift-bridge.integration-tests";
+
+fn init_os_log_test_logger() {
+    // Every test calls this, but the global logger can only be set once.
+    let _ = swift_bridge::os_log::init(OS_LOG_TEST_SUBSYSTEM, log::LevelFilter::Trace);
+}
+
+fn os_log_test_log_info(message: &str) {
+    log::info!(target: "os_log_test", "{}", message);
+}
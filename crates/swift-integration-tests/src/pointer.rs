@@ -14,6 +14,26 @@ mod ffi {
         fn rust_run_u8_pointer_tests();
     }
 
+    // Opaque Rust types converted to and from raw pointers.
+    extern "Rust" {
+        type RawPointerTestType;
+
+        #[swift_bridge(init)]
+        fn new(val: u32) -> RawPointerTestType;
+        fn val(&self) -> u32;
+
+        fn rust_raw_pointer_test_type_from_raw_pointer(ptr: *mut c_void) -> u32;
+        fn rust_raw_pointer_test_type_into_raw_pointer(val: u32) -> *mut c_void;
+    }
+
+    // Unsafe functions.
+    extern "Rust" {
+        unsafe fn rust_read_u8(ptr: *const u8) -> u8;
+        unsafe fn set_val_from(self: &mut RawPointerTestType, val_ptr: *const u32);
+
+        fn rust_run_unsafe_swift_fn_tests();
+    }
+
     // Opaque pointers.
     extern "Swift" {
         fn swift_echo_const_c_void(ptr: *const c_void) -> *const c_void;
@@ -21,6 +41,8 @@ mod ffi {
 
         fn swift_echo_const_u8(ptr: *const u8) -> *const u8;
         fn swift_echo_mut_u8(ptr: *mut u8) -> *mut u8;
+
+        unsafe fn swift_read_u8(ptr: *const u8) -> u8;
     }
 }
 
@@ -56,6 +78,19 @@ fn rust_run_u8_pointer_tests() {
     assert_eq!(unsafe { *ptr_mut_copy }, 10);
 }
 
+/// Verify that Rust can call an `unsafe` Swift function.
+fn rust_run_unsafe_swift_fn_tests() {
+    let num = 7u8;
+
+    assert_eq!(unsafe { ffi::swift_read_u8(&num) }, 7);
+}
+
+/// # Safety
+/// `ptr` must point to a valid `u8`.
+unsafe fn rust_read_u8(ptr: *const u8) -> u8 {
+    *ptr
+}
+
 fn rust_echo_const_c_void(ptr: *const c_void) -> *const c_void {
     ptr
 }
@@ -71,3 +106,30 @@ fn rust_echo_const_u8(ptr: *const u8) -> *const u8 {
 fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8 {
     ptr
 }
+
+pub struct RawPointerTestType(u32);
+
+impl RawPointerTestType {
+    fn new(val: u32) -> Self {
+        RawPointerTestType(val)
+    }
+
+    fn val(&self) -> u32 {
+        self.0
+    }
+
+    /// # Safety
+    /// `val_ptr` must point to a valid `u32`.
+    unsafe fn set_val_from(&mut self, val_ptr: *const u32) {
+        self.0 = *val_ptr;
+    }
+}
+
+fn rust_raw_pointer_test_type_from_raw_pointer(ptr: *mut c_void) -> u32 {
+    let val: RawPointerTestType = unsafe { swift_bridge::raw_pointer::from_raw_pointer(ptr) };
+    val.0
+}
+
+fn rust_raw_pointer_test_type_into_raw_pointer(val: u32) -> *mut c_void {
+    swift_bridge::raw_pointer::into_raw_pointer(RawPointerTestType(val))
+}
@@ -0,0 +1,65 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[derive(Clone)]
+    enum LineEvent {
+        Line(String),
+        BlankLine,
+    }
+
+    extern "Rust" {
+        type LineParser;
+
+        #[swift_bridge(init)]
+        fn new() -> LineParser;
+
+        fn feed(&mut self, chunk: &[u8]) -> Vec<LineEvent>;
+        fn finish(&mut self) -> Vec<LineEvent>;
+    }
+}
+
+use ffi::LineEvent;
+
+/// Splits the bytes that Swift pushes into it into lines, emitting zero or more events per chunk.
+pub struct LineParser {
+    partial_line: Vec<u8>,
+}
+
+impl LineParser {
+    fn new() -> Self {
+        LineParser {
+            partial_line: vec![],
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> Vec<LineEvent> {
+        let mut events = vec![];
+
+        for byte in chunk {
+            if *byte == b'\n' {
+                events.push(self.take_line());
+            } else {
+                self.partial_line.push(*byte);
+            }
+        }
+
+        events
+    }
+
+    fn finish(&mut self) -> Vec<LineEvent> {
+        if self.partial_line.is_empty() {
+            vec![]
+        } else {
+            vec![self.take_line()]
+        }
+    }
+
+    fn take_line(&mut self) -> LineEvent {
+        let line = std::mem::take(&mut self.partial_line);
+
+        if line.is_empty() {
+            LineEvent::BlankLine
+        } else {
+            LineEvent::Line(String::from_utf8_lossy(&line).to_string())
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use swift_bridge::reader::SwiftReader;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type SwiftReader;
+
+        fn reader_test_len(reader: &SwiftReader) -> u64;
+        fn reader_test_read_to_end(reader: SwiftReader) -> Vec<u8>;
+        fn reader_test_read_last(reader: SwiftReader, count: u64) -> Vec<u8>;
+    }
+}
+
+fn reader_test_len(reader: &SwiftReader) -> u64 {
+    reader.len()
+}
+
+/// Reads through a small buffer, so that Swift gets asked for the data in many pieces.
+fn reader_test_read_to_end(reader: SwiftReader) -> Vec<u8> {
+    let mut bytes = vec![];
+    BufReader::with_capacity(3, reader)
+        .read_to_end(&mut bytes)
+        .unwrap();
+    bytes
+}
+
+fn reader_test_read_last(mut reader: SwiftReader, count: u64) -> Vec<u8> {
+    reader.seek(SeekFrom::End(-(count as i64))).unwrap();
+
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes).unwrap();
+    bytes
+}
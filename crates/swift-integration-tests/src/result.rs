@@ -24,6 +24,7 @@ mod ffi {
     }
 
     extern "Rust" {
+        #[swift_bridge(Error)]
         type ResultTestOpaqueRustType;
 
         #[swift_bridge(init)]
@@ -89,6 +90,14 @@ mod ffi {
             succeed: bool,
         ) -> Result<(i32, ResultTestOpaqueRustType, String), ResultTransparentEnum>;
     }
+
+    extern "Rust" {
+        type ThrowingInitializer;
+
+        #[swift_bridge(init)]
+        fn new(succeed: bool) -> Result<ThrowingInitializer, ResultTransparentEnum>;
+        fn val(&self) -> i32;
+    }
 }
 
 fn rust_func_takes_result_string(arg: Result<String, String>) {
@@ -247,3 +256,21 @@ fn rust_func_return_result_tuple_transparent_enum(
         Err(ffi::ResultTransparentEnum::NamedField { data: -123 })
     }
 }
+
+pub struct ThrowingInitializer {
+    val: i32,
+}
+
+impl ThrowingInitializer {
+    fn new(succeed: bool) -> Result<Self, ffi::ResultTransparentEnum> {
+        if succeed {
+            Ok(ThrowingInitializer { val: 123 })
+        } else {
+            Err(ffi::ResultTransparentEnum::NamedField { data: -123 })
+        }
+    }
+
+    fn val(&self) -> i32 {
+        self.val
+    }
+}
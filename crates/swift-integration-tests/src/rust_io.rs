@@ -0,0 +1,63 @@
+use std::io::{Cursor, Error, ErrorKind, Write};
+use swift_bridge::rust_io::{RustReader, RustWriter};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type RustWriter;
+
+        #[swift_bridge(already_declared)]
+        type RustReader;
+
+        fn rust_io_test_uppercase_writer() -> RustWriter;
+        fn rust_io_test_finish_uppercase_writer(writer: RustWriter) -> String;
+        fn rust_io_test_failing_writer() -> RustWriter;
+        fn rust_io_test_reader(text: String) -> RustReader;
+    }
+}
+
+/// Uppercases everything written to it, and accepts at most 3 bytes per write so that Swift has
+/// to handle partial writes.
+struct UppercaseWriter(Vec<u8>);
+
+impl Write for UppercaseWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(3);
+        self.0.extend(buf[..len].iter().map(u8::to_ascii_uppercase));
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(Error::new(ErrorKind::Other, "disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Err(Error::new(ErrorKind::Other, "disk full"))
+    }
+}
+
+fn rust_io_test_uppercase_writer() -> RustWriter {
+    RustWriter::new(UppercaseWriter(vec![]))
+}
+
+fn rust_io_test_finish_uppercase_writer(writer: RustWriter) -> String {
+    let writer = writer.into_inner::<UppercaseWriter>().ok().unwrap();
+    String::from_utf8(writer.0).unwrap()
+}
+
+fn rust_io_test_failing_writer() -> RustWriter {
+    RustWriter::new(FailingWriter)
+}
+
+fn rust_io_test_reader(text: String) -> RustReader {
+    RustReader::new(Cursor::new(text.into_bytes()))
+}
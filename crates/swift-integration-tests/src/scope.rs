@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ScopeTestValue;
+
+        #[swift_bridge(init)]
+        fn new() -> ScopeTestValue;
+
+        fn scope_test_value_drop_count() -> u32;
+    }
+}
+
+static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+pub struct ScopeTestValue;
+
+impl ScopeTestValue {
+    fn new() -> Self {
+        ScopeTestValue
+    }
+}
+
+impl Drop for ScopeTestValue {
+    fn drop(&mut self) {
+        DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn scope_test_value_drop_count() -> u32 {
+    DROP_COUNT.load(Ordering::SeqCst)
+}
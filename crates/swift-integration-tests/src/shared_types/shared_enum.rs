@@ -66,6 +66,22 @@ mod ffi {
             arg: EnumWithGenericOpaqueRust,
         ) -> EnumWithGenericOpaqueRust;
     }
+
+    enum EnumWithMethods {
+        Off,
+        Level(u8),
+    }
+
+    extern "Rust" {
+        #[swift_bridge(init)]
+        fn new(level: u8) -> EnumWithMethods;
+        #[swift_bridge(associated_to = EnumWithMethods)]
+        fn max() -> EnumWithMethods;
+
+        fn is_off(self: &EnumWithMethods) -> bool;
+        fn label(self: &EnumWithMethods) -> String;
+        fn toggled(self: EnumWithMethods) -> EnumWithMethods;
+    }
 }
 
 fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
@@ -107,3 +123,34 @@ fn reflect_enum_with_generic_opaque_type(
 ) -> ffi::EnumWithGenericOpaqueRust {
     arg
 }
+
+impl ffi::EnumWithMethods {
+    fn new(level: u8) -> Self {
+        match level {
+            0 => ffi::EnumWithMethods::Off,
+            level => ffi::EnumWithMethods::Level(level),
+        }
+    }
+
+    fn max() -> Self {
+        ffi::EnumWithMethods::Level(u8::MAX)
+    }
+
+    fn is_off(&self) -> bool {
+        matches!(self, ffi::EnumWithMethods::Off)
+    }
+
+    fn label(&self) -> String {
+        match self {
+            ffi::EnumWithMethods::Off => "off".to_string(),
+            ffi::EnumWithMethods::Level(level) => format!("level {}", level),
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            ffi::EnumWithMethods::Off => Self::max(),
+            ffi::EnumWithMethods::Level(_) => ffi::EnumWithMethods::Off,
+        }
+    }
+}
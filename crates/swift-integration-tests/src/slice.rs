@@ -8,3 +8,44 @@
 // - Create SliceTests.swift
 //   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
 //   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SliceTestBuffer;
+
+        #[swift_bridge(init)]
+        fn new() -> SliceTestBuffer;
+        fn values(&self) -> &[u32];
+        fn values_mut(&mut self) -> &mut [u32];
+
+        fn rust_sum_slice(values: &[u32]) -> u32;
+        fn rust_double_slice_in_place(values: &mut [u32]);
+    }
+}
+
+pub struct SliceTestBuffer(Vec<u32>);
+
+impl SliceTestBuffer {
+    fn new() -> Self {
+        SliceTestBuffer(vec![1, 2, 3])
+    }
+
+    fn values(&self) -> &[u32] {
+        &self.0
+    }
+
+    fn values_mut(&mut self) -> &mut [u32] {
+        &mut self.0
+    }
+}
+
+fn rust_sum_slice(values: &[u32]) -> u32 {
+    values.iter().sum()
+}
+
+fn rust_double_slice_in_place(values: &mut [u32]) {
+    for value in values {
+        *value *= 2;
+    }
+}
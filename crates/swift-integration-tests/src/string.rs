@@ -4,6 +4,11 @@ mod ffi {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+
+        fn longest_str<'a>(a: &'a str, b: &'a str) -> &'a str;
+
+        fn rust_find_str(haystack: &str, needle: &str) -> Option<usize>;
+        fn rust_str_len_up_to(string: &str, byte_offset: usize) -> String;
     }
 
     extern "Swift" {
@@ -26,3 +31,19 @@ fn run_string_tests() {
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn longest_str<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if b.len() > a.len() {
+        b
+    } else {
+        a
+    }
+}
+
+fn rust_find_str(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.find(needle)
+}
+
+fn rust_str_len_up_to(string: &str, byte_offset: usize) -> String {
+    string[..byte_offset].to_string()
+}
@@ -1,3 +1,7 @@
 mod already_declared;
+mod codable;
 mod derive;
+mod identifiable;
+mod initialize;
+mod patch;
 mod swift_name;
@@ -0,0 +1,28 @@
+/// We declare a typed id that derives `PartialEq`, `Eq` and `Hash` and uses the `Codable`
+/// attribute, so that Swift can use it as a dictionary key and in Codable payloads.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_newtype_hashable_codable
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    struct CodableUserId(u64);
+
+    extern "Rust" {
+        fn rust_user_id(id: u64) -> CodableUserId;
+        fn rust_user_id_is_admin(id: CodableUserId) -> bool;
+    }
+}
+
+use ffi::CodableUserId;
+use std::collections::HashSet;
+
+fn rust_user_id(id: u64) -> CodableUserId {
+    CodableUserId(id)
+}
+
+fn rust_user_id_is_admin(id: CodableUserId) -> bool {
+    let admins: HashSet<CodableUserId> = [CodableUserId(1)].into_iter().collect();
+    admins.contains(&id)
+}
@@ -0,0 +1,26 @@
+/// We declare a struct using the `Identifiable` attribute so that SwiftUI's `List` and `ForEach`
+/// can use it directly.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_identifiable_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Identifiable = "todo_id")]
+    struct TodoItem {
+        todo_id: u32,
+        done: bool,
+    }
+
+    extern "Rust" {
+        fn rust_todo_item(todo_id: u32) -> TodoItem;
+    }
+}
+
+use ffi::TodoItem;
+
+fn rust_todo_item(todo_id: u32) -> TodoItem {
+    TodoItem {
+        todo_id,
+        done: false,
+    }
+}
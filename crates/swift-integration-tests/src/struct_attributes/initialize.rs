@@ -0,0 +1,45 @@
+/// We declare a startup configuration struct using the `initialize` attribute, which lets Swift
+/// pass it to our `initialize` function.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_initialize_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", initialize)]
+    struct AppConfig {
+        log_level: String,
+        data_directory: String,
+        verbose: bool,
+    }
+
+    extern "Rust" {
+        fn initialized_log_level() -> Option<String>;
+        fn initialized_verbose() -> bool;
+    }
+}
+
+use ffi::AppConfig;
+use std::sync::Mutex;
+
+static CONFIG: Mutex<Option<AppConfig>> = Mutex::new(None);
+
+fn initialize(config: AppConfig) {
+    *CONFIG.lock().unwrap() = Some(config);
+}
+
+fn initialized_log_level() -> Option<String> {
+    CONFIG
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|config| config.log_level.clone())
+}
+
+fn initialized_verbose() -> bool {
+    CONFIG
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|config| config.verbose)
+        .unwrap_or(false)
+}
@@ -0,0 +1,56 @@
+/// We declare a settings struct using the `patch` attribute, which lets Swift send Rust only the
+/// fields that changed.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_struct_codegen_tests.rs
+///   - shared_struct_patch_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", patch)]
+    struct PlayerSettings {
+        name: String,
+        volume: u8,
+        muted: bool,
+    }
+
+    extern "Rust" {
+        fn reset_player_settings();
+        fn update_player_settings(patch: PlayerSettingsPatch);
+        fn player_settings() -> PlayerSettings;
+    }
+}
+
+use ffi::{PlayerSettings, PlayerSettingsPatch};
+use std::sync::Mutex;
+
+static SETTINGS: Mutex<Option<PlayerSettings>> = Mutex::new(None);
+
+fn default_player_settings() -> PlayerSettings {
+    PlayerSettings {
+        name: "player".to_string(),
+        volume: 50,
+        muted: false,
+    }
+}
+
+fn reset_player_settings() {
+    *SETTINGS.lock().unwrap() = Some(default_player_settings());
+}
+
+fn update_player_settings(patch: PlayerSettingsPatch) {
+    SETTINGS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(default_player_settings)
+        .apply_patch(patch);
+}
+
+fn player_settings() -> PlayerSettings {
+    let settings = SETTINGS.lock().unwrap();
+    let settings = settings.as_ref().unwrap();
+
+    PlayerSettings {
+        name: settings.name.clone(),
+        volume: settings.volume,
+        muted: settings.muted,
+    }
+}
@@ -0,0 +1,49 @@
+//! Rust tests of code that calls into Swift can run under `cargo test` without linking any Swift.
+
+#[swift_bridge::bridge(swift_mocks)]
+mod ffi {
+    extern "Swift" {
+        type SwiftMockedGreeter;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> SwiftMockedGreeter;
+        fn greet(&self, times: u8) -> String;
+
+        fn swift_mocked_add(a: u8, b: u8) -> u8;
+    }
+
+    extern "Rust" {
+        fn rust_greet_sum(a: u8, b: u8) -> String;
+    }
+}
+
+fn rust_greet_sum(a: u8, b: u8) -> String {
+    let greeter = ffi::SwiftMockedGreeter::new("sum");
+    greeter.greet(ffi::swift_mocked_add(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ffi::swift_mocks;
+
+    /// Verify that Rust calls the mocks of the Swift functions during tests.
+    #[test]
+    fn calls_swift_mocks() {
+        swift_mocks::swift_mocked_add(|a, b| a + b);
+        swift_mocks::SwiftMockedGreeter::new(|name| {
+            assert_eq!(name, "sum");
+            ffi::SwiftMockedGreeter::new_mock()
+        });
+        swift_mocks::SwiftMockedGreeter::greet(|_greeter, times| format!("hello x{}", times));
+
+        assert_eq!(rust_greet_sum(2, 3), "hello x5");
+    }
+
+    /// Verify that calling a Swift function that was not mocked panics.
+    #[test]
+    #[should_panic(expected = "The Swift function `swift_mocked_add` was called without a mock")]
+    fn panics_without_mock() {
+        ffi::swift_mocked_add(1, 2);
+    }
+}
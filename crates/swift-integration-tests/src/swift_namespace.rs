@@ -0,0 +1,5 @@
+//! Bridge modules that nest their Swift types inside of a namespace that mirrors their Rust
+//! module path.
+
+mod audio;
+mod video;
@@ -0,0 +1,55 @@
+#[swift_bridge::bridge(swift_namespace = "engine::audio")]
+mod ffi {
+    enum MixerChannels {
+        Mono,
+        Stereo,
+    }
+
+    extern "Rust" {
+        type Mixer;
+
+        #[swift_bridge(init)]
+        fn new(channels: MixerChannels) -> Mixer;
+
+        fn channels(&self) -> MixerChannels;
+        fn set_volume(&mut self, volume: u8);
+        fn volume(&self) -> u8;
+
+        fn rust_namespace_mixer_volume(mixer: &Mixer) -> u8;
+    }
+}
+
+use ffi::MixerChannels;
+
+pub struct Mixer {
+    channels: MixerChannels,
+    volume: u8,
+}
+
+impl Mixer {
+    fn new(channels: MixerChannels) -> Self {
+        Mixer {
+            channels,
+            volume: 0,
+        }
+    }
+
+    fn channels(&self) -> MixerChannels {
+        match self.channels {
+            MixerChannels::Mono => MixerChannels::Mono,
+            MixerChannels::Stereo => MixerChannels::Stereo,
+        }
+    }
+
+    fn set_volume(&mut self, volume: u8) {
+        self.volume = volume;
+    }
+
+    fn volume(&self) -> u8 {
+        self.volume
+    }
+}
+
+fn rust_namespace_mixer_volume(mixer: &Mixer) -> u8 {
+    mixer.volume
+}
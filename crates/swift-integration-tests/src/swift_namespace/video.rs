@@ -0,0 +1,38 @@
+#[swift_bridge::bridge(swift_namespace = "engine::video")]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct FrameSize {
+        width: u32,
+        height: u32,
+    }
+
+    extern "Rust" {
+        type Renderer;
+
+        fn rust_namespace_new_renderer(size: FrameSize) -> Renderer;
+        fn frame_size(&self) -> FrameSize;
+    }
+}
+
+use ffi::FrameSize;
+
+pub struct Renderer {
+    width: u32,
+    height: u32,
+}
+
+impl Renderer {
+    fn frame_size(&self) -> FrameSize {
+        FrameSize {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn rust_namespace_new_renderer(size: FrameSize) -> Renderer {
+    Renderer {
+        width: size.width,
+        height: size.height,
+    }
+}
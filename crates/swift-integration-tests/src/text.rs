@@ -0,0 +1,43 @@
+use swift_bridge::text::{TextMatch, TextMatches};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type TextMatches;
+
+        fn text_test_find_words(text: &str, needle: &str) -> TextMatches;
+        fn text_test_find_key_values(text: &str) -> TextMatches;
+    }
+}
+
+fn text_test_find_words(text: &str, needle: &str) -> TextMatches {
+    text.match_indices(needle)
+        .map(|(start, word)| start..start + word.len())
+        .collect()
+}
+
+/// Find every `key=value` or `key=` pair. The "value" capture does not take part in a match
+/// that has an empty value.
+fn text_test_find_key_values(text: &str) -> TextMatches {
+    let mut offset = 0;
+
+    text.split(' ')
+        .filter_map(|pair| {
+            let start = offset;
+            offset += pair.len() + 1;
+
+            let (key, value) = pair.split_once('=')?;
+            let value_start = start + key.len() + 1;
+
+            Some(
+                TextMatch::new(start..start + pair.len())
+                    .with_capture("key", Some(start..start + key.len()))
+                    .with_capture(
+                        "value",
+                        (!value.is_empty()).then(|| value_start..value_start + value.len()),
+                    ),
+            )
+        })
+        .collect()
+}
@@ -21,6 +21,7 @@ mod ffi {
         fn rust_reflect_tuple_f64_and_usize_and_bool(
             tuple: (f64, usize, bool),
         ) -> (f64, usize, bool);
+        fn rust_reflect_tuple_u64_and_i64(tuple: (u64, u64, i64)) -> (u64, u64, i64);
     }
     extern "Swift" {
         fn swift_reflect_tuple_primitives(arg: (i32, u32)) -> (i32, u32);
@@ -61,6 +62,10 @@ fn rust_reflect_tuple_f64_and_usize_and_bool(tuple: (f64, usize, bool)) -> (f64,
     tuple
 }
 
+fn rust_reflect_tuple_u64_and_i64(tuple: (u64, u64, i64)) -> (u64, u64, i64) {
+    tuple
+}
+
 fn test_rust_calls_swift_tuples() {
     let val = ffi::swift_reflect_tuple_primitives((-123, 123));
     assert_eq!(val.0, -123);
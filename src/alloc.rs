@@ -0,0 +1,144 @@
+//! A global allocator that sends every heap allocation of the program to an allocator that is
+//! registered at startup.
+//!
+//! Games and embedded apps often need to control or measure every allocation, for example with a
+//! counting allocator or an arena. Make [`BridgeAllocator`] the global allocator, then register
+//! your allocator with [`set_allocator`] once your app has set it up.
+//!
+//! [`BridgeAllocator`] replaces the global allocator of the whole process, so it handles the
+//! allocations of all Rust code, not only the `RustString`s, `RustVec`s and opaque Rust types that
+//! the bridge hands to Swift.
+//!
+//! ```
+//! use std::alloc::{GlobalAlloc, Layout, System};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use swift_bridge::alloc::BridgeAllocator;
+//!
+//! #[global_allocator]
+//! static GLOBAL: BridgeAllocator = BridgeAllocator;
+//!
+//! struct CountingAllocator {
+//!     allocations: AtomicUsize,
+//! }
+//!
+//! unsafe impl GlobalAlloc for CountingAllocator {
+//!     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//!         self.allocations.fetch_add(1, Ordering::Relaxed);
+//!         System.alloc(layout)
+//!     }
+//!
+//!     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//!         System.dealloc(ptr, layout)
+//!     }
+//! }
+//!
+//! static COUNTING: CountingAllocator = CountingAllocator {
+//!     allocations: AtomicUsize::new(0),
+//! };
+//!
+//! fn main() {
+//!     let mut allocated_before = vec![1u8];
+//!
+//!     swift_bridge::alloc::set_allocator(&COUNTING).unwrap();
+//!
+//!     let greeting = String::from("Hello");
+//!     assert!(COUNTING.allocations.load(Ordering::Relaxed) >= 1);
+//!
+//!     // Memory from before the allocator was registered can still grow and be freed.
+//!     allocated_before.extend_from_slice(&[2; 100]);
+//!     drop(allocated_before);
+//!
+//!     // Only one allocator can be registered.
+//!     assert!(swift_bridge::alloc::set_allocator(&COUNTING).is_err());
+//!     # drop(greeting);
+//! }
+//! ```
+//!
+//! Memory that was allocated before the allocator was registered is still freed by the system
+//! allocator, so it is fine for the standard library to allocate before `main` runs.
+//!
+//! The registered allocator must not allocate through the global allocator itself, since that
+//! would call back into it.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::OnceLock;
+
+type Allocator = &'static (dyn GlobalAlloc + Sync);
+
+static ALLOCATOR: OnceLock<Allocator> = OnceLock::new();
+
+// Every allocation starts with a header that records which allocator made it, so that memory that
+// was allocated before `set_allocator` was called still gets freed by the system allocator.
+const SYSTEM: usize = 0;
+const REGISTERED: usize = 1;
+
+/// Register the allocator that [`BridgeAllocator`] sends allocations to from now on.
+///
+/// Returns an error if an allocator was already registered.
+pub fn set_allocator(allocator: Allocator) -> Result<(), AllocatorAlreadySet> {
+    ALLOCATOR.set(allocator).map_err(|_| AllocatorAlreadySet)
+}
+
+/// Returned by [`set_allocator`] when an allocator was already registered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AllocatorAlreadySet;
+
+impl std::fmt::Display for AllocatorAlreadySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("an allocator was already registered")
+    }
+}
+
+impl std::error::Error for AllocatorAlreadySet {}
+
+/// A global allocator that sends allocations to the allocator that was registered with
+/// [`set_allocator`], or to the system allocator until one is registered.
+///
+/// Every allocation starts with a `usize` header that records which allocator made it. The header
+/// is padded to the alignment of the allocation, so each allocation takes up
+/// `max(size_of::<usize>(), align)` more bytes than was asked for.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct BridgeAllocator;
+
+unsafe impl GlobalAlloc for BridgeAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let (with_header, offset) = match with_header(layout) {
+            Some(with_header) => with_header,
+            None => return std::ptr::null_mut(),
+        };
+
+        let (allocator, tag): (&dyn GlobalAlloc, usize) = match ALLOCATOR.get() {
+            Some(allocator) => (*allocator, REGISTERED),
+            None => (&System, SYSTEM),
+        };
+
+        let base = allocator.alloc(with_header);
+        if base.is_null() {
+            return base;
+        }
+
+        let ptr = base.add(offset);
+        (ptr as *mut usize).sub(1).write(tag);
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let (with_header, offset) = with_header(layout).unwrap();
+        let tag = (ptr as *const usize).sub(1).read();
+        let base = ptr.sub(offset);
+
+        match (tag, ALLOCATOR.get()) {
+            (REGISTERED, Some(allocator)) => allocator.dealloc(base, with_header),
+            _ => System.dealloc(base, with_header),
+        }
+    }
+}
+
+// The layout of an allocation with room for the header in front of it, and the offset of the
+// memory that the caller asked for.
+fn with_header(layout: Layout) -> Option<(Layout, usize)> {
+    let header = Layout::new::<usize>();
+    let (with_header, offset) = header.extend(layout).ok()?;
+
+    Some((with_header.pad_to_align(), offset))
+}
@@ -1,25 +1,87 @@
-use once_cell::sync::Lazy;
+//! Support for calling `async` Rust functions from Swift.
+//!
+//! An `async fn` in an `extern "Rust"` block is seen on the Swift side as a Swift `async`
+//! function. Swift passes a completion callback to Rust, the Rust future is spawned onto an
+//! [`AsyncExecutor`], and the completion callback resumes the Swift continuation with the
+//! future's output.
+//!
+//! By default futures are spawned onto a multi-threaded tokio runtime that `swift-bridge`
+//! creates on its own thread. An application that already has an executor can use it instead by
+//! calling [`set_executor`] before the first async function is called.
+//!
+//! An `async fn` in an `extern "Swift"` block is seen on the Rust side as a function that returns
+//! an `impl Future`. The Swift function is started as soon as the Rust function is called, and
+//! completes the future from a Swift `Task`.
+//!
+//! ```
+//! use std::future::Future;
+//! use std::pin::Pin;
+//! use swift_bridge::async_support::AsyncExecutor;
+//!
+//! struct AppRuntime(tokio::runtime::Runtime);
+//!
+//! impl AsyncExecutor for AppRuntime {
+//!     fn spawn(&self, task: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+//!         self.0.spawn(task);
+//!     }
+//! }
+//!
+//! let runtime = tokio::runtime::Runtime::new().unwrap();
+//! swift_bridge::async_support::set_executor(AppRuntime(runtime)).unwrap();
+//! ```
+
+use crate::sync::Lock;
+use futures_core::Stream;
+use once_cell::sync::OnceCell;
+use std::fmt::{Display, Formatter};
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{Receiver, SyncSender};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
 
-#[doc(hidden)]
-pub static ASYNC_RUNTIME: Lazy<TokioRuntime> = Lazy::new(|| {
-    let (sender, receiver) = std::sync::mpsc::sync_channel(10_000);
+type AsyncFnToSpawn = Pin<Box<dyn Future<Output = ()> + 'static + Send>>;
 
-    let runtime = TokioRuntime { sender };
+/// Polls the futures of the `async` Rust functions that Swift calls to completion.
+pub trait AsyncExecutor: Send + Sync {
+    /// Run the task to completion in the background.
+    fn spawn(&self, task: Pin<Box<dyn Future<Output = ()> + Send + 'static>>);
+}
 
-    runtime.start_runtime(receiver);
+static EXECUTOR: OnceCell<Box<dyn AsyncExecutor>> = OnceCell::new();
 
-    runtime
-});
-type AsyncFnToSpawn = Pin<Box<dyn Future<Output = ()> + 'static + Send>>;
+// The number of spawned tasks that have not finished yet.
+static PENDING_TASKS: AtomicUsize = AtomicUsize::new(0);
 
-#[doc(hidden)]
-pub struct TokioRuntime {
-    sender: SyncSender<AsyncFnToSpawn>,
+/// Use the given executor for every `async` Rust function that Swift calls.
+///
+/// Fails if an executor was already set, or if an async function was called before this, since
+/// the default tokio executor is used from then on.
+pub fn set_executor(executor: impl AsyncExecutor + 'static) -> Result<(), ExecutorAlreadySet> {
+    EXECUTOR
+        .set(Box::new(executor))
+        .map_err(|_| ExecutorAlreadySet)
+}
+
+/// The error returned by [`set_executor`] when the executor can no longer be changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutorAlreadySet;
+
+impl Display for ExecutorAlreadySet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the async executor was already set")
+    }
 }
 
+impl std::error::Error for ExecutorAlreadySet {}
+
+#[doc(hidden)]
+pub static ASYNC_RUNTIME: AsyncRuntime = AsyncRuntime;
+
+#[doc(hidden)]
+pub struct AsyncRuntime;
+
 // TODO: Audit to make sure that this is safe to be Send/Sync.
 //  Need to research Swift class thread safety. If there are cases where this can be unsafe then
 //  we can just have one tokio runtime per thread (lazily initialized) and then run async functions
@@ -33,12 +95,56 @@ unsafe impl Send for SwiftCallbackWrapper {}
 unsafe impl Sync for SwiftCallbackWrapper {}
 
 #[doc(hidden)]
-impl TokioRuntime {
+impl AsyncRuntime {
     pub fn spawn_task(&self, task: AsyncFnToSpawn) {
-        self.sender.send(task).unwrap();
+        // The task stops being pending once it finishes, or when the executor drops it.
+        let pending = PendingTask::new();
+        let task = Box::pin(async move {
+            let _pending = pending;
+            task.await
+        });
+
+        EXECUTOR
+            .get_or_init(|| Box::new(TokioRuntime::start()))
+            .spawn(task);
+    }
+}
+
+struct PendingTask;
+
+impl PendingTask {
+    fn new() -> Self {
+        PENDING_TASKS.fetch_add(1, Ordering::SeqCst);
+        PendingTask
+    }
+}
+
+impl Drop for PendingTask {
+    fn drop(&mut self) {
+        PENDING_TASKS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn pending_task_count() -> usize {
+    PENDING_TASKS.load(Ordering::SeqCst)
+}
+
+/// The default executor, a tokio runtime that runs on its own thread.
+struct TokioRuntime {
+    sender: SyncSender<AsyncFnToSpawn>,
+}
+
+impl TokioRuntime {
+    fn start() -> Self {
+        let (sender, receiver) = std::sync::mpsc::sync_channel(10_000);
+
+        Self::start_runtime(receiver);
+
+        TokioRuntime { sender }
     }
 
-    fn start_runtime(&self, receiver: Receiver<AsyncFnToSpawn>) {
+    fn start_runtime(receiver: Receiver<AsyncFnToSpawn>) {
         std::thread::spawn(move || {
             tokio::runtime::Runtime::new()
                 .unwrap()
@@ -50,3 +156,97 @@ impl TokioRuntime {
         });
     }
 }
+
+impl AsyncExecutor for TokioRuntime {
+    fn spawn(&self, task: AsyncFnToSpawn) {
+        self.sender.send(task).unwrap();
+    }
+}
+
+/// The output of a call to an `async` Swift function.
+///
+/// Created by the code that `#[swift_bridge::bridge]` generates for `async` functions in
+/// `extern "Swift"` blocks.
+#[doc(hidden)]
+pub struct SwiftFuture<T> {
+    shared: Arc<Lock<SwiftFutureState<T>>>,
+}
+
+struct SwiftFutureState<T> {
+    output: Option<T>,
+    waker: Option<Waker>,
+}
+
+#[doc(hidden)]
+impl<T> SwiftFuture<T> {
+    /// Create a future along with a pointer to the `SwiftFutureCompleter` that completes it.
+    ///
+    /// The pointer is handed to Swift, which passes it back to Rust's completion callback.
+    pub fn new() -> (Self, *mut std::ffi::c_void) {
+        let shared = Arc::new(Lock::new(SwiftFutureState {
+            output: None,
+            waker: None,
+        }));
+
+        let completer = Box::new(SwiftFutureCompleter {
+            shared: shared.clone(),
+        });
+
+        (
+            SwiftFuture { shared },
+            Box::into_raw(completer) as *mut std::ffi::c_void,
+        )
+    }
+}
+
+impl<T> Future for SwiftFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.shared.lock();
+
+        match state.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Completes a [`SwiftFuture`] once the `async` Swift function returns.
+#[doc(hidden)]
+pub struct SwiftFutureCompleter<T> {
+    shared: Arc<Lock<SwiftFutureState<T>>>,
+}
+
+#[doc(hidden)]
+impl<T> SwiftFutureCompleter<T> {
+    /// # Safety
+    ///
+    /// The pointer must have been returned by [`SwiftFuture::new`] and must not be used again.
+    pub unsafe fn from_raw(ptr: *mut std::ffi::c_void) -> Self {
+        *Box::from_raw(ptr as *mut SwiftFutureCompleter<T>)
+    }
+
+    pub fn complete(self, output: T) {
+        let mut state = self.shared.lock();
+        state.output = Some(output);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Wait for the next item of a `#[swift_bridge(Stream(Item = ...))]` type.
+///
+/// Called by the `next` method that `#[swift_bridge::bridge]` generates for the type. Streams
+/// that are not `Unpin` can be wrapped in a `Pin<Box<dyn Stream<Item = T> + Send>>`.
+#[doc(hidden)]
+pub fn next_stream_item<S: Stream + Unpin>(
+    stream: &mut S,
+) -> impl Future<Output = Option<S::Item>> + '_ {
+    std::future::poll_fn(move |cx| Pin::new(&mut *stream).poll_next(cx))
+}
@@ -0,0 +1,207 @@
+//! A primitive value that both Rust and Swift can read and write atomically.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         fn start_engine(is_paused: AtomicCell<bool>) -> AtomicCell<u64>;
+//!     }
+//! }
+//! # );
+//! use swift_bridge::atomic::AtomicCell;
+//!
+//! fn start_engine(is_paused: AtomicCell<bool>) -> AtomicCell<u64> {
+//!     let frames_rendered = AtomicCell::new(0);
+//!
+//!     let frames = frames_rendered.clone();
+//!     std::thread::spawn(move || loop {
+//!         if !is_paused.load() {
+//!             // ... render a frame ...
+//!             frames.store(frames.load() + 1);
+//!         }
+//!         # break;
+//!     });
+//!
+//!     frames_rendered
+//! }
+//! ```
+//!
+//! ```swift
+//! let isPaused = RustAtomicCell(false)
+//! let framesRendered = start_engine(isPaused)
+//!
+//! isPaused.store(true)
+//! print(framesRendered.load())
+//! ```
+//!
+//! Swift reads and writes the value directly through a pointer to it, so accessing the cell from
+//! Swift does not call into Rust.
+
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+use std::sync::Arc;
+
+/// A primitive value that both Rust and Swift can read and write atomically.
+///
+/// Clones share the same value.
+pub struct AtomicCell<T: AtomicPrimitive> {
+    value: Arc<T::Atomic>,
+}
+
+/// A primitive that can be stored in an [`AtomicCell`].
+pub trait AtomicPrimitive: Copy + private::Sealed {
+    #[doc(hidden)]
+    type Atomic: Send + Sync;
+
+    #[doc(hidden)]
+    fn new_atomic(self) -> Self::Atomic;
+
+    #[doc(hidden)]
+    fn load(atomic: &Self::Atomic) -> Self;
+
+    #[doc(hidden)]
+    fn store(atomic: &Self::Atomic, value: Self);
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+impl<T: AtomicPrimitive> AtomicCell<T> {
+    /// Create a cell that holds the given value.
+    pub fn new(value: T) -> Self {
+        AtomicCell {
+            value: Arc::new(value.new_atomic()),
+        }
+    }
+
+    /// Get the current value.
+    pub fn load(&self) -> T {
+        T::load(&self.value)
+    }
+
+    /// Replace the current value.
+    pub fn store(&self, value: T) {
+        T::store(&self.value, value)
+    }
+}
+
+impl<T: AtomicPrimitive> Clone for AtomicCell<T> {
+    fn clone(&self) -> Self {
+        AtomicCell {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T: AtomicPrimitive + Default> Default for AtomicCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+macro_rules! atomic_primitive {
+    ($ty:ty, $atomic:ty) => {
+        impl private::Sealed for $ty {}
+
+        impl AtomicPrimitive for $ty {
+            type Atomic = $atomic;
+
+            fn new_atomic(self) -> Self::Atomic {
+                <$atomic>::new(self)
+            }
+
+            fn load(atomic: &Self::Atomic) -> Self {
+                atomic.load(Ordering::SeqCst)
+            }
+
+            fn store(atomic: &Self::Atomic, value: Self) {
+                atomic.store(value, Ordering::SeqCst)
+            }
+        }
+    };
+}
+
+// Rust has no atomic floats, so we store their bits. Swift does the same.
+macro_rules! atomic_float_primitive {
+    ($ty:ty, $atomic:ty) => {
+        impl private::Sealed for $ty {}
+
+        impl AtomicPrimitive for $ty {
+            type Atomic = $atomic;
+
+            fn new_atomic(self) -> Self::Atomic {
+                <$atomic>::new(self.to_bits())
+            }
+
+            fn load(atomic: &Self::Atomic) -> Self {
+                <$ty>::from_bits(atomic.load(Ordering::SeqCst))
+            }
+
+            fn store(atomic: &Self::Atomic, value: Self) {
+                atomic.store(value.to_bits(), Ordering::SeqCst)
+            }
+        }
+    };
+}
+
+macro_rules! atomic_cell_externs {
+    ($ty:ty) => {
+        const _: () = {
+            #[export_name = concat!("__swift_bridge__$AtomicCell_", stringify!($ty), "$new")]
+            extern "C" fn _new(value: $ty) -> *mut AtomicCell<$ty> {
+                Box::into_raw(Box::new(AtomicCell::new(value)))
+            }
+
+            #[export_name = concat!("__swift_bridge__$AtomicCell_", stringify!($ty), "$clone")]
+            extern "C" fn _clone(cell: *const AtomicCell<$ty>) -> *mut AtomicCell<$ty> {
+                Box::into_raw(Box::new(unsafe { &*cell }.clone()))
+            }
+
+            #[export_name = concat!("__swift_bridge__$AtomicCell_", stringify!($ty), "$value_ptr")]
+            extern "C" fn _value_ptr(
+                cell: *const AtomicCell<$ty>,
+            ) -> *const <$ty as AtomicPrimitive>::Atomic {
+                Arc::as_ptr(&unsafe { &*cell }.value)
+            }
+
+            #[export_name = concat!("__swift_bridge__$AtomicCell_", stringify!($ty), "$_free")]
+            extern "C" fn _free(cell: *mut AtomicCell<$ty>) {
+                let _ = unsafe { Box::from_raw(cell) };
+            }
+        };
+    };
+}
+
+atomic_primitive!(u8, AtomicU8);
+atomic_primitive!(u16, AtomicU16);
+atomic_primitive!(u32, AtomicU32);
+atomic_primitive!(u64, AtomicU64);
+atomic_primitive!(usize, AtomicUsize);
+atomic_primitive!(i8, AtomicI8);
+atomic_primitive!(i16, AtomicI16);
+atomic_primitive!(i32, AtomicI32);
+atomic_primitive!(i64, AtomicI64);
+atomic_primitive!(isize, AtomicIsize);
+atomic_primitive!(bool, AtomicBool);
+atomic_float_primitive!(f32, AtomicU32);
+atomic_float_primitive!(f64, AtomicU64);
+
+atomic_cell_externs!(u8);
+atomic_cell_externs!(u16);
+atomic_cell_externs!(u32);
+atomic_cell_externs!(u64);
+atomic_cell_externs!(usize);
+atomic_cell_externs!(i8);
+atomic_cell_externs!(i16);
+atomic_cell_externs!(i32);
+atomic_cell_externs!(i64);
+atomic_cell_externs!(isize);
+atomic_cell_externs!(bool);
+atomic_cell_externs!(f32);
+atomic_cell_externs!(f64);
@@ -15,3 +15,34 @@ pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
         let _ = Box::from_raw(boxed_fn);
     }
 }
+
+/// A closure that is passed between Rust and Swift as a `Box<dyn Fn(..)>`.
+///
+/// Holds a pointer to the closure along with the function that frees it, which gets called exactly
+/// once when this is dropped. A Swift closure is retained until then, and a Rust closure is boxed.
+///
+/// The function that calls the closure is passed alongside this in a struct that is generated for
+/// each closure signature, so that it never needs to be cast to or from a data pointer.
+#[repr(C)]
+pub struct FfiClosure {
+    context: *mut std::ffi::c_void,
+    free: extern "C" fn(*mut std::ffi::c_void),
+}
+
+impl FfiClosure {
+    /// Take ownership of a closure that gets freed by calling `free(context)`.
+    pub fn new(context: *mut std::ffi::c_void, free: extern "C" fn(*mut std::ffi::c_void)) -> Self {
+        FfiClosure { context, free }
+    }
+
+    /// The pointer to the closure that gets passed to the function that calls it.
+    pub fn context(&self) -> *mut std::ffi::c_void {
+        self.context
+    }
+}
+
+impl Drop for FfiClosure {
+    fn drop(&mut self) {
+        (self.free)(self.context)
+    }
+}
@@ -0,0 +1,115 @@
+//! A record of the most recent calls that Swift made into Rust.
+//!
+//! Functions in a `#[swift_bridge::bridge(breadcrumbs)]` module record a breadcrumb every time
+//! that Swift calls them. Swift can read the breadcrumbs with `rustBreadcrumbs()` and attach them
+//! to crash reports, which helps with debugging crashes that originate inside Rust but surface as
+//! Swift stack traces.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge(breadcrumbs)]
+//! mod ffi {
+//!     extern "Rust" {
+//!         fn sync_files();
+//!     }
+//! }
+//! # );
+//!
+//! // Keep the last 64 calls instead of the default 32.
+//! swift_bridge::breadcrumbs::set_capacity(64);
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::RustString;
+use crate::sync::{Lock, LockGuard};
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::Thread;
+
+const DEFAULT_CAPACITY: usize = 32;
+
+static CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_CAPACITY);
+
+static BREADCRUMBS: Lock<VecDeque<Breadcrumb>> = Lock::new(VecDeque::new());
+
+/// A call that Swift made into Rust.
+#[derive(Debug, Clone)]
+pub struct Breadcrumb {
+    function: &'static str,
+    thread: Thread,
+}
+
+impl Breadcrumb {
+    /// The name of the function that was called, such as `some_function` or
+    /// `SomeType::some_method`.
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    /// The thread that the function was called on.
+    pub fn thread(&self) -> &Thread {
+        &self.thread
+    }
+}
+
+impl Display for Breadcrumb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.thread.name() {
+            Some(name) => write!(f, "{} on thread {}", self.function, name),
+            None => write!(f, "{} on thread {:?}", self.function, self.thread.id()),
+        }
+    }
+}
+
+/// Set how many breadcrumbs are kept. The oldest breadcrumbs are dropped first.
+///
+/// Defaults to 32. Setting the capacity to 0 stops recording breadcrumbs.
+pub fn set_capacity(capacity: usize) {
+    CAPACITY.store(capacity, Ordering::SeqCst);
+
+    let mut breadcrumbs = breadcrumbs();
+    while breadcrumbs.len() > capacity {
+        breadcrumbs.pop_front();
+    }
+}
+
+/// Get the recorded breadcrumbs, oldest first.
+pub fn snapshot() -> Vec<Breadcrumb> {
+    breadcrumbs().iter().cloned().collect()
+}
+
+/// Record that Swift called the given function.
+///
+/// Called by the code that `#[swift_bridge::bridge(breadcrumbs)]` generates.
+#[doc(hidden)]
+pub fn record(function: &'static str) {
+    let capacity = CAPACITY.load(Ordering::Relaxed);
+    if capacity == 0 {
+        return;
+    }
+
+    let breadcrumb = Breadcrumb {
+        function,
+        thread: std::thread::current(),
+    };
+
+    let mut breadcrumbs = breadcrumbs();
+    while breadcrumbs.len() >= capacity {
+        breadcrumbs.pop_front();
+    }
+    breadcrumbs.push_back(breadcrumb);
+}
+
+// A panic while the lock is held must not stop us from reporting breadcrumbs, since that is
+// exactly when they are the most useful. `Lock` is never poisoned.
+fn breadcrumbs() -> LockGuard<'static, VecDeque<Breadcrumb>> {
+    BREADCRUMBS.lock()
+}
+
+#[export_name = "__swift_bridge__$breadcrumbs$snapshot"]
+extern "C" fn __swift_bridge__breadcrumbs_snapshot() -> *mut RustString {
+    let lines: Vec<String> = snapshot().iter().map(|b| b.to_string()).collect();
+    RustString(lines.join("\n")).box_into_raw()
+}
@@ -0,0 +1,313 @@
+//! A stream of byte chunks that Swift consumes as an `AsyncSequence` of `Data`.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::byte_stream::ByteStream;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type ByteStream;
+//!
+//!         fn download(url: &str) -> ByteStream;
+//!     }
+//! }
+//! # );
+//! use swift_bridge::byte_stream::ByteStream;
+//!
+//! fn download(url: &str) -> ByteStream {
+//!     let (sender, stream) = ByteStream::channel(4);
+//!
+//!     let _url = url.to_string();
+//!     std::thread::spawn(move || {
+//!         for chunk in [b"hello ".to_vec(), b"world".to_vec()] {
+//!             if sender.blocking_send(chunk).is_err() {
+//!                 // Swift stopped consuming the stream.
+//!                 break;
+//!             }
+//!         }
+//!     });
+//!
+//!     stream
+//! }
+//! ```
+//!
+//! ```swift
+//! for await chunk in download("https://example.com") {
+//!     print(chunk.count)
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Poll, Waker};
+
+/// A stream of byte chunks that Swift consumes as an `AsyncSequence` of `Data`.
+///
+/// Chunks are sent into the stream with the [`ByteStreamSender`] that [`ByteStream::channel`]
+/// returns. The stream ends once every sender has been dropped.
+pub struct ByteStream {
+    shared: Arc<Shared>,
+}
+
+/// Sends chunks into a [`ByteStream`].
+pub struct ByteStreamSender {
+    shared: Arc<Shared>,
+}
+
+/// The error returned when sending a chunk after Swift has dropped the [`ByteStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteStreamClosed;
+
+struct Shared {
+    state: Mutex<State>,
+    // Notified when there is room in the buffer or the stream was dropped.
+    not_full: Condvar,
+}
+
+struct State {
+    chunks: VecDeque<Vec<u8>>,
+    capacity: usize,
+    // Swift's requests for the next chunk that arrived while the buffer was empty, oldest first.
+    // There is more than one if Swift iterates the stream from multiple tasks at once.
+    pending_next: VecDeque<NextChunkRequest>,
+    // Async senders that are waiting for room in the buffer.
+    waiting_senders: Vec<Waker>,
+    sender_count: usize,
+    stream_dropped: bool,
+}
+
+type NextChunkCallback = extern "C" fn(*mut c_void, *const u8, usize, bool);
+
+struct NextChunkRequest {
+    callback_wrapper: *mut c_void,
+    callback: NextChunkCallback,
+}
+
+// Swift's callback wrapper is only ever used to resume a continuation, which is thread safe.
+unsafe impl Send for NextChunkRequest {}
+
+impl NextChunkRequest {
+    // Swift copies the chunk before the callback returns.
+    fn respond(self, chunk: Option<Vec<u8>>) {
+        match chunk {
+            Some(chunk) => {
+                (self.callback)(self.callback_wrapper, chunk.as_ptr(), chunk.len(), false)
+            }
+            None => (self.callback)(self.callback_wrapper, std::ptr::null(), 0, true),
+        }
+    }
+}
+
+impl ByteStream {
+    /// Create a stream that buffers at most `capacity` chunks that Swift has not consumed yet.
+    ///
+    /// Once the buffer is full, sending waits until Swift consumes a chunk, so a fast producer
+    /// cannot outrun a slow consumer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn channel(capacity: usize) -> (ByteStreamSender, ByteStream) {
+        assert!(capacity > 0, "ByteStream capacity must be greater than 0");
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                chunks: VecDeque::new(),
+                capacity,
+                pending_next: VecDeque::new(),
+                waiting_senders: vec![],
+                sender_count: 1,
+                stream_dropped: false,
+            }),
+            not_full: Condvar::new(),
+        });
+
+        (
+            ByteStreamSender {
+                shared: shared.clone(),
+            },
+            ByteStream { shared },
+        )
+    }
+
+    fn next(&self, request: NextChunkRequest) {
+        let mut state = self.shared.lock();
+
+        if let Some(chunk) = state.chunks.pop_front() {
+            let waiting_senders = std::mem::take(&mut state.waiting_senders);
+            drop(state);
+
+            self.shared.not_full.notify_one();
+            waiting_senders.into_iter().for_each(Waker::wake);
+            request.respond(Some(chunk));
+        } else if state.sender_count == 0 {
+            drop(state);
+            request.respond(None);
+        } else {
+            state.pending_next.push_back(request);
+        }
+    }
+
+    // Ends a pending request early, for example because the Swift task that made it was
+    // cancelled. Does nothing if the request was already responded to.
+    fn cancel_next(&self, callback_wrapper: *mut c_void) {
+        let mut state = self.shared.lock();
+
+        let idx = state
+            .pending_next
+            .iter()
+            .position(|request| request.callback_wrapper == callback_wrapper);
+        if let Some(request) = idx.and_then(|idx| state.pending_next.remove(idx)) {
+            drop(state);
+            request.respond(None);
+        }
+    }
+}
+
+impl ByteStreamSender {
+    /// Send a chunk, waiting until there is room in the stream's buffer.
+    pub async fn send(&self, chunk: Vec<u8>) -> Result<(), ByteStreamClosed> {
+        let mut chunk = Some(chunk);
+
+        std::future::poll_fn(|cx| match self.try_send(self.shared.lock(), &mut chunk) {
+            Ok(result) => Poll::Ready(result),
+            Err(mut state) => {
+                state.waiting_senders.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Send a chunk from outside of an async context, blocking the current thread until there is
+    /// room in the stream's buffer.
+    pub fn blocking_send(&self, chunk: Vec<u8>) -> Result<(), ByteStreamClosed> {
+        let mut chunk = Some(chunk);
+        let mut state = self.shared.lock();
+
+        loop {
+            match self.try_send(state, &mut chunk) {
+                Ok(result) => return result,
+                Err(full) => {
+                    state = self
+                        .shared
+                        .not_full
+                        .wait(full)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                }
+            }
+        }
+    }
+
+    // Gives the lock back if the buffer is full, so that the caller can wait for room.
+    fn try_send<'a>(
+        &self,
+        mut state: MutexGuard<'a, State>,
+        chunk: &mut Option<Vec<u8>>,
+    ) -> Result<Result<(), ByteStreamClosed>, MutexGuard<'a, State>> {
+        if state.stream_dropped {
+            return Ok(Err(ByteStreamClosed));
+        }
+
+        if let Some(request) = state.pending_next.pop_front() {
+            drop(state);
+            request.respond(chunk.take());
+            return Ok(Ok(()));
+        }
+
+        if state.chunks.len() < state.capacity {
+            state.chunks.push_back(chunk.take().unwrap());
+            return Ok(Ok(()));
+        }
+
+        Err(state)
+    }
+}
+
+impl Clone for ByteStreamSender {
+    fn clone(&self) -> Self {
+        self.shared.lock().sender_count += 1;
+
+        ByteStreamSender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for ByteStreamSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.sender_count -= 1;
+
+        if state.sender_count == 0 {
+            let pending_next = std::mem::take(&mut state.pending_next);
+            drop(state);
+
+            for request in pending_next {
+                request.respond(None);
+            }
+        }
+    }
+}
+
+impl Drop for ByteStream {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.stream_dropped = true;
+        state.chunks.clear();
+        let waiting_senders = std::mem::take(&mut state.waiting_senders);
+        drop(state);
+
+        self.shared.not_full.notify_all();
+        waiting_senders.into_iter().for_each(Waker::wake);
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Display for ByteStreamClosed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the ByteStream was dropped")
+    }
+}
+
+impl std::error::Error for ByteStreamClosed {}
+
+#[export_name = "__swift_bridge__$ByteStream$next"]
+extern "C" fn __swift_bridge__ByteStream_next(
+    stream: *const ByteStream,
+    callback_wrapper: *mut c_void,
+    callback: NextChunkCallback,
+) {
+    unsafe { &*stream }.next(NextChunkRequest {
+        callback_wrapper,
+        callback,
+    })
+}
+
+#[export_name = "__swift_bridge__$ByteStream$cancel_next"]
+extern "C" fn __swift_bridge__ByteStream_cancel_next(
+    stream: *const ByteStream,
+    callback_wrapper: *mut c_void,
+) {
+    unsafe { &*stream }.cancel_next(callback_wrapper)
+}
+
+#[export_name = "__swift_bridge__$ByteStream$_free"]
+extern "C" fn __swift_bridge__ByteStream__free(stream: *mut ByteStream) {
+    unsafe {
+        let _ = Box::from_raw(stream);
+    }
+}
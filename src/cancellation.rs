@@ -0,0 +1,103 @@
+//! Support for cancelling long-running Rust functions from Swift.
+
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A token that Swift can use to ask a `#[swift_bridge(cancellable)]` function to stop early.
+///
+/// ```
+/// # const  _: &str = stringify!(
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     extern "Rust" {
+///         #[swift_bridge(cancellable)]
+///         fn sync_files(dir: &str) -> u32;
+///     }
+/// }
+/// # );
+///
+/// fn sync_files(dir: &str, cancellation_token: &swift_bridge::cancellation::CancellationToken) -> u32 {
+///     let mut synced = 0;
+///     for _file in 0..100 {
+///         if cancellation_token.is_cancelled() {
+///             break;
+///         }
+///         // ...
+///         synced += 1;
+///     }
+///     synced
+/// }
+/// ```
+///
+/// On the Swift side the function accepts an optional `CancellationToken`.
+///
+/// ```swift
+/// let token = CancellationToken()
+/// DispatchQueue.global().async {
+///     sync_files("/path/to/dir", cancellationToken: token)
+/// }
+/// token.cancel()
+/// ```
+///
+/// Tokens are cheap to clone, and clones share the same cancellation state, so a token can be
+/// handed to worker threads.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether or not cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Get a token from a pointer that Swift passed to a `#[swift_bridge(cancellable)]` function.
+    ///
+    /// A null pointer means that Swift did not pass a token, so we return one that will never be
+    /// cancelled.
+    #[doc(hidden)]
+    pub unsafe fn from_ffi_ptr(ptr: *const CancellationToken) -> Self {
+        if ptr.is_null() {
+            Self::new()
+        } else {
+            (*ptr).clone()
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$CancellationToken$new"]
+extern "C" fn __swift_bridge__CancellationToken_new() -> *mut CancellationToken {
+    Box::into_raw(Box::new(CancellationToken::new()))
+}
+
+#[export_name = "__swift_bridge__$CancellationToken$cancel"]
+extern "C" fn __swift_bridge__CancellationToken_cancel(token: *const CancellationToken) {
+    unsafe { &*token }.cancel()
+}
+
+#[export_name = "__swift_bridge__$CancellationToken$is_cancelled"]
+extern "C" fn __swift_bridge__CancellationToken_is_cancelled(
+    token: *const CancellationToken,
+) -> bool {
+    unsafe { &*token }.is_cancelled()
+}
+
+#[export_name = "__swift_bridge__$CancellationToken$_free"]
+extern "C" fn __swift_bridge__CancellationToken__free(token: *mut CancellationToken) {
+    unsafe {
+        let _ = Box::from_raw(token);
+    }
+}
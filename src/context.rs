@@ -0,0 +1,177 @@
+//! Propagation of thread-local context, such as request IDs or tracing span IDs, across calls
+//! between Swift and Rust.
+//!
+//! Both languages register context propagators under a name. For every call into or out of a
+//! `#[swift_bridge::bridge(propagate_context)]` module, the calling side captures its context and
+//! the called side restores the context that has the same name for the duration of the call.
+//!
+//! ```
+//! use std::cell::RefCell;
+//! use swift_bridge::context::ContextPropagator;
+//!
+//! thread_local! {
+//!     static REQUEST_ID: RefCell<Option<String>> = RefCell::new(None);
+//! }
+//!
+//! struct RequestIdPropagator;
+//!
+//! impl ContextPropagator for RequestIdPropagator {
+//!     fn capture(&self) -> Option<String> {
+//!         REQUEST_ID.with(|id| id.borrow().clone())
+//!     }
+//!
+//!     fn restore(&self, value: Option<String>) {
+//!         REQUEST_ID.with(|id| *id.borrow_mut() = value);
+//!     }
+//! }
+//!
+//! swift_bridge::context::register_context_propagator("request_id", RequestIdPropagator);
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::{RustStr, RustString};
+use crate::sync::Lock;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Captures and restores one kind of thread-local context.
+pub trait ContextPropagator: Send + Sync {
+    /// The current thread's value of this context, if any.
+    fn capture(&self) -> Option<String>;
+
+    /// Replace the current thread's value of this context.
+    fn restore(&self, value: Option<String>);
+}
+
+static PROPAGATORS: Lock<Vec<(String, Arc<dyn ContextPropagator>)>> = Lock::new(Vec::new());
+
+thread_local! {
+    // The context that the caller captured for each call that is currently in progress on this
+    // thread, innermost call last.
+    static FRAMES: RefCell<Vec<HashMap<String, String>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Register a propagator for the context with the given name.
+///
+/// Swift registers its propagator for the same context with `registerContextPropagator`. A
+/// context is only propagated when the caller has a value for it.
+pub fn register_context_propagator(
+    name: impl Into<String>,
+    propagator: impl ContextPropagator + 'static,
+) {
+    PROPAGATORS.lock().push((name.into(), Arc::new(propagator)));
+}
+
+// Propagators are called without holding the lock, so that they can call back into the bridge or
+// register other propagators.
+fn propagators() -> Vec<(String, Arc<dyn ContextPropagator>)> {
+    PROPAGATORS.lock().clone()
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn context_propagator_count() -> usize {
+    PROPAGATORS.lock().len()
+}
+
+/// Captures Rust's context for a call to a Swift function.
+///
+/// Called by the code that `#[swift_bridge::bridge(propagate_context)]` generates.
+#[doc(hidden)]
+pub fn propagate_to_swift() -> PropagatedContext {
+    let frame = propagators()
+        .iter()
+        .filter_map(|(name, propagator)| propagator.capture().map(|value| (name.clone(), value)))
+        .collect();
+    push_frame(frame);
+
+    PropagatedContext { _private: () }
+}
+
+/// Restores the context that Swift captured for a call to a Rust function.
+///
+/// Called by the code that `#[swift_bridge::bridge(propagate_context)]` generates.
+#[doc(hidden)]
+pub fn restore_from_swift() -> RestoredContext {
+    let frame = FRAMES.with(|frames| frames.borrow().last().cloned().unwrap_or_default());
+
+    let mut previous = vec![];
+    for (name, propagator) in propagators() {
+        if let Some(value) = frame.get(&name) {
+            let captured = propagator.capture();
+            propagator.restore(Some(value.clone()));
+            previous.push((propagator, captured));
+        }
+    }
+
+    RestoredContext { previous }
+}
+
+/// Removes the captured context once the call to Swift returns.
+#[doc(hidden)]
+pub struct PropagatedContext {
+    _private: (),
+}
+
+impl Drop for PropagatedContext {
+    fn drop(&mut self) {
+        pop_frame();
+    }
+}
+
+/// Puts back the context that the Rust function's thread had before the call.
+#[doc(hidden)]
+pub struct RestoredContext {
+    previous: Vec<(Arc<dyn ContextPropagator>, Option<String>)>,
+}
+
+impl Drop for RestoredContext {
+    fn drop(&mut self) {
+        for (propagator, value) in self.previous.drain(..).rev() {
+            propagator.restore(value);
+        }
+    }
+}
+
+fn push_frame(frame: HashMap<String, String>) {
+    FRAMES.with(|frames| frames.borrow_mut().push(frame));
+}
+
+fn pop_frame() {
+    FRAMES.with(|frames| frames.borrow_mut().pop());
+}
+
+#[export_name = "__swift_bridge__$context$push_frame"]
+extern "C" fn __swift_bridge__context_push_frame() {
+    push_frame(HashMap::new());
+}
+
+#[export_name = "__swift_bridge__$context$pop_frame"]
+extern "C" fn __swift_bridge__context_pop_frame() {
+    pop_frame();
+}
+
+#[export_name = "__swift_bridge__$context$frame_insert"]
+extern "C" fn __swift_bridge__context_frame_insert(name: RustStr, value: RustStr) {
+    FRAMES.with(|frames| {
+        if let Some(frame) = frames.borrow_mut().last_mut() {
+            frame.insert(name.to_string(), value.to_string());
+        }
+    });
+}
+
+#[export_name = "__swift_bridge__$context$frame_get"]
+extern "C" fn __swift_bridge__context_frame_get(name: RustStr) -> *mut RustString {
+    let value = FRAMES.with(|frames| {
+        frames
+            .borrow()
+            .last()
+            .and_then(|frame| frame.get(name.to_str()).cloned())
+    });
+
+    match value {
+        Some(value) => RustString(value).box_into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
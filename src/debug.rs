@@ -0,0 +1,145 @@
+//! A report of the state that swift-bridge's runtime manages, for diagnosing leaks and stuck work
+//! during development.
+//!
+//! Swift gets the same report from `rustBridgeReport()`, which is only generated for `DEBUG`
+//! builds, so that it can be shown in a debug menu.
+//!
+//! ```
+//! let report = swift_bridge::debug::report();
+//! assert_eq!(report.pending_async_tasks, 0);
+//!
+//! println!("{}", report);
+//! ```
+//!
+//! Parts of the runtime that are behind a cargo feature that is not enabled are reported as zero.
+
+#![allow(non_snake_case)]
+
+use crate::string::RustString;
+use std::fmt::{Display, Formatter};
+
+/// A snapshot of the state that swift-bridge's runtime manages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeReport {
+    /// The `#[swift_bridge(hardened)]` types that Swift holds handles to, sorted by type name.
+    pub live_handles: Vec<LiveHandles>,
+    /// The callbacks registered with `swift_bridge::memory_pressure::register_purge_callback`.
+    ///
+    /// Always zero without the `memory_pressure` feature.
+    pub purge_callbacks: usize,
+    /// The handlers registered with `swift_bridge::lifecycle::register_lifecycle_handler`.
+    ///
+    /// Always zero without the `lifecycle` feature.
+    pub lifecycle_handlers: usize,
+    /// The propagators registered with `swift_bridge::context::register_context_propagator`.
+    ///
+    /// Always zero without the `context` feature.
+    pub context_propagators: usize,
+    /// The `async` Rust functions that Swift called that have not finished yet.
+    ///
+    /// Always zero without the `async` feature.
+    pub pending_async_tasks: usize,
+    /// The values that are waiting for a `swift_bridge::scope` on the current thread to close.
+    pub deferred_frees: usize,
+}
+
+/// The number of values of a `#[swift_bridge(hardened)]` type that Swift holds handles to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveHandles {
+    /// The Rust type's name, such as `my_app::Keychain`.
+    pub type_name: &'static str,
+    /// The number of values that have not been freed.
+    pub count: usize,
+}
+
+/// Take a snapshot of the state that swift-bridge's runtime manages.
+pub fn report() -> BridgeReport {
+    BridgeReport {
+        live_handles: crate::handle_table::live_handles()
+            .into_iter()
+            .map(|(type_name, count)| LiveHandles { type_name, count })
+            .collect(),
+        purge_callbacks: purge_callbacks(),
+        lifecycle_handlers: lifecycle_handlers(),
+        context_propagators: context_propagators(),
+        pending_async_tasks: pending_async_tasks(),
+        deferred_frees: crate::scope::deferred_count(),
+    }
+}
+
+#[cfg(feature = "memory_pressure")]
+fn purge_callbacks() -> usize {
+    crate::memory_pressure::purge_callback_count()
+}
+
+#[cfg(not(feature = "memory_pressure"))]
+fn purge_callbacks() -> usize {
+    0
+}
+
+#[cfg(feature = "lifecycle")]
+fn lifecycle_handlers() -> usize {
+    crate::lifecycle::lifecycle_handler_count()
+}
+
+#[cfg(not(feature = "lifecycle"))]
+fn lifecycle_handlers() -> usize {
+    0
+}
+
+#[cfg(feature = "context")]
+fn context_propagators() -> usize {
+    crate::context::context_propagator_count()
+}
+
+#[cfg(not(feature = "context"))]
+fn context_propagators() -> usize {
+    0
+}
+
+#[cfg(feature = "async")]
+fn pending_async_tasks() -> usize {
+    crate::async_support::pending_task_count()
+}
+
+#[cfg(not(feature = "async"))]
+fn pending_async_tasks() -> usize {
+    0
+}
+
+impl Display for BridgeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Live handles:")?;
+        if self.live_handles.is_empty() {
+            writeln!(f, "    none")?;
+        }
+        for live in &self.live_handles {
+            writeln!(f, "    {}: {}", live.type_name, live.count)?;
+        }
+        writeln!(f, "Purge callbacks: {}", self.purge_callbacks)?;
+        writeln!(f, "Lifecycle handlers: {}", self.lifecycle_handlers)?;
+        writeln!(f, "Context propagators: {}", self.context_propagators)?;
+        writeln!(f, "Pending async tasks: {}", self.pending_async_tasks)?;
+        write!(f, "Deferred frees: {}", self.deferred_frees)
+    }
+}
+
+// One tab separated entry per line, which Swift's `rustBridgeReport()` parses.
+// Type names can contain spaces, such as in `Foo<(u8, u16)>`, but not tabs.
+#[export_name = "__swift_bridge__$debug$report"]
+extern "C" fn __swift_bridge__debug_report() -> *mut RustString {
+    let report = report();
+
+    let mut lines = vec![
+        format!("purge_callbacks\t{}", report.purge_callbacks),
+        format!("lifecycle_handlers\t{}", report.lifecycle_handlers),
+        format!("context_propagators\t{}", report.context_propagators),
+        format!("pending_async_tasks\t{}", report.pending_async_tasks),
+        format!("deferred_frees\t{}", report.deferred_frees),
+    ];
+    for live in &report.live_handles {
+        lines.push(format!("live_handles\t{}\t{}", live.type_name, live.count));
+    }
+
+    RustString(lines.join("\n")).box_into_raw()
+}
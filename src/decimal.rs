@@ -0,0 +1,107 @@
+//! Bridge `rust_decimal::Decimal` to Foundation's `Decimal`.
+//!
+//! Enabled by the `decimal` feature.
+//!
+//! A `Decimal` in a bridge module's function signatures becomes a Foundation `Decimal` in Swift.
+//! The decimal's mantissa, exponent and sign are passed across as they are, so amounts of money
+//! do not need to round trip through strings or lose precision by passing through a `Double`.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use rust_decimal::Decimal;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         fn add_tax(price: Decimal, rate: Decimal) -> Decimal;
+//!     }
+//! }
+//! # );
+//! use swift_bridge::decimal::Decimal;
+//!
+//! fn add_tax(price: Decimal, rate: Decimal) -> Decimal {
+//!     (price * (Decimal::ONE + rate)).round_dp(2)
+//! }
+//! ```
+//!
+//! ```swift
+//! let total = add_tax(Decimal(string: "19.99")!, Decimal(string: "0.08")!)
+//! ```
+
+pub use rust_decimal::Decimal;
+
+/// The largest scale, or number of digits after the decimal point, that a `Decimal` can have.
+const MAX_SCALE: i32 = 28;
+
+/// A decimal's FFI representation: `mantissa * 10^exponent`, negated if `is_negative` is true.
+#[repr(C)]
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct FfiDecimal {
+    mantissa_low: u64,
+    mantissa_high: u64,
+    exponent: i32,
+    is_negative: bool,
+}
+
+impl FfiDecimal {
+    #[doc(hidden)]
+    pub fn from_decimal(decimal: Decimal) -> Self {
+        let mantissa = decimal.mantissa().unsigned_abs();
+
+        FfiDecimal {
+            mantissa_low: mantissa as u64,
+            mantissa_high: (mantissa >> 64) as u64,
+            exponent: -(decimal.scale() as i32),
+            is_negative: mantissa != 0 && decimal.is_sign_negative(),
+        }
+    }
+
+    /// Convert a Foundation `Decimal` into a `Decimal`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit in a `Decimal`, which holds a 96 bit mantissa and at most
+    /// 28 digits after the decimal point, while Foundation's `Decimal` holds a 128 bit mantissa
+    /// and exponents from -128 to 127.
+    #[doc(hidden)]
+    pub fn into_decimal(self) -> Decimal {
+        self.try_into_decimal().unwrap_or_else(|| {
+            panic!(
+                "Foundation Decimal {}{}e{} does not fit in a rust_decimal::Decimal",
+                if self.is_negative { "-" } else { "" },
+                self.mantissa(),
+                self.exponent
+            )
+        })
+    }
+
+    fn try_into_decimal(self) -> Option<Decimal> {
+        let mut mantissa = self.mantissa();
+        let mut exponent = self.exponent;
+
+        // A `Decimal` has no positive exponents, so scale the mantissa up instead.
+        while exponent > 0 {
+            mantissa = mantissa.checked_mul(10)?;
+            exponent -= 1;
+        }
+        // Trailing zeros can be dropped without changing the value.
+        while exponent < -MAX_SCALE && mantissa.is_multiple_of(10) {
+            mantissa /= 10;
+            exponent += 1;
+        }
+
+        let mantissa = i128::try_from(mantissa).ok()?;
+        let mantissa = if self.is_negative {
+            -mantissa
+        } else {
+            mantissa
+        };
+
+        Decimal::try_from_i128_with_scale(mantissa, (-exponent) as u32).ok()
+    }
+
+    fn mantissa(&self) -> u128 {
+        (self.mantissa_high as u128) << 64 | self.mantissa_low as u128
+    }
+}
@@ -0,0 +1,253 @@
+//! Opaque handles for `#[swift_bridge(hardened)]` types.
+//!
+//! By default Swift holds a raw pointer to an opaque Rust type's value. A bug that frees the value
+//! twice, uses it after it was freed or passes a forged pointer back to Rust leads to undefined
+//! behavior.
+//!
+//! A type that is marked `#[swift_bridge(hardened)]` is instead owned by a [`HandleTable`], and
+//! Swift only ever holds an integer handle that Rust resolves through the table on every call.
+//! Every slot in the table has a generation that changes whenever its value is freed, so a stale or
+//! forged handle is detected and Rust panics instead of touching memory that it does not own.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(hardened)]
+//!         type Keychain;
+//!
+//!         #[swift_bridge(init)]
+//!         fn new() -> Keychain;
+//!
+//!         fn unlock(&mut self, password: &str) -> bool;
+//!     }
+//! }
+//! # );
+//! ```
+
+use crate::sync::Lock;
+use std::cell::UnsafeCell;
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+// The number of values in the tables of each hardened type, by type name.
+static LIVE_HANDLES: Lock<BTreeMap<&'static str, usize>> = Lock::new(BTreeMap::new());
+
+/// Gives access to the table that owns the values of a `#[swift_bridge(hardened)]` type.
+///
+/// Implemented by the code that `#[swift_bridge::bridge]` generates.
+pub trait Hardened: Sized + Send + 'static {
+    /// The table that owns every value of this type that Swift holds a handle to.
+    fn handle_table() -> &'static HandleTable<Self>;
+}
+
+/// Owns values on behalf of Swift and hands out generation-checked handles to them.
+///
+/// A handle is never null, so `Option`s of hardened types keep using null for `None`.
+///
+/// Values can be inserted on one thread and removed on another, so only `Send` values can be
+/// stored in a table that is shared between threads.
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use swift_bridge::handle_table::HandleTable;
+///
+/// static TABLE: HandleTable<Rc<u8>> = HandleTable::new();
+/// ```
+pub struct HandleTable<T> {
+    slots: Lock<Slots<T>>,
+}
+
+// Swift decides which threads use a value, the same way that it does for the raw pointers of types
+// that are not hardened, so the values only need to be `Send`.
+unsafe impl<T: Send> Sync for HandleTable<T> {}
+
+struct Slots<T> {
+    entries: Vec<Slot<T>>,
+    free: Vec<usize>,
+}
+
+struct Slot<T> {
+    generation: usize,
+    // Every call that resolved the handle holds a clone, so a value that gets removed while it is
+    // in use is not freed out from under the call.
+    value: Option<Arc<UnsafeCell<T>>>,
+}
+
+// Half of a handle's bits store the slot's index plus one, the other half store its generation.
+const INDEX_BITS: u32 = usize::BITS / 2;
+const INDEX_MASK: usize = (1 << INDEX_BITS) - 1;
+const GENERATION_MASK: usize = INDEX_MASK;
+
+impl<T> HandleTable<T> {
+    /// Create an empty table.
+    pub const fn new() -> Self {
+        HandleTable {
+            slots: Lock::new(Slots {
+                entries: Vec::new(),
+                free: Vec::new(),
+            }),
+        }
+    }
+
+    /// Move a value into the table and get back the handle that Swift holds on to.
+    pub fn insert(&self, value: T) -> *mut T {
+        let value = Arc::new(UnsafeCell::new(value));
+
+        let mut slots = self.slots.lock();
+
+        let index = match slots.free.pop() {
+            Some(index) => index,
+            None => {
+                assert!(
+                    slots.entries.len() < INDEX_MASK,
+                    "Too many {} values are alive at once.",
+                    std::any::type_name::<T>()
+                );
+
+                slots.entries.push(Slot {
+                    generation: 0,
+                    value: None,
+                });
+                slots.entries.len() - 1
+            }
+        };
+
+        let slot = &mut slots.entries[index];
+        slot.value = Some(value);
+        let handle = ((slot.generation << INDEX_BITS) | (index + 1)) as *mut T;
+
+        drop(slots);
+        *LIVE_HANDLES
+            .lock()
+            .entry(std::any::type_name::<T>())
+            .or_default() += 1;
+
+        handle
+    }
+
+    /// Get the value behind a handle.
+    ///
+    /// The value is kept alive until the returned [`Resolved`] is dropped. The table is not locked
+    /// in the meantime, so the call that uses the value can create and free other values.
+    ///
+    /// ```
+    /// use swift_bridge::handle_table::HandleTable;
+    ///
+    /// static NAMES: HandleTable<String> = HandleTable::new();
+    ///
+    /// let handle = NAMES.insert("Ada".to_string());
+    /// let other = NAMES.insert(NAMES.resolve(handle).to_uppercase());
+    ///
+    /// assert_eq!(*NAMES.resolve(other), "ADA");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was never returned by this table or if its value was already removed.
+    pub fn resolve(&self, handle: *const T) -> Resolved<T> {
+        let slots = self.slots.lock();
+        let index = Self::slot_index(&slots, handle as usize);
+
+        Resolved {
+            value: slots.entries[index].value.clone().unwrap(),
+        }
+    }
+
+    /// Move the value behind a handle out of the table. The handle can't be used again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was never returned by this table, if its value was already removed or
+    /// if its value is still in use, such as by a method call on another thread. The value stays
+    /// alive until that call returns.
+    pub fn remove(&self, handle: *mut T) -> T {
+        let mut slots = self.slots.lock();
+        let index = Self::slot_index(&slots, handle as usize);
+
+        let slot = &mut slots.entries[index];
+        let value = slot.value.take().unwrap();
+        slot.generation = (slot.generation + 1) & GENERATION_MASK;
+        slots.free.push(index);
+
+        drop(slots);
+        if let Some(count) = LIVE_HANDLES.lock().get_mut(std::any::type_name::<T>()) {
+            *count -= 1;
+        }
+
+        match Arc::try_unwrap(value) {
+            Ok(value) => value.into_inner(),
+            Err(_) => panic!(
+                "A {} was freed while it was still in use.",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+
+    /// The number of values that are in the table.
+    pub fn len(&self) -> usize {
+        let slots = self.slots.lock();
+        slots.entries.len() - slots.free.len()
+    }
+
+    /// Whether or not the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn slot_index(slots: &Slots<T>, handle: usize) -> usize {
+        let index = (handle & INDEX_MASK).wrapping_sub(1);
+        let generation = handle >> INDEX_BITS;
+
+        match slots.entries.get(index) {
+            Some(slot) if slot.generation == generation && slot.value.is_some() => index,
+            _ => panic!(
+                "Invalid handle to a {}. The value was already freed, or the handle was not created by swift-bridge.",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+/// The number of values that Swift holds a handle to, by type name, for every hardened type that
+/// has any.
+#[cfg(feature = "debug")]
+pub(crate) fn live_handles() -> Vec<(&'static str, usize)> {
+    LIVE_HANDLES
+        .lock()
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(type_name, count)| (*type_name, *count))
+        .collect()
+}
+
+impl<T> Default for HandleTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The value behind a handle, which stays alive for as long as this does.
+///
+/// Returned by [`HandleTable::resolve`].
+pub struct Resolved<T> {
+    value: Arc<UnsafeCell<T>>,
+}
+
+impl<T> Deref for Resolved<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.value.get() }
+    }
+}
+
+// Swift only calls `&mut self` methods and passes `&mut` arguments when it has exclusive access
+// to the value, the same as for types that are not hardened.
+impl<T> DerefMut for Resolved<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.value.get() }
+    }
+}
@@ -0,0 +1,267 @@
+//! A description of pixel memory that Swift owns, such as a locked `CVPixelBuffer`, that Rust can
+//! read and write without copying.
+//!
+//! Swift describes the memory with a pointer, a size, the number of bytes per row and a
+//! `PixelFormat`, and passes the resulting `ImageBuffer` to Rust. The `ImageBuffer` is only valid
+//! while Swift keeps the memory alive, so Swift hands it out inside of a closure and Rust
+//! functions should take it by reference.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::image::ImageBuffer;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type ImageBuffer;
+//!
+//!         fn brighten(image: &mut ImageBuffer, amount: u8);
+//!     }
+//! }
+//! # );
+//! use swift_bridge::image::ImageBuffer;
+//!
+//! fn brighten(image: &mut ImageBuffer, amount: u8) {
+//!     for row in image.rows_mut() {
+//!         for byte in row {
+//!             *byte = byte.saturating_add(amount);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ```swift
+//! try ImageBuffer.withPixelBuffer(cameraFrame) { image in
+//!     brighten(image, 20)
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+/// The layout of the pixels in an `ImageBuffer`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// Four bytes per pixel, in blue, green, red, alpha order.
+    ///
+    /// `kCVPixelFormatType_32BGRA`, the usual format of camera frames.
+    Bgra8,
+    /// Four bytes per pixel, in red, green, blue, alpha order.
+    Rgba8,
+    /// One byte per pixel.
+    ///
+    /// `kCVPixelFormatType_OneComponent8`.
+    Gray8,
+}
+
+impl PixelFormat {
+    /// The number of bytes that one pixel takes up.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Bgra8 | PixelFormat::Rgba8 => 4,
+            PixelFormat::Gray8 => 1,
+        }
+    }
+
+    fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => PixelFormat::Bgra8,
+            1 => PixelFormat::Rgba8,
+            2 => PixelFormat::Gray8,
+            _ => panic!("Unknown pixel format {}", raw),
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            PixelFormat::Bgra8 => 0,
+            PixelFormat::Rgba8 => 1,
+            PixelFormat::Gray8 => 2,
+        }
+    }
+}
+
+/// A view of pixel memory that is owned by someone else, usually Swift.
+///
+/// Rows are `bytes_per_row` bytes apart, which can be more than `width * bytes_per_pixel` when
+/// the rows are padded.
+#[derive(Debug)]
+pub struct ImageBuffer {
+    ptr: *mut u8,
+    width: usize,
+    height: usize,
+    bytes_per_row: usize,
+    pixel_format: PixelFormat,
+}
+
+impl ImageBuffer {
+    /// Describe `height` rows of pixels starting at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `height * bytes_per_row` bytes that are valid for reads and writes for
+    /// as long as the `ImageBuffer` is used, and that nothing else accesses in the meantime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row of `width` pixels does not fit in `bytes_per_row` bytes.
+    ///
+    /// ```
+    /// use swift_bridge::image::{ImageBuffer, PixelFormat};
+    ///
+    /// let mut pixels = vec![0u8; 2 * 8];
+    /// let mut image = unsafe { ImageBuffer::from_raw_parts(pixels.as_mut_ptr(), 1, 2, 8, PixelFormat::Rgba8) };
+    ///
+    /// for row in image.rows_mut() {
+    ///     row.copy_from_slice(&[255, 0, 0, 255]);
+    /// }
+    ///
+    /// assert_eq!(pixels, [255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0]);
+    /// ```
+    pub unsafe fn from_raw_parts(
+        ptr: *mut u8,
+        width: usize,
+        height: usize,
+        bytes_per_row: usize,
+        pixel_format: PixelFormat,
+    ) -> Self {
+        assert!(
+            width * pixel_format.bytes_per_pixel() <= bytes_per_row,
+            "{} {:?} pixels do not fit in {} bytes per row",
+            width,
+            pixel_format,
+            bytes_per_row
+        );
+
+        ImageBuffer {
+            ptr,
+            width,
+            height,
+            bytes_per_row,
+            pixel_format,
+        }
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of bytes from the start of one row to the start of the next.
+    pub fn bytes_per_row(&self) -> usize {
+        self.bytes_per_row
+    }
+
+    /// The layout of the pixels.
+    pub fn pixel_format(&self) -> PixelFormat {
+        self.pixel_format
+    }
+
+    /// All of the image's memory, including any padding at the end of each row.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(self.ptr, self.height * self.bytes_per_row) }
+    }
+
+    /// All of the image's memory, including any padding at the end of each row.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        if self.ptr.is_null() {
+            return &mut [];
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.height * self.bytes_per_row) }
+    }
+
+    /// The pixels of each row, without the padding at the end of the row.
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        let row_len = self.row_len();
+
+        self.as_bytes()
+            .chunks(self.bytes_per_row.max(1))
+            .map(move |row| &row[..row_len])
+    }
+
+    /// The pixels of each row, without the padding at the end of the row.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> {
+        let row_len = self.row_len();
+        let bytes_per_row = self.bytes_per_row.max(1);
+
+        self.as_bytes_mut()
+            .chunks_mut(bytes_per_row)
+            .map(move |row| &mut row[..row_len])
+    }
+
+    /// The pixels of row `y`, or `None` if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> Option<&[u8]> {
+        self.rows().nth(y)
+    }
+
+    /// The pixels of row `y`, or `None` if `y` is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [u8]> {
+        self.rows_mut().nth(y)
+    }
+
+    fn row_len(&self) -> usize {
+        self.width * self.pixel_format.bytes_per_pixel()
+    }
+}
+
+fn image<'a>(image: *const ImageBuffer) -> &'a ImageBuffer {
+    unsafe { &*image }
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$new"]
+extern "C" fn __swift_bridge__ImageBuffer_new(
+    ptr: *mut u8,
+    width: usize,
+    height: usize,
+    bytes_per_row: usize,
+    pixel_format: u32,
+) -> *mut ImageBuffer {
+    let image = unsafe {
+        ImageBuffer::from_raw_parts(
+            ptr,
+            width,
+            height,
+            bytes_per_row,
+            PixelFormat::from_raw(pixel_format),
+        )
+    };
+
+    Box::into_raw(Box::new(image))
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$width"]
+extern "C" fn __swift_bridge__ImageBuffer_width(ptr: *const ImageBuffer) -> usize {
+    image(ptr).width
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$height"]
+extern "C" fn __swift_bridge__ImageBuffer_height(ptr: *const ImageBuffer) -> usize {
+    image(ptr).height
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$bytes_per_row"]
+extern "C" fn __swift_bridge__ImageBuffer_bytes_per_row(ptr: *const ImageBuffer) -> usize {
+    image(ptr).bytes_per_row
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$pixel_format"]
+extern "C" fn __swift_bridge__ImageBuffer_pixel_format(ptr: *const ImageBuffer) -> u32 {
+    image(ptr).pixel_format.to_raw()
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$_free"]
+extern "C" fn __swift_bridge__ImageBuffer__free(image: *mut ImageBuffer) {
+    unsafe {
+        let _ = Box::from_raw(image);
+    }
+}
@@ -0,0 +1,102 @@
+//! Interior mutability for `#[swift_bridge(interior_mut)]` types.
+//!
+//! Swift can hold any number of references to an opaque Rust type's value at the same time, so
+//! handing out a `&mut` to a value that Swift might also be reading through another reference is
+//! only sound if Swift promises not to do that.
+//!
+//! A type that is marked `#[swift_bridge(interior_mut)]` implements [`InteriorMut`] instead, and
+//! every method is called on the value that it borrows, so a `&mut self` method can be called from
+//! a Swift class that is shared between many owners.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(interior_mut)]
+//!         type Counter;
+//!
+//!         fn make_counter() -> Counter;
+//!
+//!         fn increment(&mut self);
+//!         fn count(&self) -> u32;
+//!     }
+//! }
+//!
+//! // Methods are called on the `CounterInner` that the `RefCell` holds.
+//! type Counter = RefCell<CounterInner>;
+//! # );
+//! ```
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+/// A value that methods of a `#[swift_bridge(interior_mut)]` type are called on.
+///
+/// Implemented for [`RefCell`], [`Mutex`] and [`RwLock`]. Borrowing the inner value again from
+/// one of its own methods panics for a `RefCell` and deadlocks for a `Mutex` or `RwLock`.
+pub trait InteriorMut {
+    /// The type that the bridged methods are defined on.
+    type Inner;
+
+    /// Call `f` with a shared reference to the inner value. Used by `&self` methods.
+    fn with_inner<R>(&self, f: impl FnOnce(&Self::Inner) -> R) -> R;
+
+    /// Call `f` with a mutable reference to the inner value. Used by `&mut self` methods.
+    fn with_inner_mut<R>(&self, f: impl FnOnce(&mut Self::Inner) -> R) -> R;
+
+    /// Take the inner value. Used by `self` methods.
+    fn into_inner(self) -> Self::Inner;
+}
+
+impl<T> InteriorMut for RefCell<T> {
+    type Inner = T;
+
+    fn with_inner<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.borrow())
+    }
+
+    fn with_inner_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.borrow_mut())
+    }
+
+    fn into_inner(self) -> T {
+        RefCell::into_inner(self)
+    }
+}
+
+// A panic while the lock was held was already reported, so we keep using the value instead of
+// panicking again on every later call.
+impl<T> InteriorMut for Mutex<T> {
+    type Inner = T;
+
+    fn with_inner<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    fn with_inner_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    fn into_inner(self) -> T {
+        Mutex::into_inner(self).unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> InteriorMut for RwLock<T> {
+    type Inner = T;
+
+    fn with_inner<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.read().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    fn with_inner_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    fn into_inner(self) -> T {
+        RwLock::into_inner(self).unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
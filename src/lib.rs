@@ -8,7 +8,6 @@ mod std_bridge;
 
 pub use self::std_bridge::{option, result, string};
 
-#[doc(hidden)]
 #[cfg(feature = "async")]
 pub mod async_support;
 
@@ -18,6 +17,70 @@ pub mod boxed_fn_support;
 #[doc(hidden)]
 pub mod copy_support;
 
+pub mod alloc;
+
+pub mod atomic;
+
+#[cfg(feature = "breadcrumbs")]
+pub mod breadcrumbs;
+
+#[cfg(feature = "byte_stream")]
+pub mod byte_stream;
+
+pub mod cancellation;
+
+#[cfg(feature = "context")]
+pub mod context;
+
+pub mod interior_mut;
+
+#[cfg(feature = "debug")]
+pub mod debug;
+
+#[cfg(feature = "decimal")]
+pub mod decimal;
+
+pub mod handle_table;
+
+#[cfg(feature = "image")]
+pub mod image;
+
+#[cfg(feature = "lifecycle")]
+pub mod lifecycle;
+
+#[cfg(feature = "locale")]
+pub mod locale;
+
+#[cfg(feature = "memory_pressure")]
+pub mod memory_pressure;
+
+#[cfg(feature = "os_log")]
+pub mod os_log;
+
+pub mod progress;
+
+pub mod raw_pointer;
+
+#[cfg(feature = "reader")]
+pub mod reader;
+
+pub mod reentrancy;
+
+#[cfg(feature = "rust_io")]
+pub mod rust_io;
+
+pub mod scope;
+
+pub mod sync;
+
+#[cfg(feature = "text")]
+pub mod text;
+
+pub mod throttle;
+
+#[cfg(feature = "resources")]
+pub mod resources;
+
 #[doc(hidden)]
 #[repr(C)]
 pub struct FfiSlice<T> {
@@ -47,6 +110,11 @@ impl<T> FfiSlice<T> {
     pub fn as_slice(&self) -> &'static [T] {
         unsafe { std::slice::from_raw_parts(self.start, self.len) }
     }
+
+    /// Get a mutable reference to the slice that this FfiSlice points to.
+    pub fn as_mut_slice(&self) -> &'static mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.start as *mut T, self.len) }
+    }
 }
 
 // The code generation automatically implements this for all shared structs.
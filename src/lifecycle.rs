@@ -0,0 +1,78 @@
+//! Support for forwarding app lifecycle events from Swift to Rust.
+//!
+//! Rust code registers lifecycle handlers, and Swift forwards events to them by calling
+//! `notifyRustLifecycleEvent(_:)`.
+//! On platforms with UIKit, calling `forwardLifecycleEventsToRust()` once (for example when the
+//! app launches) forwards UIKit's background, foreground and termination notifications.
+//!
+//! ```
+//! use swift_bridge::lifecycle::LifecycleEvent;
+//!
+//! swift_bridge::lifecycle::register_lifecycle_handler(|event| match event {
+//!     LifecycleEvent::DidEnterBackground | LifecycleEvent::WillTerminate => {
+//!         // Flush any unsaved state to disk.
+//!     }
+//!     LifecycleEvent::WillEnterForeground => {}
+//! });
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::sync::Lock;
+
+/// An app lifecycle event observed by Swift.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LifecycleEvent {
+    /// The app moved to the background.
+    DidEnterBackground,
+    /// The app is about to move to the foreground.
+    WillEnterForeground,
+    /// The app is about to terminate.
+    WillTerminate,
+}
+
+impl LifecycleEvent {
+    // Must stay in sync with the raw values of Swift's `RustLifecycleEvent`.
+    fn from_ffi_repr(repr: u8) -> Option<Self> {
+        match repr {
+            0 => Some(LifecycleEvent::DidEnterBackground),
+            1 => Some(LifecycleEvent::WillEnterForeground),
+            2 => Some(LifecycleEvent::WillTerminate),
+            _ => None,
+        }
+    }
+}
+
+type LifecycleHandler = Box<dyn Fn(LifecycleEvent) + Send + Sync>;
+
+static LIFECYCLE_HANDLERS: Lock<Vec<LifecycleHandler>> = Lock::new(Vec::new());
+
+/// Register a handler that gets called with every lifecycle event that Swift forwards to Rust.
+///
+/// Handlers are called in the order that they were registered. A handler must not call
+/// [`register_lifecycle_handler`] or [`dispatch`], since that would deadlock.
+pub fn register_lifecycle_handler(handler: impl Fn(LifecycleEvent) + Send + Sync + 'static) {
+    LIFECYCLE_HANDLERS.lock().push(Box::new(handler));
+}
+
+/// Call every registered lifecycle handler with the given event.
+///
+/// This is what Swift's `notifyRustLifecycleEvent(_:)` calls, but it can also be called from
+/// Rust.
+pub fn dispatch(event: LifecycleEvent) {
+    for handler in LIFECYCLE_HANDLERS.lock().iter() {
+        (handler)(event);
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn lifecycle_handler_count() -> usize {
+    LIFECYCLE_HANDLERS.lock().len()
+}
+
+#[export_name = "__swift_bridge__$lifecycle$dispatch"]
+extern "C" fn __swift_bridge__lifecycle_dispatch(event: u8) {
+    if let Some(event) = LifecycleEvent::from_ffi_repr(event) {
+        dispatch(event)
+    }
+}
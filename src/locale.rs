@@ -0,0 +1,222 @@
+//! The user's Swift `Locale`, passed to Rust so that Rust can format user-facing strings the way
+//! the device is configured.
+//!
+//! Swift creates a `SwiftLocale` from any `Locale`, usually `SwiftLocale.current`, and passes it
+//! to Rust. Rust can also return a `SwiftLocale`, which Swift turns back into a `Locale`.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::locale::SwiftLocale;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type SwiftLocale;
+//!
+//!         fn format_distance(meters: f64, locale: &SwiftLocale) -> String;
+//!     }
+//! }
+//! # );
+//! use swift_bridge::locale::SwiftLocale;
+//!
+//! fn format_distance(meters: f64, locale: &SwiftLocale) -> String {
+//!     format!("{} km", locale.format_decimal(meters / 1000., 1))
+//! }
+//! ```
+//!
+//! ```swift
+//! let label = format_distance(12_345, SwiftLocale.current).toString()
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::{RustStr, RustString};
+
+/// A snapshot of a Swift `Locale`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwiftLocale {
+    identifier: String,
+    decimal_separator: String,
+    grouping_separator: String,
+    calendar: String,
+    currency_code: Option<String>,
+}
+
+impl SwiftLocale {
+    /// A locale with the given identifier, such as "de_DE", that uses "." as its decimal
+    /// separator, "," as its grouping separator and the Gregorian calendar.
+    ///
+    /// Use the `with_` methods to change them.
+    pub fn new(identifier: impl Into<String>) -> Self {
+        SwiftLocale {
+            identifier: identifier.into(),
+            decimal_separator: ".".to_string(),
+            grouping_separator: ",".to_string(),
+            calendar: "gregorian".to_string(),
+            currency_code: None,
+        }
+    }
+
+    /// Set the string that separates the integer part of a number from its fraction.
+    pub fn with_decimal_separator(mut self, separator: impl Into<String>) -> Self {
+        self.decimal_separator = separator.into();
+        self
+    }
+
+    /// Set the string that separates groups of thousands.
+    pub fn with_grouping_separator(mut self, separator: impl Into<String>) -> Self {
+        self.grouping_separator = separator.into();
+        self
+    }
+
+    /// Set the calendar, such as "gregorian" or "japanese".
+    pub fn with_calendar(mut self, calendar: impl Into<String>) -> Self {
+        self.calendar = calendar.into();
+        self
+    }
+
+    /// Set the ISO 4217 currency code, such as "EUR".
+    pub fn with_currency_code(mut self, currency_code: impl Into<String>) -> Self {
+        self.currency_code = Some(currency_code.into());
+        self
+    }
+
+    /// The locale's identifier, such as "de_DE".
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// The string that separates the integer part of a number from its fraction.
+    pub fn decimal_separator(&self) -> &str {
+        &self.decimal_separator
+    }
+
+    /// The string that separates groups of thousands.
+    pub fn grouping_separator(&self) -> &str {
+        &self.grouping_separator
+    }
+
+    /// The identifier of the locale's calendar, such as "gregorian" or "japanese".
+    pub fn calendar(&self) -> &str {
+        &self.calendar
+    }
+
+    /// The locale's ISO 4217 currency code, such as "EUR", if it has one.
+    pub fn currency_code(&self) -> Option<&str> {
+        self.currency_code.as_deref()
+    }
+
+    /// Format a number with the given number of fraction digits, using the locale's decimal and
+    /// grouping separators.
+    ///
+    /// Digits are grouped in threes.
+    ///
+    /// ```
+    /// use swift_bridge::locale::SwiftLocale;
+    ///
+    /// let german = SwiftLocale::new("de_DE")
+    ///     .with_decimal_separator(",")
+    ///     .with_grouping_separator(".");
+    ///
+    /// assert_eq!(german.format_decimal(-1234567.891, 2), "-1.234.567,89");
+    /// assert_eq!(german.format_decimal(12.7, 0), "13");
+    /// ```
+    pub fn format_decimal(&self, value: f64, fraction_digits: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let formatted = format!("{:.*}", fraction_digits, value.abs());
+        let (integer, fraction) = match formatted.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (formatted.as_str(), None),
+        };
+
+        let mut result = String::new();
+        if value.is_sign_negative()
+            && formatted
+                .bytes()
+                .any(|digit| digit != b'0' && digit != b'.')
+        {
+            result.push('-');
+        }
+
+        for (idx, digit) in integer.chars().enumerate() {
+            if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                result += &self.grouping_separator;
+            }
+            result.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            result += &self.decimal_separator;
+            result += fraction;
+        }
+
+        result
+    }
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$new"]
+extern "C" fn __swift_bridge__SwiftLocale_new(
+    identifier: RustStr,
+    decimal_separator: RustStr,
+    grouping_separator: RustStr,
+    calendar: RustStr,
+    currency_code: RustStr,
+) -> *mut SwiftLocale {
+    let currency_code = currency_code.to_str();
+
+    Box::into_raw(Box::new(SwiftLocale {
+        identifier: identifier.to_string(),
+        decimal_separator: decimal_separator.to_string(),
+        grouping_separator: grouping_separator.to_string(),
+        calendar: calendar.to_string(),
+        currency_code: (!currency_code.is_empty()).then(|| currency_code.to_string()),
+    }))
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$identifier"]
+extern "C" fn __swift_bridge__SwiftLocale_identifier(
+    locale: *const SwiftLocale,
+) -> *mut RustString {
+    RustString(unsafe { &*locale }.identifier.clone()).box_into_raw()
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$decimal_separator"]
+extern "C" fn __swift_bridge__SwiftLocale_decimal_separator(
+    locale: *const SwiftLocale,
+) -> *mut RustString {
+    RustString(unsafe { &*locale }.decimal_separator.clone()).box_into_raw()
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$grouping_separator"]
+extern "C" fn __swift_bridge__SwiftLocale_grouping_separator(
+    locale: *const SwiftLocale,
+) -> *mut RustString {
+    RustString(unsafe { &*locale }.grouping_separator.clone()).box_into_raw()
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$calendar"]
+extern "C" fn __swift_bridge__SwiftLocale_calendar(locale: *const SwiftLocale) -> *mut RustString {
+    RustString(unsafe { &*locale }.calendar.clone()).box_into_raw()
+}
+
+// Returns null if the locale has no currency code.
+#[export_name = "__swift_bridge__$SwiftLocale$currency_code"]
+extern "C" fn __swift_bridge__SwiftLocale_currency_code(
+    locale: *const SwiftLocale,
+) -> *mut RustString {
+    match unsafe { &*locale }.currency_code.clone() {
+        Some(currency_code) => RustString(currency_code).box_into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+#[export_name = "__swift_bridge__$SwiftLocale$_free"]
+extern "C" fn __swift_bridge__SwiftLocale__free(locale: *mut SwiftLocale) {
+    unsafe {
+        let _ = Box::from_raw(locale);
+    }
+}
@@ -0,0 +1,50 @@
+//! Support for purging Rust caches when Swift observes memory pressure.
+//!
+//! Rust code registers purge callbacks, and Swift runs them by calling `purgeRustCaches()`.
+//! On platforms with UIKit, calling `purgeRustCachesOnMemoryWarning()` once (for example when
+//! the app launches) runs the callbacks whenever the app receives a memory warning.
+//!
+//! ```
+//! use std::sync::Mutex;
+//!
+//! static THUMBNAILS: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+//!
+//! swift_bridge::memory_pressure::register_purge_callback(|| {
+//!     THUMBNAILS.lock().unwrap().clear();
+//! });
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::sync::Lock;
+
+type PurgeCallback = Box<dyn Fn() + Send + Sync>;
+
+static PURGE_CALLBACKS: Lock<Vec<PurgeCallback>> = Lock::new(Vec::new());
+
+/// Register a callback that frees memory that can be recomputed later, such as a cache.
+///
+/// Callbacks are called in the order that they were registered. A callback must not call
+/// [`register_purge_callback`] or [`purge`], since that would deadlock.
+pub fn register_purge_callback(callback: impl Fn() + Send + Sync + 'static) {
+    PURGE_CALLBACKS.lock().push(Box::new(callback));
+}
+
+/// Call every registered purge callback.
+///
+/// This is what Swift's `purgeRustCaches()` calls, but it can also be called from Rust.
+pub fn purge() {
+    for callback in PURGE_CALLBACKS.lock().iter() {
+        (callback)();
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn purge_callback_count() -> usize {
+    PURGE_CALLBACKS.lock().len()
+}
+
+#[export_name = "__swift_bridge__$memory_pressure$purge"]
+extern "C" fn __swift_bridge__memory_pressure_purge() {
+    purge()
+}
@@ -0,0 +1,89 @@
+//! Forward `log` records to Apple's unified logging system.
+//!
+//! Enabled by the `os_log` feature.
+//!
+//! Once [`init`] has been called, records that are logged with the `log` crate's macros show up
+//! in Console.app, Xcode's console and sysdiagnose archives alongside the app's Swift logs.
+//!
+//! ```no_run
+//! fn init_logging() {
+//!     swift_bridge::os_log::init("com.example.my-app", log::LevelFilter::Info).unwrap();
+//!
+//!     log::info!(target: "sync", "Synced {} files", 3);
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::RustStr;
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// A `log::Log` implementation that forwards records to `os.Logger`.
+///
+/// Every record is logged under the logger's subsystem, and the record's target is used as the
+/// category, so records can be filtered by module in Console.app.
+///
+/// Messages are logged as public, so they are not redacted when no debugger is attached.
+pub struct OsLogger {
+    subsystem: String,
+}
+
+impl OsLogger {
+    /// Create a logger that logs under the given subsystem, typically the app's bundle identifier.
+    pub fn new(subsystem: impl Into<String>) -> Self {
+        OsLogger {
+            subsystem: subsystem.into(),
+        }
+    }
+}
+
+/// Install an [`OsLogger`] as the global logger and set the maximum log level.
+///
+/// Fails if a global logger has already been installed.
+pub fn init(subsystem: impl Into<String>, max_level: LevelFilter) -> Result<(), SetLoggerError> {
+    log::set_boxed_logger(Box::new(OsLogger::new(subsystem)))?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+impl Log for OsLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let message = record.args().to_string();
+
+        unsafe {
+            __swift_bridge__os_log_log(
+                level_to_ffi_repr(record.level()),
+                RustStr::from_str(&self.subsystem),
+                RustStr::from_str(record.target()),
+                RustStr::from_str(&message),
+            )
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+// Must stay in sync with the `switch` in the Swift `__swift_bridge__$os_log$log` function.
+fn level_to_ffi_repr(level: Level) -> u8 {
+    match level {
+        Level::Error => 0,
+        Level::Warn => 1,
+        Level::Info => 2,
+        Level::Debug => 3,
+        Level::Trace => 4,
+    }
+}
+
+extern "C" {
+    #[link_name = "__swift_bridge__$os_log$log"]
+    fn __swift_bridge__os_log_log(
+        level: u8,
+        subsystem: RustStr,
+        category: RustStr,
+        message: RustStr,
+    );
+}
@@ -0,0 +1,62 @@
+//! Support for reporting the progress of long-running functions to Swift.
+
+/// Reports the progress of a `#[swift_bridge(progress)]` function to Swift.
+///
+/// ```
+/// # const  _: &str = stringify!(
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     extern "Rust" {
+///         #[swift_bridge(progress)]
+///         fn process_file(path: &str);
+///     }
+/// }
+/// # );
+///
+/// fn process_file(path: &str, progress: &swift_bridge::progress::Progress) {
+///     for chunk in 0..10 {
+///         // ...
+///         progress.report((chunk + 1) as f64 / 10.);
+///     }
+/// }
+/// ```
+///
+/// On the Swift side the function accepts an optional progress closure.
+///
+/// ```swift
+/// process_file("data.csv", progress: { fractionCompleted in
+///     print(fractionCompleted)
+/// })
+/// ```
+///
+/// The progress closure is passed to Rust as a `Box<dyn Fn(f64)>`, the same way as any other
+/// Swift closure. Swift closures are not `Send`, so a `Progress` can only be borrowed and cannot
+/// be sent to other threads.
+pub struct Progress {
+    callback: Box<dyn Fn(f64)>,
+    observed: bool,
+}
+
+impl Progress {
+    #[doc(hidden)]
+    pub fn new(callback: Box<dyn Fn(f64)>, observed: bool) -> Self {
+        Progress { callback, observed }
+    }
+
+    /// Report the fraction of the work that has been completed so far, typically between `0.0`
+    /// and `1.0`.
+    ///
+    /// Does nothing if Swift did not pass in a progress closure.
+    pub fn report(&self, fraction_completed: f64) {
+        if self.observed {
+            (self.callback)(fraction_completed)
+        }
+    }
+
+    /// Whether or not Swift passed in a progress closure.
+    ///
+    /// Useful for skipping expensive progress calculations when nobody is listening.
+    pub fn is_observed(&self) -> bool {
+        self.observed
+    }
+}
@@ -0,0 +1,50 @@
+//! Escape hatches for passing opaque Rust types through places that the bridge does not model,
+//! such as C callbacks or `userInfo` dictionaries.
+//!
+//! Every opaque Rust type's Swift class has an `intoRawPointer()` method and an
+//! `init(fromRawPointer:)` initializer that use the same pointer representation as the functions
+//! in this module, so a pointer can be created in one language and consumed in the other.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         type Session;
+//!
+//!         fn session_from_user_info(user_info: *mut c_void) -> Session;
+//!     }
+//! }
+//! # );
+//! # struct Session;
+//! fn session_from_user_info(user_info: *mut std::ffi::c_void) -> Session {
+//!     unsafe { swift_bridge::raw_pointer::from_raw_pointer(user_info) }
+//! }
+//! ```
+//!
+//! ```swift
+//! let userInfo = Session().intoRawPointer()
+//! // ...
+//! let session = session_from_user_info(userInfo)
+//! ```
+
+use std::ffi::c_void;
+
+/// Move a value to the heap and return a pointer to it.
+///
+/// The pointer must later be given back to [`from_raw_pointer`] or, if `T` is an opaque Rust
+/// type, to the `init(fromRawPointer:)` initializer of its Swift class. Otherwise the value is
+/// leaked.
+pub fn into_raw_pointer<T>(value: T) -> *mut c_void {
+    Box::into_raw(Box::new(value)) as *mut c_void
+}
+
+/// Take back ownership of a value from a pointer that was returned by [`into_raw_pointer`] or by
+/// the `intoRawPointer()` method of an opaque Rust type's Swift class.
+///
+/// # Safety
+///
+/// The pointer must point to a `T` and must not be used again afterwards.
+pub unsafe fn from_raw_pointer<T>(ptr: *mut c_void) -> T {
+    *Box::from_raw(ptr as *mut T)
+}
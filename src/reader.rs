@@ -0,0 +1,153 @@
+//! A Swift data source that Rust reads from through [`std::io::Read`] and [`std::io::Seek`].
+//!
+//! Swift wraps any value that conforms to its `RandomAccessReader` protocol in a `SwiftReader`
+//! and passes it to Rust. Rust reads from it on demand, so parsers can consume large
+//! Swift-managed data sources, such as asset catalogs or encrypted files, without first copying
+//! them fully into memory.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::reader::SwiftReader;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type SwiftReader;
+//!
+//!         fn count_lines(reader: SwiftReader) -> u32;
+//!     }
+//! }
+//! # );
+//! use std::io::{BufRead, BufReader};
+//! use swift_bridge::reader::SwiftReader;
+//!
+//! fn count_lines(reader: SwiftReader) -> u32 {
+//!     BufReader::new(reader).lines().count() as u32
+//! }
+//! ```
+//!
+//! ```swift
+//! struct EncryptedFile: RandomAccessReader {
+//!     let length: UInt64
+//!
+//!     func read(at offset: UInt64, length: Int) -> Data {
+//!         decryptChunk(at: offset, length: length)
+//!     }
+//! }
+//!
+//! let lines = count_lines(SwiftReader(EncryptedFile(length: 4096)))
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::ffi::c_void;
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+
+type ReadCallback = extern "C" fn(*mut c_void, u64, *mut u8, usize) -> usize;
+
+/// A Swift `RandomAccessReader` that Rust can read from.
+///
+/// Every read calls back into Swift, so wrap it in a [`std::io::BufReader`] when reading it in
+/// small pieces.
+///
+/// The Swift reader gets released when this is dropped.
+pub struct SwiftReader {
+    context: *mut c_void,
+    len: u64,
+    position: u64,
+    read: ReadCallback,
+    free: extern "C" fn(*mut c_void),
+}
+
+impl SwiftReader {
+    /// The total number of bytes that can be read.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether or not there are no bytes to read.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read into `buf` starting at `offset`, without moving the position that [`Read`] reads
+    /// from.
+    ///
+    /// Returns the number of bytes that were read, which is only less than `buf.len()` when the
+    /// end of the data was reached.
+    pub fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        if offset >= self.len || buf.is_empty() {
+            return 0;
+        }
+
+        let len = buf.len().min((self.len - offset) as usize);
+        let read = (self.read)(self.context, offset, buf.as_mut_ptr(), len);
+
+        read.min(len)
+    }
+}
+
+impl Read for SwiftReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.read_at(self.position, buf);
+        self.position += read as u64;
+
+        Ok(read)
+    }
+}
+
+impl Seek for SwiftReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl Drop for SwiftReader {
+    fn drop(&mut self) {
+        (self.free)(self.context)
+    }
+}
+
+#[export_name = "__swift_bridge__$SwiftReader$new"]
+extern "C" fn __swift_bridge__SwiftReader_new(
+    context: *mut c_void,
+    len: u64,
+    read: ReadCallback,
+    free: extern "C" fn(*mut c_void),
+) -> *mut SwiftReader {
+    Box::into_raw(Box::new(SwiftReader {
+        context,
+        len,
+        position: 0,
+        read,
+        free,
+    }))
+}
+
+#[export_name = "__swift_bridge__$SwiftReader$_free"]
+extern "C" fn __swift_bridge__SwiftReader__free(reader: *mut SwiftReader) {
+    unsafe {
+        let _ = Box::from_raw(reader);
+    }
+}
+
+#[export_name = "__swift_bridge__$SwiftReader$len"]
+extern "C" fn __swift_bridge__SwiftReader_len(reader: *const SwiftReader) -> u64 {
+    unsafe { &*reader }.len()
+}
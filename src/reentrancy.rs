@@ -0,0 +1,86 @@
+//! Detection of Swift re-entering a Rust object.
+//!
+//! A method with `#[swift_bridge(non_reentrant)]` panics with a message that names both methods
+//! when Swift calls it while another `non_reentrant` method is still running on the same object
+//! on the same thread.
+//!
+//! This usually happens when a method calls a Swift callback, and the callback calls back into the
+//! same object. Without the guard this would show up as an opaque `RefCell` "already borrowed"
+//! panic, or as a `&mut self` that aliases another reference.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         type Downloader;
+//!
+//!         #[swift_bridge(non_reentrant)]
+//!         fn download(&mut self, observer: DownloadObserver);
+//!
+//!         #[swift_bridge(non_reentrant)]
+//!         fn cancel(&mut self);
+//!     }
+//!
+//!     extern "Swift" {
+//!         type DownloadObserver;
+//!
+//!         // Panics if this calls `cancel` on the `Downloader` that is calling it.
+//!         fn on_chunk(&self, len: u32);
+//!     }
+//! }
+//! # );
+//! ```
+
+use std::cell::RefCell;
+
+thread_local! {
+    // The objects that are currently inside of a `non_reentrant` method on this thread, along with
+    // the name of that method, innermost call last.
+    static ENTERED: RefCell<Vec<(usize, &'static str)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks an object as being inside of a `non_reentrant` method until it is dropped.
+#[doc(hidden)]
+pub struct ReentrancyGuard {
+    object: usize,
+}
+
+/// Called by the code that `#[swift_bridge(non_reentrant)]` generates before calling the method.
+///
+/// Panics if another `non_reentrant` method is already running on the same object.
+#[doc(hidden)]
+pub fn enter<T>(object: *const T, method: &'static str) -> ReentrancyGuard {
+    let object = object as usize;
+
+    ENTERED.with(|entered| {
+        let mut entered = entered.borrow_mut();
+
+        if let Some((_, running)) = entered.iter().find(|(entered, _)| *entered == object) {
+            let running = *running;
+            drop(entered);
+
+            panic!(
+                "swift-bridge: Swift called `{}` while `{}` was still running on the same object. \
+This usually means that a Swift callback called back into the object that invoked it, which \
+`#[swift_bridge(non_reentrant)]` forbids.",
+                method, running
+            );
+        }
+
+        entered.push((object, method));
+    });
+
+    ReentrancyGuard { object }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(idx) = entered.iter().rposition(|(e, _)| *e == self.object) {
+                entered.remove(idx);
+            }
+        });
+    }
+}
@@ -0,0 +1,39 @@
+//! Look up the resource files that a Swift Package bundles alongside the Rust library.
+//!
+//! Enabled by the `resources` feature.
+//!
+//! Resources are declared with `CreatePackageConfig::resources` when the package is created by
+//! `swift_bridge_build::create_package`, and the package's Swift code resolves their paths inside
+//! of the package's resource bundle.
+//!
+//! ```no_run
+//! fn load_dictionary() -> String {
+//!     let path = swift_bridge::resources::path("dictionary.txt").unwrap();
+//!     std::fs::read_to_string(path).unwrap()
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::{RustStr, RustString};
+use std::path::PathBuf;
+
+/// The path to one of the package's resources, or `None` if the package does not contain a
+/// resource with the given file name.
+pub fn path(name: &str) -> Option<PathBuf> {
+    let path = unsafe { __swift_bridge__resources_path(RustStr::from_str(name)) };
+
+    if path.is_null() {
+        None
+    } else {
+        let path = unsafe { Box::from_raw(path) };
+        Some(PathBuf::from(path.0))
+    }
+}
+
+// Swift only ever sees the pointer to the `RustString`, never its fields.
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "__swift_bridge__$resources$path"]
+    fn __swift_bridge__resources_path(name: RustStr) -> *mut RustString;
+}
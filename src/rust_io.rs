@@ -0,0 +1,254 @@
+//! Rust [`std::io::Write`] and [`std::io::Read`] values that Swift uses as stream-like objects.
+//!
+//! A [`RustWriter`] lets Swift pipe data into a Rust writer, such as an encoder or a compressor,
+//! a chunk at a time. A [`RustReader`] lets Swift pull data out of a Rust reader, such as a
+//! decoder or a decompressor, a chunk at a time.
+//!
+//! Their Swift methods mirror `OutputStream` and `InputStream`.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::rust_io::RustWriter;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type RustWriter;
+//!
+//!         fn new_line_counter() -> RustWriter;
+//!         fn finish_line_counter(writer: RustWriter) -> u32;
+//!     }
+//! }
+//! # );
+//! use std::io::Write;
+//! use swift_bridge::rust_io::RustWriter;
+//!
+//! struct LineCounter(u32);
+//!
+//! impl Write for LineCounter {
+//!     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+//!         self.0 += buf.iter().filter(|byte| **byte == b'\n').count() as u32;
+//!         Ok(buf.len())
+//!     }
+//!
+//!     fn flush(&mut self) -> std::io::Result<()> {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! fn new_line_counter() -> RustWriter {
+//!     RustWriter::new(LineCounter(0))
+//! }
+//!
+//! fn finish_line_counter(writer: RustWriter) -> u32 {
+//!     writer.into_inner::<LineCounter>().ok().unwrap().0
+//! }
+//! ```
+//!
+//! ```swift
+//! let writer = new_line_counter()
+//! try writer.write(contentsOf: InputStream(url: logFile)!)
+//! let lines = finish_line_counter(writer)
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::RustString;
+use std::any::{Any, TypeId};
+use std::io::{Error, Read, Write};
+
+/// A Rust [`Write`] that Swift can write to.
+pub struct RustWriter {
+    inner: Box<dyn AnyWrite>,
+    error: Option<Error>,
+}
+
+/// A Rust [`Read`] that Swift can read from.
+pub struct RustReader {
+    inner: Box<dyn AnyRead>,
+    error: Option<Error>,
+    at_end: bool,
+}
+
+// Lets the writer or reader be moved back out of its box once Swift is done with it.
+trait AnyWrite: Write + Any {
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<W: Write + Any> AnyWrite for W {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+trait AnyRead: Read + Any {
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<R: Read + Any> AnyRead for R {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl RustWriter {
+    /// Wrap a writer so that it can be passed to Swift.
+    pub fn new<W: Write + 'static>(writer: W) -> Self {
+        RustWriter {
+            inner: Box::new(writer),
+            error: None,
+        }
+    }
+
+    /// Get back the writer that this was created from.
+    ///
+    /// Returns `Err(self)` if the writer is not a `W`.
+    pub fn into_inner<W: Write + 'static>(self) -> Result<W, Self> {
+        if Any::type_id(&*self.inner) != TypeId::of::<W>() {
+            return Err(self);
+        }
+
+        Ok(*self.inner.into_any().downcast::<W>().unwrap())
+    }
+
+    /// The error that the most recent failed write or flush from Swift returned.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+impl Write for RustWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl RustReader {
+    /// Wrap a reader so that it can be passed to Swift.
+    pub fn new<R: Read + 'static>(reader: R) -> Self {
+        RustReader {
+            inner: Box::new(reader),
+            error: None,
+            at_end: false,
+        }
+    }
+
+    /// Get back the reader that this was created from.
+    ///
+    /// Returns `Err(self)` if the reader is not an `R`.
+    pub fn into_inner<R: Read + 'static>(self) -> Result<R, Self> {
+        if Any::type_id(&*self.inner) != TypeId::of::<R>() {
+            return Err(self);
+        }
+
+        Ok(*self.inner.into_any().downcast::<R>().unwrap())
+    }
+
+    /// The error that the most recent failed read from Swift returned.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+impl Read for RustReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+// Returns the number of bytes written, or -1 if the write failed.
+#[export_name = "__swift_bridge__$RustWriter$write"]
+extern "C" fn __swift_bridge__RustWriter_write(
+    writer: *mut RustWriter,
+    buf: *const u8,
+    len: usize,
+) -> isize {
+    let writer = unsafe { &mut *writer };
+    let buf = unsafe { std::slice::from_raw_parts(buf, len) };
+
+    match writer.inner.write(buf) {
+        Ok(written) => written as isize,
+        Err(error) => {
+            writer.error = Some(error);
+            -1
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$RustWriter$flush"]
+extern "C" fn __swift_bridge__RustWriter_flush(writer: *mut RustWriter) -> bool {
+    let writer = unsafe { &mut *writer };
+
+    match writer.inner.flush() {
+        Ok(()) => true,
+        Err(error) => {
+            writer.error = Some(error);
+            false
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$RustWriter$error"]
+extern "C" fn __swift_bridge__RustWriter_error(writer: *const RustWriter) -> *mut RustString {
+    error_message(unsafe { &*writer }.error())
+}
+
+#[export_name = "__swift_bridge__$RustWriter$_free"]
+extern "C" fn __swift_bridge__RustWriter__free(writer: *mut RustWriter) {
+    unsafe {
+        let _ = Box::from_raw(writer);
+    }
+}
+
+// Returns the number of bytes read, 0 at the end of the data, or -1 if the read failed.
+#[export_name = "__swift_bridge__$RustReader$read"]
+extern "C" fn __swift_bridge__RustReader_read(
+    reader: *mut RustReader,
+    buf: *mut u8,
+    len: usize,
+) -> isize {
+    let reader = unsafe { &mut *reader };
+    let buf = unsafe { std::slice::from_raw_parts_mut(buf, len) };
+
+    match reader.inner.read(buf) {
+        Ok(read) => {
+            if read == 0 && len > 0 {
+                reader.at_end = true;
+            }
+            read as isize
+        }
+        Err(error) => {
+            reader.error = Some(error);
+            -1
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$RustReader$has_bytes_available"]
+extern "C" fn __swift_bridge__RustReader_has_bytes_available(reader: *const RustReader) -> bool {
+    !unsafe { &*reader }.at_end
+}
+
+#[export_name = "__swift_bridge__$RustReader$error"]
+extern "C" fn __swift_bridge__RustReader_error(reader: *const RustReader) -> *mut RustString {
+    error_message(unsafe { &*reader }.error())
+}
+
+#[export_name = "__swift_bridge__$RustReader$_free"]
+extern "C" fn __swift_bridge__RustReader__free(reader: *mut RustReader) {
+    unsafe {
+        let _ = Box::from_raw(reader);
+    }
+}
+
+fn error_message(error: Option<&Error>) -> *mut RustString {
+    match error {
+        Some(error) => RustString(error.to_string()).box_into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,122 @@
+//! Free the Rust values that Swift is done with in bulk.
+//!
+//! Request handlers and per-frame code often create many short lived `RustString`s, `RustVec`s
+//! and opaque Rust types. Swift frees each of them as soon as it is done with it. Inside of
+//! Swift's `withRustScope { ... }`, freeing them is deferred instead, and everything that was
+//! freed inside of the scope gets dropped in one call when the scope ends.
+//!
+//! ```swift
+//! for request in requests {
+//!     withRustScope {
+//!         let body = render(request)
+//!         send(body.toString())
+//!     }
+//! }
+//! ```
+//!
+//! Swift's `withRustScope` is enabled by the `scope` feature.
+//!
+//! Scopes belong to the thread that opened them and can be nested. Values that are freed on
+//! another thread, or while no scope is open, are dropped right away. Opaque Rust types that use
+//! `#[swift_bridge(hardened)]` or `#[swift_bridge(on_drop = ...)]` are never deferred.
+//!
+//! Rust code can open scopes too.
+//!
+//! ```
+//! use swift_bridge::scope;
+//!
+//! scope::open();
+//! scope::drop_or_defer(Box::new(String::from("Hello")));
+//! assert_eq!(scope::deferred_count(), 1);
+//!
+//! scope::close();
+//! assert_eq!(scope::deferred_count(), 0);
+//! ```
+
+#![allow(non_snake_case)]
+
+use std::cell::RefCell;
+
+// A value whose drop was deferred until its scope is closed.
+struct Deferred {
+    ptr: *mut (),
+    drop: unsafe fn(*mut ()),
+}
+
+impl Drop for Deferred {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.ptr) };
+    }
+}
+
+thread_local! {
+    static SCOPES: RefCell<Vec<Vec<Deferred>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Open a scope on the current thread.
+///
+/// Every call must be paired with a call to [`close`].
+pub fn open() {
+    SCOPES.with(|scopes| scopes.borrow_mut().push(Vec::new()));
+}
+
+/// Close the innermost scope on the current thread and drop every value that was deferred in it.
+///
+/// Does nothing if no scope is open.
+pub fn close() {
+    let deferred = SCOPES.with(|scopes| scopes.borrow_mut().pop());
+
+    // Dropping a value can free other values, so we drop them after the scope was removed.
+    drop(deferred);
+}
+
+/// Drop the value when the innermost scope on the current thread is closed, or right away if no
+/// scope is open.
+///
+/// This is what the generated functions that Swift calls to free Rust values use.
+///
+/// The value can outlive the caller, so it can not borrow anything.
+///
+/// ```compile_fail
+/// let name = String::from("Hello");
+/// swift_bridge::scope::drop_or_defer(Box::new(&name));
+/// ```
+pub fn drop_or_defer<T: 'static>(value: Box<T>) {
+    let ptr = Box::into_raw(value);
+
+    let deferred = SCOPES.with(|scopes| match scopes.borrow_mut().last_mut() {
+        Some(scope) => {
+            scope.push(Deferred {
+                ptr: ptr as *mut (),
+                drop: drop_box::<T>,
+            });
+            true
+        }
+        None => false,
+    });
+
+    if !deferred {
+        unsafe { drop_box::<T>(ptr as *mut ()) };
+    }
+}
+
+/// The number of values that are waiting for a scope on the current thread to be closed.
+pub fn deferred_count() -> usize {
+    SCOPES.with(|scopes| scopes.borrow().iter().map(Vec::len).sum())
+}
+
+unsafe fn drop_box<T>(ptr: *mut ()) {
+    drop(Box::from_raw(ptr as *mut T));
+}
+
+#[cfg(feature = "scope")]
+#[export_name = "__swift_bridge__$scope$open"]
+extern "C" fn __swift_bridge__scope_open() {
+    open()
+}
+
+#[cfg(feature = "scope")]
+#[export_name = "__swift_bridge__$scope$close"]
+extern "C" fn __swift_bridge__scope_close() {
+    close()
+}
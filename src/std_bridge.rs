@@ -4,5 +4,8 @@
 
 pub mod option;
 pub mod result;
+mod rust_channel;
+mod rust_hash_map;
+mod rust_hash_set;
 mod rust_vec;
 pub mod string;
@@ -0,0 +1,87 @@
+use macro_::channel_externs;
+use std::sync::mpsc::{Receiver, Sender};
+
+channel_externs!(u8, OptionU8, 123);
+channel_externs!(u16, OptionU16, 123);
+channel_externs!(u32, OptionU32, 123);
+channel_externs!(u64, OptionU64, 123);
+channel_externs!(usize, OptionUsize, 123);
+
+channel_externs!(i8, OptionI8, 123);
+channel_externs!(i16, OptionI16, 123);
+channel_externs!(i32, OptionI32, 123);
+channel_externs!(i64, OptionI64, 123);
+channel_externs!(isize, OptionIsize, 123);
+
+channel_externs!(f32, OptionF32, 0.123);
+channel_externs!(f64, OptionF64, 0.123);
+
+channel_externs!(bool, OptionBool, false);
+
+mod macro_ {
+    macro_rules! channel_externs {
+        ($ty:ty, $option_ty:ident, $unused_none:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Sender_", stringify!($ty), "$send")]
+                #[doc(hidden)]
+                pub extern "C" fn _send(sender: *mut Sender<$ty>, val: $ty) -> bool {
+                    let sender = unsafe { &*sender };
+                    sender.send(val).is_ok()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Sender_", stringify!($ty), "$clone")]
+                #[doc(hidden)]
+                pub extern "C" fn _clone(sender: *mut Sender<$ty>) -> *mut Sender<$ty> {
+                    let sender = unsafe { &*sender };
+                    Box::into_raw(Box::new(sender.clone()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Sender_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _sender_drop(sender: *mut Sender<$ty>) {
+                    let sender = unsafe { Box::from_raw(sender) };
+                    drop(sender)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Receiver_", stringify!($ty), "$recv")]
+                #[doc(hidden)]
+                pub extern "C" fn _recv(receiver: *mut Receiver<$ty>) -> crate::option::$option_ty {
+                    let receiver = unsafe { &*receiver };
+                    if let Ok(val) = receiver.recv() {
+                        crate::option::$option_ty { val, is_some: true }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Receiver_", stringify!($ty), "$try_recv")]
+                #[doc(hidden)]
+                pub extern "C" fn _try_recv(
+                    receiver: *mut Receiver<$ty>,
+                ) -> crate::option::$option_ty {
+                    let receiver = unsafe { &*receiver };
+                    if let Ok(val) = receiver.try_recv() {
+                        crate::option::$option_ty { val, is_some: true }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Receiver_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _receiver_drop(receiver: *mut Receiver<$ty>) {
+                    let receiver = unsafe { Box::from_raw(receiver) };
+                    drop(receiver)
+                }
+            };
+        };
+    }
+
+    pub(super) use channel_externs;
+}
@@ -0,0 +1,220 @@
+use crate::option::*;
+use crate::string::{RustStr, RustString};
+use macro_::{map_externs, map_externs_for_key};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+map_externs_for_key!(String);
+map_externs_for_key!(u8);
+map_externs_for_key!(u16);
+map_externs_for_key!(u32);
+map_externs_for_key!(u64);
+map_externs_for_key!(usize);
+map_externs_for_key!(i8);
+map_externs_for_key!(i16);
+map_externs_for_key!(i32);
+map_externs_for_key!(i64);
+map_externs_for_key!(isize);
+
+/// A `HashMap` key type that Swift can pass over FFI.
+trait MapKey: Eq + Hash + Sized {
+    /// How Swift passes the key to Rust.
+    type Ffi;
+    /// How Rust passes the key to Swift when listing the map's keys.
+    type VecElem;
+
+    fn get<V>(map: &HashMap<Self, V>, key: Self::Ffi) -> Option<&V>;
+    fn from_ffi(key: Self::Ffi) -> Self;
+    fn to_vec_elem(&self) -> Self::VecElem;
+}
+
+/// A `HashMap` value type that Swift can pass over FFI.
+trait MapValue: Sized {
+    /// How Swift passes the value to Rust.
+    type Ffi;
+    /// How Rust passes an optional value to Swift.
+    type FfiOption;
+
+    fn from_ffi(value: Self::Ffi) -> Self;
+    fn to_ffi_option(value: Option<&Self>) -> Self::FfiOption;
+}
+
+impl MapKey for String {
+    type Ffi = RustStr;
+    type VecElem = RustString;
+
+    fn get<V>(map: &HashMap<Self, V>, key: RustStr) -> Option<&V> {
+        map.get(key.to_str())
+    }
+
+    fn from_ffi(key: RustStr) -> Self {
+        key.to_string()
+    }
+
+    fn to_vec_elem(&self) -> RustString {
+        RustString(self.clone())
+    }
+}
+
+impl MapValue for String {
+    type Ffi = RustStr;
+    type FfiOption = *mut RustString;
+
+    fn from_ffi(value: RustStr) -> Self {
+        value.to_string()
+    }
+
+    fn to_ffi_option(value: Option<&Self>) -> *mut RustString {
+        match value {
+            Some(value) => RustString(value.clone()).box_into_raw(),
+            None => std::ptr::null_mut(),
+        }
+    }
+}
+
+macro_rules! primitive_map_key {
+    ($($ty:ty),*) => {
+        $(
+            impl MapKey for $ty {
+                type Ffi = $ty;
+                type VecElem = $ty;
+
+                fn get<V>(map: &HashMap<Self, V>, key: $ty) -> Option<&V> {
+                    map.get(&key)
+                }
+
+                fn from_ffi(key: $ty) -> Self {
+                    key
+                }
+
+                fn to_vec_elem(&self) -> $ty {
+                    *self
+                }
+            }
+        )*
+    };
+}
+primitive_map_key!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! primitive_map_value {
+    ($($ty:ty => $option_ty:ident, $unused_none:expr);*) => {
+        $(
+            impl MapValue for $ty {
+                type Ffi = $ty;
+                type FfiOption = $option_ty;
+
+                fn from_ffi(value: $ty) -> Self {
+                    value
+                }
+
+                fn to_ffi_option(value: Option<&Self>) -> $option_ty {
+                    match value {
+                        Some(val) => $option_ty { val: *val, is_some: true },
+                        None => $option_ty { val: $unused_none, is_some: false },
+                    }
+                }
+            }
+        )*
+    };
+}
+primitive_map_value!(
+    u8 => OptionU8, 123;
+    u16 => OptionU16, 123;
+    u32 => OptionU32, 123;
+    u64 => OptionU64, 123;
+    usize => OptionUsize, 123;
+    i8 => OptionI8, 123;
+    i16 => OptionI16, 123;
+    i32 => OptionI32, 123;
+    i64 => OptionI64, 123;
+    isize => OptionIsize, 123;
+    f32 => OptionF32, 0.123;
+    f64 => OptionF64, 0.123;
+    bool => OptionBool, false
+);
+
+mod macro_ {
+    macro_rules! map_externs_for_key {
+        ($key:ty) => {
+            map_externs!($key, u8);
+            map_externs!($key, u16);
+            map_externs!($key, u32);
+            map_externs!($key, u64);
+            map_externs!($key, usize);
+            map_externs!($key, i8);
+            map_externs!($key, i16);
+            map_externs!($key, i32);
+            map_externs!($key, i64);
+            map_externs!($key, isize);
+            map_externs!($key, f32);
+            map_externs!($key, f64);
+            map_externs!($key, bool);
+            map_externs!($key, String);
+        };
+    }
+
+    macro_rules! map_externs {
+        ($key:ty, $value:ty) => {
+            const _: () = {
+                type Map = HashMap<$key, $value>;
+
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Map {
+                    Box::into_raw(Box::new(HashMap::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(map: *mut Map) {
+                    let map = unsafe { Box::from_raw(map) };
+                    drop(map)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(map: *mut Map) -> usize {
+                    let map = unsafe { &*map };
+                    map.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(
+                    map: *mut Map,
+                    key: <$key as MapKey>::Ffi,
+                ) -> <$value as MapValue>::FfiOption {
+                    let map = unsafe { &*map };
+                    <$value as MapValue>::to_ffi_option(<$key as MapKey>::get(map, key))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(
+                    map: *mut Map,
+                    key: <$key as MapKey>::Ffi,
+                    value: <$value as MapValue>::Ffi,
+                ) {
+                    let map = unsafe { &mut *map };
+                    map.insert(
+                        <$key as MapKey>::from_ffi(key),
+                        <$value as MapValue>::from_ffi(value),
+                    );
+                }
+
+                // Swift iterates over a snapshot of the keys, so the map can be read while
+                // iterating.
+                #[export_name = concat!("__swift_bridge__$HashMap_", stringify!($key), "_", stringify!($value), "$keys")]
+                #[doc(hidden)]
+                pub extern "C" fn _keys(map: *mut Map) -> *mut Vec<<$key as MapKey>::VecElem> {
+                    let map = unsafe { &*map };
+                    let keys = map.keys().map(|key| key.to_vec_elem()).collect();
+                    Box::into_raw(Box::new(keys))
+                }
+            };
+        };
+    }
+
+    pub(super) use map_externs;
+    pub(super) use map_externs_for_key;
+}
@@ -0,0 +1,143 @@
+use crate::string::{RustStr, RustString};
+use macro_::set_externs;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+set_externs!(String);
+set_externs!(u8);
+set_externs!(u16);
+set_externs!(u32);
+set_externs!(u64);
+set_externs!(usize);
+set_externs!(i8);
+set_externs!(i16);
+set_externs!(i32);
+set_externs!(i64);
+set_externs!(isize);
+
+/// A `HashSet` element type that Swift can pass over FFI.
+trait SetElement: Eq + Hash + Sized {
+    /// How Swift passes the element to Rust.
+    type Ffi;
+    /// How Rust passes the element to Swift when listing the set's elements.
+    type VecElem;
+
+    fn contains(set: &HashSet<Self>, elem: Self::Ffi) -> bool;
+    fn remove(set: &mut HashSet<Self>, elem: Self::Ffi) -> bool;
+    fn from_ffi(elem: Self::Ffi) -> Self;
+    fn to_vec_elem(&self) -> Self::VecElem;
+}
+
+impl SetElement for String {
+    type Ffi = RustStr;
+    type VecElem = RustString;
+
+    fn contains(set: &HashSet<Self>, elem: RustStr) -> bool {
+        set.contains(elem.to_str())
+    }
+
+    fn remove(set: &mut HashSet<Self>, elem: RustStr) -> bool {
+        set.remove(elem.to_str())
+    }
+
+    fn from_ffi(elem: RustStr) -> Self {
+        elem.to_string()
+    }
+
+    fn to_vec_elem(&self) -> RustString {
+        RustString(self.clone())
+    }
+}
+
+macro_rules! primitive_set_element {
+    ($($ty:ty),*) => {
+        $(
+            impl SetElement for $ty {
+                type Ffi = $ty;
+                type VecElem = $ty;
+
+                fn contains(set: &HashSet<Self>, elem: $ty) -> bool {
+                    set.contains(&elem)
+                }
+
+                fn remove(set: &mut HashSet<Self>, elem: $ty) -> bool {
+                    set.remove(&elem)
+                }
+
+                fn from_ffi(elem: $ty) -> Self {
+                    elem
+                }
+
+                fn to_vec_elem(&self) -> $ty {
+                    *self
+                }
+            }
+        )*
+    };
+}
+primitive_set_element!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+mod macro_ {
+    macro_rules! set_externs {
+        ($ty:ty) => {
+            const _: () = {
+                type Set = HashSet<$ty>;
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Set {
+                    Box::into_raw(Box::new(HashSet::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(set: *mut Set) {
+                    let set = unsafe { Box::from_raw(set) };
+                    drop(set)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *mut Set) -> usize {
+                    let set = unsafe { &*set };
+                    set.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *mut Set, elem: <$ty as SetElement>::Ffi) -> bool {
+                    let set = unsafe { &*set };
+                    <$ty as SetElement>::contains(set, elem)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut Set, elem: <$ty as SetElement>::Ffi) -> bool {
+                    let set = unsafe { &mut *set };
+                    set.insert(<$ty as SetElement>::from_ffi(elem))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$remove")]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(set: *mut Set, elem: <$ty as SetElement>::Ffi) -> bool {
+                    let set = unsafe { &mut *set };
+                    <$ty as SetElement>::remove(set, elem)
+                }
+
+                // Swift iterates over a snapshot of the elements, so the set can be modified
+                // while iterating.
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$elements")]
+                #[doc(hidden)]
+                pub extern "C" fn _elements(
+                    set: *mut Set,
+                ) -> *mut Vec<<$ty as SetElement>::VecElem> {
+                    let set = unsafe { &*set };
+                    let elements = set.iter().map(|elem| elem.to_vec_elem()).collect();
+                    Box::into_raw(Box::new(elements))
+                }
+            };
+        };
+    }
+
+    pub(super) use set_externs;
+}
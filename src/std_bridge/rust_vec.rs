@@ -17,6 +17,13 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+/// Lets Swift copy a `Data` into a new `Vec<u8>` with a single call instead of pushing each byte.
+#[export_name = "__swift_bridge__$Vec_u8$from_slice"]
+#[doc(hidden)]
+pub extern "C" fn vec_u8_from_slice(slice: crate::FfiSlice<u8>) -> *mut Vec<u8> {
+    Box::into_raw(Box::new(slice.as_slice().to_vec()))
+}
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
@@ -31,7 +38,7 @@ mod macro_ {
                 #[doc(hidden)]
                 pub extern "C" fn _drop(vec: *mut Vec<$ty>) {
                     let vec = unsafe { Box::from_raw(vec) };
-                    drop(vec)
+                    crate::scope::drop_or_defer(vec)
                 }
 
                 #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$len")]
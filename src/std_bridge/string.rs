@@ -71,6 +71,18 @@ impl RustStr {
         std::str::from_utf8(bytes).expect("Failed to convert RustStr to &str")
     }
 
+    /// Used by the generated code for `&str` arguments, which keeps passing a Swift String to
+    /// Rust zero-copy and O(1).
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, such as the bytes of a Swift `String`.
+    #[doc(hidden)]
+    pub unsafe fn to_str_unchecked<'a>(self) -> &'a str {
+        let bytes = std::slice::from_raw_parts(self.start, self.len);
+        std::str::from_utf8_unchecked(bytes)
+    }
+
     pub fn to_string(self) -> String {
         self.to_str().to_string()
     }
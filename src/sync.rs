@@ -0,0 +1,200 @@
+//! The lock that swift-bridge's runtime uses for its own shared state, such as the tables of
+//! `#[swift_bridge(hardened)]` types and the registered lifecycle handlers.
+//!
+//! Audio render callbacks and UI code run on high priority threads, and calls through the bridge
+//! from those threads can end up waiting for a lock that a low priority thread holds. A
+//! `std::sync::Mutex` does not tell the system who it is waiting for, so a medium priority thread
+//! can keep the low priority owner from running and the high priority thread waits far longer than
+//! expected.
+//!
+//! On Apple platforms [`Lock`] is an `os_unfair_lock`, which donates the priority of the waiting
+//! thread to the thread that owns the lock. On other platforms it is a `std::sync::Mutex`.
+//!
+//! Enable the `std_locks` feature to use a `std::sync::Mutex` on Apple platforms as well.
+//!
+//! ```
+//! use swift_bridge::sync::Lock;
+//!
+//! static SAMPLES: Lock<Vec<f32>> = Lock::new(Vec::new());
+//!
+//! SAMPLES.lock().push(0.5);
+//! assert_eq!(SAMPLES.lock().len(), 1);
+//! ```
+
+use std::ops::{Deref, DerefMut};
+
+pub use self::imp::LockGuard;
+
+/// A mutual exclusion lock that avoids priority inversions on Apple platforms.
+///
+/// Unlike `std::sync::Mutex`, a `Lock` is not poisoned when a thread panics while holding it.
+///
+/// Locking a `Lock` again on the thread that already holds it is a bug that crashes on Apple
+/// platforms and deadlocks elsewhere.
+pub struct Lock<T> {
+    inner: imp::RawLock<T>,
+}
+
+impl<T> Lock<T> {
+    /// Create an unlocked `Lock` that holds the given value.
+    pub const fn new(value: T) -> Self {
+        Lock {
+            inner: imp::RawLock::new(value),
+        }
+    }
+
+    /// Block until the lock is available, then give exclusive access to the value until the guard
+    /// is dropped.
+    pub fn lock(&self) -> LockGuard<'_, T> {
+        self.inner.lock()
+    }
+
+    /// Access the value without locking, since the borrow checker already guarantees that
+    /// nothing else can.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+}
+
+impl<T: Default> Default for Lock<T> {
+    fn default() -> Self {
+        Lock::new(T::default())
+    }
+}
+
+impl<T> std::fmt::Debug for Lock<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lock").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(target_vendor = "apple", not(feature = "std_locks")))]
+mod imp {
+    use super::*;
+    use std::cell::UnsafeCell;
+    use std::marker::PhantomData;
+
+    #[allow(non_camel_case_types)]
+    #[repr(C)]
+    struct os_unfair_lock {
+        _opaque: u32,
+    }
+
+    extern "C" {
+        fn os_unfair_lock_lock(lock: *mut os_unfair_lock);
+        fn os_unfair_lock_unlock(lock: *mut os_unfair_lock);
+    }
+
+    pub struct RawLock<T> {
+        lock: UnsafeCell<os_unfair_lock>,
+        value: UnsafeCell<T>,
+    }
+
+    // The value is only ever accessed while the lock is held.
+    unsafe impl<T: Send> Send for RawLock<T> {}
+    unsafe impl<T: Send> Sync for RawLock<T> {}
+
+    impl<T> RawLock<T> {
+        pub const fn new(value: T) -> Self {
+            RawLock {
+                // OS_UNFAIR_LOCK_INIT
+                lock: UnsafeCell::new(os_unfair_lock { _opaque: 0 }),
+                value: UnsafeCell::new(value),
+            }
+        }
+
+        pub fn lock(&self) -> LockGuard<'_, T> {
+            unsafe { os_unfair_lock_lock(self.lock.get()) };
+
+            LockGuard {
+                lock: self,
+                _not_send: PhantomData,
+            }
+        }
+
+        pub fn get_mut(&mut self) -> &mut T {
+            self.value.get_mut()
+        }
+    }
+
+    /// Gives exclusive access to the value of a [`Lock`](super::Lock) until it is dropped.
+    pub struct LockGuard<'a, T> {
+        lock: &'a RawLock<T>,
+        // An os_unfair_lock must be unlocked by the thread that locked it.
+        _not_send: PhantomData<*const ()>,
+    }
+
+    unsafe impl<T: Sync> Sync for LockGuard<'_, T> {}
+
+    impl<T> Deref for LockGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { &*self.lock.value.get() }
+        }
+    }
+
+    impl<T> DerefMut for LockGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { &mut *self.lock.value.get() }
+        }
+    }
+
+    impl<T> Drop for LockGuard<'_, T> {
+        fn drop(&mut self) {
+            unsafe { os_unfair_lock_unlock(self.lock.lock.get()) };
+        }
+    }
+}
+
+#[cfg(not(all(target_vendor = "apple", not(feature = "std_locks"))))]
+mod imp {
+    use super::*;
+    use std::sync::{Mutex, MutexGuard};
+
+    pub struct RawLock<T> {
+        mutex: Mutex<T>,
+    }
+
+    impl<T> RawLock<T> {
+        pub const fn new(value: T) -> Self {
+            RawLock {
+                mutex: Mutex::new(value),
+            }
+        }
+
+        pub fn lock(&self) -> LockGuard<'_, T> {
+            LockGuard {
+                guard: self
+                    .mutex
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            }
+        }
+
+        pub fn get_mut(&mut self) -> &mut T {
+            self.mutex
+                .get_mut()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+    }
+
+    /// Gives exclusive access to the value of a [`Lock`](super::Lock) until it is dropped.
+    pub struct LockGuard<'a, T> {
+        guard: MutexGuard<'a, T>,
+    }
+
+    impl<T> Deref for LockGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.guard
+        }
+    }
+
+    impl<T> DerefMut for LockGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.guard
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! The matches of a text search, such as the matches of a regex, that Swift can turn into
+//! `Range<String.Index>`s.
+//!
+//! Rust string APIs work with byte offsets, while Swift works with `String.Index`. A
+//! `TextMatches` holds the byte range of every match along with the byte ranges of its named
+//! capture groups, and Swift converts them into ranges of the string that was searched.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! use swift_bridge::text::TextMatches;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         #[swift_bridge(already_declared)]
+//!         type TextMatches;
+//!
+//!         fn find_dates(text: &str) -> TextMatches;
+//!     }
+//! }
+//! # );
+//! use swift_bridge::text::{TextMatch, TextMatches};
+//!
+//! fn find_dates(text: &str) -> TextMatches {
+//!     // With the `regex` crate this would be
+//!     // `regex.captures_iter(text).map(|caps| ...).collect()`.
+//!     text.match_indices("2024")
+//!         .map(|(start, year)| {
+//!             TextMatch::new(start..start + year.len())
+//!                 .with_capture("year", Some(start..start + year.len()))
+//!         })
+//!         .collect()
+//! }
+//! ```
+//!
+//! ```swift
+//! let text = "Released in 2024"
+//! for date in find_dates(text).matches {
+//!     let year = text[date.range(of: "year", in: text)!]
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::string::RustString;
+use std::ops::Range;
+
+/// The matches of a search in a string, in the order that they were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextMatches {
+    matches: Vec<TextMatch>,
+}
+
+/// One match of a search in a string.
+///
+/// Holds the byte range of the whole match and the byte ranges of its named capture groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    range: Range<usize>,
+    captures: Vec<(String, Option<Range<usize>>)>,
+}
+
+impl TextMatches {
+    /// An empty list of matches.
+    pub fn new() -> Self {
+        TextMatches::default()
+    }
+
+    /// Add a match to the end of the list.
+    pub fn push(&mut self, text_match: TextMatch) {
+        self.matches.push(text_match);
+    }
+
+    /// The number of matches.
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Whether or not there are no matches.
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Iterate over the matches.
+    pub fn iter(&self) -> std::slice::Iter<'_, TextMatch> {
+        self.matches.iter()
+    }
+}
+
+impl FromIterator<TextMatch> for TextMatches {
+    fn from_iter<T: IntoIterator<Item = TextMatch>>(iter: T) -> Self {
+        TextMatches {
+            matches: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromIterator<Range<usize>> for TextMatches {
+    fn from_iter<T: IntoIterator<Item = Range<usize>>>(iter: T) -> Self {
+        iter.into_iter().map(TextMatch::new).collect()
+    }
+}
+
+impl TextMatch {
+    /// A match that covers the given byte range of the searched string.
+    pub fn new(range: Range<usize>) -> Self {
+        TextMatch {
+            range,
+            captures: vec![],
+        }
+    }
+
+    /// Add a named capture group.
+    ///
+    /// The range is `None` if the group did not take part in the match, such as an optional
+    /// group.
+    ///
+    /// ```
+    /// use swift_bridge::text::TextMatch;
+    ///
+    /// let text_match = TextMatch::new(0..10)
+    ///     .with_capture("year", Some(0..4))
+    ///     .with_capture("suffix", None);
+    ///
+    /// assert_eq!(text_match.capture("year"), Some(0..4));
+    /// assert_eq!(text_match.capture("suffix"), None);
+    /// ```
+    pub fn with_capture(mut self, name: impl Into<String>, range: Option<Range<usize>>) -> Self {
+        self.captures.push((name.into(), range));
+        self
+    }
+
+    /// The byte range of the whole match.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The byte range of a named capture group, or `None` if there is no such group or it did not
+    /// take part in the match.
+    pub fn capture(&self, name: &str) -> Option<Range<usize>> {
+        self.captures
+            .iter()
+            .find(|(capture_name, _)| capture_name == name)
+            .and_then(|(_, range)| range.clone())
+    }
+
+    /// The names of the capture groups, in the order that they were added.
+    pub fn capture_names(&self) -> impl Iterator<Item = &str> {
+        self.captures.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+impl From<Range<usize>> for TextMatch {
+    fn from(range: Range<usize>) -> Self {
+        TextMatch::new(range)
+    }
+}
+
+fn text_match<'a>(matches: *const TextMatches, idx: usize) -> &'a TextMatch {
+    &unsafe { &*matches }.matches[idx]
+}
+
+fn capture<'a>(
+    matches: *const TextMatches,
+    idx: usize,
+    capture_idx: usize,
+) -> &'a (String, Option<Range<usize>>) {
+    &text_match(matches, idx).captures[capture_idx]
+}
+
+#[export_name = "__swift_bridge__$TextMatches$len"]
+extern "C" fn __swift_bridge__TextMatches_len(matches: *const TextMatches) -> usize {
+    unsafe { &*matches }.len()
+}
+
+#[export_name = "__swift_bridge__$TextMatches$start"]
+extern "C" fn __swift_bridge__TextMatches_start(matches: *const TextMatches, idx: usize) -> usize {
+    text_match(matches, idx).range.start
+}
+
+#[export_name = "__swift_bridge__$TextMatches$end"]
+extern "C" fn __swift_bridge__TextMatches_end(matches: *const TextMatches, idx: usize) -> usize {
+    text_match(matches, idx).range.end
+}
+
+#[export_name = "__swift_bridge__$TextMatches$capture_count"]
+extern "C" fn __swift_bridge__TextMatches_capture_count(
+    matches: *const TextMatches,
+    idx: usize,
+) -> usize {
+    text_match(matches, idx).captures.len()
+}
+
+#[export_name = "__swift_bridge__$TextMatches$capture_name"]
+extern "C" fn __swift_bridge__TextMatches_capture_name(
+    matches: *const TextMatches,
+    idx: usize,
+    capture_idx: usize,
+) -> *mut RustString {
+    RustString(capture(matches, idx, capture_idx).0.clone()).box_into_raw()
+}
+
+#[export_name = "__swift_bridge__$TextMatches$capture_is_some"]
+extern "C" fn __swift_bridge__TextMatches_capture_is_some(
+    matches: *const TextMatches,
+    idx: usize,
+    capture_idx: usize,
+) -> bool {
+    capture(matches, idx, capture_idx).1.is_some()
+}
+
+// Only called when `capture_is_some` returned true.
+#[export_name = "__swift_bridge__$TextMatches$capture_start"]
+extern "C" fn __swift_bridge__TextMatches_capture_start(
+    matches: *const TextMatches,
+    idx: usize,
+    capture_idx: usize,
+) -> usize {
+    capture(matches, idx, capture_idx).1.as_ref().unwrap().start
+}
+
+// Only called when `capture_is_some` returned true.
+#[export_name = "__swift_bridge__$TextMatches$capture_end"]
+extern "C" fn __swift_bridge__TextMatches_capture_end(
+    matches: *const TextMatches,
+    idx: usize,
+    capture_idx: usize,
+) -> usize {
+    capture(matches, idx, capture_idx).1.as_ref().unwrap().end
+}
+
+#[export_name = "__swift_bridge__$TextMatches$_free"]
+extern "C" fn __swift_bridge__TextMatches__free(matches: *mut TextMatches) {
+    unsafe {
+        let _ = Box::from_raw(matches);
+    }
+}
@@ -0,0 +1,123 @@
+//! Rate limiting for Rust code that calls a Swift function at a high frequency.
+//!
+//! Progress updates and sensor readings can easily be produced thousands of times per second,
+//! which floods the main thread when every one of them updates the UI. An `extern "Swift"`
+//! function with `#[swift_bridge(throttle_ms = ...)]` is called at most once per interval, with the
+//! arguments of the latest call, on the main queue.
+//!
+//! ```
+//! # const  _: &str = stringify!(
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Swift" {
+//!         #[swift_bridge(throttle_ms = 100)]
+//!         fn show_download_progress(fraction_completed: f64);
+//!     }
+//! }
+//! # );
+//! ```
+//!
+//! The first call goes through right away. Calls that happen before the interval has passed
+//! replace each other, and the latest one goes through once the interval has passed, so the final
+//! value is never lost.
+
+use crate::sync::{Lock, LockGuard};
+use std::time::{Duration, Instant};
+
+type Call = Box<dyn FnOnce() + Send>;
+
+/// Coalesces calls so that at most one of them runs per interval.
+///
+/// Created by the code that `#[swift_bridge::bridge]` generates for `throttle_ms` functions, but
+/// also usable on its own.
+///
+/// ```
+/// use swift_bridge::throttle::Throttle;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// static THROTTLE: Throttle = Throttle::new(50);
+/// static LATEST: AtomicU32 = AtomicU32::new(0);
+///
+/// for value in 1..=100 {
+///     THROTTLE.call(move || LATEST.store(value, Ordering::SeqCst));
+/// }
+///
+/// // The first call ran right away and the last one runs once the interval has passed.
+/// assert_eq!(LATEST.load(Ordering::SeqCst), 1);
+/// std::thread::sleep(std::time::Duration::from_millis(200));
+/// assert_eq!(LATEST.load(Ordering::SeqCst), 100);
+/// ```
+pub struct Throttle {
+    interval: Duration,
+    state: Lock<State>,
+}
+
+struct State {
+    // The latest call that is waiting for the interval to pass.
+    pending: Option<Call>,
+    last_run: Option<Instant>,
+    timer_is_running: bool,
+}
+
+impl Throttle {
+    /// Run at most one call every `interval_ms` milliseconds.
+    pub const fn new(interval_ms: u64) -> Self {
+        Throttle {
+            interval: Duration::from_millis(interval_ms),
+            state: Lock::new(State {
+                pending: None,
+                last_run: None,
+                timer_is_running: false,
+            }),
+        }
+    }
+
+    /// Run the call now if the interval has passed since the last call ran, otherwise run it once
+    /// the interval has passed unless another call replaces it first.
+    ///
+    /// Delayed calls run on a background thread.
+    pub fn call(&'static self, call: impl FnOnce() + Send + 'static) {
+        let mut state = self.lock();
+
+        if state.timer_is_running {
+            state.pending = Some(Box::new(call));
+            return;
+        }
+
+        let now = Instant::now();
+        let wait = match state.last_run {
+            Some(last_run) => self.interval.saturating_sub(now - last_run),
+            None => Duration::ZERO,
+        };
+
+        if wait.is_zero() {
+            state.last_run = Some(now);
+            drop(state);
+
+            call();
+            return;
+        }
+
+        state.pending = Some(Box::new(call));
+        state.timer_is_running = true;
+        drop(state);
+
+        std::thread::spawn(move || {
+            std::thread::sleep(wait);
+
+            let mut state = self.lock();
+            let pending = state.pending.take();
+            state.last_run = Some(Instant::now());
+            state.timer_is_running = false;
+            drop(state);
+
+            if let Some(call) = pending {
+                call();
+            }
+        });
+    }
+
+    fn lock(&self) -> LockGuard<'_, State> {
+        self.state.lock()
+    }
+}